@@ -0,0 +1,52 @@
+use std::hint::black_box;
+
+use chrono::NaiveDate;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use nom_date_parsers::{
+    i18n::en::{dd_month_y4, month_dd_y4},
+    numeric::{dd_mm_y4, mm_dd_y4, y4_mm_dd},
+    types::IResult,
+};
+
+type Parser = fn(&str) -> IResult<&str, NaiveDate>;
+
+/// The parsers of the crate with the inputs and the equivalent
+/// `chrono::NaiveDate::parse_from_str` formats.
+///
+/// The `dateparser` crate isn't compared yet: it can't be fetched from the
+/// registry in the build environment, and an optional dependency which can't
+/// be resolved breaks the lockfile for every build, not only for the
+/// `bench-baselines` feature. It is to be added behind that feature once the
+/// dependency is available.
+const CASES: [(&str, Parser, &str, &str); 5] = [
+    ("dd_mm_y4", dd_mm_y4, "13/07/2024", "%d/%m/%Y"),
+    ("mm_dd_y4", mm_dd_y4, "07/13/2024", "%m/%d/%Y"),
+    ("y4_mm_dd", y4_mm_dd, "2024-07-13", "%Y-%m-%d"),
+    ("dd_month_y4", dd_month_y4, "13 July 2024", "%d %B %Y"),
+    ("month_dd_y4", month_dd_y4, "Jul 13, 2024", "%b %d, %Y"),
+];
+
+fn chrono_baseline_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chrono baseline");
+
+    for (name, parser, input, format) in CASES {
+        assert_eq!(
+            parser(input).ok().map(|(_, date)| date),
+            NaiveDate::parse_from_str(input, format).ok()
+        );
+
+        group.bench_with_input(BenchmarkId::new(name, input), input, |b, input| {
+            b.iter(|| parser(black_box(input)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new(format!("{name} parse_from_str"), input),
+            input,
+            |b, input| b.iter(|| NaiveDate::parse_from_str(black_box(input), format)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, chrono_baseline_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,171 @@
+#[allow(dead_code)]
+mod corpus;
+
+use std::hint::black_box;
+
+use chrono::Month;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use nom::{branch::alt, combinator::value};
+
+use nom_date_parsers::{
+    combinators::tag_no_case,
+    i18n::en::{bundle_dmy_with, bundle_mdy_with, named_month},
+    numeric::{self, dd_mm_only_with, dd_mm_y4, dd_only_with, dmy_with, DateOrder},
+    quick,
+    types::IResult,
+};
+
+use self::corpus::{ctx, EN, NUMERIC_DMY, NUMERIC_MDY, NUMERIC_YMD, QUICK};
+
+const MONTHS: [&str; 4] = ["January", "dec", "Sept.", "lorem"];
+
+fn numeric_dmy_benchmark(c: &mut Criterion) {
+    let ctx = ctx();
+    let mut group = c.benchmark_group("numeric dmy");
+
+    for input in ["13/07/2024", "13/07", "13"] {
+        group.bench_with_input(BenchmarkId::new("alt chain", input), input, |b, input| {
+            b.iter(|| alt((dd_mm_y4, dd_mm_only_with(&ctx), dd_only_with(&ctx)))(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("dmy", input), input, |b, input| {
+            b.iter(|| dmy_with(&ctx)(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+/// The `alt` chain of the [`tag_no_case`] parsers recognizing the same words
+/// as the [`named_month`] parser.
+fn named_month_alt_chain(input: &str) -> IResult<&str, Month> {
+    alt((
+        alt((
+            value(Month::January, tag_no_case("january")),
+            value(Month::February, tag_no_case("february")),
+            value(Month::March, tag_no_case("march")),
+            value(Month::April, tag_no_case("april")),
+            value(Month::May, tag_no_case("may")),
+            value(Month::June, tag_no_case("june")),
+            value(Month::July, tag_no_case("july")),
+            value(Month::August, tag_no_case("august")),
+            value(Month::September, tag_no_case("september")),
+            value(Month::October, tag_no_case("october")),
+            value(Month::November, tag_no_case("november")),
+            value(Month::December, tag_no_case("december")),
+        )),
+        alt((
+            value(Month::January, tag_no_case("jan")),
+            value(Month::February, tag_no_case("feb")),
+            value(Month::March, tag_no_case("mar")),
+            value(Month::April, tag_no_case("apr")),
+            value(Month::June, tag_no_case("jun")),
+            value(Month::July, tag_no_case("jul")),
+            value(Month::August, tag_no_case("aug")),
+            value(Month::September, tag_no_case("sept")),
+            value(Month::September, tag_no_case("sep")),
+            value(Month::October, tag_no_case("oct")),
+            value(Month::November, tag_no_case("nov")),
+            value(Month::December, tag_no_case("dec")),
+        )),
+    ))(input)
+}
+
+fn en_named_month_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("en named month");
+
+    for input in MONTHS {
+        group.bench_with_input(BenchmarkId::new("alt chain", input), input, |b, input| {
+            b.iter(|| named_month_alt_chain(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("lookup", input), input, |b, input| {
+            b.iter(|| named_month(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+fn numeric_bundle_benchmark(c: &mut Criterion) {
+    let ctx = ctx();
+    let mut group = c.benchmark_group("numeric bundle");
+
+    for (order, inputs) in [
+        (DateOrder::Dmy, &NUMERIC_DMY[..]),
+        (DateOrder::Mdy, &NUMERIC_MDY[..]),
+        (DateOrder::Ymd, &NUMERIC_YMD[..]),
+    ] {
+        for input in inputs {
+            group.bench_with_input(
+                BenchmarkId::new(format!("{order:?}"), input),
+                input,
+                |b, input| b.iter(|| numeric::bundle_with(&ctx, order)(black_box(input))),
+            );
+        }
+    }
+    group.finish();
+}
+
+fn quick_bundle_benchmark(c: &mut Criterion) {
+    let ctx = ctx();
+    let mut group = c.benchmark_group("quick bundle");
+
+    for input in QUICK {
+        group.bench_with_input(BenchmarkId::from_parameter(input), input, |b, input| {
+            b.iter(|| quick::bundle_with(&ctx)(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+fn en_bundle_benchmark(c: &mut Criterion) {
+    let ctx = ctx();
+    let mut group = c.benchmark_group("en bundle");
+
+    for input in EN {
+        group.bench_with_input(BenchmarkId::new("dmy", input), input, |b, input| {
+            b.iter(|| bundle_dmy_with(&ctx)(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("mdy", input), input, |b, input| {
+            b.iter(|| bundle_mdy_with(&ctx)(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+/// Parses the whole corpus with every bundle, so the regressions in the order
+/// of the alternatives are visible as the change of the throughput.
+fn corpus_benchmark(c: &mut Criterion) {
+    let ctx = ctx();
+    let mut group = c.benchmark_group("corpus");
+
+    group.throughput(Throughput::Elements(EN.len() as u64));
+    group.bench_function("en dmy", |b| {
+        b.iter(|| EN.map(|input| bundle_dmy_with(&ctx)(black_box(input)).is_ok()))
+    });
+    group.bench_function("en mdy", |b| {
+        b.iter(|| EN.map(|input| bundle_mdy_with(&ctx)(black_box(input)).is_ok()))
+    });
+
+    group.throughput(Throughput::Elements(NUMERIC_DMY.len() as u64));
+    group.bench_function("numeric dmy", |b| {
+        b.iter(|| {
+            NUMERIC_DMY
+                .map(|input| numeric::bundle_with(&ctx, DateOrder::Dmy)(black_box(input)).is_ok())
+        })
+    });
+
+    group.throughput(Throughput::Elements(QUICK.len() as u64));
+    group.bench_function("quick", |b| {
+        b.iter(|| QUICK.map(|input| quick::bundle_with(&ctx)(black_box(input)).is_ok()))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    numeric_dmy_benchmark,
+    numeric_bundle_benchmark,
+    quick_bundle_benchmark,
+    en_named_month_benchmark,
+    en_bundle_benchmark,
+    corpus_benchmark
+);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+use chrono::NaiveDate;
+use nom_date_parsers::context::ParserContext;
+
+/// The numeric dates in the `day-month-year` order with the various separators
+/// and the omitted parts.
+pub const NUMERIC_DMY: [&str; 8] = [
+    "13/07/2024",
+    "13.07.2024",
+    "13-07-24",
+    "1/7/2024",
+    "13/07",
+    "13",
+    "31/02/2024",
+    "lorem",
+];
+
+/// The numeric dates in the `month-day-year` order.
+pub const NUMERIC_MDY: [&str; 6] = [
+    "07/13/2024",
+    "07-13-24",
+    "7/1/2024",
+    "07/13",
+    "13",
+    "02/31/2024",
+];
+
+/// The `ISO 8601` calendar dates.
+pub const NUMERIC_YMD: [&str; 3] = ["2024-07-13", "2024/07/13", "24-07-13"];
+
+/// The offsets recognized by the `quick` bundle.
+pub const QUICK: [&str; 6] = ["+1", "- 42", "+2w", "-3m", "+1y", "lorem"];
+
+/// The dates in `English` as they appear in the messages and logs, from the
+/// first alternatives of the bundles to the last ones.
+pub const EN: [&str; 16] = [
+    "2 weeks ago",
+    "week 28",
+    "end of next month",
+    "next year",
+    "first monday of september",
+    "13th next month",
+    "the 13th of July",
+    "13 July 2024",
+    "Fri, 13 Sep 2024",
+    "Jul 13, 2024",
+    "13/07/2024",
+    "tomorrow",
+    "in 3 days",
+    "next Saturday",
+    "friday",
+    "lorem ipsum",
+];
+
+/// The reference date of the benchmarks.
+pub fn ctx() -> ParserContext {
+    ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+}
@@ -1,12 +1,64 @@
 use std::hint::black_box;
 
-use criterion::{criterion_group, criterion_main, Criterion};
+use chrono::{NaiveDate, Weekday};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use nom::{branch::alt, combinator::value};
 
-use nom_date_parsers::i18n::ru::bundle;
+use nom_date_parsers::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::ru::{bundle, bundle_with, full_named_weekday},
+    types::IResult,
+};
+
+const INPUTS: [&str; 4] = [
+    "Воскресенье",
+    "пт, 13 сентября 2024 г.",
+    "в следующую пятницу",
+    "13.07.2024",
+];
+
+/// The `alt` chain of the [`tag_no_case`] parsers recognizing the same words
+/// as the [`full_named_weekday`] parser.
+fn full_named_weekday_alt_chain(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("понедельник")),
+        value(Weekday::Tue, tag_no_case("вторник")),
+        value(Weekday::Wed, tag_no_case("среда")),
+        value(Weekday::Thu, tag_no_case("четверг")),
+        value(Weekday::Fri, tag_no_case("пятница")),
+        value(Weekday::Sat, tag_no_case("суббота")),
+        value(Weekday::Sun, tag_no_case("воскресенье")),
+    ))(input)
+}
+
+fn ru_named_weekday_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ru named weekday");
+
+    for input in ["Воскресенье", "понедельник", "завтра"] {
+        group.bench_with_input(BenchmarkId::new("alt chain", input), input, |b, input| {
+            b.iter(|| full_named_weekday_alt_chain(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("lookup", input), input, |b, input| {
+            b.iter(|| full_named_weekday(black_box(input)))
+        });
+    }
+    group.finish();
+}
 
 fn ru_bundle_benchmark(c: &mut Criterion) {
     c.bench_function("ru bundle", |b| b.iter(|| bundle(black_box("Воскресенье"))));
+
+    let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+    let mut group = c.benchmark_group("ru bundle_with");
+
+    for input in INPUTS {
+        group.bench_with_input(BenchmarkId::from_parameter(input), input, |b, input| {
+            b.iter(|| bundle_with(&ctx)(black_box(input)))
+        });
+    }
+    group.finish();
 }
 
-criterion_group!(benches, ru_bundle_benchmark);
+criterion_group!(benches, ru_named_weekday_benchmark, ru_bundle_benchmark);
 criterion_main!(benches);
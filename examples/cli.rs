@@ -18,25 +18,28 @@
 
 use std::io;
 
-use chrono::{Local, NaiveDate};
-use nom::branch::alt;
+use chrono::Local;
 
-use nom_date_parsers::{i18n::en, quick, types::IResult};
+use nom_date_parsers::{context::ParserContext, i18n::en, quick};
 
-fn versatile_parser(input: &str) -> IResult<&str, NaiveDate> {
+fn main() -> anyhow::Result<()> {
     // Its essential to provide parsers in the correct order due to the fact that
-    // `+10` pattern can be recognized by the `numeric::dd_only` parser instead of
-    // `quick::forward_from_now`
-    alt((quick::bundle, en::bundle_dmy))(input)
+    // `+10` pattern can be recognized by the `numeric::dd_only` parser (a part of
+    // the `dmy_named_month` component) instead of `quick::forward_from_now`
+    let versatile_parser = en::bundle_dmy_builder(ParserContext::default())
+        .with("forward_from_now", |ctx, input| {
+            quick::forward_from_now_with(ctx)(input)
+        })
+        .with("backward_from_now", |ctx, input| {
+            quick::backward_from_now_with(ctx)(input)
+        })
+        .with_order(["forward_from_now", "backward_from_now"])
+        .build();
     /*
-        Uncomment these lines, comment previous one, run example and try to type `42` as input. You will see smth like:
-        "unable to recognize the input as a date: Parsing Error: DayOutOfRange"
+        Replace the previous line with `.only(["dmy_named_month"]).build();`, run example and try to type `42` as input.
+        You will see smth like: "unable to recognize the input as a date: Parsing Error: DayOutOfRange"
     */
-    // use nom_date_parsers::prelude::dd_only;
-    // dd_only(input)
-}
 
-fn main() -> anyhow::Result<()> {
     println!("Today is: {}", Local::now().date_naive());
 
     for line in io::stdin().lines() {
@@ -52,9 +55,8 @@ fn main() -> anyhow::Result<()> {
                     }
                     Err(err) => {
                         /*
-                           Due to the fact that `bundle` parsers use `alt` combinator all related-errors are
-                           shadowed by the `Parsing Error: Nom("42", Tag)` error. So, if you want to see that `42` input is out of day-part range,
-                           it's impossible with `bundle` parsers, use `dd_only` instead
+                           The date-specific errors aren't shadowed by the generic parsing errors of the other
+                           alternatives of the `bundle` parsers, so the `42` input is reported as `DayOutOfRange`
                         */
                         println!("unable to recognize the input as a date: {err}");
                     }
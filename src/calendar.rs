@@ -0,0 +1,1036 @@
+use alloc::collections::BTreeSet;
+#[cfg(feature = "holidays")]
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::ops::Bound;
+#[cfg(feature = "holidays")]
+use core::ops::RangeBounds;
+
+use chrono::{Datelike, Days, Months, NaiveDate, TimeDelta, Weekday};
+
+#[cfg(feature = "holidays")]
+use crate::holiday::HolidayTable;
+use crate::range::DateRange;
+
+/// The unit of the relative duration, e.g. `days` in the `in 3 days`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    /// Shifts the date by the number of days
+    Day,
+    /// Shifts the date by the number of weeks (seven days)
+    Week,
+    /// Shifts the date by the number of calendar months
+    Month,
+    /// Shifts the date by the number of calendar years (twelve months)
+    Year,
+}
+
+/// Describes what to do when the day doesn't exist in the month obtained by
+/// adding months or years to the date (e.g. `31/01/2024` + 1 month or
+/// `29/02/2024` + 1 year).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MonthOverflow {
+    /// Clamps the day to the last day of the resulting month, e.g.
+    /// `31/01/2024` + 1 month is `29/02/2024` and `29/02/2024` + 1 year is
+    /// `28/02/2025`
+    #[default]
+    Clamp,
+    /// Rolls the extra days over into the next month, e.g. `31/01/2024` + 1
+    /// month is `02/03/2024` and `29/02/2024` + 1 year is `01/03/2025`
+    Roll,
+}
+
+/// Describes how the partial dates without the `year` or the `month` part
+/// (e.g. `05` or `05/01`) are completed using the reference date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResolutionPolicy {
+    /// Takes the missing parts from the reference date, e.g. `05` on
+    /// `20/07/2024` is `05/07/2024`
+    #[default]
+    CurrentPeriod,
+    /// Uses the nearest existing date on or after the reference date, e.g.
+    /// `05` on `20/07/2024` is `05/08/2024` and `05/01` is `05/01/2025`
+    NearestFuture,
+    /// Uses the nearest existing date on or before the reference date, e.g.
+    /// `25` on `20/07/2024` is `25/06/2024` and `25/12` is `25/12/2023`
+    NearestPast,
+}
+
+/// The part of the month referred to by the phrases like `beginning of July`,
+/// `mid-August` or `end of next month`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthPart {
+    /// The first day of the month
+    Beginning,
+    /// The 15th day of the month
+    Middle,
+    /// The last day of the month
+    End,
+}
+
+/// Returns the weekend (Saturday and Sunday) of the week containing the
+/// specified `date` as the [`DateRange`] or [`None`] if it is out of the range
+/// of [`NaiveDate`]. The week starts on Monday, so the weekend of the Sunday
+/// begins the day before.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{calendar::weekend, range::DateRange};
+///
+/// assert_eq!(
+///     weekend(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()),
+///     Some(DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 7, 21).unwrap(),
+///     })
+/// );
+/// ```
+pub fn weekend(date: NaiveDate) -> Option<DateRange> {
+    let end = end_of_period(date, Period::Week)?;
+
+    Some(DateRange {
+        start: end.pred_opt()?,
+        end,
+    })
+}
+
+/// Returns `true` if the specified `date` is Saturday or Sunday.
+pub fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// The maximum number of the business days (about 380 years) accepted by the
+/// [`add_business_days`] function, so the huge untrusted offsets (e.g.
+/// `+99999999bd`) are rejected instead of being computed.
+pub const MAX_BUSINESS_DAYS: u64 = 100_000;
+
+/// Returns the specified `date` shifted by the specified number of business
+/// `days` (backward if negative), the weekends are skipped. Returns [`None`]
+/// if the resulting date is out of the range of [`NaiveDate`] or the number of
+/// days exceeds the [`MAX_BUSINESS_DAYS`].
+///
+/// The `date` itself is never counted, so the next business day after Friday
+/// or Saturday is Monday, and `0` days returns the `date` unchanged. See
+/// [`BusinessCalendar`] to skip the holidays as well.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::calendar::add_business_days;
+///
+/// let friday = NaiveDate::from_ymd_opt(2024, 7, 19).unwrap();
+///
+/// assert_eq!(
+///     add_business_days(friday, 1),
+///     NaiveDate::from_ymd_opt(2024, 7, 22)
+/// );
+/// assert_eq!(
+///     add_business_days(friday, -5),
+///     NaiveDate::from_ymd_opt(2024, 7, 12)
+/// );
+/// ```
+pub fn add_business_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    BusinessCalendar::new().add_business_days(date, days)
+}
+
+/// The calendar of the business days: the days which are neither Saturday nor
+/// Sunday nor one of the holidays of the calendar. The holidays are either the
+/// specific dates or, with the `holidays` feature, the rules of the
+/// [`HolidayTable`] which recur every year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::calendar::BusinessCalendar;
+///
+/// let calendar =
+///     BusinessCalendar::new().with_holiday(NaiveDate::from_ymd_opt(2024, 7, 22).unwrap());
+/// let friday = NaiveDate::from_ymd_opt(2024, 7, 19).unwrap();
+///
+/// assert_eq!(
+///     calendar.add_business_days(friday, 1),
+///     NaiveDate::from_ymd_opt(2024, 7, 23)
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    holidays: BTreeSet<NaiveDate>,
+    #[cfg(feature = "holidays")]
+    holiday_tables: Vec<HolidayTable>,
+}
+
+impl BusinessCalendar {
+    /// Creates the calendar where only the weekends are skipped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the specified `date` to the holidays of the calendar.
+    #[must_use]
+    pub fn with_holiday(mut self, date: NaiveDate) -> Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Adds the specified dates to the holidays of the calendar.
+    #[must_use]
+    pub fn with_holidays(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(dates);
+        self
+    }
+
+    /// Adds the days of the specified [`HolidayTable`] to the holidays of the
+    /// calendar, every day recurs in every year according to its
+    /// [`HolidayRule`](crate::holiday::HolidayRule).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Month, NaiveDate};
+    /// use nom_date_parsers::{calendar::BusinessCalendar, holiday::HolidayTable};
+    ///
+    /// let table = HolidayTable::new().with("Christmas", Month::December, 25);
+    /// let calendar = BusinessCalendar::new().with_holiday_table(&table);
+    ///
+    /// assert!(!calendar.is_business_day(NaiveDate::from_ymd_opt(2025, 12, 25).unwrap()));
+    /// ```
+    #[cfg(feature = "holidays")]
+    #[must_use]
+    pub fn with_holiday_table(mut self, table: &HolidayTable) -> Self {
+        self.holiday_tables.push(table.clone());
+        self
+    }
+
+    /// Returns `true` if the specified `date` is one of the holidays of the
+    /// calendar.
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        #[cfg(feature = "holidays")]
+        if self
+            .holiday_tables
+            .iter()
+            .any(|table| table.is_holiday(date))
+        {
+            return true;
+        }
+        self.holidays.contains(&date)
+    }
+
+    /// Returns `true` if the specified `date` is neither the weekend (see
+    /// [`is_weekend`]) nor the holiday of the calendar.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Returns the specified `date` shifted by the specified number of business
+    /// `days` of the calendar (backward if negative), see
+    /// [`add_business_days`]. Returns [`None`] if the resulting date is out of
+    /// the range of [`NaiveDate`] or the number of days exceeds the
+    /// [`MAX_BUSINESS_DAYS`].
+    ///
+    /// The weekdays are skipped by whole weeks, then the days are added once
+    /// more for the holidays which fall on the skipped weekdays, the days of
+    /// the [`HolidayTable`]s are computed once for every year.
+    pub fn add_business_days(&self, date: NaiveDate, days: i64) -> Option<NaiveDate> {
+        if days.unsigned_abs() > MAX_BUSINESS_DAYS {
+            return None;
+        }
+        #[cfg(feature = "holidays")]
+        let mut table_holidays: BTreeMap<i32, Vec<NaiveDate>> = BTreeMap::new();
+        let (mut date, mut days) = (date, days);
+
+        while days != 0 {
+            let shifted = add_weekdays(date, days)?;
+            let skipped = if days > 0 {
+                (Bound::Excluded(date), Bound::Included(shifted))
+            } else {
+                (Bound::Included(shifted), Bound::Excluded(date))
+            };
+            #[allow(unused_mut)]
+            let mut holidays: BTreeSet<NaiveDate> = self.holidays.range(skipped).copied().collect();
+            #[cfg(feature = "holidays")]
+            for year in date.min(shifted).year()..=date.max(shifted).year() {
+                let dates = table_holidays.entry(year).or_insert_with(|| {
+                    self.holiday_tables
+                        .iter()
+                        .flat_map(|table| table.dates_in(year))
+                        .collect()
+                });
+                holidays.extend(dates.iter().filter(|date| skipped.contains(*date)));
+            }
+
+            let skipped_holidays =
+                holidays.iter().filter(|date| !is_weekend(**date)).count() as i64;
+            date = shifted;
+            days = skipped_holidays * days.signum();
+        }
+        Some(date)
+    }
+}
+
+/// Returns the specified `date` shifted by the specified non-zero number of
+/// weekdays (backward if negative) skipping the whole weeks at once: any seven
+/// consecutive days contain five weekdays.
+fn add_weekdays(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    let weeks = (days.abs() - 1) / 5 * days.signum();
+    let mut date = date.checked_add_signed(TimeDelta::try_weeks(weeks)?)?;
+    let mut days = days - weeks * 5;
+
+    while days != 0 {
+        date = if days > 0 {
+            date.succ_opt()?
+        } else {
+            date.pred_opt()?
+        };
+        if !is_weekend(date) {
+            days -= days.signum();
+        }
+    }
+    Some(date)
+}
+
+/// The season of the year, see [`season_range`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+/// The hemisphere which determines the months of the [`Season`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    /// The winter lasts from December to February, the summer lasts from June
+    /// to August
+    #[default]
+    Northern,
+    /// The summer lasts from December to February, the winter lasts from June
+    /// to August
+    Southern,
+}
+
+/// Returns the meteorological [`Season`] which starts in the specified `year`
+/// in the specified [`Hemisphere`] as the [`DateRange`] of the three whole
+/// months, e.g. the northern winter `2024` lasts from `01/12/2024` to
+/// `28/02/2025`. Returns [`None`] if it is out of the range of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     calendar::{season_range, Hemisphere, Season},
+///     range::DateRange,
+/// };
+///
+/// assert_eq!(
+///     season_range(Season::Winter, 2024, Hemisphere::Northern),
+///     Some(DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 12, 1).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+///     })
+/// );
+/// assert_eq!(
+///     season_range(Season::Winter, 2024, Hemisphere::Southern),
+///     Some(DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 8, 31).unwrap(),
+///     })
+/// );
+/// ```
+pub fn season_range(season: Season, year: i32, hemisphere: Hemisphere) -> Option<DateRange> {
+    let month = match (season, hemisphere) {
+        (Season::Spring, Hemisphere::Northern) | (Season::Autumn, Hemisphere::Southern) => 3,
+        (Season::Summer, Hemisphere::Northern) | (Season::Winter, Hemisphere::Southern) => 6,
+        (Season::Autumn, Hemisphere::Northern) | (Season::Spring, Hemisphere::Southern) => 9,
+        (Season::Winter, Hemisphere::Northern) | (Season::Summer, Hemisphere::Southern) => 12,
+    };
+    let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+
+    Some(DateRange {
+        start,
+        end: end_of_period(add_months(start, 2, MonthOverflow::Clamp)?, Period::Month)?,
+    })
+}
+
+/// Returns the specified `date` shifted by the specified number of `months`
+/// (backward if negative), the missing days are handled according to the
+/// specified [`MonthOverflow`] policy. Returns [`None`] if the resulting date
+/// is out of the range of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::calendar::{add_months, MonthOverflow};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+///
+/// assert_eq!(
+///     add_months(date, 1, MonthOverflow::Clamp),
+///     NaiveDate::from_ymd_opt(2024, 2, 29)
+/// );
+/// assert_eq!(
+///     add_months(date, 1, MonthOverflow::Roll),
+///     NaiveDate::from_ymd_opt(2024, 3, 2)
+/// );
+/// ```
+pub fn add_months(date: NaiveDate, months: i64, overflow: MonthOverflow) -> Option<NaiveDate> {
+    let shift = |date: NaiveDate| {
+        let abs = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+        if months < 0 {
+            date.checked_sub_months(abs)
+        } else {
+            date.checked_add_months(abs)
+        }
+    };
+
+    match overflow {
+        MonthOverflow::Clamp => shift(date),
+        MonthOverflow::Roll => {
+            shift(date.with_day(1)?)?.checked_add_days(Days::new(u64::from(date.day0())))
+        }
+    }
+}
+
+/// Returns the specified `date` shifted by the specified `amount` of periods
+/// (backward if negative) or [`None`] if the resulting date is out of the
+/// range of [`NaiveDate`].
+///
+/// The months and years are added using the [`add_months`] function with the
+/// specified [`MonthOverflow`] policy.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::calendar::{shift, MonthOverflow, Period};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+///
+/// assert_eq!(
+///     shift(date, 1, Period::Year, MonthOverflow::Clamp),
+///     NaiveDate::from_ymd_opt(2025, 2, 28)
+/// );
+/// assert_eq!(
+///     shift(date, 1, Period::Year, MonthOverflow::Roll),
+///     NaiveDate::from_ymd_opt(2025, 3, 1)
+/// );
+/// ```
+pub fn shift(
+    date: NaiveDate,
+    amount: i64,
+    period: Period,
+    overflow: MonthOverflow,
+) -> Option<NaiveDate> {
+    match period {
+        Period::Day => date.checked_add_signed(TimeDelta::try_days(amount)?),
+        Period::Week => date.checked_add_signed(TimeDelta::try_weeks(amount)?),
+        Period::Month => add_months(date, amount, overflow),
+        Period::Year => add_months(date, amount.checked_mul(12)?, overflow),
+    }
+}
+
+/// Returns the first day of the [`Period`] containing the specified `date` or
+/// [`None`] if it is out of the range of [`NaiveDate`]. The week starts on
+/// Monday.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::calendar::{start_of_period, Period};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 13).unwrap();
+///
+/// assert_eq!(
+///     start_of_period(date, Period::Week),
+///     NaiveDate::from_ymd_opt(2024, 2, 12)
+/// );
+/// assert_eq!(
+///     start_of_period(date, Period::Year),
+///     NaiveDate::from_ymd_opt(2024, 1, 1)
+/// );
+/// ```
+pub fn start_of_period(date: NaiveDate, period: Period) -> Option<NaiveDate> {
+    match period {
+        Period::Day => Some(date),
+        Period::Week => date.checked_sub_signed(TimeDelta::try_days(i64::from(
+            date.weekday().num_days_from_monday(),
+        ))?),
+        Period::Month => date.with_day(1),
+        Period::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1),
+    }
+}
+
+/// Returns the last day of the [`Period`] containing the specified `date` or
+/// [`None`] if it is out of the range of [`NaiveDate`]. The week ends on
+/// Sunday.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::calendar::{end_of_period, Period};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 13).unwrap();
+///
+/// assert_eq!(
+///     end_of_period(date, Period::Week),
+///     NaiveDate::from_ymd_opt(2024, 2, 18)
+/// );
+/// assert_eq!(
+///     end_of_period(date, Period::Month),
+///     NaiveDate::from_ymd_opt(2024, 2, 29)
+/// );
+/// ```
+pub fn end_of_period(date: NaiveDate, period: Period) -> Option<NaiveDate> {
+    match period {
+        Period::Day => Some(date),
+        Period::Week => date.checked_add_signed(TimeDelta::try_days(
+            6 - i64::from(date.weekday().num_days_from_monday()),
+        )?),
+        Period::Month => add_months(date.with_day(1)?, 1, MonthOverflow::Clamp)?.pred_opt(),
+        Period::Year => NaiveDate::from_ymd_opt(date.year(), 12, 31),
+    }
+}
+
+/// Returns the day of the month containing the specified `date` which
+/// corresponds to the specified [`MonthPart`]: the first, the 15th or the last
+/// day of the month. Returns [`None`] if it is out of the range of
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::calendar::{part_of_month, MonthPart};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 13).unwrap();
+///
+/// assert_eq!(
+///     part_of_month(date, MonthPart::Middle),
+///     NaiveDate::from_ymd_opt(2024, 2, 15)
+/// );
+/// assert_eq!(
+///     part_of_month(date, MonthPart::End),
+///     NaiveDate::from_ymd_opt(2024, 2, 29)
+/// );
+/// ```
+pub fn part_of_month(date: NaiveDate, part: MonthPart) -> Option<NaiveDate> {
+    match part {
+        MonthPart::Beginning => date.with_day(1),
+        MonthPart::Middle => date.with_day(15),
+        MonthPart::End => end_of_period(date, Period::Month),
+    }
+}
+
+/// Returns the `n`-th `weekday` of the month of the specified `date` (e.g. the
+/// 4th Thursday of November), the negative `n` counts from the end of the
+/// month (`-1` is the last one). Returns [`None`] if the month doesn't have
+/// such a weekday (e.g. the 5th Monday) or if `n` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::calendar::nth_weekday_of_month;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 9, 16).unwrap();
+///
+/// assert_eq!(
+///     nth_weekday_of_month(date, Weekday::Mon, 1),
+///     NaiveDate::from_ymd_opt(2024, 9, 2)
+/// );
+/// assert_eq!(
+///     nth_weekday_of_month(date, Weekday::Fri, -1),
+///     NaiveDate::from_ymd_opt(2024, 9, 27)
+/// );
+/// assert_eq!(nth_weekday_of_month(date, Weekday::Fri, 5), None);
+/// ```
+pub fn nth_weekday_of_month(date: NaiveDate, weekday: Weekday, n: i8) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
+    let weeks = u64::from(n.unsigned_abs()) - 1;
+    let nth = if n > 0 {
+        let first = date.with_day(1)?;
+        let days =
+            (7 + weekday.num_days_from_monday() - first.weekday().num_days_from_monday()) % 7;
+        first.checked_add_days(Days::new(u64::from(days) + 7 * weeks))?
+    } else {
+        let last = end_of_period(date, Period::Month)?;
+        let days = (7 + last.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        last.checked_sub_days(Days::new(u64::from(days) + 7 * weeks))?
+    };
+
+    (nth.year() == date.year() && nth.month() == date.month()).then_some(nth)
+}
+
+/// Completes the partial date with the specified `day` and the optional
+/// `month` parts using the specified reference date `today` according to the
+/// specified [`ResolutionPolicy`]. Returns [`None`] if there is no such date
+/// (e.g. `31/02`) or it is out of the range of [`NaiveDate`].
+///
+/// The nearest dates are searched within a year for the missing month and
+/// within eight years for the missing year, so `29/02` is always resolved.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::calendar::{resolve, ResolutionPolicy};
+///
+/// let today = NaiveDate::from_ymd_opt(2024, 7, 20).unwrap();
+///
+/// assert_eq!(
+///     resolve(today, None, 5, ResolutionPolicy::CurrentPeriod),
+///     NaiveDate::from_ymd_opt(2024, 7, 5)
+/// );
+/// assert_eq!(
+///     resolve(today, None, 5, ResolutionPolicy::NearestFuture),
+///     NaiveDate::from_ymd_opt(2024, 8, 5)
+/// );
+/// assert_eq!(
+///     resolve(today, Some(12), 25, ResolutionPolicy::NearestPast),
+///     NaiveDate::from_ymd_opt(2023, 12, 25)
+/// );
+/// ```
+pub fn resolve(
+    today: NaiveDate,
+    month: Option<u32>,
+    day: u32,
+    policy: ResolutionPolicy,
+) -> Option<NaiveDate> {
+    let step: i32 = match policy {
+        ResolutionPolicy::CurrentPeriod => {
+            return NaiveDate::from_ymd_opt(today.year(), month.unwrap_or(today.month()), day)
+        }
+        ResolutionPolicy::NearestFuture => 1,
+        ResolutionPolicy::NearestPast => -1,
+    };
+    let is_nearer = |date: &NaiveDate| (*date - today).num_days() * i64::from(step) >= 0;
+
+    match month {
+        Some(month) => (0..=8)
+            .filter_map(|i| {
+                NaiveDate::from_ymd_opt(today.year().checked_add(i * step)?, month, day)
+            })
+            .find(is_nearer),
+        None => (0..=12)
+            .filter_map(|i| {
+                add_months(
+                    today.with_day(1)?,
+                    i64::from(i * step),
+                    MonthOverflow::Clamp,
+                )?
+                .with_day(day)
+            })
+            .find(is_nearer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[rstest]
+    #[case(
+        ymd(2024, 7, 20),
+        None,
+        5,
+        ResolutionPolicy::CurrentPeriod,
+        Some(ymd(2024, 7, 5))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        None,
+        5,
+        ResolutionPolicy::NearestFuture,
+        Some(ymd(2024, 8, 5))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        None,
+        20,
+        ResolutionPolicy::NearestFuture,
+        Some(ymd(2024, 7, 20))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        None,
+        25,
+        ResolutionPolicy::NearestPast,
+        Some(ymd(2024, 6, 25))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        None,
+        20,
+        ResolutionPolicy::NearestPast,
+        Some(ymd(2024, 7, 20))
+    )]
+    #[case(
+        ymd(2024, 1, 31),
+        None,
+        30,
+        ResolutionPolicy::NearestFuture,
+        Some(ymd(2024, 3, 30))
+    )]
+    #[case(
+        ymd(2024, 3, 1),
+        None,
+        31,
+        ResolutionPolicy::NearestPast,
+        Some(ymd(2024, 1, 31))
+    )]
+    #[case(ymd(2024, 2, 1), None, 30, ResolutionPolicy::CurrentPeriod, None)]
+    #[case(
+        ymd(2024, 12, 20),
+        None,
+        5,
+        ResolutionPolicy::NearestFuture,
+        Some(ymd(2025, 1, 5))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        Some(1),
+        5,
+        ResolutionPolicy::NearestFuture,
+        Some(ymd(2025, 1, 5))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        Some(12),
+        25,
+        ResolutionPolicy::NearestFuture,
+        Some(ymd(2024, 12, 25))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        Some(12),
+        25,
+        ResolutionPolicy::NearestPast,
+        Some(ymd(2023, 12, 25))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        Some(2),
+        29,
+        ResolutionPolicy::NearestFuture,
+        Some(ymd(2028, 2, 29))
+    )]
+    #[case(
+        ymd(2024, 7, 20),
+        Some(2),
+        29,
+        ResolutionPolicy::NearestPast,
+        Some(ymd(2024, 2, 29))
+    )]
+    #[case(ymd(2024, 7, 20), Some(2), 30, ResolutionPolicy::NearestFuture, None)]
+    fn test_resolve(
+        #[case] today: NaiveDate,
+        #[case] month: Option<u32>,
+        #[case] day: u32,
+        #[case] policy: ResolutionPolicy,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(resolve(today, month, day, policy), expected);
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 1, 31), 1, MonthOverflow::Clamp, Some(ymd(2024, 2, 29)))]
+    #[case(ymd(2024, 1, 31), 1, MonthOverflow::Roll, Some(ymd(2024, 3, 2)))]
+    #[case(ymd(2023, 1, 31), 1, MonthOverflow::Roll, Some(ymd(2023, 3, 3)))]
+    #[case(ymd(2024, 3, 31), -1, MonthOverflow::Clamp, Some(ymd(2024, 2, 29)))]
+    #[case(ymd(2024, 3, 31), -1, MonthOverflow::Roll, Some(ymd(2024, 3, 2)))]
+    #[case(ymd(2024, 7, 16), 18, MonthOverflow::Roll, Some(ymd(2026, 1, 16)))]
+    #[case(ymd(2024, 7, 16), 0, MonthOverflow::Clamp, Some(ymd(2024, 7, 16)))]
+    #[case(NaiveDate::MAX, 1, MonthOverflow::Clamp, None)]
+    #[case(NaiveDate::MIN, i64::MIN, MonthOverflow::Roll, None)]
+    fn test_add_months(
+        #[case] date: NaiveDate,
+        #[case] months: i64,
+        #[case] overflow: MonthOverflow,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(add_months(date, months, overflow), expected);
+    }
+
+    #[rstest]
+    #[case(
+        ymd(2024, 7, 16),
+        3,
+        Period::Day,
+        MonthOverflow::Roll,
+        Some(ymd(2024, 7, 19))
+    )]
+    #[case(ymd(2024, 7, 16), -1, Period::Week, MonthOverflow::Clamp, Some(ymd(2024, 7, 9)))]
+    #[case(
+        ymd(2024, 5, 31),
+        1,
+        Period::Month,
+        MonthOverflow::Clamp,
+        Some(ymd(2024, 6, 30))
+    )]
+    #[case(
+        ymd(2024, 5, 31),
+        1,
+        Period::Month,
+        MonthOverflow::Roll,
+        Some(ymd(2024, 7, 1))
+    )]
+    #[case(
+        ymd(2024, 2, 29),
+        1,
+        Period::Year,
+        MonthOverflow::Clamp,
+        Some(ymd(2025, 2, 28))
+    )]
+    #[case(
+        ymd(2024, 2, 29),
+        1,
+        Period::Year,
+        MonthOverflow::Roll,
+        Some(ymd(2025, 3, 1))
+    )]
+    #[case(ymd(2024, 2, 29), -4, Period::Year, MonthOverflow::Roll, Some(ymd(2020, 2, 29)))]
+    #[case(NaiveDate::MIN, i64::MAX, Period::Year, MonthOverflow::Clamp, None)]
+    fn test_shift(
+        #[case] date: NaiveDate,
+        #[case] amount: i64,
+        #[case] period: Period,
+        #[case] overflow: MonthOverflow,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(shift(date, amount, period, overflow), expected);
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 7, 16), Period::Day, Some(ymd(2024, 7, 16)))]
+    #[case(ymd(2024, 7, 16), Period::Week, Some(ymd(2024, 7, 21)))]
+    #[case(ymd(2024, 7, 21), Period::Week, Some(ymd(2024, 7, 21)))]
+    #[case(ymd(2024, 12, 31), Period::Month, Some(ymd(2024, 12, 31)))]
+    #[case(ymd(2023, 2, 1), Period::Month, Some(ymd(2023, 2, 28)))]
+    #[case(ymd(2024, 7, 16), Period::Year, Some(ymd(2024, 12, 31)))]
+    #[case(NaiveDate::MAX, Period::Month, None)]
+    fn test_end_of_period(
+        #[case] date: NaiveDate,
+        #[case] period: Period,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(end_of_period(date, period), expected);
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 7, 16), Period::Day, Some(ymd(2024, 7, 16)))]
+    #[case(ymd(2024, 7, 16), Period::Week, Some(ymd(2024, 7, 15)))]
+    #[case(ymd(2024, 7, 15), Period::Week, Some(ymd(2024, 7, 15)))]
+    #[case(ymd(2024, 7, 16), Period::Month, Some(ymd(2024, 7, 1)))]
+    #[case(ymd(2024, 7, 16), Period::Year, Some(ymd(2024, 1, 1)))]
+    #[case(NaiveDate::MIN, Period::Week, None)]
+    fn test_start_of_period(
+        #[case] date: NaiveDate,
+        #[case] period: Period,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(start_of_period(date, period), expected);
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 7, 16), MonthPart::Beginning, Some(ymd(2024, 7, 1)))]
+    #[case(ymd(2024, 7, 16), MonthPart::Middle, Some(ymd(2024, 7, 15)))]
+    #[case(ymd(2024, 7, 16), MonthPart::End, Some(ymd(2024, 7, 31)))]
+    #[case(ymd(2023, 2, 1), MonthPart::End, Some(ymd(2023, 2, 28)))]
+    #[case(NaiveDate::MAX, MonthPart::End, None)]
+    fn test_part_of_month(
+        #[case] date: NaiveDate,
+        #[case] part: MonthPart,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(part_of_month(date, part), expected);
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 9, 16), Weekday::Mon, 1, Some(ymd(2024, 9, 2)))]
+    #[case(ymd(2024, 11, 1), Weekday::Thu, 4, Some(ymd(2024, 11, 28)))]
+    #[case(ymd(2024, 10, 1), Weekday::Tue, 1, Some(ymd(2024, 10, 1)))]
+    #[case(ymd(2024, 9, 30), Weekday::Mon, 5, Some(ymd(2024, 9, 30)))]
+    #[case(ymd(2024, 9, 30), Weekday::Fri, 5, None)]
+    #[case(ymd(2024, 5, 1), Weekday::Mon, -1, Some(ymd(2024, 5, 27)))]
+    #[case(ymd(2024, 5, 1), Weekday::Fri, -1, Some(ymd(2024, 5, 31)))]
+    #[case(ymd(2024, 11, 1), Weekday::Sun, -2, Some(ymd(2024, 11, 17)))]
+    #[case(ymd(2024, 2, 1), Weekday::Mon, -5, None)]
+    #[case(ymd(2024, 2, 1), Weekday::Mon, 0, None)]
+    fn test_nth_weekday_of_month(
+        #[case] date: NaiveDate,
+        #[case] weekday: Weekday,
+        #[case] n: i8,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(nth_weekday_of_month(date, weekday, n), expected);
+    }
+
+    #[rstest]
+    #[case(Season::Spring, 2024, Hemisphere::Northern, Some((ymd(2024, 3, 1), ymd(2024, 5, 31))))]
+    #[case(Season::Summer, 2024, Hemisphere::Northern, Some((ymd(2024, 6, 1), ymd(2024, 8, 31))))]
+    #[case(Season::Autumn, 2024, Hemisphere::Northern, Some((ymd(2024, 9, 1), ymd(2024, 11, 30))))]
+    #[case(Season::Winter, 2023, Hemisphere::Northern, Some((ymd(2023, 12, 1), ymd(2024, 2, 29))))]
+    #[case(Season::Spring, 2024, Hemisphere::Southern, Some((ymd(2024, 9, 1), ymd(2024, 11, 30))))]
+    #[case(Season::Summer, 2024, Hemisphere::Southern, Some((ymd(2024, 12, 1), ymd(2025, 2, 28))))]
+    #[case(Season::Autumn, 2024, Hemisphere::Southern, Some((ymd(2024, 3, 1), ymd(2024, 5, 31))))]
+    #[case(Season::Winter, NaiveDate::MAX.year(), Hemisphere::Northern, None)]
+    fn test_season_range(
+        #[case] season: Season,
+        #[case] year: i32,
+        #[case] hemisphere: Hemisphere,
+        #[case] expected: Option<(NaiveDate, NaiveDate)>,
+    ) {
+        assert_eq!(
+            season_range(season, year, hemisphere),
+            expected.map(|(start, end)| DateRange { start, end })
+        );
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 7, 15), Some((ymd(2024, 7, 20), ymd(2024, 7, 21))))]
+    #[case(ymd(2024, 7, 20), Some((ymd(2024, 7, 20), ymd(2024, 7, 21))))]
+    #[case(ymd(2024, 7, 21), Some((ymd(2024, 7, 20), ymd(2024, 7, 21))))]
+    #[case(ymd(2024, 12, 30), Some((ymd(2025, 1, 4), ymd(2025, 1, 5))))]
+    #[case(NaiveDate::MAX, None)]
+    fn test_weekend(#[case] date: NaiveDate, #[case] expected: Option<(NaiveDate, NaiveDate)>) {
+        assert_eq!(
+            weekend(date),
+            expected.map(|(start, end)| DateRange { start, end })
+        );
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 7, 16), 0, Some(ymd(2024, 7, 16)))]
+    #[case(ymd(2024, 7, 20), 0, Some(ymd(2024, 7, 20)))]
+    #[case(ymd(2024, 7, 16), 1, Some(ymd(2024, 7, 17)))]
+    #[case(ymd(2024, 7, 19), 1, Some(ymd(2024, 7, 22)))]
+    #[case(ymd(2024, 7, 20), 1, Some(ymd(2024, 7, 22)))]
+    #[case(ymd(2024, 7, 17), 5, Some(ymd(2024, 7, 24)))]
+    #[case(ymd(2024, 7, 22), -1, Some(ymd(2024, 7, 19)))]
+    #[case(ymd(2024, 7, 21), -1, Some(ymd(2024, 7, 19)))]
+    #[case(NaiveDate::MAX, 1, None)]
+    fn test_add_business_days(
+        #[case] date: NaiveDate,
+        #[case] days: i64,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(add_business_days(date, days), expected);
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 7, 19), 1, Some(ymd(2024, 7, 24)))]
+    #[case(ymd(2024, 7, 24), -1, Some(ymd(2024, 7, 19)))]
+    #[case(ymd(2024, 7, 16), 5, Some(ymd(2024, 7, 25)))]
+    #[case(ymd(2024, 7, 22), 0, Some(ymd(2024, 7, 22)))]
+    fn test_business_calendar_add_business_days(
+        #[case] date: NaiveDate,
+        #[case] days: i64,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        let calendar = BusinessCalendar::new().with_holidays([
+            ymd(2024, 7, 22),
+            ymd(2024, 7, 23),
+            ymd(2024, 7, 27),
+        ]);
+
+        assert_eq!(calendar.add_business_days(date, days), expected);
+    }
+
+    #[cfg(feature = "holidays")]
+    #[rstest]
+    #[case(ymd(2024, 12, 24), 1, Some(ymd(2024, 12, 26)))]
+    #[case(ymd(2025, 12, 24), 1, Some(ymd(2025, 12, 26)))]
+    #[case(ymd(2024, 3, 28), 1, Some(ymd(2024, 4, 2)))]
+    fn test_business_calendar_with_holiday_table(
+        #[case] date: NaiveDate,
+        #[case] days: i64,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        use crate::holiday::HolidayRule;
+
+        let table = HolidayTable::new()
+            .with("Christmas", chrono::Month::December, 25)
+            .with_rule("Good Friday", HolidayRule::EasterOffset(-2))
+            .with_rule("Easter Monday", HolidayRule::EasterOffset(1));
+        let calendar = BusinessCalendar::new().with_holiday_table(&table);
+
+        assert_eq!(calendar.add_business_days(date, days), expected);
+    }
+
+    #[test]
+    fn test_business_calendar_add_business_days_by_steps() {
+        let calendar = BusinessCalendar::new().with_holidays([
+            ymd(2024, 7, 22),
+            ymd(2024, 7, 23),
+            ymd(2024, 7, 27),
+            ymd(2024, 8, 15),
+        ]);
+        let step = |date: NaiveDate, days: i64| {
+            (0..days.abs()).try_fold(date, |mut date, _| loop {
+                date = if days > 0 {
+                    date.succ_opt()?
+                } else {
+                    date.pred_opt()?
+                };
+                if calendar.is_business_day(date) {
+                    break Some(date);
+                }
+            })
+        };
+
+        for date in (0..14).map(|day| ymd(2024, 7, 14) + Days::new(day)) {
+            for days in -40..=40 {
+                assert_eq!(
+                    calendar.add_business_days(date, days),
+                    step(date, days),
+                    "{date} {days}"
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(MAX_BUSINESS_DAYS as i64, Some(ymd(2407, 11, 6)))]
+    #[case(-(MAX_BUSINESS_DAYS as i64), Some(ymd(1641, 3, 26)))]
+    #[case(MAX_BUSINESS_DAYS as i64 + 1, None)]
+    #[case(i64::MIN, None)]
+    fn test_add_business_days_limit(#[case] days: i64, #[case] expected: Option<NaiveDate>) {
+        assert_eq!(add_business_days(ymd(2024, 7, 16), days), expected);
+    }
+
+    #[cfg(feature = "holidays")]
+    #[test]
+    fn test_business_calendar_with_holiday_table_limit() {
+        use crate::holiday::HolidayRule;
+
+        let table = HolidayTable::new()
+            .with("Christmas", chrono::Month::December, 25)
+            .with_rule("Easter Monday", HolidayRule::EasterOffset(1));
+        let calendar = BusinessCalendar::new().with_holiday_table(&table);
+        let date = calendar
+            .add_business_days(ymd(2024, 7, 16), MAX_BUSINESS_DAYS as i64)
+            .unwrap();
+
+        assert!(calendar.is_business_day(date));
+        assert_eq!(
+            calendar.add_business_days(ymd(2024, 7, 16), MAX_BUSINESS_DAYS as i64 + 1),
+            None
+        );
+    }
+}
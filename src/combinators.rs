@@ -0,0 +1,841 @@
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+use nom::{
+    branch::alt,
+    character::complete::{multispace0, satisfy},
+    combinator::{not, opt},
+    error::{ErrorKind, ParseError},
+    sequence::{delimited, preceded, terminated},
+    Needed, Parser,
+};
+
+#[cfg(feature = "numeric")]
+pub use crate::numeric::numeric_date_parts_separator;
+use crate::{error::Error, types::IResult};
+pub use crate::{
+    i18n::{day_month_separator, weekday_separator},
+    range::range_separator,
+};
+
+/// Recognizes the `case insensitive` tag.
+///
+/// Unlike the [`nom::bytes::complete::tag_no_case`] parser, the characters are
+/// compared one by one, so the tag is recognized correctly even if the
+/// lowercase and uppercase forms of a character have different lengths in
+/// bytes (e.g. the `Kelvin sign` and `k`). Also the final form of the Greek
+/// `sigma` is treated as the regular one.
+///
+/// The consumed part of the input is returned as is.
+pub fn tag_no_case(tag: &str) -> impl Fn(&str) -> IResult<&str, &str> + '_ {
+    tag_with(tag, eq_no_case)
+}
+
+/// Recognizes the `case insensitive` tag using the case mapping of the Turkic
+/// languages, see [`tag_no_case`].
+///
+/// The dotted and dotless `i` are distinct letters there: the uppercase form of
+/// `i` is `İ` and the uppercase form of `ı` is `I`, so `SALI` is recognized by
+/// the `salı` tag, but not by the `sali` one.
+///
+/// The consumed part of the input is returned as is.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::combinators::tag_no_case_turkic;
+///
+/// assert_eq!(tag_no_case_turkic("salı")("SALI")?, ("", "SALI"));
+/// assert_eq!(
+///     tag_no_case_turkic("pazartesi")("PAZARTESİ")?,
+///     ("", "PAZARTESİ")
+/// );
+/// assert!(tag_no_case_turkic("sali")("SALI").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn tag_no_case_turkic(tag: &str) -> impl Fn(&str) -> IResult<&str, &str> + '_ {
+    tag_with(tag, eq_no_case_turkic)
+}
+
+/// The maximum length in bytes of the lowercase word recognized by the
+/// [`lookup_no_case`] combinator.
+const MAX_LOOKUP_WORD_LEN: usize = 32;
+
+/// Creates a parser which recognizes the longest `case insensitive` word at the
+/// start of the input found by the specified `lookup` function and returns the
+/// value of the word.
+///
+/// Unlike the [`alt`] chain of the [`tag_no_case`] parsers, the leading letters
+/// of the input are lowercased (see [`tag_no_case`] for the case mapping) only
+/// once into the buffer on the stack, then the `lookup` is called with the
+/// lowercase prefixes of the letters starting from the longest one, so it can
+/// be a `match` on the words. The words must consist of the letters and be at
+/// most 32 bytes long in the lowercase form.
+///
+/// The consumed part of the input isn't required to end at the word boundary,
+/// e.g. `jul` is recognized in the `jul2024` and `julyish` inputs.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::combinators::lookup_no_case;
+///
+/// let month = |word: &str| match word {
+///     "jul" | "july" => Some(Month::July),
+///     "aug" | "august" => Some(Month::August),
+///     _ => None,
+/// };
+///
+/// assert_eq!(lookup_no_case(month)("July 13")?, (" 13", Month::July));
+/// assert_eq!(lookup_no_case(month)("AUG.")?, (".", Month::August));
+/// assert!(lookup_no_case(month)("13 July").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn lookup_no_case<T>(lookup: impl Fn(&str) -> Option<T>) -> impl Fn(&str) -> IResult<&str, T> {
+    move |input: &str| {
+        let mut buffer = [0_u8; MAX_LOOKUP_WORD_LEN];
+        // The lowercase and the input lengths of the prefixes ending at the
+        // character boundaries
+        let mut prefixes = [(0, 0); MAX_LOOKUP_WORD_LEN];
+        let mut len = 0;
+        let mut count = 0;
+
+        'letters: for (position, c) in input.char_indices() {
+            // The lowercase ASCII and Cyrillic letters are the most common, so
+            // they skip the lookups in the Unicode tables
+            let mut lowercase = [c; 3];
+            let mut lowercase_count = 1;
+            if !matches!(c, 'a'..='z' | 'а'..='я' | 'ё') {
+                if !c.is_alphabetic() {
+                    break;
+                }
+                lowercase_count = 0;
+                for folded in fold_case(c) {
+                    lowercase[lowercase_count] = folded;
+                    lowercase_count += 1;
+                }
+            }
+            for folded in &lowercase[..lowercase_count] {
+                if len + folded.len_utf8() > MAX_LOOKUP_WORD_LEN {
+                    break 'letters;
+                }
+                len += folded.encode_utf8(&mut buffer[len..]).len();
+            }
+            prefixes[count] = (len, position + c.len_utf8());
+            count += 1;
+        }
+
+        core::str::from_utf8(&buffer[..len])
+            .ok()
+            .and_then(|lowercase| {
+                prefixes[..count]
+                    .iter()
+                    .rev()
+                    .find_map(|&(lowercase_len, consumed)| {
+                        lookup(&lowercase[..lowercase_len]).map(|value| (&input[consumed..], value))
+                    })
+            })
+            .ok_or(nom::Err::Error(Error::from_error_kind(
+                input,
+                ErrorKind::Tag,
+            )))
+    }
+}
+
+/// Asserts that every one of the lowercase `words` of the [`lookup_no_case`]
+/// table fits its buffer and is recognized as a whole by the `parser`.
+#[cfg(all(test, any(feature = "en", feature = "ru")))]
+pub(crate) fn assert_lookup_words<T>(parser: impl Fn(&str) -> IResult<&str, T>, words: &[&str]) {
+    for word in words {
+        assert!(
+            word.len() <= MAX_LOOKUP_WORD_LEN,
+            "`{word}` is longer than {MAX_LOOKUP_WORD_LEN} bytes"
+        );
+        assert!(
+            matches!(parser(word), Ok(("", _))),
+            "`{word}` isn't recognized"
+        );
+    }
+}
+
+/// Creates a parser which recognizes the `case insensitive` word at the start
+/// of the input which is a prefix of at least `min_len` characters of one or
+/// more of the specified `words` (in the lowercase form, see
+/// [`lookup_no_case`]) and returns their value, e.g. `thurs` or `th` of the
+/// `thursday`.
+///
+/// The prefix must be unambiguous: all the words starting with it must have
+/// the same value, so `t` of the `tuesday` and the `thursday` isn't recognized.
+/// The recognized word must end at the [`word_boundary`], so `fridge` isn't
+/// recognized as `fri` of the `friday`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::combinators::lookup_prefix_no_case;
+///
+/// let words = [("tuesday", Weekday::Tue), ("thursday", Weekday::Thu)];
+///
+/// assert_eq!(
+///     lookup_prefix_no_case(&words, 2)("Tu 13")?,
+///     (" 13", Weekday::Tue)
+/// );
+/// assert_eq!(
+///     lookup_prefix_no_case(&words, 2)("thurs.")?,
+///     (".", Weekday::Thu)
+/// );
+/// assert!(lookup_prefix_no_case(&words, 1)("t").is_err());
+/// assert!(lookup_prefix_no_case(&words, 2)("thug").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn lookup_prefix_no_case<'w, T: Copy + PartialEq>(
+    words: &'w [(&'w str, T)],
+    min_len: usize,
+) -> impl Fn(&str) -> IResult<&str, T> + 'w {
+    move |input: &str| {
+        terminated(
+            lookup_no_case(|prefix| {
+                if prefix.chars().count() < min_len {
+                    return None;
+                }
+                let mut values = words
+                    .iter()
+                    .filter(|(word, _)| word.starts_with(prefix))
+                    .map(|&(_, value)| value);
+                let first = values.next()?;
+
+                values.all(|value| value == first).then_some(first)
+            }),
+            word_boundary,
+        )(input)
+    }
+}
+
+/// Recognizes the tag comparing the characters one by one with the specified
+/// function.
+fn tag_with(tag: &str, eq: fn(char, char) -> bool) -> impl Fn(&str) -> IResult<&str, &str> + '_ {
+    move |input: &str| {
+        let mut input_chars = input.char_indices();
+        let mut consumed = 0;
+
+        for expected in tag.chars() {
+            match input_chars.next() {
+                Some((position, actual)) if eq(actual, expected) => {
+                    consumed = position + actual.len_utf8();
+                }
+                _ => {
+                    return Err(nom::Err::Error(Error::from_error_kind(
+                        input,
+                        ErrorKind::Tag,
+                    )))
+                }
+            }
+        }
+
+        Ok((&input[consumed..], &input[..consumed]))
+    }
+}
+
+/// Compares two characters ignoring their case.
+#[inline]
+fn eq_no_case(a: char, b: char) -> bool {
+    a == b || fold_case(a).eq(fold_case(b))
+}
+
+/// Compares two characters ignoring their case using the Turkic case mapping.
+#[inline]
+fn eq_no_case_turkic(a: char, b: char) -> bool {
+    a == b || fold_case(turkic_lowercase(a)).eq(fold_case(turkic_lowercase(b)))
+}
+
+/// Returns the lowercase form of the dotted `İ` and the dotless `I` in the
+/// Turkic languages, other characters are returned as is.
+#[inline]
+fn turkic_lowercase(c: char) -> char {
+    match c {
+        'İ' => 'i',
+        'I' => 'ı',
+        c => c,
+    }
+}
+
+/// Returns the lowercase form of the character, the final form of the Greek
+/// `sigma` is replaced with the regular one.
+#[inline]
+fn fold_case(c: char) -> impl Iterator<Item = char> {
+    match c {
+        'ς' => 'σ',
+        c => c,
+    }
+    .to_lowercase()
+}
+
+/// Checks that the word ends at the current position: the next character must
+/// not be a letter or a digit. Consumes nothing.
+///
+/// # Examples
+///
+/// ```
+/// use nom::{bytes::complete::tag, sequence::terminated};
+/// use nom_date_parsers::combinators::word_boundary;
+///
+/// assert_eq!(
+///     terminated(tag("г"), word_boundary)("г, Москва")?,
+///     (", Москва", "г")
+/// );
+/// assert!(terminated(tag("г"), word_boundary)("где").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn word_boundary(input: &str) -> IResult<&str, ()> {
+    not(satisfy(char::is_alphanumeric))(input)
+}
+
+/// Recognizes the `case insensitive` English ordinal suffix of the number
+/// (`st`, `nd`, `rd` or `th`) followed by the [`word_boundary`].
+///
+/// # Examples
+///
+/// ```
+/// use nom::sequence::terminated;
+/// use nom_date_parsers::{combinators::ordinal_suffix, numeric::dd};
+///
+/// assert_eq!(terminated(dd, ordinal_suffix)("13th July")?, (" July", 13));
+/// assert_eq!(terminated(dd, ordinal_suffix)("1ST")?, ("", 1));
+/// assert!(terminated(dd, ordinal_suffix)("3rdly").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn ordinal_suffix(input: &str) -> IResult<&str, &str> {
+    terminated(
+        alt((
+            tag_no_case("st"),
+            tag_no_case("nd"),
+            tag_no_case("rd"),
+            tag_no_case("th"),
+        )),
+        word_boundary,
+    )(input)
+}
+
+/// Creates a parser which recognizes the optional `prefix` followed by the
+/// output of the specified `parser`, the output of the `prefix` is discarded.
+///
+/// # Examples
+///
+/// ```
+/// use nom::bytes::complete::tag;
+/// use nom_date_parsers::{combinators::optional_prefix, numeric::dd_mm_y4};
+///
+/// let mut parser = optional_prefix(tag("on "), dd_mm_y4);
+///
+/// assert_eq!(parser("on 13/07/2024")?, parser("13/07/2024")?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn optional_prefix<'a, O1, O2, F, G>(
+    prefix: F,
+    parser: G,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O2>
+where
+    F: Parser<&'a str, O1, Error<&'a str>>,
+    G: Parser<&'a str, O2, Error<&'a str>>,
+{
+    preceded(opt(prefix), parser)
+}
+
+/// Creates a parser which recognizes the output of the specified `parser`
+/// followed by the optional `suffix`, the output of the `suffix` is discarded.
+///
+/// # Examples
+///
+/// ```
+/// use nom::bytes::complete::tag;
+/// use nom_date_parsers::{combinators::optional_suffix, numeric::dd_mm_y4};
+///
+/// let mut parser = optional_suffix(dd_mm_y4, tag(" AD"));
+///
+/// assert_eq!(parser("13/07/2024 AD")?, parser("13/07/2024")?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn optional_suffix<'a, O1, O2, F, G>(
+    parser: F,
+    suffix: G,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O1>
+where
+    F: Parser<&'a str, O1, Error<&'a str>>,
+    G: Parser<&'a str, O2, Error<&'a str>>,
+{
+    terminated(parser, opt(suffix))
+}
+
+/// Creates a parser which recognizes the output of the specified `parser`
+/// surrounded by any number of whitespaces (including the newlines), e.g. the
+/// untrimmed input of the chat message.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::{combinators::trimmed, numeric::dd_mm_y4};
+///
+/// assert_eq!(
+///     trimmed(dd_mm_y4)(" 13/07/2024\n")?,
+///     trimmed(dd_mm_y4)("13/07/2024")?
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn trimmed<'a, O, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    delimited(multispace0, parser, multispace0)
+}
+
+/// Creates a parser which returns the byte range of the input recognized by
+/// the specified `parser` along with its output, e.g. to highlight the date in
+/// the UI. The whitespaces (including the newlines) surrounding the recognized
+/// part are excluded from the range, so the range of the bundle parsers covers
+/// just the date.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{combinators::with_span, i18n::en::bundle_dmy};
+///
+/// let input = "  13 July 2024, London";
+/// let (_, (span, date)) = with_span(bundle_dmy)(input)?;
+///
+/// assert_eq!(&input[span], "13 July 2024");
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 7, 13).unwrap());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn with_span<'a, O, F>(
+    mut parser: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (Range<usize>, O)>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (rest, output) = parser.parse(input)?;
+        let consumed = &input[..input.len() - rest.len()];
+        let end = consumed.trim_end().len();
+        let start = end - consumed[..end].trim_start().len();
+
+        Ok((rest, (start..end, output)))
+    }
+}
+
+/// Creates a parser which makes the specified `complete` parser usable for the
+/// incremental parsing of the partial input (e.g. the network or `stdin`
+/// streams): returns `nom::Err::Incomplete` if the result may change when more
+/// input arrives, otherwise returns the result of the `parser`.
+///
+/// The result may change while the input after the recognized date (or the
+/// whole input if the `parser` fails) consists only of the letters, digits and
+/// the `/`, `-`, `.`, `:` and `+` separators, e.g. `13/07` may continue as
+/// `13/07/2024` and `13/0` as `13/07`. So the result is returned only after
+/// any other character (e.g. a whitespace, a comma or a newline) arrives.
+///
+/// # Examples
+///
+/// ```
+/// use nom::Needed;
+/// use nom_date_parsers::{combinators::streaming, numeric::dd_mm_y4};
+///
+/// let mut parser = streaming(dd_mm_y4);
+///
+/// assert_eq!(
+///     parser("13/07/20"),
+///     Err(nom::Err::Incomplete(Needed::Unknown))
+/// );
+/// assert_eq!(
+///     parser("13/07/2024"),
+///     Err(nom::Err::Incomplete(Needed::Unknown))
+/// );
+/// assert!(parser("13/07/2024\n").is_ok());
+/// ```
+pub fn streaming<'a, O, F>(mut parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    move |input: &'a str| match parser.parse(input) {
+        Ok((remaining, _)) if may_continue(remaining) => Err(nom::Err::Incomplete(Needed::Unknown)),
+        Err(nom::Err::Error(_) | nom::Err::Failure(_)) if may_continue(input) => {
+            Err(nom::Err::Incomplete(Needed::Unknown))
+        }
+        result => result,
+    }
+}
+
+/// Checks whether the input may be the beginning of a date (or its part), see
+/// [`streaming`].
+fn may_continue(input: &str) -> bool {
+    input
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, '/' | '-' | '.' | ':' | '+'))
+}
+
+/// Creates a parser which recognizes the date in the `&[u8]` buffer (e.g. read
+/// from the socket or the file) using the specified `&str` parser without
+/// copying the buffer: the UTF-8 prefix of the buffer is validated in place
+/// and the remaining input and the input of the errors are mapped back onto
+/// the buffer.
+///
+/// The input after the first invalid UTF-8 sequence is never recognized and
+/// is returned as the remaining input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{combinators::bytes, numeric::dd_mm_y4};
+///
+/// assert_eq!(
+///     bytes(dd_mm_y4)(b"13/07/2024\r\n")?,
+///     (&b"\r\n"[..], NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn bytes<'a, O, F>(mut parser: F) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    move |input: &'a [u8]| {
+        let valid = match core::str::from_utf8(input) {
+            Ok(valid) => valid,
+            Err(e) => core::str::from_utf8(&input[..e.valid_up_to()]).unwrap_or_default(),
+        };
+        let remaining = |rest: &'a str| &input[valid.len() - rest.len()..];
+
+        parser
+            .parse(valid)
+            .map(|(rest, output)| (remaining(rest), output))
+            .map_err(|e| e.map(|e| e.map_input(remaining)))
+    }
+}
+
+/// Creates a parser which normalizes the input before recognizing it with the
+/// specified `parser`, so the near-miss inputs (e.g. typed on the phone or
+/// copied from the web pages) are recognized by the `language-specific`
+/// parsers:
+/// - the runs of the Unicode whitespaces (e.g. the `NO-BREAK SPACE` or the
+///   tabs) are replaced with a single space;
+/// - the Unicode dashes (e.g. the `EN DASH` or the `MINUS SIGN`) are replaced
+///   with the `-` and the `SOFT HYPHEN` is removed;
+/// - the typographic apostrophes (e.g. `’` or `ʼ`) are replaced with the `'`;
+/// - the combining diacritical marks (e.g. the stress mark in `за́втра`) are
+///   removed, the decomposed Cyrillic `ё` and `й` are composed.
+///
+/// The input is copied only if it needs the normalization. The remaining
+/// input and the input of the errors are mapped back onto the original input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{combinators::normalized, i18n::en::dd_month_y4};
+///
+/// assert_eq!(
+///     normalized(dd_month_y4)("13\u{a0}July\u{a0}\u{a0}2024!")?,
+///     ("!", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn normalized<'a, O, F>(mut parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: for<'n> Parser<&'n str, O, Error<&'n str>>,
+{
+    move |input: &'a str| {
+        let Some((normalized, offsets)) = normalize(input) else {
+            return parser.parse(input);
+        };
+        let original = |rest: &str| &input[offsets[normalized.len() - rest.len()]..];
+
+        parser
+            .parse(&normalized)
+            .map(|(rest, output)| (original(rest), output))
+            .map_err(|e| e.map(|e| e.map_input(original)))
+    }
+}
+
+/// Normalizes the input as described in [`normalized`], returns the normalized
+/// input and the offsets of its bytes (and of its end) in the original input,
+/// or [`None`] if the input is already normalized.
+fn normalize(input: &str) -> Option<(String, Vec<usize>)> {
+    if !needs_normalization(input) {
+        return None;
+    }
+
+    let mut normalized = String::with_capacity(input.len());
+    let mut offsets = Vec::with_capacity(input.len() + 1);
+    let mut chars = input.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        let c = match c {
+            c if c.is_whitespace() => {
+                while chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+                ' '
+            }
+            '\u{00AD}' | '\u{0300}'..='\u{036F}' => continue,
+            '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE63}' | '\u{FF0D}' => '-',
+            '\u{2018}' | '\u{2019}' | '\u{201B}' | '\u{02BC}' | '\u{FF07}' => '\'',
+            c => match chars.peek().and_then(|&(_, mark)| compose(c, mark)) {
+                Some(composed) => {
+                    chars.next();
+                    composed
+                }
+                None => c,
+            },
+        };
+        offsets.extend(core::iter::repeat(offset).take(c.len_utf8()));
+        normalized.push(c);
+    }
+    offsets.push(input.len());
+
+    Some((normalized, offsets))
+}
+
+/// Checks whether the input contains the characters changed by the
+/// [`normalize`] function.
+fn needs_normalization(input: &str) -> bool {
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        let changed = match c {
+            ' ' => chars.peek().is_some_and(|c| c.is_whitespace()),
+            c if c.is_whitespace() => true,
+            '\u{00AD}' | '\u{0300}'..='\u{036F}' => true,
+            '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE63}' | '\u{FF0D}' => true,
+            '\u{2018}' | '\u{2019}' | '\u{201B}' | '\u{02BC}' | '\u{FF07}' => true,
+            _ => false,
+        };
+        if changed {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Composes the Cyrillic letter with the following combining mark (`е` with
+/// the diaeresis into `ё` and `и` with the breve into `й`).
+fn compose(c: char, mark: char) -> Option<char> {
+    match (c, mark) {
+        ('е', '\u{0308}') => Some('ё'),
+        ('Е', '\u{0308}') => Some('Ё'),
+        ('и', '\u{0306}') => Some('й'),
+        ('И', '\u{0306}') => Some('Й'),
+        _ => None,
+    }
+}
+
+/// Recognizes zero or more Unicode bidirectional formatting characters (e.g.
+/// the `RIGHT-TO-LEFT MARK` or the isolates), which are often inserted around
+/// the numbers and words in the right-to-left texts.
+pub fn bidi_marks(input: &str) -> IResult<&str, &str> {
+    nom::bytes::complete::take_while(is_bidi_mark)(input)
+}
+
+/// Checks whether the character is a Unicode bidirectional formatting
+/// character.
+fn is_bidi_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::combinator::value;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("today", "Today", Ok(("", "Today")))]
+    #[case("today", "TODAY!", Ok(("!", "TODAY")))]
+    #[case("среда", "срЕдА", Ok(("", "срЕдА")))]
+    #[case("среда", "СРЕДА 13", Ok((" 13", "СРЕДА")))]
+    #[case("δευτέρα", "ΔΕΥΤΈΡΑ", Ok(("", "ΔΕΥΤΈΡΑ")))]
+    #[case("σάββατος", "ΣΆΒΒΑΤΟΣ", Ok(("", "ΣΆΒΒΑΤΟΣ")))]
+    #[case("σάββατος", "σάββατος", Ok(("", "σάββατος")))]
+    #[case("σάββατος", "Σάββατος", Ok(("", "Σάββατος")))]
+    #[case("kelvin", "\u{212A}elvin", Ok(("", "\u{212A}elvin")))]
+    #[case("\u{212A}elvin", "Kelvin", Ok(("", "Kelvin")))]
+    #[case(
+        "среда",
+        "сре",
+        Err(nom::Err::Error(Error::Nom("сре", ErrorKind::Tag)))
+    )]
+    #[case(
+        "среда",
+        "средство",
+        Err(nom::Err::Error(Error::Nom("средство", ErrorKind::Tag)))
+    )]
+    #[case("среда", "", Err(nom::Err::Error(Error::Nom("", ErrorKind::Tag))))]
+    fn test_tag_no_case(
+        #[case] tag: &str,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, &str>,
+    ) {
+        assert_eq!(tag_no_case(tag)(input), expected);
+    }
+
+    #[rstest]
+    #[case("salı", "SALI", Ok(("", "SALI")))]
+    #[case("salı", "Salı", Ok(("", "Salı")))]
+    #[case("pazartesi", "PAZARTESİ", Ok(("", "PAZARTESİ")))]
+    #[case("ılık", "Ilık", Ok(("", "Ilık")))]
+    #[case(
+        "sali",
+        "SALI",
+        Err(nom::Err::Error(Error::Nom("SALI", ErrorKind::Tag)))
+    )]
+    #[case(
+        "salı",
+        "SALİ",
+        Err(nom::Err::Error(Error::Nom("SALİ", ErrorKind::Tag)))
+    )]
+    #[case("çarşamba", "ÇARŞAMBA", Ok(("", "ÇARŞAMBA")))]
+    fn test_tag_no_case_turkic(
+        #[case] tag: &str,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, &str>,
+    ) {
+        assert_eq!(tag_no_case_turkic(tag)(input), expected);
+    }
+
+    #[rstest]
+    #[case("May", Ok(("", 5)))]
+    #[case("MAYDAY", Ok(("DAY", 5)))]
+    #[case("mayo 13", Ok((" 13", 6)))]
+    #[case("Sept.", Ok((".", 9)))]
+    #[case("\u{212A}elvin", Ok(("", 0)))]
+    #[case("СЕНТЯБРЯ", Ok(("", 9)))]
+    #[case("ma", Err(nom::Err::Error(Error::Nom("ma", ErrorKind::Tag))))]
+    #[case("13 may", Err(nom::Err::Error(Error::Nom("13 may", ErrorKind::Tag))))]
+    #[case("", Err(nom::Err::Error(Error::Nom("", ErrorKind::Tag))))]
+    fn test_lookup_no_case(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
+        let lookup = |word: &str| match word {
+            "may" => Some(5),
+            "mayo" => Some(6),
+            "sep" | "sept" | "сентября" => Some(9),
+            "kelvin" => Some(0),
+            _ => None,
+        };
+
+        assert_eq!(lookup_no_case(lookup)(input), expected);
+    }
+
+    #[test]
+    fn test_lookup_no_case_long_word() {
+        let input = "a".repeat(100);
+
+        assert_eq!(
+            lookup_no_case(|word| (word == "aaa").then_some(3))(&input),
+            Ok((&input[3..], 3))
+        );
+    }
+
+    #[rstest]
+    #[case("", Ok(("", ())))]
+    #[case(" года", Ok((" года", ())))]
+    #[case(".", Ok((".", ())))]
+    #[case("да", Err(nom::Err::Error(Error::Nom("да", ErrorKind::Not))))]
+    #[case("1", Err(nom::Err::Error(Error::Nom("1", ErrorKind::Not))))]
+    fn test_word_boundary(#[case] input: &str, #[case] expected: IResult<&str, ()>) {
+        assert_eq!(word_boundary(input), expected);
+    }
+
+    #[rstest]
+    #[case("st", Ok(("", "st")))]
+    #[case("ND of July", Ok((" of July", "ND")))]
+    #[case("th,", Ok((",", "th")))]
+    #[case("thursday", Err(nom::Err::Error(Error::Nom("ursday", ErrorKind::Not))))]
+    #[case("x", Err(nom::Err::Error(Error::Nom("x", ErrorKind::Tag))))]
+    fn test_ordinal_suffix(#[case] input: &str, #[case] expected: IResult<&str, &str>) {
+        assert_eq!(ordinal_suffix(input), expected);
+    }
+
+    #[rstest]
+    #[case("today", Ok(("", "today")))]
+    #[case("  today\n", Ok(("", "today")))]
+    #[case("\ttoday later", Ok(("later", "today")))]
+    #[case(" later", Err(nom::Err::Error(Error::Nom("later", ErrorKind::Tag))))]
+    fn test_trimmed(#[case] input: &str, #[case] expected: IResult<&str, &str>) {
+        assert_eq!(trimmed(tag_no_case("today"))(input), expected);
+    }
+
+    #[rstest]
+    #[case("today", Ok(("", (0..5, "today"))))]
+    #[case("  Today!", Ok(("!", (2..7, "Today"))))]
+    #[case("\ttoday\nlater", Ok(("later", (1..6, "today"))))]
+    #[case("сегодня today", Ok(("", (0..20, "today"))))]
+    #[case(" later", Err(nom::Err::Error(Error::Nom("later", ErrorKind::Tag))))]
+    fn test_with_span(#[case] input: &str, #[case] expected: IResult<&str, (Range<usize>, &str)>) {
+        assert_eq!(
+            with_span(trimmed(preceded(
+                opt(tag_no_case("сегодня ")),
+                tag_no_case("today")
+            )))(input),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("13", Ok(("13", "")))]
+    #[case("\u{200F}13", Ok(("13", "\u{200F}")))]
+    #[case("\u{2067}\u{200E}13\u{2069}", Ok(("13\u{2069}", "\u{2067}\u{200E}")))]
+    #[case("\u{202B}מחר\u{202C}", Ok(("מחר\u{202C}", "\u{202B}")))]
+    fn test_bidi_marks(#[case] input: &str, #[case] expected: IResult<&str, &str>) {
+        assert_eq!(bidi_marks(input), expected);
+    }
+
+    #[rstest]
+    #[case("СРЕДА 13".as_bytes(), Ok((&b" 13"[..], "СРЕДА")))]
+    #[case(b"today\xff", Ok((&b"\xff"[..], "today")))]
+    #[case(b"to\xffday", Err(nom::Err::Error(Error::Nom(&b"to\xffday"[..], ErrorKind::Tag))))]
+    #[case(b"\xff", Err(nom::Err::Error(Error::Nom(&b"\xff"[..], ErrorKind::Tag))))]
+    fn test_bytes(#[case] input: &[u8], #[case] expected: IResult<&[u8], &str>) {
+        let parser = tag_no_case("среда").or(tag_no_case("today"));
+
+        assert_eq!(bytes(parser)(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024", None)]
+    #[case("new year's eve", None)]
+    #[case("13\u{a0}July", Some("13 July"))]
+    #[case("13 \t July", Some("13 July"))]
+    #[case("new year\u{2019}s eve", Some("new year's eve"))]
+    #[case("13\u{2013}14 July", Some("13-14 July"))]
+    #[case("после\u{ad}завтра", Some("послезавтра"))]
+    #[case("за\u{301}втра", Some("завтра"))]
+    #[case("в четве\u{308}рг", Some("в четвёрг"))]
+    #[case("второи\u{306}", Some("второй"))]
+    fn test_normalize(#[case] input: &str, #[case] expected: Option<&str>) {
+        assert_eq!(
+            normalize(input).map(|(normalized, _)| normalized),
+            expected.map(String::from)
+        );
+    }
+
+    #[rstest]
+    #[case("new year\u{2019}s eve!", Ok(("!", "new year's eve")))]
+    #[case("NEW\u{a0}\u{a0}YEAR\u{2019}S EVE\u{a0}2024", Ok(("\u{a0}2024", "new year's eve")))]
+    #[case("to\u{ad}day", Ok(("", "today")))]
+    #[case(
+        "new year\u{2019}s",
+        Err(nom::Err::Error(Error::Nom("new year\u{2019}s", ErrorKind::Tag)))
+    )]
+    fn test_normalized(#[case] input: &str, #[case] expected: IResult<&str, &str>) {
+        fn phrase(input: &str) -> IResult<&str, &'static str> {
+            alt((
+                value("new year's eve", tag_no_case("new year's eve")),
+                value("today", tag_no_case("today")),
+            ))(input)
+        }
+
+        assert_eq!(normalized(phrase)(input), expected);
+    }
+}
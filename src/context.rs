@@ -0,0 +1,981 @@
+use alloc::sync::Arc;
+use core::fmt;
+
+#[cfg(feature = "clock")]
+use chrono::Local;
+use chrono::NaiveDate;
+use nom::{
+    character::complete::multispace0,
+    combinator::{eof, opt},
+    sequence::pair,
+    Parser,
+};
+
+use crate::{
+    calendar::{self, BusinessCalendar, Hemisphere, MonthOverflow, ResolutionPolicy},
+    error::Error,
+    parser::{BundleBuilder, ContextFn, ContextParser, FallbackChain},
+    time::trailing_time,
+    types::IResult,
+};
+
+/// The source of the reference date (`today`) used by the parsers which
+/// compute dates relative to the current one.
+///
+/// Without the `clock` feature only the fixed and custom clocks are available.
+#[derive(Clone)]
+#[cfg_attr(feature = "clock", derive(Default))]
+#[non_exhaustive]
+pub enum Clock {
+    /// Uses the current date in the [`Local`] time zone
+    #[cfg(feature = "clock")]
+    #[default]
+    Local,
+    /// Always uses the specified date
+    Fixed(NaiveDate),
+    /// Calls the specified function to obtain the reference date
+    Custom(Arc<dyn Fn() -> NaiveDate + Send + Sync>),
+}
+
+impl Clock {
+    /// Returns the reference date
+    pub fn today(&self) -> NaiveDate {
+        match self {
+            #[cfg(feature = "clock")]
+            Clock::Local => Local::now().date_naive(),
+            Clock::Fixed(date) => *date,
+            Clock::Custom(clock) => clock(),
+        }
+    }
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "clock")]
+            Clock::Local => f.write_str("Local"),
+            Clock::Fixed(date) => f.debug_tuple("Fixed").field(date).finish(),
+            Clock::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Describes what to do with the input remaining after the date recognized by
+/// the bundle parsers (e.g. the time in the `13/07/2024 14:00`).
+///
+/// To parse the time as well use the [`date_time`](crate::time::date_time)
+/// parser.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrailingInput {
+    /// Returns the remaining input as is
+    #[default]
+    Keep,
+    /// Returns an error if anything except whitespaces remains after the date
+    RequireEnd,
+    /// Skips the optional [`trailing_time`], then returns an error if
+    /// anything except whitespaces remains
+    IgnoreTime,
+}
+
+/// The separator of the numeric date parts, see [`SeparatorPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Separator {
+    /// The `/` symbol, e.g. `13/07/2024`
+    Slash,
+    /// The `-` symbol, e.g. `13-07-2024`
+    Dash,
+    /// The `.` symbol, e.g. `13.07.2024`
+    Dot,
+    /// Any number of spaces and tabs, e.g. `13 07 2024`
+    Space,
+}
+
+impl Separator {
+    /// Returns the bit of the separator in the [`SeparatorPolicy`] mask
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Describes which separators of the numeric date parts are accepted by the
+/// numeric `_with` parsers (e.g.
+/// [`dd_mm_y4_with`](crate::numeric::dd_mm_y4_with)) and whether both
+/// separators of the date must be the same.
+///
+/// By default all the [`Separator`]s are accepted and may be mixed within one
+/// date (e.g. `2024/06-13`), like the
+/// [`numeric_date_parts_separator`](crate::numeric::numeric_date_parts_separator)
+/// does.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::context::{Separator, SeparatorPolicy};
+///
+/// let policy = SeparatorPolicy::only(&[Separator::Slash, Separator::Dot]).with_consistency(true);
+///
+/// assert!(policy.allows(Separator::Dot));
+/// assert!(!policy.allows(Separator::Dash));
+/// assert!(policy.is_consistent());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeparatorPolicy {
+    allowed: u8,
+    consistent: bool,
+}
+
+impl Default for SeparatorPolicy {
+    fn default() -> Self {
+        Self::any()
+    }
+}
+
+impl SeparatorPolicy {
+    /// Accepts all the [`Separator`]s and allows to mix them
+    pub const fn any() -> Self {
+        Self::only(&[
+            Separator::Slash,
+            Separator::Dash,
+            Separator::Dot,
+            Separator::Space,
+        ])
+    }
+
+    /// Accepts only the specified [`Separator`]s and allows to mix them
+    pub const fn only(separators: &[Separator]) -> Self {
+        let mut allowed = 0;
+        let mut i = 0;
+        while i < separators.len() {
+            allowed |= separators[i].bit();
+            i += 1;
+        }
+
+        Self {
+            allowed,
+            consistent: false,
+        }
+    }
+
+    /// Specifies whether both separators of the date must be the same (e.g.
+    /// `2024/06-13` is rejected)
+    #[must_use]
+    pub const fn with_consistency(mut self, consistent: bool) -> Self {
+        self.consistent = consistent;
+        self
+    }
+
+    /// Returns `true` if the specified [`Separator`] is accepted
+    pub const fn allows(&self, separator: Separator) -> bool {
+        self.allowed & separator.bit() != 0
+    }
+
+    /// Returns `true` if both separators of the date must be the same
+    pub const fn is_consistent(&self) -> bool {
+        self.consistent
+    }
+}
+
+/// The range of the four-digit years accepted by the [`y4`](crate::numeric::y4)
+/// parser and the parsers built on it, the years out of the range are rejected
+/// with [`Error::YearOutOfRange`].
+///
+/// By default the years `1900..=2100` are accepted, since the years like
+/// `0000` are hardly ever intended in the user input. The context-free parsers
+/// (e.g. [`dd_mm_y4`](crate::numeric::dd_mm_y4)) always use the default range,
+/// the `_with` parsers (e.g. [`dd_mm_y4_with`](crate::numeric::dd_mm_y4_with))
+/// use the range of the [`ParserContext`], see
+/// [`ParserContext::with_year_range`].
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::context::YearRange;
+///
+/// assert!(YearRange::default().contains(2024));
+/// assert!(!YearRange::default().contains(42));
+/// assert!(YearRange::any().contains(42));
+/// assert!(!YearRange::new(2000, 2099).contains(1999));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearRange {
+    first: u32,
+    last: u32,
+}
+
+impl Default for YearRange {
+    fn default() -> Self {
+        Self::new(1900, 2100)
+    }
+}
+
+impl YearRange {
+    /// Accepts the years from `first` to `last` inclusive
+    pub const fn new(first: u32, last: u32) -> Self {
+        Self { first, last }
+    }
+
+    /// Accepts every four-digit year, i.e. `0000..=9999`
+    pub const fn any() -> Self {
+        Self::new(0, 9999)
+    }
+
+    /// Returns the first accepted year
+    pub const fn first(&self) -> u32 {
+        self.first
+    }
+
+    /// Returns the last accepted year
+    pub const fn last(&self) -> u32 {
+        self.last
+    }
+
+    /// Returns `true` if the specified `year` is accepted
+    pub const fn contains(&self, year: u32) -> bool {
+        self.first <= year && year <= self.last
+    }
+}
+
+/// The default century pivot of the two-digit years, see
+/// [`ParserContext::with_century_pivot`].
+pub const DEFAULT_CENTURY_PIVOT: u8 = 70;
+
+/// Returns the full year for the specified two-digit year according to the
+/// specified century pivot, see [`ParserContext::with_century_pivot`].
+pub(crate) fn full_year(y2: u32, century_pivot: u8) -> i32 {
+    if y2 >= century_pivot as u32 {
+        1900 + y2 as i32
+    } else {
+        2000 + y2 as i32
+    }
+}
+
+/// The context of the parsers which compute dates relative to the current one
+/// (e.g. [`dd_only`](crate::numeric::dd_only) or
+/// [`forward_from_now`](crate::quick::forward_from_now)).
+///
+/// By default the current date in the [`Local`] time zone is used as `today`,
+/// but it can be replaced with a fixed date or an arbitrary [`Clock`], so the
+/// results of the parsers become deterministic.
+///
+/// Every such parser has the `_with` counterpart (e.g.
+/// [`dd_only_with`](crate::numeric::dd_only_with)) which creates the parser
+/// bound to the specified context. The most commonly used parsers are also
+/// available as methods.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, i18n::en};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     ctx.dd_only("13")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     en::bundle_dmy_with(&ctx)("tomorrow")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParserContext {
+    clock: Clock,
+    trailing_input: TrailingInput,
+    century_pivot: u8,
+    max_input_len: Option<usize>,
+    month_overflow: MonthOverflow,
+    separators: SeparatorPolicy,
+    resolution: ResolutionPolicy,
+    hemisphere: Hemisphere,
+    business_calendar: BusinessCalendar,
+    weekday_prefixes: bool,
+    year_range: YearRange,
+}
+
+#[cfg(feature = "clock")]
+impl Default for ParserContext {
+    fn default() -> Self {
+        Self::with_defaults(Clock::default())
+    }
+}
+
+impl ParserContext {
+    /// Creates the context which uses the current date in the [`Local`] time
+    /// zone as `today`
+    #[cfg(feature = "clock")]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the context which uses the specified date as `today`, the
+    /// other settings are default.
+    ///
+    /// Unlike [`ParserContext::new`], is available without the `clock`
+    /// feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::context::ParserContext;
+    ///
+    /// let today = NaiveDate::from_ymd_opt(2024, 7, 16).unwrap();
+    ///
+    /// assert_eq!(ParserContext::fixed(today).today(), today);
+    /// ```
+    pub fn fixed(today: NaiveDate) -> Self {
+        Self::with_defaults(Clock::Fixed(today))
+    }
+
+    fn with_defaults(clock: Clock) -> Self {
+        Self {
+            clock,
+            trailing_input: TrailingInput::default(),
+            century_pivot: DEFAULT_CENTURY_PIVOT,
+            max_input_len: None,
+            month_overflow: MonthOverflow::default(),
+            separators: SeparatorPolicy::default(),
+            resolution: ResolutionPolicy::default(),
+            hemisphere: Hemisphere::default(),
+            business_calendar: BusinessCalendar::default(),
+            weekday_prefixes: false,
+            year_range: YearRange::default(),
+        }
+    }
+
+    /// Uses the specified date as `today`
+    #[must_use]
+    pub fn with_today(self, today: NaiveDate) -> Self {
+        self.with_clock(Clock::Fixed(today))
+    }
+
+    /// Uses the specified [`Clock`] to obtain `today`
+    #[must_use]
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Uses the specified function to obtain `today`
+    #[must_use]
+    pub fn with_clock_fn(self, clock: impl Fn() -> NaiveDate + Send + Sync + 'static) -> Self {
+        self.with_clock(Clock::Custom(Arc::new(clock)))
+    }
+
+    /// Specifies what the bundle parsers do with the input remaining after the
+    /// date
+    #[must_use]
+    pub fn with_trailing_input(mut self, trailing_input: TrailingInput) -> Self {
+        self.trailing_input = trailing_input;
+        self
+    }
+
+    /// Specifies the century pivot of the two-digit years: the years from
+    /// `pivot` to `99` belong to the `20th` century (`19xx`), while the years
+    /// less than `pivot` belong to the `21st` century (`20xx`).
+    ///
+    /// The default pivot is [`DEFAULT_CENTURY_PIVOT`], so the two-digit years
+    /// are mapped onto the `1970..=2069` range.
+    #[must_use]
+    pub fn with_century_pivot(mut self, century_pivot: u8) -> Self {
+        self.century_pivot = century_pivot;
+        self
+    }
+
+    /// Limits the length of the input (in bytes) accepted by the bundle
+    /// parsers: the longer input is rejected with [`Error::InputTooLong`]
+    /// before parsing. By default the length is unlimited.
+    ///
+    /// Protects the services which pass untrusted input directly to the
+    /// parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nom_date_parsers::{context::ParserContext, error::Error, i18n::en};
+    ///
+    /// let ctx = ParserContext::new().with_max_input_len(16);
+    ///
+    /// assert!(en::bundle_dmy_with(&ctx)("13/07/2024").is_ok());
+    /// assert_eq!(
+    ///     en::bundle_dmy_with(&ctx)(&"13/07/2024 ".repeat(1000)),
+    ///     Err(nom::Err::Error(Error::InputTooLong))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_max_input_len(mut self, max_input_len: usize) -> Self {
+        self.max_input_len = Some(max_input_len);
+        self
+    }
+
+    /// Specifies how the relative parsers (e.g. `+1m` or `in a month`) handle
+    /// the day which doesn't exist in the resulting month, see
+    /// [`MonthOverflow`]. By default the day is clamped to the last day of
+    /// the month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom::{bytes::complete::tag, combinator::value};
+    /// use nom_date_parsers::{
+    ///     calendar::{MonthOverflow, Period},
+    ///     context::ParserContext,
+    ///     i18n::relative_duration,
+    /// };
+    ///
+    /// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+    /// let in_a_month = |ctx| relative_duration(ctx, 1, value((1, Period::Month), tag("+1m")));
+    ///
+    /// assert_eq!(
+    ///     in_a_month(&ctx)("+1m")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+    /// );
+    ///
+    /// let roll = ctx.clone().with_month_overflow(MonthOverflow::Roll);
+    ///
+    /// assert_eq!(
+    ///     in_a_month(&roll)("+1m")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 3, 2).unwrap()
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_month_overflow(mut self, month_overflow: MonthOverflow) -> Self {
+        self.month_overflow = month_overflow;
+        self
+    }
+
+    /// Specifies the separators of the numeric date parts accepted by the
+    /// numeric `_with` parsers, see [`SeparatorPolicy`]. By default all the
+    /// separators are accepted and may be mixed within one date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::{
+    ///     context::{ParserContext, Separator, SeparatorPolicy},
+    ///     numeric::dd_mm_y4_with,
+    /// };
+    ///
+    /// let ctx = ParserContext::new()
+    ///     .with_separators(SeparatorPolicy::only(&[Separator::Dot]).with_consistency(true));
+    ///
+    /// assert_eq!(
+    ///     dd_mm_y4_with(&ctx)("13.07.2024")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+    /// );
+    /// assert!(dd_mm_y4_with(&ctx)("13/07/2024").is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_separators(mut self, separators: SeparatorPolicy) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Specifies how the partial dates without the `year` or the `month` part
+    /// (e.g. `05` or `05/01`) are completed, see [`ResolutionPolicy`]. By
+    /// default the missing parts are taken from the reference date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::{
+    ///     calendar::ResolutionPolicy, context::ParserContext, numeric::dd_only_with,
+    /// };
+    ///
+    /// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 20).unwrap());
+    ///
+    /// assert_eq!(
+    ///     dd_only_with(&ctx)("05")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 7, 5).unwrap()
+    /// );
+    ///
+    /// let future = ctx.clone().with_resolution(ResolutionPolicy::NearestFuture);
+    ///
+    /// assert_eq!(
+    ///     dd_only_with(&future)("05")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 8, 5).unwrap()
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_resolution(mut self, resolution: ResolutionPolicy) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Specifies the [`Hemisphere`] which determines the months of the seasons
+    /// recognized by the season parsers (e.g. `next summer`). By default the
+    /// seasons of the northern hemisphere are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::{calendar::Hemisphere, context::ParserContext, i18n::en};
+    ///
+    /// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+    ///
+    /// assert_eq!(
+    ///     en::season_with(&ctx)("next summer")?.1,
+    ///     NaiveDate::from_ymd_opt(2025, 6, 1).unwrap()
+    /// );
+    ///
+    /// let southern = ctx.clone().with_hemisphere(Hemisphere::Southern);
+    ///
+    /// assert_eq!(
+    ///     en::season_with(&southern)("next summer")?.1,
+    ///     NaiveDate::from_ymd_opt(2025, 12, 1).unwrap()
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_hemisphere(mut self, hemisphere: Hemisphere) -> Self {
+        self.hemisphere = hemisphere;
+        self
+    }
+
+    /// Specifies the [`BusinessCalendar`] which determines the holidays skipped
+    /// by the business day parsers (e.g. `+5bd` or `next business day`). By
+    /// default only the weekends are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::{calendar::BusinessCalendar, context::ParserContext, quick};
+    ///
+    /// let ctx = ParserContext::new()
+    ///     .with_today(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())
+    ///     .with_business_calendar(
+    ///         BusinessCalendar::new().with_holiday(NaiveDate::from_ymd_opt(2024, 7, 22).unwrap()),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     quick::bundle_with(&ctx)("+1bd")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 7, 23).unwrap()
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_business_calendar(mut self, business_calendar: BusinessCalendar) -> Self {
+        self.business_calendar = business_calendar;
+        self
+    }
+
+    /// Enables the recognition of the weekdays abbreviated to any unambiguous
+    /// prefix of at least two letters (e.g. `thurs.`, `tu`, `weds`, `воскр` or
+    /// `суб`) by the weekday parsers of the context (e.g.
+    /// [`en::current_named_weekday_only_with`](crate::i18n::en::current_named_weekday_only_with)
+    /// or [`ru::next_named_weekday_with`](crate::i18n::ru::next_named_weekday_with)),
+    /// see [`en::named_weekday_prefix`](crate::i18n::en::named_weekday_prefix).
+    /// Disabled by default, since the short words (e.g. `we` or `sat`) are
+    /// more likely to be mistaken for the weekdays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::{context::ParserContext, i18n::en};
+    ///
+    /// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+    ///
+    /// assert!(en::next_named_weekday_with(&ctx)("next tu").is_err());
+    ///
+    /// let ctx = ctx.with_weekday_prefixes(true);
+    ///
+    /// assert_eq!(
+    ///     en::next_named_weekday_with(&ctx)("next tu")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 7, 23).unwrap()
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_weekday_prefixes(mut self, weekday_prefixes: bool) -> Self {
+        self.weekday_prefixes = weekday_prefixes;
+        self
+    }
+
+    /// Uses the specified [`YearRange`] to validate the four-digit years
+    /// recognized by the `_with` parsers (e.g.
+    /// [`dd_mm_y4_with`](crate::numeric::dd_mm_y4_with) or
+    /// [`dmy_with`](crate::numeric::dmy_with)), the years out of the range are
+    /// rejected with [`Error::YearOutOfRange`]. The default range is
+    /// `1900..=2100`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::{
+    ///     context::{ParserContext, YearRange},
+    ///     error::Error,
+    ///     numeric::dd_mm_y4_with,
+    /// };
+    ///
+    /// let ctx = ParserContext::new();
+    ///
+    /// assert_eq!(
+    ///     dd_mm_y4_with(&ctx)("13/07/1812"),
+    ///     Err(nom::Err::Error(Error::YearOutOfRange {
+    ///         value: 1812,
+    ///         input: "1812"
+    ///     }))
+    /// );
+    ///
+    /// let ctx = ctx.with_year_range(YearRange::new(1800, 2100));
+    ///
+    /// assert_eq!(
+    ///     dd_mm_y4_with(&ctx)("13/07/1812")?.1,
+    ///     NaiveDate::from_ymd_opt(1812, 7, 13).unwrap()
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_year_range(mut self, year_range: YearRange) -> Self {
+        self.year_range = year_range;
+        self
+    }
+
+    /// Creates the reusable [`ContextParser`] which owns the context and
+    /// recognizes the date using the specified function
+    pub fn build<O>(self, parser: ContextFn<O>) -> ContextParser<O> {
+        ContextParser::new(self, parser)
+    }
+
+    /// Creates the [`FallbackChain`] which owns the context, the tiers of the
+    /// parsers are added with the [`FallbackChain::tier`]
+    pub fn build_chain<O>(self) -> FallbackChain<O> {
+        FallbackChain::new(self)
+    }
+
+    /// Creates the [`BundleBuilder`] which owns the context, the component
+    /// parsers are added with the [`BundleBuilder::with`]
+    pub fn bundle_builder<O>(self) -> BundleBuilder<O> {
+        BundleBuilder::new(self)
+    }
+
+    /// Returns the [`Clock`] of the context
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the reference date of the context
+    pub fn today(&self) -> NaiveDate {
+        self.clock.today()
+    }
+
+    /// Returns the [`TrailingInput`] mode of the context
+    pub fn trailing_input(&self) -> TrailingInput {
+        self.trailing_input
+    }
+
+    /// Returns the century pivot of the two-digit years
+    pub fn century_pivot(&self) -> u8 {
+        self.century_pivot
+    }
+
+    /// Returns the maximum length of the input (in bytes), `None` means
+    /// unlimited
+    pub fn max_input_len(&self) -> Option<usize> {
+        self.max_input_len
+    }
+
+    /// Returns the [`MonthOverflow`] policy of the context
+    pub fn month_overflow(&self) -> MonthOverflow {
+        self.month_overflow
+    }
+
+    /// Returns the [`SeparatorPolicy`] of the context
+    pub fn separators(&self) -> SeparatorPolicy {
+        self.separators
+    }
+
+    /// Returns the [`ResolutionPolicy`] of the context
+    pub fn resolution(&self) -> ResolutionPolicy {
+        self.resolution
+    }
+
+    /// Returns the [`Hemisphere`] of the context
+    pub fn hemisphere(&self) -> Hemisphere {
+        self.hemisphere
+    }
+
+    /// Returns the [`BusinessCalendar`] of the context
+    pub fn business_calendar(&self) -> &BusinessCalendar {
+        &self.business_calendar
+    }
+
+    /// Returns whether the weekdays abbreviated to the unambiguous prefixes are
+    /// recognized
+    pub fn weekday_prefixes(&self) -> bool {
+        self.weekday_prefixes
+    }
+
+    /// Returns the [`YearRange`] of the context
+    pub fn year_range(&self) -> YearRange {
+        self.year_range
+    }
+
+    /// Completes the partial date with the specified `day` and the optional
+    /// `month` parts according to the [`ResolutionPolicy`] of the context, see
+    /// [`calendar::resolve`]
+    pub fn resolve(&self, month: Option<u32>, day: u32) -> Option<NaiveDate> {
+        calendar::resolve(self.today(), month, day, self.resolution)
+    }
+
+    /// Returns the full year for the specified two-digit year according to the
+    /// century pivot of the context
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nom_date_parsers::context::ParserContext;
+    ///
+    /// assert_eq!(ParserContext::new().full_year(24), 2024);
+    /// assert_eq!(ParserContext::new().full_year(70), 1970);
+    /// assert_eq!(
+    ///     ParserContext::new().with_century_pivot(30).full_year(42),
+    ///     1942
+    /// );
+    /// ```
+    pub fn full_year(&self, y2: u32) -> i32 {
+        full_year(y2, self.century_pivot)
+    }
+
+    /// Creates a parser which recognizes the date (or the date and time) using
+    /// the specified `parser` and handles the remaining input according to the
+    /// [`TrailingInput`] mode of the context. The leading whitespaces are
+    /// skipped, so the bundle parsers recognize the untrimmed input (e.g.
+    /// ` tomorrow`), the trailing ones are skipped by the
+    /// [`TrailingInput::RequireEnd`] and [`TrailingInput::IgnoreTime`] modes.
+    ///
+    /// Returns [`Error::InputTooLong`] without running the `parser` if the
+    /// input exceeds the [`max_input_len`](ParserContext::max_input_len).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom::error::ErrorKind;
+    /// use nom_date_parsers::{
+    ///     context::{ParserContext, TrailingInput},
+    ///     error::Error,
+    ///     numeric::dd_mm_y4,
+    /// };
+    ///
+    /// let ctx = ParserContext::new().with_trailing_input(TrailingInput::IgnoreTime);
+    ///
+    /// assert_eq!(
+    ///     ctx.trailing(dd_mm_y4)("13/07/2024 14:00")?,
+    ///     ("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     ctx.trailing(dd_mm_y4)("13/07/2024 later"),
+    ///     Err(nom::Err::Error(Error::Nom("later", ErrorKind::Eof)))
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn trailing<'a, 'c, O, P>(
+        &'c self,
+        mut parser: P,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, O> + 'c
+    where
+        P: Parser<&'a str, O, Error<&'a str>> + 'c,
+    {
+        move |input: &'a str| {
+            if self
+                .max_input_len
+                .is_some_and(|max_input_len| input.len() > max_input_len)
+            {
+                return Err(nom::Err::Error(Error::InputTooLong));
+            }
+            let (input, _) = multispace0(input)?;
+            let (input, date) = parser.parse(input)?;
+
+            let input = match self.trailing_input {
+                TrailingInput::Keep => input,
+                TrailingInput::RequireEnd => pair(multispace0, eof)(input)?.0,
+                TrailingInput::IgnoreTime => {
+                    let (input, _) = opt(trailing_time)(input)?;
+                    pair(multispace0, eof)(input)?.0
+                }
+            };
+
+            Ok((input, date))
+        }
+    }
+
+    /// Same as [`numeric::dd_only`](crate::numeric::dd_only), but uses the
+    /// context
+    #[cfg(feature = "numeric")]
+    pub fn dd_only<'a>(&self, input: &'a str) -> IResult<&'a str, NaiveDate> {
+        crate::numeric::dd_only_with(self)(input)
+    }
+
+    /// Same as [`numeric::dd_mm_only`](crate::numeric::dd_mm_only), but uses
+    /// the context
+    #[cfg(feature = "numeric")]
+    pub fn dd_mm_only<'a>(&self, input: &'a str) -> IResult<&'a str, NaiveDate> {
+        crate::numeric::dd_mm_only_with(self)(input)
+    }
+
+    /// Same as [`numeric::mm_dd_only`](crate::numeric::mm_dd_only), but uses
+    /// the context
+    #[cfg(feature = "numeric")]
+    pub fn mm_dd_only<'a>(&self, input: &'a str) -> IResult<&'a str, NaiveDate> {
+        crate::numeric::mm_dd_only_with(self)(input)
+    }
+
+    /// Same as [`quick::forward_from_now`](crate::quick::forward_from_now),
+    /// but uses the context
+    #[cfg(feature = "quick")]
+    pub fn forward_from_now<'a>(&self, input: &'a str) -> IResult<&'a str, NaiveDate> {
+        crate::quick::forward_from_now_with(self)(input)
+    }
+
+    /// Same as [`quick::backward_from_now`](crate::quick::backward_from_now),
+    /// but uses the context
+    #[cfg(feature = "quick")]
+    pub fn backward_from_now<'a>(&self, input: &'a str) -> IResult<&'a str, NaiveDate> {
+        crate::quick::backward_from_now_with(self)(input)
+    }
+
+    /// Same as [`quick::bundle`](crate::quick::bundle), but uses the context
+    #[cfg(feature = "quick")]
+    pub fn quick_bundle<'a>(&self, input: &'a str) -> IResult<&'a str, NaiveDate> {
+        crate::quick::bundle_with(self)(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_clock() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 16).unwrap();
+
+        assert_eq!(Clock::Local.today(), Local::now().date_naive());
+        assert_eq!(Clock::Fixed(date).today(), date);
+        assert_eq!(Clock::Custom(Arc::new(move || date)).today(), date);
+    }
+
+    #[test]
+    fn test_parser_context() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 16).unwrap();
+
+        assert_eq!(ParserContext::new().today(), Local::now().date_naive());
+        assert_eq!(ParserContext::new().with_today(date).today(), date);
+        assert_eq!(
+            ParserContext::new().with_clock_fn(move || date).today(),
+            date
+        );
+    }
+
+    #[test]
+    fn test_separator_policy() {
+        let policy = SeparatorPolicy::only(&[Separator::Slash, Separator::Space]);
+
+        assert!(policy.allows(Separator::Slash));
+        assert!(policy.allows(Separator::Space));
+        assert!(!policy.allows(Separator::Dash));
+        assert!(!policy.allows(Separator::Dot));
+        assert!(!policy.is_consistent());
+        assert!(policy.with_consistency(true).is_consistent());
+        assert_eq!(SeparatorPolicy::default(), SeparatorPolicy::any());
+        assert!(!SeparatorPolicy::only(&[]).allows(Separator::Slash));
+    }
+
+    #[rstest::rstest]
+    #[case(DEFAULT_CENTURY_PIVOT, 0, 2000)]
+    #[case(DEFAULT_CENTURY_PIVOT, 69, 2069)]
+    #[case(DEFAULT_CENTURY_PIVOT, 70, 1970)]
+    #[case(DEFAULT_CENTURY_PIVOT, 99, 1999)]
+    #[case(0, 0, 1900)]
+    #[case(100, 99, 2099)]
+    fn test_full_year(#[case] century_pivot: u8, #[case] y2: u32, #[case] expected: i32) {
+        assert_eq!(
+            ParserContext::new()
+                .with_century_pivot(century_pivot)
+                .full_year(y2),
+            expected
+        );
+    }
+
+    #[cfg(feature = "numeric")]
+    #[rstest::rstest]
+    #[case(TrailingInput::Keep, "13/07/2024", Ok(("", 13)))]
+    #[case(TrailingInput::Keep, "13/07/2024 14:00", Ok((" 14:00", 13)))]
+    #[case(TrailingInput::Keep, " 13/07/2024\n", Ok(("\n", 13)))]
+    #[case(TrailingInput::RequireEnd, "13/07/2024 ", Ok(("", 13)))]
+    #[case(TrailingInput::RequireEnd, "\t13/07/2024", Ok(("", 13)))]
+    #[case(
+        TrailingInput::RequireEnd,
+        "13/07/2024 14:00",
+        Err(nom::Err::Error(Error::Nom("14:00", nom::error::ErrorKind::Eof)))
+    )]
+    #[case(TrailingInput::IgnoreTime, "13/07/2024", Ok(("", 13)))]
+    #[case(TrailingInput::IgnoreTime, "13/07/2024 14:00", Ok(("", 13)))]
+    #[case(TrailingInput::IgnoreTime, "13/07/2024T14:00:30", Ok(("", 13)))]
+    #[case(
+        TrailingInput::IgnoreTime,
+        "13/07/2024 14:00 later",
+        Err(nom::Err::Error(Error::Nom("later", nom::error::ErrorKind::Eof)))
+    )]
+    fn test_trailing(
+        #[case] trailing_input: TrailingInput,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, u32>,
+    ) {
+        use chrono::Datelike;
+
+        let ctx = ParserContext::new().with_trailing_input(trailing_input);
+
+        assert_eq!(
+            ctx.trailing(crate::numeric::dd_mm_y4)(input).map(|(input, date)| (input, date.day())),
+            expected
+        );
+    }
+
+    #[cfg(feature = "numeric")]
+    #[rstest::rstest]
+    #[case(None, "13/07/2024", Ok(("", 13)))]
+    #[case(Some(10), "13/07/2024", Ok(("", 13)))]
+    #[case(Some(9), "13/07/2024", Err(nom::Err::Error(Error::InputTooLong)))]
+    #[case(
+        Some(0),
+        "",
+        Err(nom::Err::Error(Error::Context(
+            "day",
+            Box::new(Error::Nom("", nom::error::ErrorKind::Eof))
+        )))
+    )]
+    fn test_trailing_max_input_len(
+        #[case] max_input_len: Option<usize>,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, u32>,
+    ) {
+        use chrono::Datelike;
+
+        let ctx = match max_input_len {
+            Some(max_input_len) => ParserContext::new().with_max_input_len(max_input_len),
+            None => ParserContext::new(),
+        };
+
+        assert_eq!(ctx.max_input_len(), max_input_len);
+        assert_eq!(
+            ctx.trailing(crate::numeric::dd_mm_y4)(input).map(|(input, date)| (input, date.day())),
+            expected
+        );
+    }
+}
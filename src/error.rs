@@ -1,29 +1,583 @@
-use std::num::ParseIntError;
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::{fmt, num::ParseIntError};
 
-use nom::error::{ErrorKind, FromExternalError, ParseError};
+use nom::error::{ContextError, ErrorKind, FromExternalError, ParseError};
 
 #[derive(Debug, PartialEq)]
 pub enum Error<I> {
-    DayOutOfRange,
-    MonthOutOfRange,
+    /// The `day` is out of the range `1..=31`, the `input` starts with the
+    /// offending value
+    DayOutOfRange {
+        value: u32,
+        input: I,
+    },
+    /// The `month` is out of the range `1..=12`, the `input` starts with the
+    /// offending value
+    MonthOutOfRange {
+        value: u32,
+        input: I,
+    },
+    /// The `year` is out of the [`YearRange`](crate::context::YearRange) of
+    /// the parser, the `input` starts with the offending value
+    YearOutOfRange {
+        value: u32,
+        input: I,
+    },
+    WeekOutOfRange,
+    DayOfYearOutOfRange,
     NonExistentDate,
+    DayOutOfMonth {
+        year: i32,
+        month: u32,
+        day: u32,
+    },
+    DateOutOfRange,
+    WeekdayMismatch,
+    ReversedRange,
+    HourOutOfRange,
+    MinuteOutOfRange,
+    SecondOutOfRange,
+    OffsetOutOfRange,
+    InputTooLong,
     ParseIntError(I, ErrorKind, ParseIntError),
+    /// The generic parsing error which occurred while parsing the named
+    /// component of the date (e.g. `day` or `weekday`), see [`ContextError`]
+    Context(&'static str, Box<Error<I>>),
 
     Nom(I, ErrorKind),
 }
 
+impl<'i> Error<&'i str> {
+    /// Converts the error into the one which owns the input, so it can outlive
+    /// the input buffer (e.g. to be returned from the function which reads the
+    /// input or to be boxed into `Box<dyn std::error::Error>`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nom::error::ErrorKind;
+    /// use nom_date_parsers::error::Error;
+    ///
+    /// let input = String::from("later");
+    /// let error = Error::Nom(input.as_str(), ErrorKind::Tag).to_owned();
+    /// drop(input);
+    ///
+    /// assert_eq!(error, Error::Nom(String::from("later"), ErrorKind::Tag));
+    /// ```
+    pub fn to_owned(&self) -> Error<String> {
+        match self {
+            Error::DayOutOfRange { value, input } => Error::DayOutOfRange {
+                value: *value,
+                input: input.to_string(),
+            },
+            Error::MonthOutOfRange { value, input } => Error::MonthOutOfRange {
+                value: *value,
+                input: input.to_string(),
+            },
+            Error::YearOutOfRange { value, input } => Error::YearOutOfRange {
+                value: *value,
+                input: input.to_string(),
+            },
+            Error::WeekOutOfRange => Error::WeekOutOfRange,
+            Error::DayOfYearOutOfRange => Error::DayOfYearOutOfRange,
+            Error::NonExistentDate => Error::NonExistentDate,
+            Error::DayOutOfMonth { year, month, day } => Error::DayOutOfMonth {
+                year: *year,
+                month: *month,
+                day: *day,
+            },
+            Error::DateOutOfRange => Error::DateOutOfRange,
+            Error::WeekdayMismatch => Error::WeekdayMismatch,
+            Error::ReversedRange => Error::ReversedRange,
+            Error::HourOutOfRange => Error::HourOutOfRange,
+            Error::MinuteOutOfRange => Error::MinuteOutOfRange,
+            Error::SecondOutOfRange => Error::SecondOutOfRange,
+            Error::OffsetOutOfRange => Error::OffsetOutOfRange,
+            Error::InputTooLong => Error::InputTooLong,
+            Error::ParseIntError(input, kind, e) => {
+                Error::ParseIntError(input.to_string(), *kind, e.clone())
+            }
+            Error::Context(context, e) => Error::Context(context, Box::new(Error::to_owned(e))),
+            Error::Nom(input, kind) => Error::Nom(input.to_string(), *kind),
+        }
+    }
+
+    /// Returns the byte offset of the remaining input of the error in the
+    /// specified `input` passed to the parser, or `None` if the error doesn't
+    /// carry the remaining input (e.g. [`Error::NonExistentDate`]) or it isn't
+    /// the end of the `input`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nom_date_parsers::numeric::dd_mm_y4;
+    ///
+    /// let input = "13/ab/2024";
+    /// let Err(nom::Err::Error(error)) = dd_mm_y4(input) else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// assert_eq!(error.offset_in(input), Some(3));
+    /// ```
+    pub fn offset_in(&self, input: &str) -> Option<usize> {
+        let remaining = self.remaining()?;
+
+        input
+            .len()
+            .checked_sub(remaining.len())
+            .filter(|&offset| input.get(offset..) == Some(*remaining))
+    }
+
+    /// Attaches the byte offset of the remaining input of the error in the
+    /// specified `input` passed to the parser, see [`Error::offset_in`], so
+    /// the error is displayed with its position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nom_date_parsers::numeric::dd_mm_y4;
+    ///
+    /// let input = "13/07/0042";
+    /// let Err(nom::Err::Error(error)) = dd_mm_y4(input) else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     error.located(input).to_string(),
+    ///     "the year 42 is out of the accepted range at `0042` (offset 6)"
+    /// );
+    /// ```
+    pub fn located(self, input: &str) -> Located<'i> {
+        Located {
+            offset: self.offset_in(input),
+            error: self,
+        }
+    }
+}
+
+/// The maximum number of the characters of the remaining input shown by the
+/// [`Display`](fmt::Display) implementation of the [`Error`], the longer
+/// input is truncated.
+const SHOWN_INPUT_CHARS: usize = 20;
+
+/// Displays the remaining input of the [`Error`] in the backticks truncated to
+/// the [`SHOWN_INPUT_CHARS`] characters.
+struct Shown<'a, I>(&'a I);
+
+impl<I: fmt::Display> fmt::Display for Shown<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = self.0.to_string();
+
+        match input.char_indices().nth(SHOWN_INPUT_CHARS) {
+            Some((end, _)) => write!(f, "`{}…`", &input[..end]),
+            None => write!(f, "`{input}`"),
+        }
+    }
+}
+
+/// The [`Error`] with the byte offset of its remaining input in the input
+/// passed to the parser, see [`Error::located`].
+#[derive(Debug, PartialEq)]
+pub struct Located<'a> {
+    pub error: Error<&'a str>,
+    /// The byte offset of the remaining input of the error, `None` if the
+    /// error doesn't carry the remaining input
+    pub offset: Option<usize>,
+}
+
+impl fmt::Display for Located<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.offset {
+            Some(offset) => write!(f, "{} (offset {offset})", self.error),
+            None => self.error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Located<'_> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Error<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DayOutOfRange { value, input } => {
+                write!(
+                    f,
+                    "the day {value} is out of the range 1..=31 at {}",
+                    Shown(input)
+                )
+            }
+            Error::MonthOutOfRange { value, input } => {
+                write!(
+                    f,
+                    "the month {value} is out of the range 1..=12 at {}",
+                    Shown(input)
+                )
+            }
+            Error::YearOutOfRange { value, input } => {
+                write!(
+                    f,
+                    "the year {value} is out of the accepted range at {}",
+                    Shown(input)
+                )
+            }
+            Error::WeekOutOfRange => f.write_str("the week is out of the range 1..=53"),
+            Error::DayOfYearOutOfRange => {
+                f.write_str("the day of the year is out of the range 1..=366")
+            }
+            Error::NonExistentDate => f.write_str("the date doesn't exist"),
+            Error::DayOutOfMonth { year, month, day } => {
+                write!(f, "the day {day} doesn't exist in {year}-{month:02}")
+            }
+            Error::DateOutOfRange => f.write_str("the date is out of the supported range"),
+            Error::WeekdayMismatch => f.write_str("the weekday doesn't match the date"),
+            Error::ReversedRange => f.write_str("the end of the range precedes its start"),
+            Error::HourOutOfRange => f.write_str("the hour is out of the range 0..=23"),
+            Error::MinuteOutOfRange => f.write_str("the minute is out of the range 0..=59"),
+            Error::SecondOutOfRange => f.write_str("the second is out of the range 0..=59"),
+            Error::OffsetOutOfRange => f.write_str("the UTC offset is out of range"),
+            Error::InputTooLong => f.write_str("the input is too long"),
+            Error::ParseIntError(input, _, e) => {
+                write!(f, "invalid number at {}: {e}", Shown(input))
+            }
+            Error::Context(context, e) => write!(f, "{e} while parsing the {context}"),
+            Error::Nom(input, kind) => {
+                write!(
+                    f,
+                    "unexpected input at {} ({})",
+                    Shown(input),
+                    kind.description()
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: fmt::Debug + fmt::Display> std::error::Error for Error<I> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ParseIntError(_, _, e) => Some(e),
+            Error::Context(_, e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+impl<I> Error<I> {
+    /// Converts the input of the error with the specified function, e.g. maps
+    /// the `&str` input onto the corresponding part of the `&[u8]` buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nom::error::ErrorKind;
+    /// use nom_date_parsers::error::Error;
+    ///
+    /// assert_eq!(
+    ///     Error::Nom("later", ErrorKind::Tag).map_input(str::len),
+    ///     Error::Nom(5, ErrorKind::Tag)
+    /// );
+    /// ```
+    pub fn map_input<J>(self, f: impl FnOnce(I) -> J) -> Error<J> {
+        match self {
+            Error::DayOutOfRange { value, input } => Error::DayOutOfRange {
+                value,
+                input: f(input),
+            },
+            Error::MonthOutOfRange { value, input } => Error::MonthOutOfRange {
+                value,
+                input: f(input),
+            },
+            Error::YearOutOfRange { value, input } => Error::YearOutOfRange {
+                value,
+                input: f(input),
+            },
+            Error::WeekOutOfRange => Error::WeekOutOfRange,
+            Error::DayOfYearOutOfRange => Error::DayOfYearOutOfRange,
+            Error::NonExistentDate => Error::NonExistentDate,
+            Error::DayOutOfMonth { year, month, day } => Error::DayOutOfMonth { year, month, day },
+            Error::DateOutOfRange => Error::DateOutOfRange,
+            Error::WeekdayMismatch => Error::WeekdayMismatch,
+            Error::ReversedRange => Error::ReversedRange,
+            Error::HourOutOfRange => Error::HourOutOfRange,
+            Error::MinuteOutOfRange => Error::MinuteOutOfRange,
+            Error::SecondOutOfRange => Error::SecondOutOfRange,
+            Error::OffsetOutOfRange => Error::OffsetOutOfRange,
+            Error::InputTooLong => Error::InputTooLong,
+            Error::ParseIntError(input, kind, e) => Error::ParseIntError(f(input), kind, e),
+            Error::Context(context, e) => Error::Context(context, Box::new(e.map_input(f))),
+            Error::Nom(input, kind) => Error::Nom(f(input), kind),
+        }
+    }
+
+    /// Returns the name of the date component (e.g. `day` or `weekday`) which
+    /// was being parsed when the generic parsing error occurred, see
+    /// [`ContextError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nom_date_parsers::numeric::dd_mm_y4;
+    ///
+    /// let Err(nom::Err::Error(error)) = dd_mm_y4("13/ab/2024") else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// assert_eq!(error.context(), Some("month"));
+    /// ```
+    pub fn context(&self) -> Option<&'static str> {
+        match self {
+            Error::Context(context, _) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns the remaining input of the error, including the one of the
+    /// error wrapped into the [`Error::Context`].
+    fn remaining(&self) -> Option<&I> {
+        match self {
+            Error::DayOutOfRange { input, .. }
+            | Error::MonthOutOfRange { input, .. }
+            | Error::YearOutOfRange { input, .. }
+            | Error::ParseIntError(input, ..)
+            | Error::Nom(input, _) => Some(input),
+            Error::Context(_, e) => e.remaining(),
+            _ => None,
+        }
+    }
+
+    /// Returns the rank of the error used to choose the most meaningful error
+    /// among the failed alternatives: the generic parsing errors have the
+    /// lowest rank, the errors of the recognized but invalid dates have the
+    /// highest one.
+    fn rank(&self) -> u8 {
+        match self {
+            Error::Nom(..) | Error::ParseIntError(..) | Error::Context(..) => 0,
+            Error::DayOutOfRange { .. }
+            | Error::MonthOutOfRange { .. }
+            | Error::YearOutOfRange { .. }
+            | Error::WeekOutOfRange
+            | Error::DayOfYearOutOfRange
+            | Error::HourOutOfRange
+            | Error::MinuteOutOfRange
+            | Error::SecondOutOfRange
+            | Error::OffsetOutOfRange => 1,
+            Error::NonExistentDate
+            | Error::DayOutOfMonth { .. }
+            | Error::DateOutOfRange
+            | Error::WeekdayMismatch
+            | Error::ReversedRange => 2,
+            Error::InputTooLong => 3,
+        }
+    }
+}
+
 impl<I> ParseError<I> for Error<I> {
     fn from_error_kind(input: I, kind: ErrorKind) -> Self {
         Error::Nom(input, kind)
     }
 
+    /// Combines the errors of the alternatives (e.g. of the
+    /// [`alt`](nom::branch::alt) combinator): the date-specific errors (e.g.
+    /// [`Error::DayOutOfRange`]) aren't shadowed by the generic parsing errors
+    /// of the following alternatives. If the errors have the same rank, the
+    /// error of the last alternative is returned.
+    fn or(self, other: Self) -> Self {
+        if self.rank() > other.rank() {
+            self
+        } else {
+            other
+        }
+    }
+
     fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
         other
     }
 }
 
+/// Attaches the name of the date component (e.g. `day` or `weekday`) to the
+/// generic parsing errors, see the [`context`](nom::error::context)
+/// combinator. The date-specific errors (e.g. [`Error::DayOutOfRange`])
+/// already describe the component, so they are returned as is. The innermost
+/// context wins, so the error reports the component which actually failed.
+impl<I> ContextError<I> for Error<I> {
+    fn add_context(_input: I, context: &'static str, other: Self) -> Self {
+        match other {
+            Error::Nom(..) | Error::ParseIntError(..) => Error::Context(context, Box::new(other)),
+            other => other,
+        }
+    }
+}
+
 impl<I> FromExternalError<I, ParseIntError> for Error<I> {
     fn from_external_error(input: I, kind: ErrorKind, e: ParseIntError) -> Self {
         Self::ParseIntError(input, kind, e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::{branch::alt, bytes::complete::tag, combinator::value};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::numeric::dd;
+
+    #[rstest]
+    #[case(
+        Error::DayOutOfRange { value: 42, input: "42" },
+        Error::Nom("42", ErrorKind::Tag),
+        Error::DayOutOfRange { value: 42, input: "42" }
+    )]
+    #[case(
+        Error::Nom("42", ErrorKind::Digit),
+        Error::DayOutOfRange { value: 42, input: "42" },
+        Error::DayOutOfRange { value: 42, input: "42" }
+    )]
+    #[case(
+        Error::DayOutOfRange { value: 42, input: "42" },
+        Error::NonExistentDate,
+        Error::NonExistentDate
+    )]
+    #[case(
+        Error::NonExistentDate,
+        Error::DayOutOfRange { value: 42, input: "42" },
+        Error::NonExistentDate
+    )]
+    #[case(
+        Error::Nom("42", ErrorKind::Digit),
+        Error::Nom("42", ErrorKind::Tag),
+        Error::Nom("42", ErrorKind::Tag)
+    )]
+    fn test_or(
+        #[case] first: Error<&str>,
+        #[case] second: Error<&str>,
+        #[case] expected: Error<&str>,
+    ) {
+        assert_eq!(first.or(second), expected);
+    }
+
+    #[rstest]
+    #[case("42", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42" })))]
+    #[case("x", Ok(("", 0)))]
+    #[case("y", Err(nom::Err::Error(Error::Nom("y", ErrorKind::Tag))))]
+    fn test_alt(#[case] input: &str, #[case] expected: crate::types::IResult<&str, u32>) {
+        assert_eq!(alt((dd, value(0, tag("x"))))(input), expected);
+    }
+
+    #[rstest]
+    #[case(
+        Error::Nom("x", ErrorKind::Tag),
+        Error::Context("day", Box::new(Error::Nom("x", ErrorKind::Tag)))
+    )]
+    #[case(Error::DayOutOfRange { value: 42, input: "42" }, Error::DayOutOfRange { value: 42, input: "42" })]
+    #[case(
+        Error::Context("month", Box::new(Error::Nom("x", ErrorKind::Tag))),
+        Error::Context("month", Box::new(Error::Nom("x", ErrorKind::Tag)))
+    )]
+    fn test_add_context(#[case] error: Error<&str>, #[case] expected: Error<&str>) {
+        assert_eq!(Error::add_context("x", "day", error), expected);
+    }
+
+    #[rstest]
+    #[case("13/ab/2024", Some("month"))]
+    #[case("ab/07/2024", Some("day"))]
+    #[case("13/07/20", Some("year"))]
+    #[case("42/07/2024", None)]
+    #[case("13/07/0042", None)]
+    fn test_numeric_context(#[case] input: &str, #[case] expected: Option<&'static str>) {
+        let Err(nom::Err::Error(error)) = crate::numeric::dd_mm_y4(input) else {
+            panic!("{input} is recognized");
+        };
+
+        assert_eq!(error.context(), expected);
+    }
+
+    #[rstest]
+    #[case(Error::DayOutOfRange { value: 42, input: "42" }, "the day 42 is out of the range 1..=31 at `42`")]
+    #[case(Error::YearOutOfRange { value: 42, input: "0042" }, "the year 42 is out of the accepted range at `0042`")]
+    #[case(Error::NonExistentDate, "the date doesn't exist")]
+    #[case(
+        Error::DayOutOfMonth { year: 2023, month: 2, day: 29 },
+        "the day 29 doesn't exist in 2023-02"
+    )]
+    #[case(
+        Error::Nom("later", ErrorKind::Eof),
+        "unexpected input at `later` (End of file)"
+    )]
+    #[case(Error::ParseIntError("x", ErrorKind::MapRes, "x".parse::<u32>().unwrap_err()), "invalid number at `x`: invalid digit found in string")]
+    #[case(
+        Error::Context("month", Box::new(Error::Nom("later", ErrorKind::Tag))),
+        "unexpected input at `later` (Tag) while parsing the month"
+    )]
+    #[case(
+        Error::Nom("on the 13th of July, 2024", ErrorKind::Tag),
+        "unexpected input at `on the 13th of July,…` (Tag)"
+    )]
+    fn test_display(#[case] error: Error<&str>, #[case] expected: &str) {
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "13/ab/2024",
+        Some(3),
+        "invalid number at `ab/2024`: invalid digit found in string while parsing the month \
+         (offset 3)"
+    )]
+    #[case(
+        "13/07/0042",
+        Some(6),
+        "the year 42 is out of the accepted range at `0042` (offset 6)"
+    )]
+    #[case("31/02/2024", None, "the date doesn't exist")]
+    fn test_located(
+        #[case] input: &str,
+        #[case] expected_offset: Option<usize>,
+        #[case] expected: &str,
+    ) {
+        let Err(nom::Err::Error(error)) = crate::numeric::dd_mm_y4(input) else {
+            panic!("{input} is recognized");
+        };
+        let located = error.located(input);
+
+        assert_eq!(located.offset, expected_offset);
+        assert_eq!(located.to_string(), expected);
+    }
+
+    #[test]
+    fn test_offset_in_other_input() {
+        assert_eq!(
+            Error::Nom("later", ErrorKind::Tag).offset_in("sooner"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_owned() {
+        let error: Box<dyn std::error::Error> = {
+            let input = String::from("x");
+            let error = "x".parse::<u32>().unwrap_err();
+            Box::new(Error::ParseIntError(input.as_str(), ErrorKind::MapRes, error).to_owned())
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "invalid number at `x`: invalid digit found in string"
+        );
+        assert!(error.source().is_some());
+        assert_eq!(
+            Error::WeekdayMismatch.to_owned(),
+            Error::<String>::WeekdayMismatch
+        );
+    }
+}
@@ -0,0 +1,590 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use chrono::NaiveDate;
+use nom::error::ErrorKind;
+
+use crate::{context::ParserContext, error::Error, parser::ContextFn, types::IResult};
+
+/// Describes where the part (`day`, `month` or `year`) of the recognized date
+/// came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    /// The part is written in the input
+    Input,
+    /// The part is missing in the input and taken from the reference date
+    ReferenceDate,
+    /// The two-digit year is written in the input and expanded using the
+    /// century pivot, see [`ParserContext::with_century_pivot`]
+    CenturyPivot,
+    /// The date is computed relative to the reference date (e.g. `tomorrow` or
+    /// `+ 3`)
+    Relative,
+}
+
+/// The explanation of the date recognized by the [`explain`] function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The path of the parser which recognized the date, e.g.
+    /// `numeric::dd_mm_only`
+    pub parser: &'static str,
+    /// The recognized date
+    pub date: NaiveDate,
+    /// The reference date (`today`) of the context
+    pub reference_date: NaiveDate,
+    /// The century pivot of the context
+    pub century_pivot: u8,
+    /// The origin of the `day` part
+    pub day: Origin,
+    /// The origin of the `month` part
+    pub month: Origin,
+    /// The origin of the `year` part
+    pub year: Origin,
+}
+
+impl fmt::Display for Explanation {
+    /// Formats the explanation as the sentence, e.g. `2024-04-03 (recognized
+    /// by numeric::dd_mm_only): the year is taken from the reference date
+    /// 2024-07-16`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (recognized by {})", self.date, self.parser)?;
+
+        let parts = [
+            ("day", self.day),
+            ("month", self.month),
+            ("year", self.year),
+        ];
+        if parts.iter().all(|(_, origin)| *origin == Origin::Relative) {
+            return write!(
+                f,
+                ": computed relative to the reference date {}",
+                self.reference_date
+            );
+        }
+        let mut separator = ": ";
+        for (part, origin) in parts {
+            match origin {
+                Origin::Input | Origin::Relative => continue,
+                Origin::ReferenceDate => write!(
+                    f,
+                    "{separator}the {part} is taken from the reference date {}",
+                    self.reference_date
+                )?,
+                Origin::CenturyPivot => write!(
+                    f,
+                    "{separator}the {part} is expanded using the century pivot {}",
+                    self.century_pivot
+                )?,
+            }
+            separator = ", ";
+        }
+
+        Ok(())
+    }
+}
+
+/// The parser tried by the [`explain`] and [`parse_candidates`] functions.
+struct NamedParser {
+    name: &'static str,
+    parser: ContextFn,
+    origins: [Origin; 3],
+}
+
+const WRITTEN: [Origin; 3] = [Origin::Input, Origin::Input, Origin::Input];
+const YEAR_FROM_REFERENCE: [Origin; 3] = [Origin::Input, Origin::Input, Origin::ReferenceDate];
+#[cfg(any(feature = "quick", feature = "en", feature = "ru"))]
+const RELATIVE: [Origin; 3] = [Origin::Relative, Origin::Relative, Origin::Relative];
+
+/// Returns the parsers tried by the [`explain`] and [`parse_candidates`]
+/// functions.
+fn named_parsers() -> Vec<NamedParser> {
+    #[allow(unused_mut)]
+    let mut parsers: Vec<NamedParser> = Vec::new();
+
+    #[cfg(feature = "numeric")]
+    {
+        use crate::numeric::*;
+
+        parsers.extend([
+            NamedParser {
+                name: "numeric::y4_mm_dd",
+                parser: |ctx, input| y4_mm_dd_with(ctx)(input),
+                origins: WRITTEN,
+            },
+            NamedParser {
+                name: "numeric::dd_mm_y4",
+                parser: |ctx, input| dd_mm_y4_with(ctx)(input),
+                origins: WRITTEN,
+            },
+            NamedParser {
+                name: "numeric::dd_mm_y2",
+                parser: |ctx, input| dd_mm_y2_with(ctx)(input),
+                origins: [Origin::Input, Origin::Input, Origin::CenturyPivot],
+            },
+            NamedParser {
+                name: "numeric::dd_mm_only",
+                parser: |ctx, input| dd_mm_only_with(ctx)(input),
+                origins: YEAR_FROM_REFERENCE,
+            },
+            NamedParser {
+                name: "numeric::dd_only",
+                parser: |ctx, input| dd_only_with(ctx)(input),
+                origins: [Origin::Input, Origin::ReferenceDate, Origin::ReferenceDate],
+            },
+        ]);
+    }
+
+    #[cfg(feature = "quick")]
+    {
+        use crate::quick::*;
+
+        parsers.extend([
+            NamedParser {
+                name: "quick::forward_from_now",
+                parser: |ctx, input| forward_from_now_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "quick::backward_from_now",
+                parser: |ctx, input| backward_from_now_with(ctx)(input),
+                origins: RELATIVE,
+            },
+        ]);
+    }
+
+    #[cfg(feature = "en")]
+    {
+        use crate::i18n::en::*;
+
+        parsers.extend([
+            NamedParser {
+                name: "en::dd_month_y4",
+                parser: |_, input| dd_month_y4(input),
+                origins: WRITTEN,
+            },
+            NamedParser {
+                name: "en::month_dd_y4",
+                parser: |_, input| month_dd_y4(input),
+                origins: WRITTEN,
+            },
+            NamedParser {
+                name: "en::dd_month_only",
+                parser: |ctx, input| dd_month_only_with(ctx)(input),
+                origins: YEAR_FROM_REFERENCE,
+            },
+            NamedParser {
+                name: "en::yesterday",
+                parser: |ctx, input| yesterday_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "en::today",
+                parser: |ctx, input| today_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "en::tomorrow",
+                parser: |ctx, input| tomorrow_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "en::in_duration",
+                parser: |ctx, input| in_duration_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "en::duration_ago",
+                parser: |ctx, input| duration_ago_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "en::last_named_weekday",
+                parser: |ctx, input| last_named_weekday_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "en::this_named_weekday",
+                parser: |ctx, input| this_named_weekday_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "en::next_named_weekday",
+                parser: |ctx, input| next_named_weekday_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "en::current_named_weekday_only",
+                parser: |ctx, input| current_named_weekday_only_with(ctx)(input),
+                origins: RELATIVE,
+            },
+        ]);
+    }
+
+    #[cfg(feature = "ru")]
+    {
+        use crate::i18n::ru::*;
+
+        parsers.extend([
+            NamedParser {
+                name: "ru::dd_month_y4",
+                parser: |_, input| dd_month_y4(input),
+                origins: WRITTEN,
+            },
+            NamedParser {
+                name: "ru::dd_month_only",
+                parser: |ctx, input| dd_month_only_with(ctx)(input),
+                origins: YEAR_FROM_REFERENCE,
+            },
+            NamedParser {
+                name: "ru::yesterday",
+                parser: |ctx, input| yesterday_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "ru::today",
+                parser: |ctx, input| today_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "ru::tomorrow",
+                parser: |ctx, input| tomorrow_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "ru::in_duration",
+                parser: |ctx, input| in_duration_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "ru::duration_ago",
+                parser: |ctx, input| duration_ago_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "ru::last_named_weekday",
+                parser: |ctx, input| last_named_weekday_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "ru::this_named_weekday",
+                parser: |ctx, input| this_named_weekday_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "ru::next_named_weekday",
+                parser: |ctx, input| next_named_weekday_with(ctx)(input),
+                origins: RELATIVE,
+            },
+            NamedParser {
+                name: "ru::current_named_weekday_only",
+                parser: |ctx, input| current_named_weekday_only_with(ctx)(input),
+                origins: RELATIVE,
+            },
+        ]);
+    }
+
+    parsers
+}
+
+/// Recognizes the date using the `numeric` (in the `day-month-year` order),
+/// `quick`, `en` and `ru` parsers (those enabled by the features) and explains
+/// the result: which parser recognized the date and where its parts came from,
+/// e.g. to confirm the date with the user (`I understood 03/04 as 3 April
+/// 2024`).
+///
+/// The parser which consumes the longest part of the input is chosen, the
+/// remaining input is handled according to the
+/// [`TrailingInput`](crate::context::TrailingInput) mode of the context.
+///
+/// If none of the parsers recognizes the date, returns the first date-specific
+/// error (e.g. [`Error::NonExistentDate`]) or [`Error::Nom`] with the
+/// [`ErrorKind::Alt`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     explain::{explain, Origin},
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let (_, explanation) = explain("03/04", &ctx)?;
+///
+/// assert_eq!(explanation.parser, "numeric::dd_mm_only");
+/// assert_eq!(
+///     explanation.date,
+///     NaiveDate::from_ymd_opt(2024, 4, 3).unwrap()
+/// );
+/// assert_eq!(explanation.year, Origin::ReferenceDate);
+/// assert_eq!(
+///     explanation.to_string(),
+///     "2024-04-03 (recognized by numeric::dd_mm_only): the year is taken from the reference \
+///      date 2024-07-16"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn explain<'a>(input: &'a str, ctx: &ParserContext) -> IResult<&'a str, Explanation> {
+    let mut best: Option<(&'a str, NaiveDate, NamedParser)> = None;
+    let mut error = None;
+
+    for candidate in named_parsers() {
+        let parser = candidate.parser;
+        match ctx.trailing(move |input| parser(ctx, input))(input) {
+            Ok((remaining, date)) => {
+                if best.as_ref().map_or(true, |(best_remaining, ..)| {
+                    remaining.len() < best_remaining.len()
+                }) {
+                    best = Some((remaining, date, candidate));
+                }
+            }
+            Err(nom::Err::Error(
+                Error::Nom(..) | Error::ParseIntError(..) | Error::Context(..),
+            )) => {}
+            Err(nom::Err::Error(e)) => {
+                error.get_or_insert(e);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    let Some((remaining, date, candidate)) = best else {
+        return Err(nom::Err::Error(
+            error.unwrap_or(Error::Nom(input, ErrorKind::Alt)),
+        ));
+    };
+    let [day, month, year] = candidate.origins;
+
+    Ok((
+        remaining,
+        Explanation {
+            parser: candidate.name,
+            date,
+            reference_date: ctx.today(),
+            century_pivot: ctx.century_pivot(),
+            day,
+            month,
+            year,
+        },
+    ))
+}
+
+/// The date recognized by one of the parsers tried by the [`parse_candidates`]
+/// function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Candidate {
+    /// The recognized date
+    pub date: NaiveDate,
+    /// The path of the parser which recognized the date, e.g.
+    /// `numeric::dd_mm_only`
+    pub parser: &'static str,
+    /// The score in the range `0..=100`, see [`parse_candidates`]
+    pub score: u8,
+}
+
+/// Returns the score of the date recognized from the `consumed` characters of
+/// the input of the `total` characters with the parts of the specified
+/// `origins`.
+fn score(origins: [Origin; 3], consumed: usize, total: usize) -> u8 {
+    let written: usize = origins
+        .iter()
+        .map(|origin| match origin {
+            Origin::Input | Origin::Relative => 2,
+            Origin::CenturyPivot => 1,
+            Origin::ReferenceDate => 0,
+        })
+        .sum();
+    let coverage = if total == 0 { 0 } else { consumed * 50 / total };
+
+    (written * 50 / 6 + coverage) as u8
+}
+
+/// Recognizes the date using all the parsers tried by the [`explain`] function
+/// and returns every recognized date ordered from the most to the least likely
+/// one, e.g. to let the user choose the date from the fuzzy input instead of
+/// taking the first date recognized by the [`alt`](nom::branch::alt) parser.
+///
+/// Every candidate gets the score in the range `0..=100`:
+/// - up to `50` for the completeness of the date: the full dates (e.g.
+///   `13/07/2024` or `tomorrow`) get `50`, the dates with the year expanded
+///   using the century pivot (`13/07/24`) get `41`, the dates with the year
+///   taken from the reference date (`13/07`) get `33` and the bare days (`13`)
+///   get `16`
+/// - up to `50` for the part of the input consumed by the parser, so the
+///   parsers recognizing the whole input win over those recognizing its prefix
+///
+/// The surrounding whitespaces are ignored, as well as the
+/// [`TrailingInput`](crate::context::TrailingInput) mode of the context. The
+/// candidates with the same score keep the order of the parsers, returns the
+/// empty vector if none of the parsers recognizes the date.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use nom_date_parsers::explain::parse_candidates;
+///
+/// let candidates = parse_candidates("13/07/2024");
+///
+/// assert_eq!(candidates[0].parser, "numeric::dd_mm_y4");
+/// assert_eq!(candidates[0].score, 100);
+/// assert!(candidates[1..]
+///     .iter()
+///     .all(|candidate| candidate.score < 100));
+/// ```
+#[cfg(feature = "clock")]
+pub fn parse_candidates(input: &str) -> Vec<Candidate> {
+    parse_candidates_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`parse_candidates`] function which uses the reference date of
+/// the specified [`ParserContext`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, explain::parse_candidates_with};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let candidates = parse_candidates_with(&ctx)("13 July");
+///
+/// assert_eq!(candidates[0].parser, "en::dd_month_only");
+/// assert_eq!(
+///     candidates[0].date,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// ```
+pub fn parse_candidates_with(ctx: &ParserContext) -> impl Fn(&str) -> Vec<Candidate> + '_ {
+    move |input: &str| {
+        let input = input.trim();
+        let total = input.chars().count();
+
+        let mut candidates: Vec<Candidate> = named_parsers()
+            .into_iter()
+            .filter_map(|named| {
+                let (remaining, date) = (named.parser)(ctx, input).ok()?;
+                let consumed = total - remaining.chars().count();
+
+                Some(Candidate {
+                    date,
+                    parser: named.name,
+                    score: score(named.origins, consumed, total),
+                })
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.score.cmp(&a.score));
+
+        candidates
+    }
+}
+
+#[cfg(all(test, feature = "numeric", feature = "quick", feature = "en"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::context::TrailingInput;
+
+    use super::*;
+
+    fn ctx() -> ParserContext {
+        ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+    }
+
+    #[rstest]
+    #[case("13/07/2024", "numeric::dd_mm_y4", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), WRITTEN)]
+    #[case("2024-07-13", "numeric::y4_mm_dd", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), WRITTEN)]
+    #[case("13/07/24", "numeric::dd_mm_y2", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), [Origin::Input, Origin::Input, Origin::CenturyPivot])]
+    #[case("03/04", "numeric::dd_mm_only", NaiveDate::from_ymd_opt(2024, 4, 3).unwrap(), YEAR_FROM_REFERENCE)]
+    #[case("13", "numeric::dd_only", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), [Origin::Input, Origin::ReferenceDate, Origin::ReferenceDate])]
+    #[case("+ 16", "quick::forward_from_now", NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(), RELATIVE)]
+    #[case("13 July 2024", "en::dd_month_y4", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), WRITTEN)]
+    #[case("13 July", "en::dd_month_only", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), YEAR_FROM_REFERENCE)]
+    #[case("tomorrow", "en::tomorrow", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap(), RELATIVE)]
+    #[case("in 2 weeks", "en::in_duration", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap(), RELATIVE)]
+    #[case("next friday", "en::next_named_weekday", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap(), RELATIVE)]
+    fn test_explain(
+        #[case] input: &str,
+        #[case] parser: &str,
+        #[case] date: NaiveDate,
+        #[case] origins: [Origin; 3],
+    ) {
+        let (remaining, explanation) = explain(input, &ctx()).unwrap();
+
+        assert_eq!(remaining, "");
+        assert_eq!(explanation.parser, parser);
+        assert_eq!(explanation.date, date);
+        assert_eq!(
+            [explanation.day, explanation.month, explanation.year],
+            origins
+        );
+    }
+
+    #[rstest]
+    #[case("31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("someday", Err(nom::Err::Error(Error::Nom("someday", ErrorKind::Alt))))]
+    fn test_explain_error(#[case] input: &str, #[case] expected: IResult<&str, Explanation>) {
+        let ctx = ctx().with_trailing_input(TrailingInput::RequireEnd);
+
+        assert_eq!(explain(input, &ctx), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024", "2024-07-13 (recognized by numeric::dd_mm_y4)")]
+    #[case(
+        "13",
+        "2024-07-13 (recognized by numeric::dd_only): the month is taken from the reference date \
+         2024-07-16, the year is taken from the reference date 2024-07-16"
+    )]
+    #[case(
+        "13/07/24",
+        "2024-07-13 (recognized by numeric::dd_mm_y2): the year is expanded using the century \
+         pivot 70"
+    )]
+    #[case(
+        "yesterday",
+        "2024-07-15 (recognized by en::yesterday): computed relative to the reference date \
+         2024-07-16"
+    )]
+    fn test_explanation_display(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(explain(input, &ctx()).unwrap().1.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024", &[("numeric::dd_mm_y4", 100), ("numeric::dd_mm_only", 58), ("numeric::dd_only", 26)])]
+    #[case(" 13/07 ", &[("numeric::dd_mm_only", 83), ("numeric::dd_only", 36)])]
+    #[case("13 July", &[("en::dd_month_only", 83), ("numeric::dd_only", 30)])]
+    #[case("tomorrow", &[("en::tomorrow", 100)])]
+    #[case("someday", &[])]
+    fn test_parse_candidates_with(#[case] input: &str, #[case] expected: &[(&str, u8)]) {
+        let candidates = parse_candidates_with(&ctx())(input);
+
+        assert_eq!(
+            candidates
+                .iter()
+                .map(|candidate| (candidate.parser, candidate.score))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_candidates_with_dates() {
+        let candidates = parse_candidates_with(&ctx())("03/04/24");
+
+        assert_eq!(
+            candidates[0],
+            Candidate {
+                date: NaiveDate::from_ymd_opt(2024, 4, 3).unwrap(),
+                parser: "numeric::dd_mm_y2",
+                score: 91,
+            }
+        );
+        assert_eq!(
+            candidates[1].date,
+            NaiveDate::from_ymd_opt(2024, 4, 3).unwrap()
+        );
+    }
+}
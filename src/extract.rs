@@ -0,0 +1,373 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use chrono::NaiveDate;
+
+use crate::{
+    context::{ParserContext, TrailingInput},
+    numeric::DateOrder,
+    parse::common_parsers,
+    parser::ContextFn,
+};
+
+pub use crate::i18n::Language;
+
+/// The configuration of the [`extract_best_date`] function.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     extract::{Config, Language},
+/// };
+///
+/// let config = Config::new()
+///     .with_context(
+///         ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()),
+///     )
+///     .with_languages([Language::En])
+///     .with_min_confidence(50);
+///
+/// assert_eq!(config.languages(), &[Language::En]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Config {
+    ctx: ParserContext,
+    languages: Vec<Language>,
+    min_confidence: u8,
+}
+
+#[cfg(feature = "clock")]
+impl Default for Config {
+    fn default() -> Self {
+        Self::from_context(ParserContext::default())
+    }
+}
+
+impl Config {
+    /// Creates the configuration which uses the default [`ParserContext`] and
+    /// all the languages enabled by the features.
+    #[cfg(feature = "clock")]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the configuration which uses the specified [`ParserContext`]
+    /// and all the languages enabled by the features, available without the
+    /// `clock` feature.
+    pub fn from_context(ctx: ParserContext) -> Self {
+        Self {
+            ctx,
+            languages: Language::all(),
+            min_confidence: 0,
+        }
+    }
+
+    /// Sets the [`ParserContext`] used to resolve the dates relative to the
+    /// reference date. The [`TrailingInput`] mode of the context is ignored,
+    /// since the date may be followed by any text.
+    #[must_use]
+    pub fn with_context(mut self, ctx: ParserContext) -> Self {
+        self.ctx = ctx;
+        self
+    }
+
+    /// Sets the languages to detect, the earlier language wins if the same
+    /// part of the message is recognized by several languages.
+    #[must_use]
+    pub fn with_languages(mut self, languages: impl IntoIterator<Item = Language>) -> Self {
+        self.languages = languages.into_iter().collect();
+        self
+    }
+
+    /// Sets the minimum confidence (`0..=100`) of the returned date, the less
+    /// confident dates are ignored.
+    #[must_use]
+    pub fn with_min_confidence(mut self, min_confidence: u8) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Returns the [`ParserContext`] of the configuration
+    pub fn context(&self) -> &ParserContext {
+        &self.ctx
+    }
+
+    /// Returns the languages to detect
+    pub fn languages(&self) -> &[Language] {
+        &self.languages
+    }
+
+    /// Returns the minimum confidence of the returned date
+    pub fn min_confidence(&self) -> u8 {
+        self.min_confidence
+    }
+}
+
+/// The date found in the message by the [`extract_best_date`] function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parsed {
+    /// The recognized date
+    pub date: NaiveDate,
+    /// The byte range of the recognized part of the message
+    pub span: Range<usize>,
+    /// The language of the recognized part, [`None`] if the date was
+    /// recognized by the language-independent parsers (e.g.
+    /// [`dd_mm_y4`](crate::numeric::dd_mm_y4))
+    pub language: Option<Language>,
+    /// The confidence in the range `0..=100`, see [`extract_best_date`]
+    pub confidence: u8,
+}
+
+/// Checks whether the text continues the recognized part of the message: the
+/// next character (in the order of the `chars`) is a letter or a digit, or a
+/// punctuation symbol followed by a digit (e.g. the `/02/2024` after `31`).
+fn continues_word(mut chars: impl Iterator<Item = char>) -> bool {
+    match chars.next() {
+        Some(c) if c.is_alphanumeric() => true,
+        Some(c) if !c.is_whitespace() => chars.next().is_some_and(|c| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+/// Returns the heuristic confidence of the date recognized from the `matched`
+/// part of the message.
+fn confidence(matched: &str, whole_message: bool) -> u8 {
+    let digits = matched.chars().filter(char::is_ascii_digit).count();
+    let letters = matched.chars().filter(|c| c.is_alphabetic()).count();
+
+    let confidence = match (digits, letters) {
+        // A bare number (e.g. `13`) is usually not a date
+        _ if digits == matched.chars().count() => 20,
+        // A numeric date with the year (e.g. `13/07/2024` or `13.07.24`)
+        (6.., 0) => 80,
+        // A numeric date without the year (e.g. `13/07`) or a quick one
+        (_, 0) => 50,
+        // A relative word or a named weekday (e.g. `tomorrow`)
+        (0, _) => 60,
+        // A date with the named month (e.g. `13 July`)
+        _ => 80,
+    };
+
+    if whole_message {
+        confidence + 20
+    } else {
+        confidence
+    }
+}
+
+/// Finds the most likely date in the free-form `message` (e.g. the chat
+/// message) using the language-independent parsers (`iso8601`, `numeric` in
+/// the `day-month-year` order and `quick`) and the bundle parsers of the
+/// languages of the [`Config`], and resolves it relative to the reference
+/// date of the [`Config::context`].
+///
+/// The date may be surrounded by any text, but must start and end at the word
+/// boundaries (so `13` isn't found in `a13b` and `31/02/2024` isn't recognized
+/// as `31`). Every found date gets the
+/// heuristic confidence:
+/// - `20` for a bare number (e.g. `13`)
+/// - `50` for a numeric date without the year (e.g. `13/07`)
+/// - `60` for a date written in words (e.g. `tomorrow` or `friday`)
+/// - `80` for a numeric date with the year or a date with the named month
+/// - `+20` if the date is the whole message
+///
+/// The date with the highest confidence is returned, then the longest one,
+/// then the leftmost one. Returns [`None`] if no date with at least the
+/// [`Config::min_confidence`] is found.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     extract::{extract_best_date, Config, Language},
+/// };
+///
+/// let config = Config::new().with_context(
+///     ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()),
+/// );
+/// let parsed =
+///     extract_best_date("Hi! Can we move the call for 3 people to 13 July?", &config).unwrap();
+///
+/// assert_eq!(parsed.date, NaiveDate::from_ymd_opt(2024, 7, 13).unwrap());
+/// assert_eq!(parsed.language, Some(Language::En));
+/// assert_eq!(parsed.span, 41..48);
+/// assert_eq!(parsed.confidence, 80);
+/// ```
+pub fn extract_best_date(message: &str, config: &Config) -> Option<Parsed> {
+    let ctx = config.ctx.clone().with_trailing_input(TrailingInput::Keep);
+    let mut parsers: Vec<(Option<Language>, ContextFn)> = common_parsers()
+        .into_iter()
+        .map(|parser| (None, parser))
+        .collect();
+    parsers.extend(
+        config
+            .languages
+            .iter()
+            .map(|&language| (Some(language), language.bundle(DateOrder::Dmy))),
+    );
+
+    let trimmed = message.trim();
+    let mut best: Option<Parsed> = None;
+
+    for (start, c) in message.char_indices() {
+        if c.is_whitespace() || continues_word(message[..start].chars().rev()) {
+            continue;
+        }
+
+        for &(language, parser) in &parsers {
+            let Ok((remaining, date)) = parser(&ctx, &message[start..]) else {
+                continue;
+            };
+            if continues_word(remaining.chars()) {
+                continue;
+            }
+
+            let end = message.len() - remaining.len();
+            let matched = message[start..end].trim_end();
+            if matched.is_empty() {
+                continue;
+            }
+            let end = start + matched.len();
+            let candidate = Parsed {
+                date,
+                span: start..end,
+                language,
+                confidence: confidence(matched, matched == trimmed),
+            };
+
+            if best.as_ref().map_or(true, |best| {
+                (candidate.confidence, candidate.span.len()) > (best.confidence, best.span.len())
+            }) {
+                best = Some(candidate);
+            }
+        }
+    }
+
+    best.filter(|best| best.confidence >= config.min_confidence)
+}
+
+/// Finds all the non-overlapping dates in the free-form `text` using the
+/// specified `parsers` and returns them with their byte ranges in the order of
+/// appearance.
+///
+/// The dates must start and end at the word boundaries (see
+/// [`extract_best_date`]), the longest date recognized at the position wins
+/// (the earlier parser wins on a tie) and the bare numbers (e.g. `3` in the
+/// `3 people`) are skipped.
+#[cfg(feature = "en")]
+pub(crate) fn find_all(
+    ctx: &ParserContext,
+    parsers: &[ContextFn],
+    text: &str,
+) -> Vec<(Range<usize>, NaiveDate)> {
+    let ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+    let mut found = Vec::new();
+    let mut next = 0;
+
+    for (start, c) in text.char_indices() {
+        if start < next || c.is_whitespace() || continues_word(text[..start].chars().rev()) {
+            continue;
+        }
+
+        let mut longest: Option<(usize, NaiveDate)> = None;
+        for parser in parsers {
+            let Ok((remaining, date)) = parser(&ctx, &text[start..]) else {
+                continue;
+            };
+            if continues_word(remaining.chars()) {
+                continue;
+            }
+
+            let matched = text[start..text.len() - remaining.len()].trim_end();
+            if matched.is_empty() || matched.chars().all(|c| c.is_ascii_digit()) {
+                continue;
+            }
+            if longest.map_or(true, |(len, _)| matched.len() > len) {
+                longest = Some((matched.len(), date));
+            }
+        }
+
+        if let Some((len, date)) = longest {
+            found.push((start..start + len, date));
+            next = start + len;
+        }
+    }
+
+    found
+}
+
+#[cfg(all(test, feature = "numeric", feature = "quick", feature = "en"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn config() -> Config {
+        Config::new()
+            .with_context(
+                ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()),
+            )
+            .with_languages([Language::En])
+    }
+
+    #[rstest]
+    #[case("13/07/2024", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), 0..10, None, 100)]
+    #[case("  tomorrow  ", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap(), 2..10, Some(Language::En), 80)]
+    #[case("see you tomorrow!", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap(), 8..16, Some(Language::En), 60)]
+    #[case("I have 3 cats, call me on 20/07", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(), 26..31, None, 50)]
+    #[case("deadline: Fri, 13 September 2024.", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap(), 10..32, Some(Language::En), 80)]
+    #[case("ok, in 2 weeks then", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap(), 4..14, Some(Language::En), 80)]
+    #[case("room 42, 2024-07-13", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), 9..19, None, 80)]
+    fn test_extract_best_date(
+        #[case] message: &str,
+        #[case] date: NaiveDate,
+        #[case] span: Range<usize>,
+        #[case] language: Option<Language>,
+        #[case] confidence: u8,
+    ) {
+        assert_eq!(
+            extract_best_date(message, &config()),
+            Some(Parsed {
+                date,
+                span,
+                language,
+                confidence
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("", 0)]
+    #[case("hello there", 0)]
+    #[case("a13b", 0)]
+    #[case("I have 3 cats", 30)]
+    #[case("31/02/2024", 0)]
+    fn test_extract_best_date_none(#[case] message: &str, #[case] min_confidence: u8) {
+        assert_eq!(
+            extract_best_date(message, &config().with_min_confidence(min_confidence)),
+            None
+        );
+    }
+
+    #[cfg(feature = "ru")]
+    #[test]
+    fn test_extract_best_date_languages() {
+        let config = config().with_languages(Language::all());
+
+        assert_eq!(
+            extract_best_date("Давай встретимся 13 июля 2024 года в 14:00", &config),
+            Some(Parsed {
+                date: NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+                span: 32..57,
+                language: Some(Language::Ru),
+                confidence: 80,
+            })
+        );
+    }
+}
@@ -0,0 +1,696 @@
+use alloc::{format, string::String};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::{context::Separator, i18n::Language, numeric::DateOrder};
+
+#[cfg(feature = "en")]
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The names of the months in the genitive case, e.g. `13 июля`.
+#[cfg(feature = "ru")]
+const RU_MONTHS: [&str; 12] = [
+    "января",
+    "февраля",
+    "марта",
+    "апреля",
+    "мая",
+    "июня",
+    "июля",
+    "августа",
+    "сентября",
+    "октября",
+    "ноября",
+    "декабря",
+];
+
+#[cfg(feature = "de")]
+const DE_MONTHS: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+#[cfg(feature = "es")]
+const ES_MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+#[cfg(feature = "fr")]
+const FR_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+#[cfg(feature = "ro")]
+const RO_MONTHS: [&str; 12] = [
+    "ianuarie",
+    "februarie",
+    "martie",
+    "aprilie",
+    "mai",
+    "iunie",
+    "iulie",
+    "august",
+    "septembrie",
+    "octombrie",
+    "noiembrie",
+    "decembrie",
+];
+
+#[cfg(feature = "bg")]
+const BG_MONTHS: [&str; 12] = [
+    "януари",
+    "февруари",
+    "март",
+    "април",
+    "май",
+    "юни",
+    "юли",
+    "август",
+    "септември",
+    "октомври",
+    "ноември",
+    "декември",
+];
+
+/// The names of the months in the genitive case, e.g. `13 липня`.
+#[cfg(feature = "uk")]
+const UK_MONTHS: [&str; 12] = [
+    "січня",
+    "лютого",
+    "березня",
+    "квітня",
+    "травня",
+    "червня",
+    "липня",
+    "серпня",
+    "вересня",
+    "жовтня",
+    "листопада",
+    "грудня",
+];
+
+/// The names of the months in the genitive case, e.g. `13 lipca`.
+#[cfg(feature = "pl")]
+const PL_MONTHS: [&str; 12] = [
+    "stycznia",
+    "lutego",
+    "marca",
+    "kwietnia",
+    "maja",
+    "czerwca",
+    "lipca",
+    "sierpnia",
+    "września",
+    "października",
+    "listopada",
+    "grudnia",
+];
+
+#[cfg(feature = "pt")]
+const PT_MONTHS: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+
+#[cfg(feature = "it")]
+const IT_MONTHS: [&str; 12] = [
+    "gennaio",
+    "febbraio",
+    "marzo",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "agosto",
+    "settembre",
+    "ottobre",
+    "novembre",
+    "dicembre",
+];
+
+#[cfg(feature = "tr")]
+const TR_MONTHS: [&str; 12] = [
+    "Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim",
+    "Kasım", "Aralık",
+];
+
+/// Formats the date as the numeric date with the two-digit `day` and `month`
+/// parts and the four-digit `year` part in the specified [`DateOrder`],
+/// separated by the specified [`Separator`] ([`Separator::Space`] is a single
+/// space).
+///
+/// The result is recognized by the numeric parsers of the same order (e.g.
+/// [`dmy`](crate::numeric::dmy) for the [`DateOrder::Dmy`]).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::Separator, format::numeric, numeric::DateOrder};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 7, 3).unwrap();
+///
+/// assert_eq!(
+///     numeric(date, DateOrder::Dmy, Separator::Slash),
+///     "03/07/2024"
+/// );
+/// assert_eq!(numeric(date, DateOrder::Mdy, Separator::Dot), "07.03.2024");
+/// assert_eq!(numeric(date, DateOrder::Ymd, Separator::Dash), "2024-07-03");
+/// ```
+pub fn numeric(date: NaiveDate, order: DateOrder, separator: Separator) -> String {
+    let separator = match separator {
+        Separator::Slash => '/',
+        Separator::Dash => '-',
+        Separator::Dot => '.',
+        Separator::Space => ' ',
+    };
+    let (year, month, day) = (date.year(), date.month(), date.day());
+
+    match order {
+        DateOrder::Dmy => format!("{day:02}{separator}{month:02}{separator}{year:04}"),
+        DateOrder::Mdy => format!("{month:02}{separator}{day:02}{separator}{year:04}"),
+        DateOrder::Ymd => format!("{year:04}{separator}{month:02}{separator}{day:02}"),
+    }
+}
+
+/// Formats the date with the full name of the month in the specified
+/// [`Language`] (e.g. `13 July 2024`, `13 июля 2024` or `13. Juli 2024`),
+/// which is recognized by the bundle parser of the language.
+///
+/// Returns [`None`] if the bundle parser of the language doesn't recognize the
+/// named months.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{format::named_month, i18n::Language};
+///
+/// assert_eq!(
+///     named_month(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), Language::En),
+///     Some(String::from("13 July 2024"))
+/// );
+/// ```
+#[allow(unused_variables)]
+pub fn named_month(date: NaiveDate, language: Language) -> Option<String> {
+    let (year, month, day) = (date.year(), date.month0() as usize, date.day());
+
+    match language {
+        #[cfg(feature = "en")]
+        Language::En => Some(format!("{day} {} {year:04}", EN_MONTHS[month])),
+        #[cfg(feature = "ru")]
+        Language::Ru => Some(format!("{day} {} {year:04}", RU_MONTHS[month])),
+        #[cfg(feature = "de")]
+        Language::De => Some(format!("{day}. {} {year:04}", DE_MONTHS[month])),
+        #[cfg(feature = "es")]
+        Language::Es => Some(format!("{day} de {} de {year:04}", ES_MONTHS[month])),
+        #[cfg(feature = "fr")]
+        Language::Fr => Some(format!("{day} {} {year:04}", FR_MONTHS[month])),
+        #[cfg(feature = "zh")]
+        Language::Zh => Some(format!("{year:04}年{}月{day}日", month + 1)),
+        #[cfg(feature = "ro")]
+        Language::Ro => Some(format!("{day} {} {year:04}", RO_MONTHS[month])),
+        #[cfg(feature = "bg")]
+        Language::Bg => Some(format!("{day} {} {year:04}", BG_MONTHS[month])),
+        #[cfg(feature = "hy")]
+        Language::Hy => None,
+        #[cfg(feature = "ka")]
+        Language::Ka => None,
+        #[cfg(feature = "az")]
+        Language::Az => None,
+        #[cfg(feature = "uz")]
+        Language::Uz => None,
+        #[cfg(feature = "uz-cyrl")]
+        Language::UzCyrl => None,
+        #[cfg(feature = "he")]
+        Language::He => None,
+        #[cfg(feature = "uk")]
+        Language::Uk => Some(format!("{day} {} {year:04}", UK_MONTHS[month])),
+        #[cfg(feature = "pl")]
+        Language::Pl => Some(format!("{day} {} {year:04}", PL_MONTHS[month])),
+        #[cfg(feature = "ja")]
+        Language::Ja => Some(format!("{year:04}年{}月{day}日", month + 1)),
+        #[cfg(feature = "pt")]
+        Language::Pt => Some(format!("{day} de {} de {year:04}", PT_MONTHS[month])),
+        #[cfg(feature = "it")]
+        Language::It => Some(format!("{day} {} {year:04}", IT_MONTHS[month])),
+        #[cfg(feature = "tr")]
+        Language::Tr => Some(format!("{day} {} {year:04}", TR_MONTHS[month])),
+        #[cfg(feature = "ar")]
+        Language::Ar => None,
+    }
+}
+
+/// Returns the words for `yesterday`, `today` and `tomorrow` recognized by the
+/// bundle parser of the language.
+fn relative_days(language: Language) -> [&'static str; 3] {
+    match language {
+        #[cfg(feature = "en")]
+        Language::En => ["yesterday", "today", "tomorrow"],
+        #[cfg(feature = "ru")]
+        Language::Ru => ["вчера", "сегодня", "завтра"],
+        #[cfg(feature = "de")]
+        Language::De => ["gestern", "heute", "morgen"],
+        #[cfg(feature = "es")]
+        Language::Es => ["ayer", "hoy", "mañana"],
+        #[cfg(feature = "fr")]
+        Language::Fr => ["hier", "aujourd'hui", "demain"],
+        #[cfg(feature = "zh")]
+        Language::Zh => ["昨天", "今天", "明天"],
+        #[cfg(feature = "hy")]
+        Language::Hy => ["երեկ", "այսօր", "վաղը"],
+        #[cfg(feature = "ka")]
+        Language::Ka => ["გუშინ", "დღეს", "ხვალ"],
+        #[cfg(feature = "az")]
+        Language::Az => ["dünən", "bu gün", "sabah"],
+        #[cfg(feature = "uz")]
+        Language::Uz => ["kecha", "bugun", "ertaga"],
+        #[cfg(feature = "uz-cyrl")]
+        Language::UzCyrl => ["кеча", "бугун", "эртага"],
+        #[cfg(feature = "ro")]
+        Language::Ro => ["ieri", "azi", "mâine"],
+        #[cfg(feature = "bg")]
+        Language::Bg => ["вчера", "днес", "утре"],
+        #[cfg(feature = "he")]
+        Language::He => ["אתמול", "היום", "מחר"],
+        #[cfg(feature = "uk")]
+        Language::Uk => ["вчора", "сьогодні", "завтра"],
+        #[cfg(feature = "pl")]
+        Language::Pl => ["wczoraj", "dzisiaj", "jutro"],
+        #[cfg(feature = "ja")]
+        Language::Ja => ["昨日", "今日", "明日"],
+        #[cfg(feature = "pt")]
+        Language::Pt => ["ontem", "hoje", "amanhã"],
+        #[cfg(feature = "it")]
+        Language::It => ["ieri", "oggi", "domani"],
+        #[cfg(feature = "tr")]
+        Language::Tr => ["dün", "bugün", "yarın"],
+        #[cfg(feature = "ar")]
+        Language::Ar => ["أمس", "اليوم", "غدًا"],
+    }
+}
+
+/// Returns the weekday of the previous (`-1`), current (`0`) or next (`1`)
+/// week, e.g. `next friday`, if the bundle parser of the language recognizes
+/// it.
+#[allow(unused_variables)]
+fn relative_weekday(language: Language, weeks: i64, weekday: Weekday) -> Option<String> {
+    #[cfg(feature = "en")]
+    if language == Language::En {
+        let modifier = ["last", "this", "next"][(weeks + 1) as usize];
+        let weekday = [
+            "monday",
+            "tuesday",
+            "wednesday",
+            "thursday",
+            "friday",
+            "saturday",
+            "sunday",
+        ][weekday.num_days_from_monday() as usize];
+
+        return Some(format!("{modifier} {weekday}"));
+    }
+    #[cfg(feature = "ru")]
+    if language == Language::Ru {
+        // The weekdays in the accusative case with the modifiers of the same
+        // gender, e.g. `в следующий вторник` or `в следующую среду`.
+        let (weekday, modifiers) = match weekday {
+            Weekday::Mon => ("понедельник", ["прошлый", "этот", "следующий"]),
+            Weekday::Tue => ("вторник", ["прошлый", "этот", "следующий"]),
+            Weekday::Wed => ("среду", ["прошлую", "эту", "следующую"]),
+            Weekday::Thu => ("четверг", ["прошлый", "этот", "следующий"]),
+            Weekday::Fri => ("пятницу", ["прошлую", "эту", "следующую"]),
+            Weekday::Sat => ("субботу", ["прошлую", "эту", "следующую"]),
+            Weekday::Sun => ("воскресенье", ["прошлое", "это", "следующее"]),
+        };
+
+        return Some(format!("в {} {weekday}", modifiers[(weeks + 1) as usize]));
+    }
+    #[cfg(feature = "uk")]
+    if language == Language::Uk {
+        // The weekdays in the accusative case with the modifiers of the same
+        // gender, e.g. `у наступний вівторок` or `у наступну середу`.
+        let (weekday, modifiers) = match weekday {
+            Weekday::Mon => ("понеділок", ["минулий", "цей", "наступний"]),
+            Weekday::Tue => ("вівторок", ["минулий", "цей", "наступний"]),
+            Weekday::Wed => ("середу", ["минулу", "цю", "наступну"]),
+            Weekday::Thu => ("четвер", ["минулий", "цей", "наступний"]),
+            Weekday::Fri => ("п'ятницю", ["минулу", "цю", "наступну"]),
+            Weekday::Sat => ("суботу", ["минулу", "цю", "наступну"]),
+            Weekday::Sun => ("неділю", ["минулу", "цю", "наступну"]),
+        };
+
+        return Some(format!("у {} {weekday}", modifiers[(weeks + 1) as usize]));
+    }
+
+    None
+}
+
+/// Formats the date relative to the specified reference date `today` in the
+/// specified [`Language`], so the result is recognized by the bundle parser of
+/// the language with the same reference date:
+/// - `yesterday`, `today` or `tomorrow` for the adjacent days;
+/// - the weekday of the previous, current or next week, e.g. `next friday`
+///   (only in `English`, `Russian` and `Ukrainian`);
+/// - the [`numeric`] date with the [`Separator::Slash`] otherwise, in the
+///   [`DateOrder::Ymd`] for `Chinese` and the [`DateOrder::Dmy`] for the other
+///   languages, e.g. `13/07/2024`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{format::relative, i18n::Language};
+///
+/// let today = NaiveDate::from_ymd_opt(2024, 7, 16).unwrap();
+///
+/// assert_eq!(
+///     relative(
+///         NaiveDate::from_ymd_opt(2024, 7, 17).unwrap(),
+///         today,
+///         Language::En
+///     ),
+///     "tomorrow"
+/// );
+/// assert_eq!(
+///     relative(
+///         NaiveDate::from_ymd_opt(2024, 7, 26).unwrap(),
+///         today,
+///         Language::En
+///     ),
+///     "next friday"
+/// );
+/// assert_eq!(
+///     relative(
+///         NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(),
+///         today,
+///         Language::En
+///     ),
+///     "13/08/2024"
+/// );
+/// ```
+pub fn relative(date: NaiveDate, today: NaiveDate, language: Language) -> String {
+    let days = date.signed_duration_since(today).num_days();
+    if (-1..=1).contains(&days) {
+        return String::from(relative_days(language)[(days + 1) as usize]);
+    }
+
+    let monday =
+        |date: NaiveDate| date.num_days_from_ce() - date.weekday().num_days_from_monday() as i32;
+    let weeks = (monday(date) - monday(today)) as i64 / 7;
+    if (-1..=1).contains(&weeks) {
+        if let Some(phrase) = relative_weekday(language, weeks, date.weekday()) {
+            return phrase;
+        }
+    }
+
+    #[cfg(feature = "zh")]
+    if language == Language::Zh {
+        return numeric(date, DateOrder::Ymd, Separator::Slash);
+    }
+    #[cfg(feature = "ja")]
+    if language == Language::Ja {
+        return numeric(date, DateOrder::Ymd, Separator::Slash);
+    }
+
+    numeric(date, DateOrder::Dmy, Separator::Slash)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        combinators::normalized,
+        context::{ParserContext, YearRange},
+        i18n::bundle_for_with,
+        numeric::{bundle_with, parse_ambiguous_with},
+        parse::parse_date_with,
+    };
+
+    /// The deterministic `xorshift` pseudo-random generator, so the failed
+    /// cases are reproducible.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            self.next() % n
+        }
+
+        fn date(&mut self) -> NaiveDate {
+            let year = 1000 + self.below(2000) as i32;
+            NaiveDate::from_yo_opt(year, 1).unwrap()
+                + chrono::Days::new(
+                    self.below(365 + NaiveDate::from_yo_opt(year, 366).map_or(0, |_| 1)),
+                )
+        }
+    }
+
+    fn ctx() -> ParserContext {
+        ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .with_year_range(YearRange::any())
+    }
+
+    /// Returns the orders of the numeric dates recognized by the bundle parser
+    /// of the language.
+    fn numeric_orders(language: Language) -> Vec<DateOrder> {
+        #[cfg(feature = "en")]
+        if language == Language::En {
+            return Vec::from([DateOrder::Dmy, DateOrder::Mdy]);
+        }
+        #[cfg(feature = "zh")]
+        if language == Language::Zh {
+            return Vec::from([DateOrder::Ymd]);
+        }
+        #[cfg(feature = "ja")]
+        if language == Language::Ja {
+            return Vec::from([DateOrder::Ymd]);
+        }
+
+        Vec::from([DateOrder::Dmy])
+    }
+
+    const ORDERS: [DateOrder; 3] = [DateOrder::Dmy, DateOrder::Mdy, DateOrder::Ymd];
+    const SEPARATORS: [Separator; 4] = [
+        Separator::Slash,
+        Separator::Dash,
+        Separator::Dot,
+        Separator::Space,
+    ];
+
+    #[test]
+    fn test_numeric_roundtrip() {
+        let ctx = ctx();
+        let mut rng = Rng(0x5eed_da7e);
+
+        for _ in 0..500 {
+            let date = rng.date();
+            for order in ORDERS {
+                for separator in SEPARATORS {
+                    let input = numeric(date, order, separator);
+
+                    assert_eq!(bundle_with(&ctx, order)(&input), Ok(("", date)), "{input}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bundles_roundtrip() {
+        let ctx = ctx();
+        let mut rng = Rng(0x00b0_d1e5);
+
+        for _ in 0..200 {
+            let date = rng.date();
+            for language in Language::all() {
+                for order in numeric_orders(language) {
+                    for separator in SEPARATORS {
+                        let input = numeric(date, order, separator);
+
+                        assert_eq!(
+                            bundle_for_with(&ctx, language, order)(&input),
+                            Ok(("", date)),
+                            "{language:?}: {input}"
+                        );
+                    }
+                }
+                if let Some(input) = named_month(date, language) {
+                    assert_eq!(
+                        bundle_for_with(&ctx, language, DateOrder::Dmy)(&input),
+                        Ok(("", date)),
+                        "{language:?}: {input}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "en")]
+    #[rstest]
+    #[case((2024, 7, 15), "yesterday")]
+    #[case((2024, 7, 16), "today")]
+    #[case((2024, 7, 17), "tomorrow")]
+    #[case((2024, 7, 19), "this friday")]
+    #[case((2024, 7, 26), "next friday")]
+    #[case((2024, 7, 8), "last monday")]
+    #[case((2024, 7, 29), "29/07/2024")]
+    #[case((2023, 7, 16), "16/07/2023")]
+    fn test_relative_en(#[case] date: (i32, u32, u32), #[case] expected: &str) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(relative(date, ctx().today(), Language::En), expected);
+    }
+
+    #[cfg(feature = "ru")]
+    #[rstest]
+    #[case((2024, 7, 17), "завтра")]
+    #[case((2024, 7, 24), "в следующую среду")]
+    #[case((2024, 7, 14), "в прошлое воскресенье")]
+    #[case((2024, 7, 18), "в этот четверг")]
+    #[case((2024, 7, 7), "07/07/2024")]
+    fn test_relative_ru(#[case] date: (i32, u32, u32), #[case] expected: &str) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(relative(date, ctx().today(), Language::Ru), expected);
+    }
+
+    #[test]
+    fn test_relative_roundtrip() {
+        let ctx = ctx();
+
+        for days in -40..=40 {
+            let date = ctx.today() + chrono::TimeDelta::try_days(days).unwrap();
+            for language in Language::all() {
+                let input = relative(date, ctx.today(), language);
+
+                assert_eq!(
+                    bundle_for_with(&ctx, language, DateOrder::Dmy)(&input),
+                    Ok(("", date)),
+                    "{language:?}: {input}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_input_doesnt_panic() {
+        const ALPHABET: &[char] = &[
+            '0', '1', '2', '3', '4', '5', '9', '/', '-', '.', ',', ':', '+', ' ', '\t', '\n', 'a',
+            'd', 'e', 'o', 'T', 'W', 'Z', 'я', 'ё', 'Ж', '年', '月', '日', 'ß', 'İ', '\u{a0}',
+            '\u{ad}', '\u{301}', '\u{200f}', '\u{2019}', '\u{2212}', '🗓',
+        ];
+        const WORDS: &[&str] = &[
+            "today",
+            "next",
+            "friday",
+            "july",
+            "of",
+            "ago",
+            "in",
+            "week",
+            "day",
+            "the",
+            "завтра",
+            "июля",
+            "года",
+            "morgen",
+            "mañana",
+            "demain",
+            "2024",
+            "13",
+            "31",
+        ];
+        let ctx = ctx();
+        let mut rng = Rng(0xf022);
+
+        for _ in 0..3000 {
+            let mut input = String::new();
+            for _ in 0..rng.below(12) {
+                if rng.below(4) == 0 {
+                    input.push_str(WORDS[rng.below(WORDS.len() as u64) as usize]);
+                } else {
+                    input.push(ALPHABET[rng.below(ALPHABET.len() as u64) as usize]);
+                }
+            }
+
+            let _ = parse_date_with(&ctx)(&input);
+            let _ = parse_ambiguous_with(&ctx)(&input);
+            for order in ORDERS {
+                let _ = bundle_with(&ctx, order)(&input);
+            }
+            for language in Language::all() {
+                for order in ORDERS {
+                    let _ = bundle_for_with(&ctx, language, order)(&input);
+                    let _ = normalized(bundle_for_with(&ctx, language, order))(&input);
+                }
+            }
+        }
+    }
+}
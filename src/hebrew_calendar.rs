@@ -0,0 +1,525 @@
+use core::ops::Range;
+
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, satisfy, space1},
+    combinator::{map_res, not, opt, value},
+    error::context,
+    sequence::{pair, preceded, terminated, tuple},
+};
+
+use crate::{
+    combinators::{lookup_no_case, tag_no_case, word_boundary},
+    error::Error,
+    numeric::dd,
+    types::IResult,
+};
+
+/// The month of the Hebrew calendar, in the order of the civil year starting
+/// with the `Tishrei`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HebrewMonth {
+    Tishrei,
+    Cheshvan,
+    Kislev,
+    Tevet,
+    Shevat,
+    /// The `Adar` of the common year or the `Adar II` of the leap year (when
+    /// the `Purim` is celebrated)
+    Adar,
+    /// The additional month of the leap year
+    AdarI,
+    /// The `Adar` of the leap year
+    AdarII,
+    Nisan,
+    Iyar,
+    Sivan,
+    Tammuz,
+    Av,
+    Elul,
+}
+
+/// The day number (counted like the
+/// [`NaiveDate::num_days_from_ce`](chrono::Datelike::num_days_from_ce)) of the
+/// `1 Tishrei 1`.
+const EPOCH: i64 = -1_373_427;
+
+/// Checks whether the Hebrew `year` is the leap one, which has 13 months
+/// (the `Adar I` and the `Adar II` instead of the `Adar`).
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::hebrew_calendar::is_leap_year;
+///
+/// assert!(is_leap_year(5784));
+/// assert!(!is_leap_year(5785));
+/// ```
+pub fn is_leap_year(year: i32) -> bool {
+    (7 * i64::from(year) + 1).rem_euclid(19) < 7
+}
+
+/// Returns the number of the days from the epoch to the molad of the `Tishrei`
+/// of the `year`, delayed to avoid the `Rosh Hashanah` on Sunday, Wednesday or
+/// Friday.
+fn elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = 12_084 + 13_753 * months;
+    let days = 29 * months + parts.div_euclid(25_920);
+
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// Returns the day number of the `1 Tishrei` of the `year`.
+fn new_year(year: i64) -> i64 {
+    let previous = elapsed_days(year - 1);
+    let current = elapsed_days(year);
+    let next = elapsed_days(year + 1);
+
+    // The delays keep the year lengths in the allowed ranges
+    let delay = if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    };
+
+    EPOCH + current + delay
+}
+
+/// Returns the number of the days in the `month` (numbered from the `Nisan`,
+/// the `Adar II` is the 13th) of the `year`.
+fn month_length(month: u32, year: i32) -> i64 {
+    let year_length = new_year(i64::from(year) + 1) - new_year(i64::from(year));
+
+    match month {
+        2 | 4 | 6 | 10 | 13 => 29,
+        12 if !is_leap_year(year) => 29,
+        // The `Cheshvan` is long only in the complete years (355 or 385 days)
+        8 if year_length % 10 != 5 => 29,
+        // The `Kislev` is short only in the deficient years (353 or 383 days)
+        9 if year_length % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+
+impl HebrewMonth {
+    /// Returns the number of the month (from the `Nisan`, the `Adar II` is
+    /// the 13th) in the specified year, [`None`] if the month doesn't exist in
+    /// the year.
+    fn number(self, year: i32) -> Option<u32> {
+        let leap = is_leap_year(year);
+
+        Some(match self {
+            HebrewMonth::Nisan => 1,
+            HebrewMonth::Iyar => 2,
+            HebrewMonth::Sivan => 3,
+            HebrewMonth::Tammuz => 4,
+            HebrewMonth::Av => 5,
+            HebrewMonth::Elul => 6,
+            HebrewMonth::Tishrei => 7,
+            HebrewMonth::Cheshvan => 8,
+            HebrewMonth::Kislev => 9,
+            HebrewMonth::Tevet => 10,
+            HebrewMonth::Shevat => 11,
+            HebrewMonth::Adar if leap => 13,
+            HebrewMonth::Adar => 12,
+            HebrewMonth::AdarI if leap => 12,
+            HebrewMonth::AdarII if leap => 13,
+            HebrewMonth::AdarI | HebrewMonth::AdarII => return None,
+        })
+    }
+}
+
+/// Converts the date of the Hebrew calendar into the Gregorian [`NaiveDate`].
+///
+/// Returns [`None`] if the date doesn't exist, e.g. the `30 Kislev` of the
+/// deficient year or the `Adar I` of the common year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::hebrew_calendar::{to_gregorian, HebrewMonth};
+///
+/// assert_eq!(
+///     to_gregorian(5784, HebrewMonth::Nisan, 15),
+///     NaiveDate::from_ymd_opt(2024, 4, 23)
+/// );
+/// assert_eq!(to_gregorian(5785, HebrewMonth::AdarI, 1), None);
+/// ```
+pub fn to_gregorian(year: i32, month: HebrewMonth, day: u32) -> Option<NaiveDate> {
+    if year < 1 {
+        return None;
+    }
+    let month = month.number(year)?;
+    if day == 0 || i64::from(day) > month_length(month, year) {
+        return None;
+    }
+
+    let last_month = if is_leap_year(year) { 13 } else { 12 };
+    let length = |months: Range<u32>| months.map(|month| month_length(month, year)).sum::<i64>();
+    // The year starts with the `Tishrei` (7th), so the months from the `Nisan`
+    // follow the end of the numbering
+    let preceding_days = if month < 7 {
+        length(7..last_month + 1) + length(1..month)
+    } else {
+        length(7..month)
+    };
+    let days = new_year(i64::from(year)) + preceding_days + i64::from(day) - 1;
+
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(days).ok()?)
+}
+
+/// Returns the month of the lowercase month name, either transliterated or in
+/// the Hebrew script.
+fn month_name(word: &str) -> Option<HebrewMonth> {
+    Some(match word {
+        "tishrei" | "tishri" | "תשרי" => HebrewMonth::Tishrei,
+        "cheshvan" | "heshvan" | "marcheshvan" | "marheshvan" | "חשון" | "חשוון" | "מרחשון"
+        | "מרחשוון" => HebrewMonth::Cheshvan,
+        "kislev" | "כסלו" | "כסליו" => HebrewMonth::Kislev,
+        "tevet" | "teves" | "טבת" => HebrewMonth::Tevet,
+        "shevat" | "shvat" | "שבט" => HebrewMonth::Shevat,
+        "adar" | "אדר" => HebrewMonth::Adar,
+        "nisan" | "nissan" | "ניסן" => HebrewMonth::Nisan,
+        "iyar" | "iyyar" | "אייר" => HebrewMonth::Iyar,
+        "sivan" | "סיון" | "סיוון" => HebrewMonth::Sivan,
+        "tammuz" | "tamuz" | "תמוז" => HebrewMonth::Tammuz,
+        "av" | "אב" => HebrewMonth::Av,
+        "elul" | "אלול" => HebrewMonth::Elul,
+        _ => return None,
+    })
+}
+
+/// Recognizes the number of the `Adar` of the leap year after the space or the
+/// `hyphen` symbol, e.g. the `II` of the `Adar II` or the `א׳` of the `אדר א׳`.
+fn adar_number(input: &str) -> IResult<&str, HebrewMonth> {
+    preceded(
+        alt((space1, tag("-"))),
+        terminated(
+            alt((
+                value(
+                    HebrewMonth::AdarII,
+                    alt((
+                        tag_no_case("ii"),
+                        tag("2"),
+                        tag_no_case("bet"),
+                        tag_no_case("sheni"),
+                        tag("ב׳"),
+                        tag("ב'"),
+                        tag("שני"),
+                        tag("ב"),
+                    )),
+                ),
+                value(
+                    HebrewMonth::AdarI,
+                    alt((
+                        tag_no_case("i"),
+                        tag("1"),
+                        tag_no_case("aleph"),
+                        tag_no_case("alef"),
+                        tag_no_case("rishon"),
+                        tag("א׳"),
+                        tag("א'"),
+                        tag("ראשון"),
+                        tag("א"),
+                    )),
+                ),
+            )),
+            word_boundary,
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` month of the Hebrew calendar, either
+/// transliterated or in the Hebrew script, optionally preceded by the `ב`
+/// preposition (e.g. `בניסן`).
+///
+/// The following words are accepted:
+/// - `tishrei` | `tishri` | `תשרי` -> [`HebrewMonth::Tishrei`]
+/// - `cheshvan` | `heshvan` | `marcheshvan` | `marheshvan` | `חשון` | `חשוון` |
+///   `מרחשון` | `מרחשוון` -> [`HebrewMonth::Cheshvan`]
+/// - `kislev` | `כסלו` | `כסליו` -> [`HebrewMonth::Kislev`]
+/// - `tevet` | `teves` | `טבת` -> [`HebrewMonth::Tevet`]
+/// - `shevat` | `shvat` | `שבט` -> [`HebrewMonth::Shevat`]
+/// - `adar` | `אדר` -> [`HebrewMonth::Adar`]
+/// - `adar i` | `adar 1` | `adar aleph` | `adar alef` | `adar rishon` | `אדר
+///   א׳` | `אדר ראשון` -> [`HebrewMonth::AdarI`]
+/// - `adar ii` | `adar 2` | `adar bet` | `adar sheni` | `אדר ב׳` | `אדר שני` ->
+///   [`HebrewMonth::AdarII`]
+/// - `nisan` | `nissan` | `ניסן` -> [`HebrewMonth::Nisan`]
+/// - `iyar` | `iyyar` | `אייר` -> [`HebrewMonth::Iyar`]
+/// - `sivan` | `סיון` | `סיוון` -> [`HebrewMonth::Sivan`]
+/// - `tammuz` | `tamuz` | `תמוז` -> [`HebrewMonth::Tammuz`]
+/// - `av` | `אב` -> [`HebrewMonth::Av`]
+/// - `elul` | `אלול` -> [`HebrewMonth::Elul`]
+///
+/// The number of the `Adar` may also be separated by the `hyphen` symbol (e.g.
+/// `Adar-II`), the `geresh` of the Hebrew one may be omitted or written as the
+/// apostrophe.
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::hebrew_calendar::{named_month, HebrewMonth};
+///
+/// assert_eq!(named_month("Nisan")?.1, HebrewMonth::Nisan);
+/// assert_eq!(named_month("בניסן")?.1, HebrewMonth::Nisan);
+/// assert_eq!(named_month("Adar II")?.1, HebrewMonth::AdarII);
+/// assert_eq!(named_month("אדר א׳")?.1, HebrewMonth::AdarI);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, HebrewMonth> {
+    let (input, month) = preceded(opt(tag("ב")), lookup_no_case(month_name))(input)?;
+
+    match month {
+        HebrewMonth::Adar => alt((adar_number, |input| Ok((input, month))))(input),
+        _ => Ok((input, month)),
+    }
+}
+
+/// Recognizes exactly four digits of the Hebrew year (e.g. `5784`).
+fn year(input: &str) -> IResult<&str, i32> {
+    context(
+        "year",
+        map_res(
+            terminated(
+                take_while_m_n(4, 4, |c: char| c.is_ascii_digit()),
+                not(satisfy(|c| c.is_ascii_digit())),
+            ),
+            |s: &str| s.parse(),
+        ),
+    )(input)
+}
+
+/// Converts the recognized parts of the date with the [`to_gregorian`]
+/// function, returns [`Error::NonExistentDate`] if the date doesn't exist.
+fn gregorian<'a>(
+    year: i32,
+    month: HebrewMonth,
+    day: u32,
+) -> Result<NaiveDate, nom::Err<Error<&'a str>>> {
+    to_gregorian(year, month, day).ok_or(nom::Err::Error(Error::NonExistentDate))
+}
+
+/// Recognizes the date of the Hebrew calendar in the `day month year` order
+/// separated by the spaces, e.g. `15 Nisan 5784` or `15 בניסן 5784`, and
+/// returns the corresponding Gregorian [`NaiveDate`]. The month is recognized
+/// by the [`named_month`] parser.
+///
+/// Returns [`Error::NonExistentDate`] if the date doesn't exist, see
+/// [`to_gregorian`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{error::Error, hebrew_calendar::dd_month_y4};
+///
+/// assert_eq!(
+///     dd_month_y4("15 Nisan 5784")?.1,
+///     NaiveDate::from_ymd_opt(2024, 4, 23).unwrap()
+/// );
+/// assert_eq!(
+///     dd_month_y4("30 Kislev 5784"),
+///     Err(nom::Err::Error(Error::NonExistentDate))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, (day, _, month, _, year)) = tuple((dd, space1, named_month, space1, year))(input)?;
+
+    Ok((input, gregorian(year, month, day)?))
+}
+
+/// Recognizes the date of the Hebrew calendar in the `month day, year` order,
+/// e.g. `Nisan 15, 5784` (the `comma` symbol is optional), and returns the
+/// corresponding Gregorian [`NaiveDate`], see [`dd_month_y4`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::hebrew_calendar::month_dd_y4;
+///
+/// assert_eq!(
+///     month_dd_y4("Tishrei 1, 5785")?.1,
+///     NaiveDate::from_ymd_opt(2024, 10, 3).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn month_dd_y4(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, (month, _, day, _, year)) =
+        tuple((named_month, space1, dd, pair(opt(char(',')), space1), year))(input)?;
+
+    Ok((input, gregorian(year, month, day)?))
+}
+
+/// Recognizes the date of the Hebrew calendar using either the
+/// [`dd_month_y4`] or the [`month_dd_y4`] parser and returns the corresponding
+/// Gregorian [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::hebrew_calendar::date;
+///
+/// assert_eq!(
+///     date("14 Adar II 5784")?.1,
+///     NaiveDate::from_ymd_opt(2024, 3, 24).unwrap()
+/// );
+/// assert_eq!(
+///     date("Elul 29, 5784")?.1,
+///     NaiveDate::from_ymd_opt(2024, 10, 2).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date(input: &str) -> IResult<&str, NaiveDate> {
+    alt((dd_month_y4, month_dd_y4))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(5784, HebrewMonth::Tishrei, 1, NaiveDate::from_ymd_opt(2023, 9, 16))]
+    #[case(5785, HebrewMonth::Tishrei, 1, NaiveDate::from_ymd_opt(2024, 10, 3))]
+    #[case(5784, HebrewMonth::Kislev, 25, NaiveDate::from_ymd_opt(2023, 12, 8))]
+    #[case(5784, HebrewMonth::AdarI, 14, NaiveDate::from_ymd_opt(2024, 2, 23))]
+    #[case(5784, HebrewMonth::AdarII, 14, NaiveDate::from_ymd_opt(2024, 3, 24))]
+    #[case(5784, HebrewMonth::Adar, 14, NaiveDate::from_ymd_opt(2024, 3, 24))]
+    #[case(5783, HebrewMonth::Adar, 14, NaiveDate::from_ymd_opt(2023, 3, 7))]
+    #[case(5784, HebrewMonth::Nisan, 15, NaiveDate::from_ymd_opt(2024, 4, 23))]
+    #[case(5784, HebrewMonth::Elul, 29, NaiveDate::from_ymd_opt(2024, 10, 2))]
+    #[case(5785, HebrewMonth::Cheshvan, 30, NaiveDate::from_ymd_opt(2024, 12, 1))]
+    #[case(5784, HebrewMonth::Kislev, 30, None)]
+    #[case(5784, HebrewMonth::Cheshvan, 30, None)]
+    #[case(5783, HebrewMonth::AdarII, 1, None)]
+    #[case(5784, HebrewMonth::Iyar, 30, None)]
+    #[case(5784, HebrewMonth::Nisan, 0, None)]
+    #[case(0, HebrewMonth::Nisan, 1, None)]
+    fn test_to_gregorian(
+        #[case] year: i32,
+        #[case] month: HebrewMonth,
+        #[case] day: u32,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(to_gregorian(year, month, day), expected);
+    }
+
+    #[test]
+    fn test_to_gregorian_consecutive_days() {
+        // The last day of every month is followed by the first day of the next
+        // one through the leap and the common years
+        for year in 5780..5790 {
+            let months: &[HebrewMonth] = if is_leap_year(year) {
+                &[
+                    HebrewMonth::Tishrei,
+                    HebrewMonth::Cheshvan,
+                    HebrewMonth::Kislev,
+                    HebrewMonth::Tevet,
+                    HebrewMonth::Shevat,
+                    HebrewMonth::AdarI,
+                    HebrewMonth::AdarII,
+                    HebrewMonth::Nisan,
+                    HebrewMonth::Iyar,
+                    HebrewMonth::Sivan,
+                    HebrewMonth::Tammuz,
+                    HebrewMonth::Av,
+                    HebrewMonth::Elul,
+                ]
+            } else {
+                &[
+                    HebrewMonth::Tishrei,
+                    HebrewMonth::Cheshvan,
+                    HebrewMonth::Kislev,
+                    HebrewMonth::Tevet,
+                    HebrewMonth::Shevat,
+                    HebrewMonth::Adar,
+                    HebrewMonth::Nisan,
+                    HebrewMonth::Iyar,
+                    HebrewMonth::Sivan,
+                    HebrewMonth::Tammuz,
+                    HebrewMonth::Av,
+                    HebrewMonth::Elul,
+                ]
+            };
+            let mut expected = to_gregorian(year, HebrewMonth::Tishrei, 1).unwrap();
+
+            for &month in months {
+                for day in 1..=30 {
+                    let Some(date) = to_gregorian(year, month, day) else {
+                        assert!(day >= 29, "{day} {month:?} {year}");
+                        break;
+                    };
+                    assert_eq!(date, expected, "{day} {month:?} {year}");
+                    expected = expected.succ_opt().unwrap();
+                }
+            }
+
+            assert_eq!(
+                to_gregorian(year + 1, HebrewMonth::Tishrei, 1),
+                Some(expected)
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("Tishrei", Ok(("", HebrewMonth::Tishrei)))]
+    #[case("MARCHESHVAN", Ok(("", HebrewMonth::Cheshvan)))]
+    #[case("nissan 15", Ok((" 15", HebrewMonth::Nisan)))]
+    #[case("Adar 5784", Ok((" 5784", HebrewMonth::Adar)))]
+    #[case("Adar I 5784", Ok((" 5784", HebrewMonth::AdarI)))]
+    #[case("adar-ii", Ok(("", HebrewMonth::AdarII)))]
+    #[case("Adar Sheni", Ok(("", HebrewMonth::AdarII)))]
+    #[case("Adar 15, 5784", Ok((" 15, 5784", HebrewMonth::Adar)))]
+    #[case("תשרי", Ok(("", HebrewMonth::Tishrei)))]
+    #[case("בניסן", Ok(("", HebrewMonth::Nisan)))]
+    #[case("אדר ב׳ 5784", Ok((" 5784", HebrewMonth::AdarII)))]
+    #[case("באדר א' 5784", Ok((" 5784", HebrewMonth::AdarI)))]
+    #[case("אדר ראשון", Ok(("", HebrewMonth::AdarI)))]
+    #[case("אב", Ok(("", HebrewMonth::Av)))]
+    #[case("July", Err(nom::Err::Error(Error::Nom("July", ErrorKind::Tag))))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, HebrewMonth>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("15 Nisan 5784", Ok(("", NaiveDate::from_ymd_opt(2024, 4, 23).unwrap())))]
+    #[case("15 בניסן 5784", Ok(("", NaiveDate::from_ymd_opt(2024, 4, 23).unwrap())))]
+    #[case("1 tishrei 5785, ok", Ok((", ok", NaiveDate::from_ymd_opt(2024, 10, 3).unwrap())))]
+    #[case("14 Adar II 5784", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 24).unwrap())))]
+    #[case("14 אדר א׳ 5784", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 23).unwrap())))]
+    #[case("Nisan 15, 5784", Ok(("", NaiveDate::from_ymd_opt(2024, 4, 23).unwrap())))]
+    #[case("Kislev 25 5784", Ok(("", NaiveDate::from_ymd_opt(2023, 12, 8).unwrap())))]
+    #[case("30 Kislev 5784", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("1 Adar I 5785", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(
+        "15 Nisan 57845",
+        Err(nom::Err::Error(Error::Nom("15 Nisan 57845", ErrorKind::Tag)))
+    )]
+    #[case(
+        "15 Nisan",
+        Err(nom::Err::Error(Error::Nom("15 Nisan", ErrorKind::Tag)))
+    )]
+    fn test_date(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(date(input), expected);
+    }
+}
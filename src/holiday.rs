@@ -0,0 +1,413 @@
+use alloc::{string::String, vec::Vec};
+
+use chrono::{Datelike, Days, Month, NaiveDate, Weekday};
+use nom::{
+    error::{ErrorKind, ParseError},
+    sequence::terminated,
+    Parser,
+};
+
+use crate::{
+    calendar::nth_weekday_of_month,
+    combinators::{tag_no_case, word_boundary},
+    context::ParserContext,
+    error::Error,
+    numeric::opt_year,
+    types::IResult,
+};
+
+/// The rule which determines the date of the named day in the given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HolidayRule {
+    /// The fixed day of the month, e.g. `December 25` for the `Christmas`
+    Fixed { month: Month, day: u32 },
+    /// The `n`-th weekday of the month, e.g. the 4th Thursday of November for
+    /// the `Thanksgiving`. The negative `n` counts from the end of the month,
+    /// e.g. `-1` is the last Monday of May for the `Memorial Day`
+    NthWeekdayOfMonth {
+        n: i8,
+        weekday: Weekday,
+        month: Month,
+    },
+    /// The number of days after (or before if negative) the Western Easter
+    /// Sunday, see [`easter`], e.g. `-2` for the `Good Friday`
+    EasterOffset(i64),
+}
+
+impl HolidayRule {
+    /// Returns the date of the named day in the specified `year` or [`None`]
+    /// if the day doesn't exist in the year (e.g. `February 29` in the
+    /// non-leap year or the 5th Monday of the month which has only four).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Month, NaiveDate, Weekday};
+    /// use nom_date_parsers::holiday::HolidayRule;
+    ///
+    /// let thanksgiving = HolidayRule::NthWeekdayOfMonth {
+    ///     n: 4,
+    ///     weekday: Weekday::Thu,
+    ///     month: Month::November,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     thanksgiving.date_in(2024),
+    ///     NaiveDate::from_ymd_opt(2024, 11, 28)
+    /// );
+    /// assert_eq!(
+    ///     HolidayRule::EasterOffset(-2).date_in(2024),
+    ///     NaiveDate::from_ymd_opt(2024, 3, 29)
+    /// );
+    /// ```
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            HolidayRule::Fixed { month, day } => {
+                NaiveDate::from_ymd_opt(year, month.number_from_month(), day)
+            }
+            HolidayRule::NthWeekdayOfMonth { n, weekday, month } => nth_weekday_of_month(
+                NaiveDate::from_ymd_opt(year, month.number_from_month(), 1)?,
+                weekday,
+                n,
+            ),
+            HolidayRule::EasterOffset(days) => {
+                let easter = easter(year)?;
+                if days < 0 {
+                    easter.checked_sub_days(Days::new(days.unsigned_abs()))
+                } else {
+                    easter.checked_add_days(Days::new(days as u64))
+                }
+            }
+        }
+    }
+}
+
+/// Returns the date of the Western (Gregorian) Easter Sunday in the specified
+/// `year` computed with the anonymous Gregorian algorithm or [`None`] if the
+/// date is out of the range of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::holiday::easter;
+///
+/// assert_eq!(easter(2024), NaiveDate::from_ymd_opt(2024, 3, 31));
+/// assert_eq!(easter(2025), NaiveDate::from_ymd_opt(2025, 4, 20));
+/// ```
+pub fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+
+    NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)
+}
+
+/// The table of the named days (e.g. `Christmas` -> `December 25` or
+/// `Thanksgiving` -> the 4th Thursday of November) recognized by the
+/// [`holiday`] parser.
+///
+/// The language-specific tables (e.g. `i18n::en::holiday::table`) may be
+/// extended at runtime with the custom named days of the application.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::holiday::{HolidayRule, HolidayTable};
+///
+/// let mut table = HolidayTable::new().with("Christmas", Month::December, 25);
+/// table.register("Company Day", Month::March, 3);
+///
+/// assert_eq!(
+///     table.recognize("company day")?.1,
+///     HolidayRule::Fixed {
+///         month: Month::March,
+///         day: 3
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidayTable {
+    holidays: Vec<(String, HolidayRule)>,
+}
+
+impl HolidayTable {
+    /// Creates the empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the named day which falls on the specified `day` of the `month`.
+    pub fn with(mut self, name: impl Into<String>, month: Month, day: u32) -> Self {
+        self.register(name, month, day);
+        self
+    }
+
+    /// Adds the named day which date is determined by the specified `rule`.
+    pub fn with_rule(mut self, name: impl Into<String>, rule: HolidayRule) -> Self {
+        self.register_rule(name, rule);
+        self
+    }
+
+    /// Adds the named day which falls on the specified `day` of the `month` to
+    /// the existing table.
+    pub fn register(&mut self, name: impl Into<String>, month: Month, day: u32) {
+        self.register_rule(name, HolidayRule::Fixed { month, day });
+    }
+
+    /// Adds the named day which date is determined by the specified `rule` to
+    /// the existing table.
+    pub fn register_rule(&mut self, name: impl Into<String>, rule: HolidayRule) {
+        self.holidays.push((name.into(), rule));
+    }
+
+    /// Returns `true` if one of the days of the table falls on the specified
+    /// `date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Month, NaiveDate};
+    /// use nom_date_parsers::holiday::HolidayTable;
+    ///
+    /// let table = HolidayTable::new().with("Christmas", Month::December, 25);
+    ///
+    /// assert!(table.is_holiday(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()));
+    /// assert!(!table.is_holiday(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap()));
+    /// ```
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays
+            .iter()
+            .any(|(_, rule)| rule.date_in(date.year()) == Some(date))
+    }
+
+    /// Returns the dates of the days of the table in the specified `year`, the
+    /// days which don't exist in the year (e.g. `February 29`) are skipped.
+    pub(crate) fn dates_in(&self, year: i32) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.holidays
+            .iter()
+            .filter_map(move |(_, rule)| rule.date_in(year))
+    }
+
+    /// Recognizes the `case insensitive` name of the day from the table
+    /// followed by the word boundary and returns its [`HolidayRule`]. The
+    /// longest name wins, so `Christmas Eve` isn't recognized as `Christmas`.
+    pub fn recognize<'a>(&self, input: &'a str) -> IResult<&'a str, HolidayRule> {
+        self.holidays
+            .iter()
+            .filter_map(|(name, rule)| {
+                terminated(tag_no_case(name), word_boundary)(input)
+                    .ok()
+                    .map(|(rest, _)| (rest, *rule))
+            })
+            .min_by_key(|(rest, _)| rest.len())
+            .ok_or(nom::Err::Error(Error::from_error_kind(
+                input,
+                ErrorKind::Tag,
+            )))
+    }
+}
+
+/// Creates a parser which recognizes the name of the day from the specified
+/// `table` followed by the optional year recognized by the language-specific
+/// `year` parser (e.g. ` 2025` in the `Christmas 2025`) and returns the
+/// corresponding [`NaiveDate`]. If the year is omitted, the year of the
+/// reference date of the specified [`ParserContext`] is used.
+///
+/// Returns [`Error::NonExistentDate`] if the day doesn't exist in the year,
+/// see [`HolidayRule::date_in`]. The [`Error::YearOutOfRange`] error of the
+/// `year` parser isn't swallowed, so the name followed by the rejected year
+/// (e.g. `Christmas 1800`) isn't recognized.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate};
+/// use nom::{bytes::complete::tag, sequence::preceded};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     holiday::{holiday, HolidayTable},
+///     numeric::y4,
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let table = HolidayTable::new().with("Christmas", Month::December, 25);
+/// let mut parser = holiday(&ctx, &table, |input| {
+///     preceded(tag(" "), y4)(input).map(|(input, year)| (input, year as i32))
+/// });
+///
+/// assert_eq!(
+///     parser("Christmas")?.1,
+///     NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()
+/// );
+/// assert_eq!(
+///     parser("Christmas 2025")?.1,
+///     NaiveDate::from_ymd_opt(2025, 12, 25).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn holiday<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    table: &'c HolidayTable,
+    mut year: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, i32, Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, rule) = table.recognize(input)?;
+        let (input, year) = opt_year(|input| year.parse(input))(input)?;
+
+        Ok((
+            input,
+            rule.date_in(year.unwrap_or(ctx.today().year()))
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        bytes::complete::tag,
+        combinator::{map, value},
+        sequence::preceded,
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::numeric::y4_with;
+
+    fn table() -> HolidayTable {
+        HolidayTable::new()
+            .with("Christmas", Month::December, 25)
+            .with("Christmas Eve", Month::December, 24)
+            .with("Leap Day", Month::February, 29)
+            .with_rule(
+                "Thanksgiving",
+                HolidayRule::NthWeekdayOfMonth {
+                    n: 4,
+                    weekday: Weekday::Thu,
+                    month: Month::November,
+                },
+            )
+            .with_rule("Easter", HolidayRule::EasterOffset(0))
+    }
+
+    fn fixed(month: Month, day: u32) -> HolidayRule {
+        HolidayRule::Fixed { month, day }
+    }
+
+    fn nth(n: i8, weekday: Weekday, month: Month) -> HolidayRule {
+        HolidayRule::NthWeekdayOfMonth { n, weekday, month }
+    }
+
+    #[rstest]
+    #[case(
+        fixed(Month::December, 25),
+        2024,
+        NaiveDate::from_ymd_opt(2024, 12, 25)
+    )]
+    #[case(fixed(Month::February, 29), 2023, None)]
+    #[case(
+        nth(4, Weekday::Thu, Month::November),
+        2024,
+        NaiveDate::from_ymd_opt(2024, 11, 28)
+    )]
+    #[case(
+        nth(4, Weekday::Thu, Month::November),
+        2018,
+        NaiveDate::from_ymd_opt(2018, 11, 22)
+    )]
+    #[case(
+        nth(2, Weekday::Sun, Month::May),
+        2024,
+        NaiveDate::from_ymd_opt(2024, 5, 12)
+    )]
+    #[case(
+        nth(1, Weekday::Mon, Month::September),
+        2024,
+        NaiveDate::from_ymd_opt(2024, 9, 2)
+    )]
+    #[case(nth(-1, Weekday::Mon, Month::May), 2024, NaiveDate::from_ymd_opt(2024, 5, 27))]
+    #[case(nth(-1, Weekday::Fri, Month::May), 2024, NaiveDate::from_ymd_opt(2024, 5, 31))]
+    #[case(nth(-2, Weekday::Sun, Month::November), 2024, NaiveDate::from_ymd_opt(2024, 11, 17))]
+    #[case(nth(5, Weekday::Mon, Month::February), 2024, None)]
+    #[case(nth(-5, Weekday::Mon, Month::February), 2024, None)]
+    #[case(nth(0, Weekday::Mon, Month::February), 2024, None)]
+    #[case(HolidayRule::EasterOffset(-2), 2024, NaiveDate::from_ymd_opt(2024, 3, 29))]
+    #[case(
+        HolidayRule::EasterOffset(49),
+        2025,
+        NaiveDate::from_ymd_opt(2025, 6, 8)
+    )]
+    fn test_date_in(
+        #[case] rule: HolidayRule,
+        #[case] year: i32,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(rule.date_in(year), expected);
+    }
+
+    #[rstest]
+    #[case(2000, NaiveDate::from_ymd_opt(2000, 4, 23))]
+    #[case(2019, NaiveDate::from_ymd_opt(2019, 4, 21))]
+    #[case(2024, NaiveDate::from_ymd_opt(2024, 3, 31))]
+    #[case(2025, NaiveDate::from_ymd_opt(2025, 4, 20))]
+    #[case(2038, NaiveDate::from_ymd_opt(2038, 4, 25))]
+    #[case(2285, NaiveDate::from_ymd_opt(2285, 3, 22))]
+    fn test_easter(#[case] year: i32, #[case] expected: Option<NaiveDate>) {
+        assert_eq!(easter(year), expected);
+    }
+
+    #[rstest]
+    #[case("christmas", Ok(("", fixed(Month::December, 25))))]
+    #[case("Christmas Eve!", Ok(("!", fixed(Month::December, 24))))]
+    #[case("Christmas Evening", Ok((" Evening", fixed(Month::December, 25))))]
+    #[case(
+        "Christmastime",
+        Err(nom::Err::Error(Error::Nom("Christmastime", ErrorKind::Tag)))
+    )]
+    fn test_recognize(#[case] input: &str, #[case] expected: IResult<&str, HolidayRule>) {
+        assert_eq!(table().recognize(input), expected);
+    }
+
+    #[rstest]
+    #[case("Christmas", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 25).unwrap())))]
+    #[case("Christmas 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 12, 25).unwrap())))]
+    #[case("Leap Day", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case("Leap Day 2025", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("Thanksgiving", Ok(("", NaiveDate::from_ymd_opt(2024, 11, 28).unwrap())))]
+    #[case("Easter 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 4, 20).unwrap())))]
+    fn test_holiday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::fixed(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+        let table = table();
+        let year = value(2025, tag(" 2025"));
+
+        assert_eq!(holiday(&ctx, &table, year)(input), expected);
+    }
+    #[test]
+    fn test_holiday_year_out_of_range() {
+        let ctx = ParserContext::fixed(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+        let table = table();
+        let year = map(preceded(tag(" "), y4_with(&ctx)), |year| year as i32);
+
+        assert_eq!(
+            holiday(&ctx, &table, year)("Christmas 1800"),
+            Err(nom::Err::Error(Error::YearOutOfRange {
+                value: 1800,
+                input: "1800"
+            }))
+        );
+    }
+}
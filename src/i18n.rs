@@ -1,17 +1,1636 @@
-use chrono::{Datelike, Local, NaiveDate, TimeDelta, Weekday};
+#[cfg(feature = "clock")]
+use chrono::Local;
+use chrono::{Datelike, Days, Month, NaiveDate, TimeDelta, Weekday};
+#[cfg(any(feature = "zh", feature = "ja"))]
+use nom::error::{ErrorKind, ParseError};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{space0, space1},
+    combinator::{map, map_opt, opt, value, verify},
+    error::context,
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    Parser,
+};
 
+pub use crate::calendar::{
+    end_of_period, part_of_month, start_of_period, Hemisphere, MonthPart, Period, Season,
+};
+use crate::{
+    calendar::{self, season_range as calendar_season_range, shift, MonthOverflow},
+    context::ParserContext,
+    error::Error,
+    numeric::{dd, mm, numeric_date_parts_separator, opt_year, y4, y4_with},
+    range::DateRange,
+    types::IResult,
+};
+
+#[cfg(feature = "any")]
+pub mod any;
+#[cfg(feature = "ar")]
+pub mod ar;
+#[cfg(feature = "az")]
+pub mod az;
+#[cfg(feature = "bg")]
+pub mod bg;
+#[cfg(feature = "de")]
+pub mod de;
 #[cfg(feature = "en")]
 pub mod en;
+#[cfg(feature = "es")]
+pub mod es;
+#[cfg(feature = "fr")]
+pub mod fr;
+#[cfg(feature = "he")]
+pub mod he;
+#[cfg(feature = "hy")]
+pub mod hy;
+#[cfg(feature = "it")]
+pub mod it;
+#[cfg(feature = "ja")]
+pub mod ja;
+#[cfg(feature = "ka")]
+pub mod ka;
+#[cfg(feature = "pl")]
+pub mod pl;
+#[cfg(feature = "pt")]
+pub mod pt;
+#[cfg(feature = "ro")]
+pub mod ro;
 #[cfg(feature = "ru")]
 pub mod ru;
+#[cfg(feature = "tr")]
+pub mod tr;
+#[cfg(feature = "uk")]
+pub mod uk;
+#[cfg(feature = "uz")]
+pub mod uz;
+#[cfg(feature = "zh")]
+pub mod zh;
+
+mod language;
+mod spec;
+
+#[cfg(feature = "clock")]
+pub use self::language::bundle_for;
+pub use self::language::{bundle_for_with, Language};
+pub use self::spec::LanguageSpec;
 
 /// Returns the [`NaiveDate`] for the specified [`Weekday`] in the current week
+/// or [`None`] if the date is out of the range of [`NaiveDate`]
 ///
 /// Suppose today is `16/07/2024`, so the `naive_date_for_weekday(Weekday::Mon)`
 /// will return the `15/07/2024` and the `naive_date_for_weekday(Weekday::Sat)`
 /// will return the `21/07/2024`
-pub fn naive_date_for_weekday(weekday: Weekday) -> NaiveDate {
-    let now = Local::now().date_naive();
-    now.checked_add_signed(TimeDelta::try_days(weekday as i64 - now.weekday() as i64).unwrap())
-        .unwrap()
+#[cfg(feature = "clock")]
+pub fn naive_date_for_weekday(weekday: Weekday) -> Option<NaiveDate> {
+    naive_date_for_weekday_at(Local::now().date_naive(), weekday)
+}
+
+/// Returns the [`NaiveDate`] for the specified [`Weekday`] in the week of the
+/// specified date or [`None`] if the date is out of the range of [`NaiveDate`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::naive_date_for_weekday_at;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 7, 16).unwrap();
+///
+/// assert_eq!(
+///     naive_date_for_weekday_at(date, Weekday::Mon),
+///     NaiveDate::from_ymd_opt(2024, 7, 15)
+/// );
+/// assert_eq!(
+///     naive_date_for_weekday_at(date, Weekday::Sun),
+///     NaiveDate::from_ymd_opt(2024, 7, 21)
+/// );
+/// assert_eq!(
+///     naive_date_for_weekday_at(NaiveDate::MAX, Weekday::Sun),
+///     None
+/// );
+/// ```
+pub fn naive_date_for_weekday_at(date: NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    date.checked_add_signed(TimeDelta::try_days(
+        weekday.num_days_from_monday() as i64 - date.weekday().num_days_from_monday() as i64,
+    )?)
+}
+
+/// Creates a parser which recognizes the relative day using the specified
+/// `parser` and returns the reference date of the specified [`ParserContext`]
+/// shifted by the specified number of `days`, or [`Error::DateOutOfRange`] if
+/// the resulting date is out of the range of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::bytes::complete::tag;
+/// use nom_date_parsers::{context::ParserContext, error::Error, i18n::relative_day};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     relative_day(&ctx, -1, tag("yesterday"))("yesterday")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 15).unwrap()
+/// );
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::MAX);
+///
+/// assert_eq!(
+///     relative_day(&ctx, 1, tag("tomorrow"))("tomorrow"),
+///     Err(nom::Err::Error(Error::DateOutOfRange))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn relative_day<'a, 'c, O, P>(
+    ctx: &'c ParserContext,
+    days: i64,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, O, Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, _) = parser.parse(input)?;
+
+        Ok((
+            input,
+            TimeDelta::try_days(days)
+                .and_then(|delta| ctx.today().checked_add_signed(delta))
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Returns the specified `date` shifted by the specified `amount` of periods or
+/// [`None`] if the resulting date is out of the range of [`NaiveDate`].
+///
+/// Same as the [`shift`] function with the [`MonthOverflow::Clamp`] policy, so
+/// the day is clamped to the last day of the resulting month (e.g.
+/// `31/01/2024` + 1 month is `29/02/2024`).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::{shift_date, Period};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+///
+/// assert_eq!(
+///     shift_date(date, 1, Period::Month),
+///     NaiveDate::from_ymd_opt(2024, 2, 29)
+/// );
+/// assert_eq!(
+///     shift_date(date, -2, Period::Week),
+///     NaiveDate::from_ymd_opt(2024, 1, 17)
+/// );
+/// ```
+pub fn shift_date(date: NaiveDate, amount: i64, period: Period) -> Option<NaiveDate> {
+    shift(date, amount, period, MonthOverflow::Clamp)
+}
+
+/// Creates a parser which recognizes the relative duration using the specified
+/// `parser`, which returns the amount and the [`Period`], and returns the
+/// reference date of the specified [`ParserContext`] shifted forward (if the
+/// `sign` is positive) or backward (if the `sign` is negative) by the
+/// duration according to the [`MonthOverflow`] policy of the context, see
+/// [`shift`]. Returns [`Error::DateOutOfRange`] if the
+/// resulting date is out of the range of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{relative_duration, Period},
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     relative_duration(&ctx, 1, value((2, Period::Week), tag("in two weeks")))("in two weeks")?
+///         .1,
+///     NaiveDate::from_ymd_opt(2024, 7, 30).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn relative_duration<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    sign: i64,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, (u32, Period), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, (amount, period)) = parser.parse(input)?;
+
+        Ok((
+            input,
+            shift(
+                ctx.today(),
+                sign.signum() * i64::from(amount),
+                period,
+                ctx.month_overflow(),
+            )
+            .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the relative period (e.g. `next week` or
+/// `last month`) using the specified language-specific `parser`, which returns
+/// the number of periods (e.g. `1` for `next` and `-1` for `last`) and the
+/// [`Period`], and returns the reference date of the specified
+/// [`ParserContext`] shifted by this number of periods according to the
+/// [`MonthOverflow`] policy of the context (e.g. the same weekday of the next
+/// week). Returns [`Error::DateOutOfRange`] if the resulting date is out of the
+/// range of [`NaiveDate`].
+///
+/// Use the [`relative_period_range`] to get the whole period instead.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{relative_period, Period},
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     relative_period(&ctx, value((1, Period::Week), tag("next week")))("next week")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 23).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn relative_period<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, (i64, Period), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, (amount, period)) = parser.parse(input)?;
+
+        Ok((
+            input,
+            shift(ctx.today(), amount, period, ctx.month_overflow())
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Same as [`relative_period`], but returns the whole period containing the
+/// shifted reference date as the [`DateRange`] (e.g. from Monday to Sunday of
+/// the next week), see [`start_of_period`] and [`end_of_period`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{relative_period_range, Period},
+///     range::DateRange,
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     relative_period_range(&ctx, value((-1, Period::Month), tag("last month")))("last month")?.1,
+///     DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn relative_period_range<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DateRange> + 'c
+where
+    P: Parser<&'a str, (i64, Period), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, (amount, period)) = parser.parse(input)?;
+        let range = shift(ctx.today(), amount, period, ctx.month_overflow()).and_then(|date| {
+            Some(DateRange {
+                start: start_of_period(date, period)?,
+                end: end_of_period(date, period)?,
+            })
+        });
+
+        Ok((input, range.ok_or(nom::Err::Error(Error::DateOutOfRange))?))
+    }
+}
+
+/// Creates a parser which recognizes the weekday modifier (e.g. `next`) using
+/// the specified `parser`, followed by at least one space and the weekday
+/// recognized by the specified language-specific `named_weekday` parser.
+/// Returns the date of the weekday in the week shifted by the specified number
+/// of `weeks` from the week of the reference date of the specified
+/// [`ParserContext`], or [`Error::DateOutOfRange`] if the resulting date is out
+/// of the range of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{context::ParserContext, i18n::relative_weekday, types::IResult};
+///
+/// fn friday(input: &str) -> IResult<&str, Weekday> {
+///     value(Weekday::Fri, tag("friday"))(input)
+/// }
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     relative_weekday(&ctx, 1, tag("next"), friday)("next friday")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 26).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn relative_weekday<'a, 'c, O, P>(
+    ctx: &'c ParserContext,
+    weeks: i64,
+    mut parser: P,
+    named_weekday: fn(&str) -> IResult<&str, Weekday>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, O, Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, _) = parser.parse(input)?;
+        let (input, weekday) = preceded(space1, context("weekday", named_weekday))(input)?;
+
+        Ok((
+            input,
+            TimeDelta::try_weeks(weeks)
+                .and_then(|delta| ctx.today().checked_add_signed(delta))
+                .and_then(|date| naive_date_for_weekday_at(date, weekday))
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the number of the calendar week using the
+/// specified language-specific `parser` (e.g. `KW 29`) and returns the Monday
+/// of the ISO 8601 week with this number in the ISO week-numbering year of the
+/// reference date of the specified [`ParserContext`].
+///
+/// Returns [`Error::NonExistentDate`] if the year has no week with this number
+/// (the 53rd week exists only in the long years).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, sequence::preceded};
+/// use nom_date_parsers::{context::ParserContext, error::Error, i18n::week_of_year, numeric::ww};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     week_of_year(&ctx, preceded(tag("week "), ww))("week 29")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 15).unwrap()
+/// );
+/// assert_eq!(
+///     week_of_year(&ctx, preceded(tag("week "), ww))("week 53"),
+///     Err(nom::Err::Error(Error::NonExistentDate))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn week_of_year<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, u32, Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, week) = parser.parse(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_isoywd_opt(ctx.today().iso_week().year(), week, Weekday::Mon)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the day of the year and the optional year
+/// using the specified language-specific `parser` (e.g. `day 195 of 2024`) and
+/// returns the corresponding [`NaiveDate`]. If the year is omitted, the year
+/// of the reference date of the specified [`ParserContext`] is used.
+///
+/// Returns [`Error::NonExistentDate`] if the year has no such day (the `366th`
+/// day exists only in the leap years).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{
+///     bytes::complete::tag,
+///     character::complete::i32,
+///     combinator::opt,
+///     sequence::{pair, preceded},
+/// };
+/// use nom_date_parsers::{context::ParserContext, i18n::day_of_year, numeric::ddd};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let parser = || pair(preceded(tag("day "), ddd), opt(preceded(tag(" of "), i32)));
+///
+/// assert_eq!(
+///     day_of_year(&ctx, parser())("day 195")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     day_of_year(&ctx, parser())("day 1 of 2025")?.1,
+///     NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn day_of_year<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, (u32, Option<i32>), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, (day, year)) = parser.parse(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_yo_opt(year.unwrap_or(ctx.today().year()), day)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// The month referred to by the language-specific phrase, see [`month_part`],
+/// [`nth_weekday_of_month`] and [`day_of_month`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthRef {
+    /// The named month of the specified year or of the year of the reference
+    /// date if the year is omitted (e.g. `July`)
+    Named(Month, Option<i32>),
+    /// The month shifted by the specified number of months from the month of
+    /// the reference date (e.g. `1` for the `next month`)
+    Relative(i64),
+    /// The named month of the year shifted by the specified number of years
+    /// from the year of the reference date (e.g. `1` for the `July next year`)
+    NamedRelative(Month, i64),
+}
+
+impl MonthRef {
+    /// Returns the first day of the referred month, the relative months and
+    /// the omitted years are resolved from the reference date of the specified
+    /// [`ParserContext`].
+    fn first_day(&self, ctx: &ParserContext) -> Option<NaiveDate> {
+        match *self {
+            MonthRef::Named(month, year) => NaiveDate::from_ymd_opt(
+                year.unwrap_or(ctx.today().year()),
+                month.number_from_month(),
+                1,
+            ),
+            MonthRef::Relative(months) => ctx
+                .today()
+                .with_day(1)
+                .and_then(|date| shift(date, months, Period::Month, MonthOverflow::Clamp)),
+            MonthRef::NamedRelative(month, years) => NaiveDate::from_ymd_opt(
+                i32::try_from(years)
+                    .ok()
+                    .and_then(|years| ctx.today().year().checked_add(years))?,
+                month.number_from_month(),
+                1,
+            ),
+        }
+    }
+}
+
+/// Creates a parser which recognizes the [`MonthPart`] and the [`MonthRef`]
+/// using the specified language-specific `parser` (e.g. `end of July`) and
+/// returns the corresponding day of the month, see [`part_of_month`]. The
+/// relative months are resolved from the reference date of the specified
+/// [`ParserContext`].
+///
+/// Returns [`Error::DateOutOfRange`] if the resulting date is out of the range
+/// of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{month_part, MonthPart, MonthRef},
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let mut parser = month_part(
+///     &ctx,
+///     value(
+///         (MonthPart::End, MonthRef::Relative(1)),
+///         tag("end of next month"),
+///     ),
+/// );
+///
+/// assert_eq!(
+///     parser("end of next month")?.1,
+///     NaiveDate::from_ymd_opt(2024, 8, 31).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn month_part<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, (MonthPart, MonthRef), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, (part, month)) = parser.parse(input)?;
+
+        Ok((
+            input,
+            month
+                .first_day(ctx)
+                .and_then(|date| part_of_month(date, part))
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the `day` and the [`MonthRef`] using the
+/// specified language-specific `parser` (e.g. `13th of next month`) and
+/// returns the day of the referred month. The relative months are resolved
+/// from the reference date of the specified [`ParserContext`].
+///
+/// If the month is shorter than the day (e.g. `31st of next month` in
+/// January), the day is handled according to the [`MonthOverflow`] policy of
+/// the context: clamped to the last day of the month or rolled over into the
+/// next month.
+///
+/// Returns [`Error::DateOutOfRange`] if the resulting date is out of the range
+/// of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{day_of_month, MonthRef},
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 1, 16).unwrap());
+/// let mut parser = day_of_month(
+///     &ctx,
+///     value((31, MonthRef::Relative(1)), tag("31st of next month")),
+/// );
+///
+/// assert_eq!(
+///     parser("31st of next month")?.1,
+///     NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn day_of_month<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, (u32, MonthRef), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, (day, month)) = parser.parse(input)?;
+        let date = month
+            .first_day(ctx)
+            .and_then(|first_day| match ctx.month_overflow() {
+                MonthOverflow::Clamp => first_day
+                    .with_day(day)
+                    .or_else(|| part_of_month(first_day, MonthPart::End)),
+                MonthOverflow::Roll => {
+                    first_day.checked_add_days(Days::new(u64::from(day.saturating_sub(1))))
+                }
+            });
+
+        Ok((input, date.ok_or(nom::Err::Error(Error::DateOutOfRange))?))
+    }
+}
+
+/// Recognizes the year and month separated by the `dash` symbol (e.g.
+/// `2024-10` in the `last friday of 2024-10`) and returns the corresponding
+/// [`MonthRef::Named`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::{y4_mm_month, MonthRef};
+///
+/// assert_eq!(
+///     y4_mm_month("2024-10")?.1,
+///     MonthRef::Named(Month::October, Some(2024))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn y4_mm_month(input: &str) -> IResult<&str, MonthRef> {
+    y4_mm_month_by(y4)(input)
+}
+
+/// Creates the [`y4_mm_month`] parser which uses the
+/// [`YearRange`](crate::context::YearRange) of the specified [`ParserContext`].
+pub fn y4_mm_month_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, MonthRef> + '_ {
+    y4_mm_month_by(y4_with(ctx))
+}
+
+/// Creates the [`y4_mm_month`] parser which uses the specified four-digit year
+/// parser.
+fn y4_mm_month_by<Y>(y4: Y) -> impl Fn(&str) -> IResult<&str, MonthRef>
+where
+    Y: Fn(&str) -> IResult<&str, u32>,
+{
+    move |input: &str| {
+        map_opt(separated_pair(&y4, tag("-"), mm), |(year, month)| {
+            Month::try_from(month as u8)
+                .ok()
+                .map(|month| MonthRef::Named(month, Some(year as i32)))
+        })(input)
+    }
+}
+
+/// Creates a parser which recognizes the ordinal number `n`, the weekday and
+/// the [`MonthRef`] using the specified language-specific `parser` (e.g.
+/// `first monday of september`) and returns the `n`-th weekday of the month,
+/// see [`nth_weekday_of_month`](crate::calendar::nth_weekday_of_month). The
+/// relative months are resolved from the reference date of the specified
+/// [`ParserContext`].
+///
+/// Returns [`Error::NonExistentDate`] if the month doesn't have such a weekday
+/// (e.g. the 5th Monday) and [`Error::DateOutOfRange`] if the month is out of
+/// the range of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate, Weekday};
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{nth_weekday_of_month, MonthRef},
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let mut parser = nth_weekday_of_month(
+///     &ctx,
+///     value(
+///         (-1, Weekday::Fri, MonthRef::Relative(1)),
+///         tag("last friday of next month"),
+///     ),
+/// );
+///
+/// assert_eq!(
+///     parser("last friday of next month")?.1,
+///     NaiveDate::from_ymd_opt(2024, 8, 30).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn nth_weekday_of_month<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, (i8, Weekday, MonthRef), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, (n, weekday, month)) = parser.parse(input)?;
+        let first_day = month
+            .first_day(ctx)
+            .ok_or(nom::Err::Error(Error::DateOutOfRange))?;
+
+        Ok((
+            input,
+            calendar::nth_weekday_of_month(first_day, weekday, n)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// The season referred to by the language-specific phrase, see [`season`] and
+/// [`season_range`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeasonRef {
+    /// The season which starts in the specified year (e.g. `winter 2024` lasts
+    /// from December 2024 to February 2025) or the current season if the year
+    /// is omitted (e.g. `summer`)
+    Named(Season, Option<i32>),
+    /// The season shifted by the specified number of years from the current
+    /// season (e.g. `1` for the `next spring`)
+    Relative(Season, i64),
+}
+
+impl SeasonRef {
+    /// Returns the range of the referred season in the [`Hemisphere`] of the
+    /// specified [`ParserContext`]. The current season is the one containing
+    /// the reference date or, if there is no such season, the one starting in
+    /// the year of the reference date.
+    fn range(&self, ctx: &ParserContext) -> Option<DateRange> {
+        let today = ctx.today();
+        let (season, years) = match *self {
+            SeasonRef::Named(season, Some(year)) => {
+                return calendar_season_range(season, year, ctx.hemisphere())
+            }
+            SeasonRef::Named(season, None) => (season, 0),
+            SeasonRef::Relative(season, years) => (season, years),
+        };
+        let previous_year = today.year().checked_sub(1)?;
+        let current_year = match calendar_season_range(season, previous_year, ctx.hemisphere()) {
+            Some(range) if range.contains(today) => previous_year,
+            _ => today.year(),
+        };
+
+        calendar_season_range(
+            season,
+            i32::try_from(years)
+                .ok()
+                .and_then(|years| current_year.checked_add(years))?,
+            ctx.hemisphere(),
+        )
+    }
+}
+
+/// Creates a parser which recognizes the [`SeasonRef`] using the specified
+/// language-specific `parser` (e.g. `next summer`) and returns the whole
+/// season as the [`DateRange`], see
+/// [`season_range`](crate::calendar::season_range). The relative seasons are
+/// resolved from the reference date and the months of the seasons are
+/// determined by the [`Hemisphere`] of the specified [`ParserContext`].
+///
+/// Returns [`Error::DateOutOfRange`] if the resulting range is out of the range
+/// of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{season_range, Season, SeasonRef},
+///     range::DateRange,
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 1, 16).unwrap());
+/// let mut parser = season_range(
+///     &ctx,
+///     value(SeasonRef::Relative(Season::Winter, 0), tag("this winter")),
+/// );
+///
+/// assert_eq!(
+///     parser("this winter")?.1,
+///     DateRange {
+///         start: NaiveDate::from_ymd_opt(2023, 12, 1).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn season_range<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DateRange> + 'c
+where
+    P: Parser<&'a str, SeasonRef, Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, season) = parser.parse(input)?;
+
+        Ok((
+            input,
+            season
+                .range(ctx)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the [`SeasonRef`] using the specified
+/// language-specific `parser` (e.g. `next summer`) and returns the first day
+/// of the season, see [`season_range`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{season, Season, SeasonRef},
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let mut parser = season(
+///     &ctx,
+///     value(SeasonRef::Relative(Season::Spring, 1), tag("next spring")),
+/// );
+///
+/// assert_eq!(
+///     parser("next spring")?.1,
+///     NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn season<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, SeasonRef, Error<&'a str>> + 'c,
+{
+    let mut parser = season_range(ctx, parser);
+
+    move |input: &'a str| {
+        let (input, range) = parser(input)?;
+
+        Ok((input, range.start))
+    }
+}
+
+/// Creates a parser which recognizes the number of weeks using the specified
+/// language-specific `parser` (e.g. `1` for the `next weekend`) and returns the
+/// weekend of the week shifted by this number of weeks from the week of the
+/// reference date of the specified [`ParserContext`] as the [`DateRange`], see
+/// [`weekend`](crate::calendar::weekend).
+///
+/// Returns [`Error::DateOutOfRange`] if the resulting range is out of the range
+/// of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{context::ParserContext, i18n::weekend_range, range::DateRange};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     weekend_range(&ctx, value(1, tag("next weekend")))("next weekend")?.1,
+///     DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 7, 27).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 7, 28).unwrap(),
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn weekend_range<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DateRange> + 'c
+where
+    P: Parser<&'a str, i64, Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, weeks) = parser.parse(input)?;
+
+        Ok((
+            input,
+            TimeDelta::try_weeks(weeks)
+                .and_then(|delta| ctx.today().checked_add_signed(delta))
+                .and_then(calendar::weekend)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the number of weeks using the specified
+/// language-specific `parser` (e.g. `1` for the `next weekend`) and returns the
+/// Saturday of the weekend, see [`weekend_range`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{context::ParserContext, i18n::weekend};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 21).unwrap());
+///
+/// assert_eq!(
+///     weekend(&ctx, value(0, tag("this weekend")))("this weekend")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 20).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn weekend<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, i64, Error<&'a str>> + 'c,
+{
+    let mut parser = weekend_range(ctx, parser);
+
+    move |input: &'a str| {
+        let (input, range) = parser(input)?;
+
+        Ok((input, range.start))
+    }
+}
+
+/// Creates a parser which recognizes the number of business days using the
+/// specified language-specific `parser` (e.g. `1` for the `next business day`)
+/// and returns the reference date of the specified [`ParserContext`] shifted by
+/// this number of business days of the
+/// [`BusinessCalendar`](crate::calendar::BusinessCalendar) of the context, see
+/// [`add_business_days`](crate::calendar::add_business_days).
+///
+/// Returns [`Error::DateOutOfRange`] if the resulting date is out of the range
+/// of [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::{bytes::complete::tag, combinator::value};
+/// use nom_date_parsers::{context::ParserContext, i18n::business_day};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap());
+///
+/// assert_eq!(
+///     business_day(&ctx, value(1, tag("next business day")))("next business day")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 22).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn business_day<'a, 'c, P>(
+    ctx: &'c ParserContext,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate> + 'c
+where
+    P: Parser<&'a str, i64, Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, days) = parser.parse(input)?;
+
+        Ok((
+            input,
+            ctx.business_calendar()
+                .add_business_days(ctx.today(), days)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Recognizes a separator between the `day` and the named `month` parts: either
+/// the `dot` symbol followed by any number of spaces (e.g. `13. Juli`) or the
+/// [`numeric_date_parts_separator`].
+pub fn day_month_separator(input: &str) -> IResult<&str, ()> {
+    alt((
+        value((), pair(tag("."), space0)),
+        numeric_date_parts_separator,
+    ))(input)
+}
+
+/// Creates a parser which recognizes the `day`, named `month` and `year` parts
+/// separated by the [`numeric_date_parts_separator`] and returns the
+/// [`NaiveDate`] with the selected parts if the date exists, otherwise returns
+/// [`Error::NonExistentDate`]. The `day` part may also be followed by the `dot`
+/// symbol and any number of spaces (e.g. `13. Juli 2024`).
+///
+/// The `month` part is recognized by the specified language-specific
+/// `named_month` parser.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate};
+/// use nom::{bytes::complete::tag_no_case, combinator::value};
+/// use nom_date_parsers::{i18n::dd_named_month_y4, types::IResult};
+///
+/// fn july(input: &str) -> IResult<&str, Month> {
+///     value(Month::July, tag_no_case("july"))(input)
+/// }
+///
+/// assert_eq!(
+///     dd_named_month_y4(july)("13 July 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_named_month_y4(
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| {
+        let (input, (dd, (), month, (), y4)) = tuple((
+            dd,
+            day_month_separator,
+            context("month", named_month),
+            numeric_date_parts_separator,
+            y4,
+        ))(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(y4 as i32, month.number_from_month(), dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the named `month`, `day` and `year` parts
+/// and returns the [`NaiveDate`] with the selected parts if the date exists,
+/// otherwise returns [`Error::NonExistentDate`].
+///
+/// The `month` and `day` parts are separated by the
+/// [`numeric_date_parts_separator`], the `day` and `year` parts are separated
+/// either by the `comma` symbol followed by any number of spaces or by the
+/// [`numeric_date_parts_separator`].
+///
+/// The `month` part is recognized by the specified language-specific
+/// `named_month` parser.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate};
+/// use nom::{bytes::complete::tag_no_case, combinator::value};
+/// use nom_date_parsers::{i18n::named_month_dd_y4, types::IResult};
+///
+/// fn july(input: &str) -> IResult<&str, Month> {
+///     value(Month::July, tag_no_case("july"))(input)
+/// }
+///
+/// assert_eq!(
+///     named_month_dd_y4(july)("July 13, 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month_dd_y4(
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| {
+        let (input, (month, (), dd, (), y4)) = tuple((
+            context("month", named_month),
+            numeric_date_parts_separator,
+            dd,
+            alt((
+                value((), pair(tag(","), space0)),
+                numeric_date_parts_separator,
+            )),
+            y4,
+        ))(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(y4 as i32, month.number_from_month(), dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the `day` and named `month` parts
+/// separated by the [`numeric_date_parts_separator`] (or the `dot` symbol
+/// followed by any number of spaces) and returns the [`NaiveDate`] with the
+/// selected day, month and current year if the date exists, otherwise returns
+/// [`Error::NonExistentDate`].
+///
+/// The `month` part is recognized by the specified language-specific
+/// `named_month` parser.
+#[cfg(feature = "clock")]
+pub fn dd_named_month_only(
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| dd_named_month_only_with(&ParserContext::default(), named_month)(input)
+}
+
+/// Same as [`dd_named_month_only`], but takes the year from the reference date
+/// of the specified [`ParserContext`].
+pub fn dd_named_month_only_with(
+    ctx: &ParserContext,
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (dd, (), month)) =
+            tuple((dd, day_month_separator, context("month", named_month)))(input)?;
+
+        Ok((
+            input,
+            ctx.resolve(Some(month.number_from_month()), dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Creates a parser which recognizes the `day-month-year` date with either the
+/// numeric or the named `month` part and the optional `month` and `year`
+/// parts, e.g. `13/07/2024`, `13 July 2024`, `13 July`, `13.07` or `13`.
+///
+/// Does the same as the `alt((dd_named_month_y4, dd_named_month_only, dmy))`
+/// combination, but parses the leading `day` part only once and then branches
+/// on what follows it, so the error of the matched branch is returned as is
+/// (e.g. [`Error::NonExistentDate`] for the `31 February 2024`).
+///
+/// The `month` part is recognized either by the specified language-specific
+/// `named_month` parser or by the [`mm`] parser, the missing parts are taken
+/// from the current date.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate};
+/// use nom::{bytes::complete::tag_no_case, combinator::value};
+/// use nom_date_parsers::{i18n::dmy_named_month, types::IResult};
+///
+/// fn july(input: &str) -> IResult<&str, Month> {
+///     value(Month::July, tag_no_case("july"))(input)
+/// }
+///
+/// assert_eq!(
+///     dmy_named_month(july)("13 July 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     dmy_named_month(july)("13/07/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dmy_named_month(
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| dmy_named_month_with(&ParserContext::default(), named_month)(input)
+}
+
+/// Same as [`dmy_named_month`], but takes the missing parts from the reference
+/// date of the specified [`ParserContext`].
+pub fn dmy_named_month_with(
+    ctx: &ParserContext,
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dmy_named_month_by(ctx, named_month)
+}
+
+/// Same as [`dmy_named_month_with`], but accepts any `named_month` parser
+/// (e.g. the closure borrowing the [`LanguageSpec`]).
+fn dmy_named_month_by<'c, M>(
+    ctx: &'c ParserContext,
+    named_month: M,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + 'c
+where
+    M: Fn(&str) -> IResult<&str, Month> + 'c,
+{
+    move |input: &str| {
+        let (input, (day, month_y4)) = pair(
+            dd,
+            opt_year(pair(
+                alt((
+                    preceded(
+                        day_month_separator,
+                        map(&named_month, |month| month.number_from_month()),
+                    ),
+                    preceded(numeric_date_parts_separator, mm),
+                )),
+                opt_year(preceded(numeric_date_parts_separator, y4_with(ctx))),
+            )),
+        )(input)?;
+        let date = match month_y4 {
+            Some((month, Some(y4))) => NaiveDate::from_ymd_opt(y4 as i32, month, day),
+            Some((month, None)) => ctx.resolve(Some(month), day),
+            None => ctx.resolve(None, day),
+        };
+
+        Ok((input, date.ok_or(nom::Err::Error(Error::NonExistentDate))?))
+    }
+}
+
+/// Creates a parser which recognizes the `month-day-year` numeric date with
+/// the optional `year` part, the `day-month-year` date with the named `month`
+/// part and the optional `year` part or the single `day` part, e.g.
+/// `07/13/2024`, `07/13`, `13 July 2024`, `13 July` or `13`.
+///
+/// Does the same as the `alt((dd_named_month_y4, dd_named_month_only, mdy))`
+/// combination, but parses the leading number only once and then branches on
+/// what follows it.
+///
+/// The `month` part is recognized either by the specified language-specific
+/// `named_month` parser or by the [`mm`] parser, the missing parts are taken
+/// from the current date.
+#[cfg(feature = "clock")]
+pub fn mdy_named_month(
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| mdy_named_month_with(&ParserContext::default(), named_month)(input)
+}
+
+/// Same as [`mdy_named_month`], but takes the missing parts from the reference
+/// date of the specified [`ParserContext`].
+pub fn mdy_named_month_with(
+    ctx: &ParserContext,
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    mdy_named_month_by(ctx, named_month)
+}
+
+/// Same as [`mdy_named_month_with`], but accepts any `named_month` parser.
+fn mdy_named_month_by<'c, M>(
+    ctx: &'c ParserContext,
+    named_month: M,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + 'c
+where
+    M: Fn(&str) -> IResult<&str, Month> + 'c,
+{
+    move |input: &str| {
+        let (input, number) = dd(input)?;
+        let (input, month_day_y4) = opt_year(pair(
+            alt((
+                preceded(
+                    day_month_separator,
+                    map(&named_month, |month| (month.number_from_month(), number)),
+                ),
+                preceded(
+                    numeric_date_parts_separator,
+                    map(verify(dd, |_| number <= 12), |day| (number, day)),
+                ),
+            )),
+            opt_year(preceded(numeric_date_parts_separator, y4_with(ctx))),
+        ))(input)?;
+        let date = match month_day_y4 {
+            Some(((month, day), Some(y4))) => NaiveDate::from_ymd_opt(y4 as i32, month, day),
+            Some(((month, day), None)) => ctx.resolve(Some(month), day),
+            None => ctx.resolve(None, number),
+        };
+
+        Ok((input, date.ok_or(nom::Err::Error(Error::NonExistentDate))?))
+    }
+}
+
+/// Recognizes the `unit` suffix (e.g. `年`) of the date part surrounded by any
+/// number of spaces.
+#[cfg(any(feature = "zh", feature = "ja"))]
+pub(crate) fn unit_suffix<'a>(unit: &'static str) -> impl Fn(&'a str) -> IResult<&'a str, ()> {
+    move |input: &'a str| value((), tuple((space0, tag(unit), space0)))(input)
+}
+
+/// Recognizes one of the specified `day_suffixes` (e.g. `日`) preceded by any
+/// number of spaces.
+#[cfg(any(feature = "zh", feature = "ja"))]
+pub(crate) fn day_suffix<'a>(
+    day_suffixes: &'static [&'static str],
+) -> impl Fn(&'a str) -> IResult<&'a str, ()> {
+    move |input: &'a str| {
+        let (input, _) = space0(input)?;
+
+        day_suffixes
+            .iter()
+            .find_map(|suffix| input.strip_prefix(suffix))
+            .map(|input| (input, ()))
+            .ok_or(nom::Err::Error(Error::from_error_kind(
+                input,
+                ErrorKind::Tag,
+            )))
+    }
+}
+
+/// Recognizes the `year`, `month` and `day` parts followed by the `年` and `月`
+/// suffixes and one of the specified `day_suffixes` (e.g. `2024年7月13日`) and
+/// returns the parts as is, so the `year` parser may recognize any year
+/// notation (e.g. the era and its year).
+#[cfg(any(feature = "zh", feature = "ja"))]
+pub(crate) fn y_mm_dd_suffixed<'a, O, Y>(
+    year: Y,
+    day_suffixes: &'static [&'static str],
+) -> impl Fn(&'a str) -> IResult<&'a str, (O, u32, u32)>
+where
+    Y: Fn(&'a str) -> IResult<&'a str, O>,
+{
+    move |input: &'a str| {
+        let (input, (year, (), mm, (), dd, ())) = tuple((
+            &year,
+            unit_suffix("年"),
+            mm,
+            unit_suffix("月"),
+            dd,
+            day_suffix(day_suffixes),
+        ))(input)?;
+
+        Ok((input, (year, mm, dd)))
+    }
+}
+
+/// Recognizes a separator between the weekday and the date: either the `comma`
+/// symbol (or the Arabic `،`) followed by any number of spaces or at least one
+/// space.
+pub fn weekday_separator(input: &str) -> IResult<&str, ()> {
+    alt((
+        value((), pair(alt((tag(","), tag("،"))), space0)),
+        value((), space1),
+    ))(input)
+}
+
+/// Creates a parser which recognizes the optional leading weekday (recognized
+/// by the specified language-specific `named_weekday` parser) followed by the
+/// separator (either the `comma` symbol followed by any number of spaces or at
+/// least one space) and the date recognized by the specified `parser`.
+///
+/// The weekday is consumed but not validated, see
+/// [`weekday_prefixed_checked`] for the parser which checks that the weekday
+/// matches the date.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom::{bytes::complete::tag_no_case, combinator::value};
+/// use nom_date_parsers::{i18n::weekday_prefixed, numeric::dd_mm_y4, types::IResult};
+///
+/// fn friday(input: &str) -> IResult<&str, Weekday> {
+///     value(Weekday::Fri, tag_no_case("fri"))(input)
+/// }
+///
+/// assert_eq!(
+///     weekday_prefixed(friday, dd_mm_y4)("Fri, 13/09/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 9, 13).unwrap()
+/// );
+/// assert_eq!(
+///     weekday_prefixed(friday, dd_mm_y4)("13/09/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 9, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn weekday_prefixed<'a, P>(
+    named_weekday: fn(&str) -> IResult<&str, Weekday>,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate>
+where
+    P: Parser<&'a str, NaiveDate, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (input, _) = opt(terminated(named_weekday, weekday_separator))(input)?;
+
+        parser.parse(input)
+    }
+}
+
+/// Same as [`weekday_prefixed`], but returns [`Error::WeekdayMismatch`] if the
+/// recognized weekday doesn't match the weekday of the recognized date.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom::{bytes::complete::tag_no_case, combinator::value};
+/// use nom_date_parsers::{
+///     error::Error, i18n::weekday_prefixed_checked, numeric::dd_mm_y4, types::IResult,
+/// };
+///
+/// fn friday(input: &str) -> IResult<&str, Weekday> {
+///     value(Weekday::Fri, tag_no_case("fri"))(input)
+/// }
+///
+/// assert_eq!(
+///     weekday_prefixed_checked(friday, dd_mm_y4)("Fri 13/09/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 9, 13).unwrap()
+/// );
+/// assert_eq!(
+///     weekday_prefixed_checked(friday, dd_mm_y4)("Fri 14/09/2024"),
+///     Err(nom::Err::Error(Error::WeekdayMismatch))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn weekday_prefixed_checked<'a, P>(
+    named_weekday: fn(&str) -> IResult<&str, Weekday>,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDate>
+where
+    P: Parser<&'a str, NaiveDate, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (input, weekday) = opt(terminated(named_weekday, weekday_separator))(input)?;
+        let (input, date) = parser.parse(input)?;
+
+        match weekday {
+            Some(weekday) if weekday != date.weekday() => {
+                Err(nom::Err::Error(Error::WeekdayMismatch))
+            }
+            _ => Ok((input, date)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::bytes::complete::tag_no_case;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::numeric::{dd_mm_y4, ww};
+
+    use super::*;
+
+    fn friday(input: &str) -> IResult<&str, Weekday> {
+        value(Weekday::Fri, tag_no_case("fri"))(input)
+    }
+
+    #[rstest]
+    #[case(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap(), 3, Period::Day, NaiveDate::from_ymd_opt(2024, 7, 19))]
+    #[case(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap(), -1, Period::Week, NaiveDate::from_ymd_opt(2024, 7, 9))]
+    #[case(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(), 1, Period::Month, NaiveDate::from_ymd_opt(2024, 2, 29))]
+    #[case(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(), -1, Period::Month, NaiveDate::from_ymd_opt(2024, 2, 29))]
+    #[case(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(), 1, Period::Year, NaiveDate::from_ymd_opt(2025, 2, 28))]
+    #[case(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(), -4, Period::Year, NaiveDate::from_ymd_opt(2020, 2, 29))]
+    #[case(NaiveDate::MAX, 1, Period::Day, None)]
+    #[case(NaiveDate::MIN, -1, Period::Year, None)]
+    #[case(NaiveDate::MIN, i64::MAX, Period::Year, None)]
+    fn test_shift_date(
+        #[case] date: NaiveDate,
+        #[case] amount: i64,
+        #[case] period: Period,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(shift_date(date, amount, period), expected);
+    }
+
+    #[rstest]
+    #[case(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap(), "week 29", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap(), "week 1", Ok(("", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())))]
+    #[case(NaiveDate::from_ymd_opt(2020, 7, 16).unwrap(), "week 53", Ok(("", NaiveDate::from_ymd_opt(2020, 12, 28).unwrap())))]
+    #[case(NaiveDate::from_ymd_opt(2025, 12, 30).unwrap(), "week 1", Ok(("", NaiveDate::from_ymd_opt(2025, 12, 29).unwrap())))]
+    #[case(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap(), "week 53", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap(), "week 54", Err(nom::Err::Error(Error::WeekOutOfRange)))]
+    fn test_week_of_year(
+        #[case] today: NaiveDate,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(today);
+
+        assert_eq!(
+            week_of_year(&ctx, preceded(tag("week "), ww))(input),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(1, Period::Week, Ok(("", NaiveDate::from_ymd_opt(2024, 8, 7).unwrap())))]
+    #[case(-1, Period::Month, Ok(("", NaiveDate::from_ymd_opt(2024, 6, 30).unwrap())))]
+    #[case(1, Period::Year, Ok(("", NaiveDate::from_ymd_opt(2025, 7, 31).unwrap())))]
+    #[case(i64::MAX, Period::Day, Err(nom::Err::Error(Error::DateOutOfRange)))]
+    fn test_relative_period(
+        #[case] amount: i64,
+        #[case] period: Period,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 31).unwrap());
+
+        assert_eq!(
+            relative_period(&ctx, |input| Ok((input, (amount, period))))(""),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(1, Period::Week, Ok(("", DateRange { start: NaiveDate::from_ymd_opt(2024, 8, 5).unwrap(), end: NaiveDate::from_ymd_opt(2024, 8, 11).unwrap() })))]
+    #[case(-1, Period::Month, Ok(("", DateRange { start: NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(), end: NaiveDate::from_ymd_opt(2024, 6, 30).unwrap() })))]
+    #[case(1, Period::Year, Ok(("", DateRange { start: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), end: NaiveDate::from_ymd_opt(2025, 12, 31).unwrap() })))]
+    #[case(-1, Period::Day, Ok(("", DateRange { start: NaiveDate::from_ymd_opt(2024, 7, 30).unwrap(), end: NaiveDate::from_ymd_opt(2024, 7, 30).unwrap() })))]
+    #[case(i64::MAX, Period::Week, Err(nom::Err::Error(Error::DateOutOfRange)))]
+    fn test_relative_period_range(
+        #[case] amount: i64,
+        #[case] period: Period,
+        #[case] expected: IResult<&str, DateRange>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 31).unwrap());
+
+        assert_eq!(
+            relative_period_range(&ctx, |input| Ok((input, (amount, period))))(""),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(MonthOverflow::Clamp, 13, MonthRef::Relative(1), Ok(("", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case(MonthOverflow::Clamp, 31, MonthRef::Relative(1), Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case(MonthOverflow::Roll, 31, MonthRef::Relative(1), Ok(("", NaiveDate::from_ymd_opt(2024, 3, 2).unwrap())))]
+    #[case(MonthOverflow::Clamp, 31, MonthRef::Relative(-2), Ok(("", NaiveDate::from_ymd_opt(2023, 11, 30).unwrap())))]
+    #[case(MonthOverflow::Clamp, 1, MonthRef::NamedRelative(Month::July, 1), Ok(("", NaiveDate::from_ymd_opt(2025, 7, 1).unwrap())))]
+    #[case(MonthOverflow::Clamp, 29, MonthRef::NamedRelative(Month::February, -1), Ok(("", NaiveDate::from_ymd_opt(2023, 2, 28).unwrap())))]
+    #[case(
+        MonthOverflow::Clamp,
+        1,
+        MonthRef::NamedRelative(Month::July, i64::MAX),
+        Err(nom::Err::Error(Error::DateOutOfRange))
+    )]
+    fn test_day_of_month(
+        #[case] overflow: MonthOverflow,
+        #[case] day: u32,
+        #[case] month: MonthRef,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())
+            .with_month_overflow(overflow);
+
+        assert_eq!(
+            day_of_month(&ctx, |input| Ok((input, (day, month))))(""),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(MonthPart::Beginning, MonthRef::Relative(1), Ok(("", NaiveDate::from_ymd_opt(2024, 8, 1).unwrap())))]
+    #[case(MonthPart::End, MonthRef::Relative(-5), Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case(MonthPart::Middle, MonthRef::Named(Month::February, Some(2023)), Ok(("", NaiveDate::from_ymd_opt(2023, 2, 15).unwrap())))]
+    #[case(MonthPart::End, MonthRef::Named(Month::September, None), Ok(("", NaiveDate::from_ymd_opt(2024, 9, 30).unwrap())))]
+    #[case(
+        MonthPart::End,
+        MonthRef::Relative(i64::MAX),
+        Err(nom::Err::Error(Error::DateOutOfRange))
+    )]
+    fn test_month_part(
+        #[case] part: MonthPart,
+        #[case] month: MonthRef,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 31).unwrap());
+
+        assert_eq!(
+            month_part(&ctx, |input| Ok((input, (part, month))))(""),
+            expected
+        );
+    }
+
+    fn month(input: &str) -> IResult<&str, Month> {
+        alt((
+            value(Month::February, tag_no_case("feb")),
+            value(Month::July, tag_no_case("jul")),
+        ))(input)
+    }
+
+    #[rstest]
+    #[case("13 jul 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13/07/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13-jul", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("13 aug", Ok((" aug", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("31 feb 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dmy_named_month_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+
+        assert_eq!(dmy_named_month_with(&ctx, month)(input), expected);
+    }
+
+    #[rstest]
+    #[case("07/13/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 jul 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("07-13", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("13/07", Ok(("/07", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("02/31/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_mdy_named_month_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+
+        assert_eq!(mdy_named_month_with(&ctx, month)(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/09/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("Fri 13/09/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("Fri,13/09/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("fri,  14/09/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 14).unwrap())))]
+    fn test_weekday_prefixed(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(weekday_prefixed(friday, dd_mm_y4)(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/09/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("Fri, 13/09/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("Fri 14/09/2024", Err(nom::Err::Error(Error::WeekdayMismatch)))]
+    fn test_weekday_prefixed_checked(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(weekday_prefixed_checked(friday, dd_mm_y4)(input), expected);
+    }
 }
@@ -0,0 +1,99 @@
+use chrono::NaiveDate;
+use nom::error::{ErrorKind, ParseError};
+
+use crate::{
+    context::ParserContext, error::Error, i18n::Language, numeric::DateOrder, types::IResult,
+};
+
+/// Tries the bundle parsers of all the languages enabled by the features (see
+/// [`Language::all`]) and returns the recognized date together with the
+/// [`Language`] of the bundle which recognized it. Useful when the language of
+/// the input is unknown (e.g. the messages of the chat with the mixed-language
+/// audience).
+///
+/// The bundle which consumes the most of the input wins, the earlier language
+/// of the [`Language::all`] wins on a tie (e.g. the numeric dates recognized by
+/// every bundle are attributed to `English` if the `en` feature is enabled).
+/// The numeric dates are recognized in the `day-month-year` order.
+///
+/// If none of the bundles recognizes the date, returns the most meaningful
+/// error (see [`Error::or`](crate::error::Error)) or [`Error::Nom`] with the
+/// [`ErrorKind::Alt`] if no languages are enabled.
+#[cfg(feature = "clock")]
+pub fn bundle(input: &str) -> IResult<&str, (NaiveDate, Language)> {
+    bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{any, Language},
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     any::bundle_with(&ctx)("tomorrow")?.1,
+///     (NaiveDate::from_ymd_opt(2024, 7, 17).unwrap(), Language::En)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn bundle_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, (NaiveDate, Language)> + '_ {
+    move |input: &str| {
+        let mut best: Option<(&str, (NaiveDate, Language))> = None;
+        let mut error: Option<Error<&str>> = None;
+
+        for language in Language::all() {
+            match language.bundle(DateOrder::Dmy)(ctx, input) {
+                Ok((remaining, date)) => {
+                    if best
+                        .as_ref()
+                        .map_or(true, |(rest, _)| remaining.len() < rest.len())
+                    {
+                        best = Some((remaining, (date, language)));
+                    }
+                }
+                Err(nom::Err::Error(e)) => {
+                    error = Some(match error {
+                        Some(previous) => previous.or(e),
+                        None => e,
+                    });
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        best.ok_or_else(|| nom::Err::Error(error.unwrap_or(Error::Nom(input, ErrorKind::Alt))))
+    }
+}
+
+#[cfg(all(test, feature = "en", feature = "ru"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("tomorrow", Ok(("", (NaiveDate::from_ymd_opt(2024, 7, 17).unwrap(), Language::En))))]
+    #[case("завтра", Ok(("", (NaiveDate::from_ymd_opt(2024, 7, 17).unwrap(), Language::Ru))))]
+    #[case("13 июля 2024 года", Ok(("", (NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), Language::Ru))))]
+    #[case("13/07/2024 later", Ok((" later", (NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(), Language::En))))]
+    #[case("31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_bundle_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, (NaiveDate, Language)>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_with(&ctx)(input), expected);
+    }
+}
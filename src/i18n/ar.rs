@@ -0,0 +1,119 @@
+mod relative;
+mod weekday;
+
+use chrono::NaiveDate;
+use nom::{branch::alt, sequence::delimited};
+
+use crate::{
+    combinators::bidi_marks,
+    context::ParserContext,
+    i18n::weekday_prefixed,
+    numeric::{dd_mm_y2_with, dmy_with},
+    types::IResult,
+};
+
+pub use self::{relative::*, weekday::*};
+
+/// Uses the following parsers to recognize the `numeric` and
+/// `language-specific` dates in `Arabic`. Uses the `day-month-year` sequence:
+/// - Numeric date parsers:
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_y2`](crate::numeric::dd_mm_y2)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
+/// - Language-specific
+///     - [`day_before_yesterday`]
+///     - [`yesterday`]
+///     - [`today`]
+///     - [`tomorrow`]
+///     - [`day_after_tomorrow`]
+///     - [`current_named_weekday_only`]
+///
+/// The numeric dates may be written with either the ascii or the Eastern
+/// Arabic digits (e.g. `١٣/٠٧/٢٠٢٤`) and prefixed with the weekday (e.g.
+/// `الجمعة، ١٣/٠٩`), which is consumed but not validated, see
+/// [`weekday_prefixed`]. The Unicode bidirectional formatting characters
+/// around the date are skipped.
+///
+/// If the specified date doesn't exist, returns `nom::Err::Error`
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ar::bundle_dmy;
+///
+/// assert_eq!(
+///     bundle_dmy("١٣/٠٧/٢٠٢٤")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn bundle_dmy(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle_dmy`] parser which uses the reference date and the
+/// century pivot of the specified [`ParserContext`].
+pub fn bundle_dmy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(delimited(
+            bidi_marks,
+            alt((
+                weekday_prefixed(named_weekday, alt((dd_mm_y2_with(ctx), dmy_with(ctx)))),
+                day_before_yesterday_with(ctx),
+                yesterday_with(ctx),
+                today_with(ctx),
+                tomorrow_with(ctx),
+                day_after_tomorrow_with(ctx),
+                current_named_weekday_only_with(ctx),
+            )),
+            bidi_marks,
+        ))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Datelike, Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("١", Ok(("", Local::now().date_naive().with_day(1).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 12, 3).unwrap())))]
+    #[case("١٣/٠٦/٢٠٢٤", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("\u{200F}13.06.2024\u{200F}", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("أول أمس", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("أمس", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("اليوم", Ok(("", Local::now().date_naive())))]
+    #[case("غدًا", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("بعد غد", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_bundle_dmy(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(bundle_dmy(input), expected)
+    }
+
+    #[rstest]
+    #[case("١٣/٠٧", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("٢٠", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("١٣/٠٧/٢٤", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("الجمعة، ١٣/٠٩/٢٤", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("الجمعة، ١٣/٠٩/٢٠٢٤", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("يوم الجمعة ١٣-٠٩-٢٠٢٤", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("يوم الجمعة", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("الأحد", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case(
+        "٣١/٠٢/٢٠٢٤",
+        Err(nom::Err::Error(crate::error::Error::NonExistentDate))
+    )]
+    fn test_bundle_dmy_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_dmy_with(&ctx)(input), expected)
+    }
+}
@@ -0,0 +1,245 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{opt, value},
+    sequence::{terminated, tuple},
+};
+
+use crate::{combinators::tag_no_case, context::ParserContext, i18n::relative_day, types::IResult};
+
+/// Recognizes the word `أمس` (or `امس` without the `hamza`).
+fn yesterday_word(input: &str) -> IResult<&str, ()> {
+    value((), alt((tag_no_case("أمس"), tag_no_case("امس"))))(input)
+}
+
+/// Recognizes the words `أول أمس` or `أول من أمس` (the `hamza` may be
+/// omitted, e.g. `اول امس`) in `Arabic` and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ar::day_before_yesterday;
+///
+/// assert_eq!(
+///     day_before_yesterday("أول أمس")?.1,
+///     Local::now().sub(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    day_before_yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_before_yesterday`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_before_yesterday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_day(
+            ctx,
+            -2,
+            tuple((
+                alt((tag_no_case("أول"), tag_no_case("اول"))),
+                space1,
+                opt(terminated(tag_no_case("من"), space1)),
+                yesterday_word,
+            )),
+        )(input)
+    }
+}
+
+/// Recognizes the words `أمس` (or `امس`), `الأمس` or `البارحة` in `Arabic`
+/// and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ar::yesterday;
+///
+/// assert_eq!(
+///     yesterday("أمس")?.1,
+///     Local::now().sub(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_day(
+            ctx,
+            -1,
+            alt((
+                yesterday_word,
+                value((), tag_no_case("الأمس")),
+                value((), tag_no_case("البارحة")),
+            )),
+        )(input)
+    }
+}
+
+/// Recognizes the word `اليوم` in `Arabic` and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use nom_date_parsers::i18n::ar::today;
+///
+/// assert_eq!(today("اليوم")?.1, Local::now().date_naive());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn today(input: &str) -> IResult<&str, NaiveDate> {
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| value(ctx.today(), tag_no_case("اليوم"))(input)
+}
+
+/// Recognizes the word `غدًا` (or `غداً`, `غدا` without the `tanween`) in
+/// `Arabic` and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ar::tomorrow;
+///
+/// assert_eq!(
+///     tomorrow("غدًا")?.1,
+///     Local::now().add(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_day(
+            ctx,
+            1,
+            alt((tag_no_case("غدًا"), tag_no_case("غداً"), tag_no_case("غدا"))),
+        )(input)
+    }
+}
+
+/// Recognizes the words `بعد غد` (or `بعد غدٍ`) or `بعد الغد` in `Arabic` and
+/// returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ar::day_after_tomorrow;
+///
+/// assert_eq!(
+///     day_after_tomorrow("بعد غد")?.1,
+///     Local::now().add(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_after_tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    day_after_tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_after_tomorrow`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_after_tomorrow_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_day(
+            ctx,
+            2,
+            tuple((
+                tag_no_case("بعد"),
+                space1,
+                alt((
+                    value((), tag_no_case("الغد")),
+                    value((), terminated(tag_no_case("غد"), opt(tag("\u{064D}")))),
+                )),
+            )),
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("أول أمس", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("أول من أمس", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("اول امس", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    fn test_day_before_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_before_yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("أمس", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("امس", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("الأمس", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("البارحة", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    fn test_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("اليوم", Ok(("", Local::now().date_naive())))]
+    fn test_today(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(today(input), expected);
+    }
+
+    #[rstest]
+    #[case("غدًا", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("غداً", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("غدا", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    fn test_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(tomorrow(input), expected);
+    }
+
+    #[rstest]
+    #[case("بعد غد", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    #[case("بعد غدٍ", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    #[case("بعد الغد", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_day_after_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_after_tomorrow(input), expected);
+    }
+}
@@ -0,0 +1,121 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{
+    branch::alt,
+    character::complete::space1,
+    combinator::{opt, value},
+    sequence::{pair, preceded},
+};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the weekday in `Arabic`, optionally preceded by the word `يوم`
+/// (e.g. `يوم الجمعة`).
+///
+/// The following words are accepted:
+/// - `الاثنين` | `الإثنين` -> [`Weekday::Mon`]
+/// - `الثلاثاء` -> [`Weekday::Tue`]
+/// - `الأربعاء` | `الاربعاء` -> [`Weekday::Wed`]
+/// - `الخميس` -> [`Weekday::Thu`]
+/// - `الجمعة` -> [`Weekday::Fri`]
+/// - `السبت` -> [`Weekday::Sat`]
+/// - `الأحد` | `الاحد` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ar::named_weekday;
+///
+/// assert_eq!(named_weekday("الجمعة")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("يوم الأربعاء")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    preceded(
+        opt(pair(tag_no_case("يوم"), space1)),
+        alt((
+            value(Weekday::Mon, tag_no_case("الاثنين")),
+            value(Weekday::Mon, tag_no_case("الإثنين")),
+            value(Weekday::Tue, tag_no_case("الثلاثاء")),
+            value(Weekday::Wed, tag_no_case("الأربعاء")),
+            value(Weekday::Wed, tag_no_case("الاربعاء")),
+            value(Weekday::Thu, tag_no_case("الخميس")),
+            value(Weekday::Fri, tag_no_case("الجمعة")),
+            value(Weekday::Sat, tag_no_case("السبت")),
+            value(Weekday::Sun, tag_no_case("الأحد")),
+            value(Weekday::Sun, tag_no_case("الاحد")),
+        )),
+    )(input)
+}
+
+/// Recognizes the weekday in `Arabic` using the [`named_weekday`] parser and
+/// returns the corresponding [`NaiveDate`] for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{ar::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("الأربعاء")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("الاثنين", Ok(("", Weekday::Mon)))]
+    #[case("الإثنين", Ok(("", Weekday::Mon)))]
+    #[case("الثلاثاء", Ok(("", Weekday::Tue)))]
+    #[case("الاربعاء", Ok(("", Weekday::Wed)))]
+    #[case("الخميس", Ok(("", Weekday::Thu)))]
+    #[case("يوم الجمعة", Ok(("", Weekday::Fri)))]
+    #[case("السبت", Ok(("", Weekday::Sat)))]
+    #[case("الأحد", Ok(("", Weekday::Sun)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("الاثنين", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("يوم الثلاثاء", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
@@ -0,0 +1,184 @@
+use chrono::NaiveDate;
+use nom::combinator::value;
+
+use crate::{combinators::tag_no_case, context::ParserContext, i18n::relative_day, types::IResult};
+
+/// Recognizes the `case insensitive` word `srağagün` in `Azerbaijani` and
+/// returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::az::day_before_yesterday;
+///
+/// assert_eq!(
+///     day_before_yesterday("srağagün")?.1,
+///     Local::now().sub(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    day_before_yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_before_yesterday`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_before_yesterday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -2, tag_no_case("srağagün"))(input)
+}
+
+/// Recognizes the `case insensitive` word `dünən` in `Azerbaijani` and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::az::yesterday;
+///
+/// assert_eq!(
+///     yesterday("dünən")?.1,
+///     Local::now().sub(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -1, tag_no_case("dünən"))(input)
+}
+
+/// Recognizes the `case insensitive` phrase `bu gün` in `Azerbaijani` and
+/// returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use nom_date_parsers::i18n::az::today;
+///
+/// assert_eq!(today("bu gün")?.1, Local::now().date_naive());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn today(input: &str) -> IResult<&str, NaiveDate> {
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| value(ctx.today(), tag_no_case("bu gün"))(input)
+}
+
+/// Recognizes the `case insensitive` word `sabah` in `Azerbaijani` and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::az::tomorrow;
+///
+/// assert_eq!(
+///     tomorrow("sabah")?.1,
+///     Local::now().add(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 1, tag_no_case("sabah"))(input)
+}
+
+/// Recognizes the `case insensitive` word `birigün` in `Azerbaijani` and
+/// returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::az::day_after_tomorrow;
+///
+/// assert_eq!(
+///     day_after_tomorrow("birigün")?.1,
+///     Local::now().add(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_after_tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    day_after_tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_after_tomorrow`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_after_tomorrow_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 2, tag_no_case("birigün"))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Srağagün", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    fn test_day_before_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_before_yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("Dünən", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    fn test_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("Bu gün", Ok(("", Local::now().date_naive())))]
+    fn test_today(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(today(input), expected);
+    }
+
+    #[rstest]
+    #[case("Sabah", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    fn test_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(tomorrow(input), expected);
+    }
+
+    #[rstest]
+    #[case("Birigün", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_day_after_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_after_tomorrow(input), expected);
+    }
+}
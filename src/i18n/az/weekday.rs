@@ -0,0 +1,175 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, combinator::value};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Azerbaijani`.
+///
+/// The abbreviations end with a `dot` symbol by themselves, so there is no
+/// separate parser for the dotted form.
+///
+/// The following words are accepted:
+/// - `b.e.` -> [`Weekday::Mon`]
+/// - `ç.a.` -> [`Weekday::Tue`]
+/// - `ç.` -> [`Weekday::Wed`]
+/// - `c.a.` -> [`Weekday::Thu`]
+/// - `c.` -> [`Weekday::Fri`]
+/// - `ş.` -> [`Weekday::Sat`]
+/// - `b.` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::az::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("c.")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("b.e.")),
+        value(Weekday::Tue, tag_no_case("ç.a.")),
+        value(Weekday::Wed, tag_no_case("ç.")),
+        value(Weekday::Thu, tag_no_case("c.a.")),
+        value(Weekday::Fri, tag_no_case("c.")),
+        value(Weekday::Sat, tag_no_case("ş.")),
+        value(Weekday::Sun, tag_no_case("b.")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Azerbaijani`.
+///
+/// The following words are accepted:
+/// - `bazar ertəsi` -> [`Weekday::Mon`]
+/// - `çərşənbə axşamı` -> [`Weekday::Tue`]
+/// - `çərşənbə` -> [`Weekday::Wed`]
+/// - `cümə axşamı` -> [`Weekday::Thu`]
+/// - `cümə` -> [`Weekday::Fri`]
+/// - `şənbə` -> [`Weekday::Sat`]
+/// - `bazar` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::az::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("çərşənbə")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("bazar ertəsi")),
+        value(Weekday::Tue, tag_no_case("çərşənbə axşamı")),
+        value(Weekday::Wed, tag_no_case("çərşənbə")),
+        value(Weekday::Thu, tag_no_case("cümə axşamı")),
+        value(Weekday::Fri, tag_no_case("cümə")),
+        value(Weekday::Sat, tag_no_case("şənbə")),
+        value(Weekday::Sun, tag_no_case("bazar")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Azerbaijani`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::az::named_weekday;
+///
+/// assert_eq!(named_weekday("c.")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Cümə")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((full_named_weekday, short_named_weekday))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Azerbaijani` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{az::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Çərşənbə")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("b.e.", Ok(("", Weekday::Mon)))]
+    #[case("Ç.A.", Ok(("", Weekday::Tue)))]
+    #[case("Ç.", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("bazar ertəsi", Ok(("", Weekday::Mon)))]
+    #[case("Çərşənbə axşamı", Ok(("", Weekday::Tue)))]
+    #[case("ÇƏRŞƏNBƏ", Ok(("", Weekday::Wed)))]
+    #[case("bazar", Ok(("", Weekday::Sun)))]
+    #[case("Cümə axşamı", Ok(("", Weekday::Thu)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("b.e.", Ok(("", Weekday::Mon)))]
+    #[case("çərşənbə axşamı", Ok(("", Weekday::Tue)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("b.e.", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Çərşənbə axşamı", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
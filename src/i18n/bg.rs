@@ -0,0 +1,108 @@
+mod month;
+mod relative;
+mod weekday;
+
+use chrono::NaiveDate;
+
+use crate::{context::ParserContext, i18n::LanguageSpec, types::IResult};
+
+pub use self::{month::*, relative::*, weekday::*};
+
+/// The weekday and month names and the relative words in `Bulgarian` recognized
+/// by the [`bundle_dmy`] parser, see [`LanguageSpec`].
+///
+/// The forms are the same as the ones of the [`named_weekday`],
+/// [`named_month`] and relative word parsers (e.g. [`yesterday`]).
+pub const SPEC: LanguageSpec<'static> = LanguageSpec::new(
+    [
+        &["понеделник", "пн", "пн."],
+        &["вторник", "вт", "вт."],
+        &["сряда", "ср", "ср."],
+        &["четвъртък", "чт", "чт."],
+        &["петък", "пт", "пт."],
+        &["събота", "сб", "сб."],
+        &["неделя", "нд", "нд."],
+    ],
+    [
+        &["януари", "яну", "яну."],
+        &["февруари", "фев", "фев."],
+        &["март", "мар", "мар."],
+        &["април", "апр", "апр."],
+        &["май", "май."],
+        &["юни", "юни."],
+        &["юли", "юли."],
+        &["август", "авг", "авг."],
+        &["септември", "сеп", "сеп."],
+        &["октомври", "окт", "окт."],
+        &["ноември", "ное", "ное."],
+        &["декември", "дек", "дек."],
+    ],
+)
+.with_relative_days(&[
+    ("онзи ден", -2),
+    ("вчера", -1),
+    ("днес", 0),
+    ("утре", 1),
+    ("вдругиден", 2),
+]);
+
+/// Recognizes the `numeric` and `language-specific` dates in `Bulgarian` using
+/// the [`LanguageSpec::bundle_with`] parser with the [`SPEC`] tables. Uses the
+/// `day-month-year` sequence, so the same dates as the following parsers are
+/// recognized:
+/// - Numeric date parsers:
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
+/// - Language-specific
+///     - [`dd_month_y4`]
+///     - [`dd_month_only`]
+///     - [`day_before_yesterday`]
+///     - [`yesterday`]
+///     - [`today`]
+///     - [`tomorrow`]
+///     - [`day_after_tomorrow`]
+///     - [`current_named_weekday_only`]
+///
+/// The numeric and named month dates may be prefixed with the weekday (e.g.
+/// `пт 13.09`), which is consumed but not validated, see
+/// [`weekday_prefixed`](crate::i18n::weekday_prefixed).
+///
+/// If the specified date doesn't exist, returns `nom::Err::Error`
+#[cfg(feature = "clock")]
+pub fn bundle_dmy(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle_dmy`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_dmy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| SPEC.bundle_with(ctx)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Datelike, Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1", Ok(("", Local::now().date_naive().with_day(1).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 12, 3).unwrap())))]
+    #[case("13.06.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("13 юли 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 юли", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("пт. 13 сеп. 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("Онзи ден", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("Вчера", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("днес", Ok(("", Local::now().date_naive())))]
+    #[case("Утре", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("вдругиден", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_bundle_dmy(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(bundle_dmy(input), expected)
+    }
+}
@@ -0,0 +1,223 @@
+use chrono::{Month, NaiveDate};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `Bulgarian`.
+///
+/// The following words are accepted:
+/// - `яну` -> [`Month::January`]
+/// - `фев` -> [`Month::February`]
+/// - `мар` -> [`Month::March`]
+/// - `апр` -> [`Month::April`]
+/// - `май` -> [`Month::May`]
+/// - `юни` -> [`Month::June`]
+/// - `юли` -> [`Month::July`]
+/// - `авг` -> [`Month::August`]
+/// - `сеп` -> [`Month::September`]
+/// - `окт` -> [`Month::October`]
+/// - `ное` -> [`Month::November`]
+/// - `дек` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::bg::short_named_month;
+///
+/// assert_eq!(short_named_month("юли")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("яну")),
+        value(Month::February, tag_no_case("фев")),
+        value(Month::March, tag_no_case("мар")),
+        value(Month::April, tag_no_case("апр")),
+        value(Month::May, tag_no_case("май")),
+        value(Month::June, tag_no_case("юни")),
+        value(Month::July, tag_no_case("юли")),
+        value(Month::August, tag_no_case("авг")),
+        value(Month::September, tag_no_case("сеп")),
+        value(Month::October, tag_no_case("окт")),
+        value(Month::November, tag_no_case("ное")),
+        value(Month::December, tag_no_case("дек")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `Bulgarian` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `Bulgarian`.
+///
+/// The following words are accepted:
+/// - `януари` -> [`Month::January`]
+/// - `февруари` -> [`Month::February`]
+/// - `март` -> [`Month::March`]
+/// - `април` -> [`Month::April`]
+/// - `май` -> [`Month::May`]
+/// - `юни` -> [`Month::June`]
+/// - `юли` -> [`Month::July`]
+/// - `август` -> [`Month::August`]
+/// - `септември` -> [`Month::September`]
+/// - `октомври` -> [`Month::October`]
+/// - `ноември` -> [`Month::November`]
+/// - `декември` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::bg::full_named_month;
+///
+/// assert_eq!(full_named_month("Юли")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("януари")),
+        value(Month::February, tag_no_case("февруари")),
+        value(Month::March, tag_no_case("март")),
+        value(Month::April, tag_no_case("април")),
+        value(Month::May, tag_no_case("май")),
+        value(Month::June, tag_no_case("юни")),
+        value(Month::July, tag_no_case("юли")),
+        value(Month::August, tag_no_case("август")),
+        value(Month::September, tag_no_case("септември")),
+        value(Month::October, tag_no_case("октомври")),
+        value(Month::November, tag_no_case("ноември")),
+        value(Month::December, tag_no_case("декември")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `Bulgarian`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::bg::named_month;
+///
+/// assert_eq!(named_month("юли")?.1, Month::July);
+/// assert_eq!(named_month("юли")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `Bulgarian` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::bg::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 юли 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `Bulgarian` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::bg::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 юли")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("яну", Ok(("", Month::January)))]
+    #[case("ФЕВ", Ok(("", Month::February)))]
+    #[case("Дек", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("яну.", Ok(("", Month::January)))]
+    #[case("Дек.", Ok(("", Month::December)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("януари", Ok(("", Month::January)))]
+    #[case("Май", Ok(("", Month::May)))]
+    #[case("СЕПТЕМВРИ", Ok(("", Month::September)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("мар", Ok(("", Month::March)))]
+    #[case("март", Ok(("", Month::March)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 юли 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1-яну-2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 февруари 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 юли", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 юли", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 юли" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
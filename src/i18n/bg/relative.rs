@@ -0,0 +1,184 @@
+use chrono::NaiveDate;
+use nom::combinator::value;
+
+use crate::{combinators::tag_no_case, context::ParserContext, i18n::relative_day, types::IResult};
+
+/// Recognizes the `case insensitive` phrase `онзи ден` in `Bulgarian` and
+/// returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::bg::day_before_yesterday;
+///
+/// assert_eq!(
+///     day_before_yesterday("онзи ден")?.1,
+///     Local::now().sub(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    day_before_yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_before_yesterday`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_before_yesterday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -2, tag_no_case("онзи ден"))(input)
+}
+
+/// Recognizes the `case insensitive` word `вчера` in `Bulgarian` and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::bg::yesterday;
+///
+/// assert_eq!(
+///     yesterday("вчера")?.1,
+///     Local::now().sub(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -1, tag_no_case("вчера"))(input)
+}
+
+/// Recognizes the `case insensitive` word `днес` in `Bulgarian` and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use nom_date_parsers::i18n::bg::today;
+///
+/// assert_eq!(today("днес")?.1, Local::now().date_naive());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn today(input: &str) -> IResult<&str, NaiveDate> {
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| value(ctx.today(), tag_no_case("днес"))(input)
+}
+
+/// Recognizes the `case insensitive` word `утре` in `Bulgarian` and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::bg::tomorrow;
+///
+/// assert_eq!(
+///     tomorrow("утре")?.1,
+///     Local::now().add(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 1, tag_no_case("утре"))(input)
+}
+
+/// Recognizes the `case insensitive` word `вдругиден` in `Bulgarian` and
+/// returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::bg::day_after_tomorrow;
+///
+/// assert_eq!(
+///     day_after_tomorrow("вдругиден")?.1,
+///     Local::now().add(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_after_tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    day_after_tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_after_tomorrow`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_after_tomorrow_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 2, tag_no_case("вдругиден"))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Онзи ден", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    fn test_day_before_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_before_yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("Вчера", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    fn test_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("Днес", Ok(("", Local::now().date_naive())))]
+    fn test_today(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(today(input), expected);
+    }
+
+    #[rstest]
+    #[case("Утре", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    fn test_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(tomorrow(input), expected);
+    }
+
+    #[rstest]
+    #[case("Вдругиден", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_day_after_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_after_tomorrow(input), expected);
+    }
+}
@@ -0,0 +1,189 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Bulgarian`.
+///
+/// The following words are accepted:
+/// - `пн` -> [`Weekday::Mon`]
+/// - `вт` -> [`Weekday::Tue`]
+/// - `ср` -> [`Weekday::Wed`]
+/// - `чт` -> [`Weekday::Thu`]
+/// - `пт` -> [`Weekday::Fri`]
+/// - `сб` -> [`Weekday::Sat`]
+/// - `нд` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::bg::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("пт")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("пн")),
+        value(Weekday::Tue, tag_no_case("вт")),
+        value(Weekday::Wed, tag_no_case("ср")),
+        value(Weekday::Thu, tag_no_case("чт")),
+        value(Weekday::Fri, tag_no_case("пт")),
+        value(Weekday::Sat, tag_no_case("сб")),
+        value(Weekday::Sun, tag_no_case("нд")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Bulgarian` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Bulgarian`.
+///
+/// The following words are accepted:
+/// - `понеделник` -> [`Weekday::Mon`]
+/// - `вторник` -> [`Weekday::Tue`]
+/// - `сряда` -> [`Weekday::Wed`]
+/// - `четвъртък` -> [`Weekday::Thu`]
+/// - `петък` -> [`Weekday::Fri`]
+/// - `събота` -> [`Weekday::Sat`]
+/// - `неделя` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::bg::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("сряда")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("понеделник")),
+        value(Weekday::Tue, tag_no_case("вторник")),
+        value(Weekday::Wed, tag_no_case("сряда")),
+        value(Weekday::Thu, tag_no_case("четвъртък")),
+        value(Weekday::Fri, tag_no_case("петък")),
+        value(Weekday::Sat, tag_no_case("събота")),
+        value(Weekday::Sun, tag_no_case("неделя")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Bulgarian`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::bg::named_weekday;
+///
+/// assert_eq!(named_weekday("пт")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Петък")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Bulgarian` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{bg::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Сряда")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("пн", Ok(("", Weekday::Mon)))]
+    #[case("ВТ", Ok(("", Weekday::Tue)))]
+    #[case("Ср", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("пн.", Ok(("", Weekday::Mon)))]
+    #[case("ВТ.", Ok(("", Weekday::Tue)))]
+    #[case("Ср.", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("понеделник", Ok(("", Weekday::Mon)))]
+    #[case("Вторник", Ok(("", Weekday::Tue)))]
+    #[case("СРЯДА", Ok(("", Weekday::Wed)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("пн", Ok(("", Weekday::Mon)))]
+    #[case("вторник", Ok(("", Weekday::Tue)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("пн", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Вторник", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
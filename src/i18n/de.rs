@@ -0,0 +1,96 @@
+mod month;
+mod relative;
+mod weekday;
+
+use chrono::NaiveDate;
+use nom::branch::alt;
+
+use crate::{
+    context::ParserContext,
+    i18n::{dmy_named_month_with, weekday_prefixed},
+    types::IResult,
+};
+
+pub use self::{month::*, relative::*, weekday::*};
+
+/// Uses the following parsers to recognize the `numeric` and
+/// `language-specific` dates in `German`. Uses the `day-month-year` sequence:
+/// - Numeric date parsers:
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
+/// - Language-specific
+///     - [`dd_month_y4`]
+///     - [`dd_month_only`]
+///     - [`day_before_yesterday`]
+///     - [`yesterday`]
+///     - [`today`]
+///     - [`tomorrow`]
+///     - [`day_after_tomorrow`]
+///     - [`calendar_week`]
+///     - [`current_named_weekday_only`]
+///
+/// The numeric and named month dates may be prefixed with the weekday (e.g.
+/// `Fr. 13.09.`), which is consumed but not validated, see
+/// [`weekday_prefixed`].
+///
+/// If the specified date doesn't exist, returns `nom::Err::Error`
+#[cfg(feature = "clock")]
+pub fn bundle(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(alt((
+            calendar_week_with(ctx),
+            weekday_prefixed(named_weekday, dmy_named_month_with(ctx, named_month)),
+            day_before_yesterday_with(ctx),
+            yesterday_with(ctx),
+            today_with(ctx),
+            tomorrow_with(ctx),
+            day_after_tomorrow_with(ctx),
+            current_named_weekday_only_with(ctx),
+        )))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Datelike, Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1", Ok(("", Local::now().date_naive().with_day(1).unwrap())))]
+    #[case("03.12", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 12, 3).unwrap())))]
+    #[case("13.06.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("13. Juli 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13. Juli", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("vorgestern", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("Gestern", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("heute", Ok(("", Local::now().date_naive())))]
+    #[case("Morgen", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("übermorgen", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_bundle(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(bundle(input), expected)
+    }
+
+    #[rstest]
+    #[case("Freitag", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("Fr. 13.09.", Ok((".", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("Freitag, 13. September 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("Di 13.8", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case("KW 29", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    fn test_bundle_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_with(&ctx)(input), expected)
+    }
+}
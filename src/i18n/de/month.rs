@@ -0,0 +1,234 @@
+use chrono::{Month, NaiveDate};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `German`.
+///
+/// The following words are accepted:
+/// - `jan` | `jän` -> [`Month::January`]
+/// - `feb` -> [`Month::February`]
+/// - `mär` | `mrz` -> [`Month::March`]
+/// - `apr` -> [`Month::April`]
+/// - `mai` -> [`Month::May`]
+/// - `jun` -> [`Month::June`]
+/// - `jul` -> [`Month::July`]
+/// - `aug` -> [`Month::August`]
+/// - `sep` | `sept` -> [`Month::September`]
+/// - `okt` -> [`Month::October`]
+/// - `nov` -> [`Month::November`]
+/// - `dez` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::de::short_named_month;
+///
+/// assert_eq!(short_named_month("jul")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("jan")),
+        value(Month::January, tag_no_case("jän")),
+        value(Month::February, tag_no_case("feb")),
+        value(Month::March, tag_no_case("mär")),
+        value(Month::March, tag_no_case("mrz")),
+        value(Month::April, tag_no_case("apr")),
+        value(Month::May, tag_no_case("mai")),
+        value(Month::June, tag_no_case("jun")),
+        value(Month::July, tag_no_case("jul")),
+        value(Month::August, tag_no_case("aug")),
+        value(Month::September, tag_no_case("sept")),
+        value(Month::September, tag_no_case("sep")),
+        value(Month::October, tag_no_case("okt")),
+        value(Month::November, tag_no_case("nov")),
+        value(Month::December, tag_no_case("dez")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `German` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `German`.
+///
+/// The following words are accepted:
+/// - `januar` | `jänner` -> [`Month::January`]
+/// - `februar` | `feber` -> [`Month::February`]
+/// - `märz` | `maerz` -> [`Month::March`]
+/// - `april` -> [`Month::April`]
+/// - `mai` -> [`Month::May`]
+/// - `juni` -> [`Month::June`]
+/// - `juli` -> [`Month::July`]
+/// - `august` -> [`Month::August`]
+/// - `september` -> [`Month::September`]
+/// - `oktober` -> [`Month::October`]
+/// - `november` -> [`Month::November`]
+/// - `dezember` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::de::full_named_month;
+///
+/// assert_eq!(full_named_month("Juli")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("januar")),
+        value(Month::January, tag_no_case("jänner")),
+        value(Month::February, tag_no_case("februar")),
+        value(Month::February, tag_no_case("feber")),
+        value(Month::March, tag_no_case("märz")),
+        value(Month::March, tag_no_case("maerz")),
+        value(Month::April, tag_no_case("april")),
+        value(Month::May, tag_no_case("mai")),
+        value(Month::June, tag_no_case("juni")),
+        value(Month::July, tag_no_case("juli")),
+        value(Month::August, tag_no_case("august")),
+        value(Month::September, tag_no_case("september")),
+        value(Month::October, tag_no_case("oktober")),
+        value(Month::November, tag_no_case("november")),
+        value(Month::December, tag_no_case("dezember")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `German`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::de::named_month;
+///
+/// assert_eq!(named_month("Jul.")?.1, Month::July);
+/// assert_eq!(named_month("Juli")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `German` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::de::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13. Juli 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `German` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::de::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13. Juli")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("jan", Ok(("", Month::January)))]
+    #[case("Jän", Ok(("", Month::January)))]
+    #[case("MRZ", Ok(("", Month::March)))]
+    #[case("Dez", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("Jan.", Ok(("", Month::January)))]
+    #[case("Okt.", Ok(("", Month::October)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("januar", Ok(("", Month::January)))]
+    #[case("März", Ok(("", Month::March)))]
+    #[case("MÄRZ", Ok(("", Month::March)))]
+    #[case("Maerz", Ok(("", Month::March)))]
+    #[case("Jänner", Ok(("", Month::January)))]
+    #[case("DEZEMBER", Ok(("", Month::December)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("mär", Ok(("", Month::March)))]
+    #[case("märz", Ok(("", Month::March)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13. Juli 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.Juli 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1 Jan. 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31. Februar 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13. Juli", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32. Juli", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32. Juli" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
@@ -0,0 +1,256 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    character::complete::space0,
+    combinator::{map, value},
+    sequence::tuple,
+};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::{relative_day, week_of_year},
+    numeric::ww,
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` word `vorgestern` in `German` and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::de::day_before_yesterday;
+///
+/// assert_eq!(
+///     day_before_yesterday("Vorgestern")?.1,
+///     Local::now().sub(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    day_before_yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_before_yesterday`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_before_yesterday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -2, tag_no_case("vorgestern"))(input)
+}
+
+/// Recognizes the `case insensitive` word `gestern` in `German` and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::de::yesterday;
+///
+/// assert_eq!(
+///     yesterday("gestern")?.1,
+///     Local::now().sub(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -1, tag_no_case("gestern"))(input)
+}
+
+/// Recognizes the `case insensitive` word `heute` in `German` and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use nom_date_parsers::i18n::de::today;
+///
+/// assert_eq!(today("Heute")?.1, Local::now().date_naive());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn today(input: &str) -> IResult<&str, NaiveDate> {
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| value(ctx.today(), tag_no_case("heute"))(input)
+}
+
+/// Recognizes the `case insensitive` word `morgen` in `German` and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::de::tomorrow;
+///
+/// assert_eq!(
+///     tomorrow("morgen")?.1,
+///     Local::now().add(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 1, tag_no_case("morgen"))(input)
+}
+
+/// Recognizes the `case insensitive` words `übermorgen` or `uebermorgen` in
+/// `German` and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::de::day_after_tomorrow;
+///
+/// assert_eq!(
+///     day_after_tomorrow("Übermorgen")?.1,
+///     Local::now().add(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_after_tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    day_after_tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_after_tomorrow`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_after_tomorrow_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_day(
+            ctx,
+            2,
+            alt((tag_no_case("übermorgen"), tag_no_case("uebermorgen"))),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` calendar week notation in `German` (e.g.
+/// `KW 29`, `KW29` or `Kalenderwoche 29`) and returns the Monday of the ISO
+/// 8601 week in the current year, see [`week_of_year`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, Weekday};
+/// use nom_date_parsers::i18n::de::calendar_week;
+///
+/// let date = calendar_week("KW 29")?.1;
+///
+/// assert_eq!(date.iso_week().week(), 29);
+/// assert_eq!(date.weekday(), Weekday::Mon);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn calendar_week(input: &str) -> IResult<&str, NaiveDate> {
+    calendar_week_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`calendar_week`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn calendar_week_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        week_of_year(
+            ctx,
+            map(
+                tuple((
+                    alt((tag_no_case("kalenderwoche"), tag_no_case("kw"))),
+                    space0,
+                    ww,
+                )),
+                |(_, _, week)| week,
+            ),
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::error::Error;
+
+    #[rstest]
+    #[case("Vorgestern", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    fn test_day_before_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_before_yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("Gestern", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    fn test_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("heute", Ok(("", Local::now().date_naive())))]
+    #[case("HEUTE", Ok(("", Local::now().date_naive())))]
+    fn test_today(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(today(input), expected);
+    }
+
+    #[rstest]
+    #[case("Morgen", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    fn test_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(tomorrow(input), expected);
+    }
+
+    #[rstest]
+    #[case("übermorgen", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    #[case("ÜBERMORGEN", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    #[case("Uebermorgen", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_day_after_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_after_tomorrow(input), expected);
+    }
+
+    #[rstest]
+    #[case("KW 29", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("kw29", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("Kalenderwoche 1", Ok(("", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())))]
+    #[case("KW 53", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("KW 54", Err(nom::Err::Error(Error::WeekOutOfRange)))]
+    fn test_calendar_week_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(calendar_week_with(&ctx)(input), expected);
+    }
+}
@@ -0,0 +1,192 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `German`.
+///
+/// The following words are accepted:
+/// - `mo` -> [`Weekday::Mon`]
+/// - `di` -> [`Weekday::Tue`]
+/// - `mi` -> [`Weekday::Wed`]
+/// - `do` -> [`Weekday::Thu`]
+/// - `fr` -> [`Weekday::Fri`]
+/// - `sa` -> [`Weekday::Sat`]
+/// - `so` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::de::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("Fr")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("mo")),
+        value(Weekday::Tue, tag_no_case("di")),
+        value(Weekday::Wed, tag_no_case("mi")),
+        value(Weekday::Thu, tag_no_case("do")),
+        value(Weekday::Fri, tag_no_case("fr")),
+        value(Weekday::Sat, tag_no_case("sa")),
+        value(Weekday::Sun, tag_no_case("so")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `German` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `German`.
+///
+/// The following words are accepted:
+/// - `montag` -> [`Weekday::Mon`]
+/// - `dienstag` -> [`Weekday::Tue`]
+/// - `mittwoch` -> [`Weekday::Wed`]
+/// - `donnerstag` -> [`Weekday::Thu`]
+/// - `freitag` -> [`Weekday::Fri`]
+/// - `samstag` | `sonnabend` -> [`Weekday::Sat`]
+/// - `sonntag` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::de::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("Mittwoch")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("montag")),
+        value(Weekday::Tue, tag_no_case("dienstag")),
+        value(Weekday::Wed, tag_no_case("mittwoch")),
+        value(Weekday::Thu, tag_no_case("donnerstag")),
+        value(Weekday::Fri, tag_no_case("freitag")),
+        value(Weekday::Sat, tag_no_case("samstag")),
+        value(Weekday::Sat, tag_no_case("sonnabend")),
+        value(Weekday::Sun, tag_no_case("sonntag")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `German`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::de::named_weekday;
+///
+/// assert_eq!(named_weekday("Fr.")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Freitag")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `German` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{de::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Mittwoch")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("mo", Ok(("", Weekday::Mon)))]
+    #[case("DI", Ok(("", Weekday::Tue)))]
+    #[case("Mi", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("Mo.", Ok(("", Weekday::Mon)))]
+    #[case("DO.", Ok(("", Weekday::Thu)))]
+    #[case("so.", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("montag", Ok(("", Weekday::Mon)))]
+    #[case("Dienstag", Ok(("", Weekday::Tue)))]
+    #[case("DONNERSTAG", Ok(("", Weekday::Thu)))]
+    #[case("Sonnabend", Ok(("", Weekday::Sat)))]
+    #[case("Samstag", Ok(("", Weekday::Sat)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("So", Ok(("", Weekday::Sun)))]
+    #[case("Sonntag", Ok(("", Weekday::Sun)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("Mo", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Dienstag", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
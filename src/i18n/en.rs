@@ -1,80 +1,856 @@
+mod business;
+#[cfg(feature = "holidays")]
+pub mod holiday;
+mod month;
+mod recurrence;
 mod relative;
+mod season;
 mod weekday;
+mod weekend;
 
-use chrono::NaiveDate;
-use nom::branch::alt;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use nom::{
+    branch::alt,
+    character::complete::{char, space0, space1},
+    combinator::{opt, value},
+    sequence::{pair, preceded, terminated, tuple},
+};
 
 use crate::{
-    numeric::{dd_mm_only, dd_mm_y4, dd_only, mm_dd_only, mm_dd_y4},
+    combinators::{optional_prefix, tag_no_case},
+    context::{ParserContext, TrailingInput},
+    extract::find_all,
+    i18n::{
+        dmy_named_month_with, mdy_named_month_with, weekday_prefixed, weekday_prefixed_checked,
+    },
+    list::{self, date_list, shared_month_list_with},
+    parse::common_parsers,
+    parser::BundleBuilder,
+    range::{self, date_range, open_date_range, shared_month_range_with, DateRange, OpenDateRange},
+    time::date_time,
     types::IResult,
 };
 
-pub use self::{relative::*, weekday::*};
+pub use self::{
+    business::*, month::*, recurrence::*, relative::*, season::*, weekday::*, weekend::*,
+};
 
 /// Uses the following parsers to recognize the `numeric` and
 /// `language-specific` dates in `English`. Uses the `day-month-year` sequence:
 /// - Numeric date parsers:
-///     - [`dd_mm_y4`]
-///     - [`dd_mm_only`]
-///     - [`dd_only`]
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
 /// - Language-specific
+///     - [`dd_month_y4`]
+///     - [`month_dd_y4`]
+///     - [`dd_month_only`]
+///     - [`ordinal_date`]
 ///     - [`yesterday`]
 ///     - [`today`]
 ///     - [`tomorrow`]
+///     - [`in_duration`]
+///     - [`duration_ago`]
+///     - [`calendar_week`]
+///     - [`day_of_year`]
+///     - [`month_part`]
+///     - [`relative_period`]
+///     - [`nth_weekday_of_month`]
+///     - [`day_of_month`]
+///     - [`last_named_weekday`]
+///     - [`this_named_weekday`]
+///     - [`next_named_weekday`]
 ///     - [`current_named_weekday_only`]
 ///
+/// The numeric and named month dates may be prefixed with the weekday (e.g.
+/// `Fri 13/09`), which is consumed but not validated, see
+/// [`weekday_prefixed`].
+///
 /// If the specified date doesn't exist, returns `nom::Err::Error`
+#[cfg(feature = "clock")]
 pub fn bundle_dmy(input: &str) -> IResult<&str, NaiveDate> {
-    alt((
-        dd_mm_y4,
-        dd_mm_only,
-        dd_only,
-        yesterday,
-        today,
-        tomorrow,
-        current_named_weekday_only,
-    ))(input)
+    bundle_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle_dmy`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_dmy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(alt((
+            duration_ago_with(ctx),
+            calendar_week_with(ctx),
+            day_of_year_with(ctx),
+            month_part_with(ctx),
+            relative_period_with(ctx),
+            nth_weekday_of_month_with(ctx),
+            day_of_month_with(ctx),
+            weekday_prefixed(
+                named_weekday,
+                alt((
+                    ordinal_date_with(ctx),
+                    dmy_named_month_with(ctx, named_month),
+                    month_dd_y4,
+                )),
+            ),
+            yesterday_with(ctx),
+            today_with(ctx),
+            tomorrow_with(ctx),
+            in_duration_with(ctx),
+            last_named_weekday_with(ctx),
+            this_named_weekday_with(ctx),
+            next_named_weekday_with(ctx),
+            current_named_weekday_only_with(ctx),
+        )))(input)
+    }
 }
 
 /// Uses the following parsers to recognize the `numeric` and
 /// `language-specific` dates in `English`. Uses the `month-day-year` sequence:
 /// - Numeric date parsers:
-///     - [`mm_dd_y4`]
-///     - [`mm_dd_only`]
-///     - [`dd_only`]
+///     - [`mm_dd_y4`](crate::numeric::mm_dd_y4)
+///     - [`mm_dd_only`](crate::numeric::mm_dd_only)
+///     - [`dd_only`](crate::numeric::dd_only)
 /// - Language-specific
+///     - [`month_dd_y4`]
+///     - [`dd_month_y4`]
+///     - [`dd_month_only`]
+///     - [`ordinal_date`]
 ///     - [`yesterday`]
 ///     - [`today`]
 ///     - [`tomorrow`]
+///     - [`in_duration`]
+///     - [`duration_ago`]
+///     - [`calendar_week`]
+///     - [`day_of_year`]
+///     - [`month_part`]
+///     - [`relative_period`]
+///     - [`nth_weekday_of_month`]
+///     - [`day_of_month`]
+///     - [`last_named_weekday`]
+///     - [`this_named_weekday`]
+///     - [`next_named_weekday`]
 ///     - [`current_named_weekday_only`]
 ///
+/// The numeric and named month dates may be prefixed with the weekday (e.g.
+/// `Fri 13/09`), which is consumed but not validated, see
+/// [`weekday_prefixed`].
+///
 /// If the specified date doesn't exist, returns `nom::Err::Error`
+#[cfg(feature = "clock")]
 pub fn bundle_mdy(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_mdy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle_mdy`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_mdy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(alt((
+            duration_ago_with(ctx),
+            calendar_week_with(ctx),
+            day_of_year_with(ctx),
+            month_part_with(ctx),
+            relative_period_with(ctx),
+            nth_weekday_of_month_with(ctx),
+            day_of_month_with(ctx),
+            weekday_prefixed(
+                named_weekday,
+                alt((
+                    ordinal_date_with(ctx),
+                    mdy_named_month_with(ctx, named_month),
+                    month_dd_y4,
+                )),
+            ),
+            yesterday_with(ctx),
+            today_with(ctx),
+            tomorrow_with(ctx),
+            in_duration_with(ctx),
+            last_named_weekday_with(ctx),
+            this_named_weekday_with(ctx),
+            next_named_weekday_with(ctx),
+            current_named_weekday_only_with(ctx),
+        )))(input)
+    }
+}
+
+/// Creates the [`BundleBuilder`] prefilled with the components of the
+/// [`bundle_dmy`] parser in the same order, so they can be excluded or
+/// reordered. The components are named after the parsers: `duration_ago`,
+/// `calendar_week`, `day_of_year`, `month_part`, `relative_period`,
+/// `nth_weekday_of_month`, `day_of_month`, `ordinal_date`, `dmy_named_month`
+/// (the numeric and named month dates, see [`dmy_named_month_with`]),
+/// `month_dd_y4`, `yesterday`, `today`, `tomorrow`, `in_duration`,
+/// `last_named_weekday`, `this_named_weekday`, `next_named_weekday` and
+/// `current_named_weekday_only`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, i18n::en::bundle_dmy_builder};
+///
+/// let parser = bundle_dmy_builder(
+///     ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()),
+/// )
+/// .without("current_named_weekday_only")
+/// .with_order(["tomorrow"])
+/// .build();
+///
+/// assert_eq!(
+///     parser("tomorrow")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()
+/// );
+/// assert!(parser("friday").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn bundle_dmy_builder(ctx: ParserContext) -> BundleBuilder {
+    ctx.bundle_builder()
+        .with("duration_ago", |ctx, input| duration_ago_with(ctx)(input))
+        .with("calendar_week", |ctx, input| calendar_week_with(ctx)(input))
+        .with("day_of_year", |ctx, input| day_of_year_with(ctx)(input))
+        .with("month_part", |ctx, input| month_part_with(ctx)(input))
+        .with("relative_period", |ctx, input| {
+            relative_period_with(ctx)(input)
+        })
+        .with("nth_weekday_of_month", |ctx, input| {
+            nth_weekday_of_month_with(ctx)(input)
+        })
+        .with("day_of_month", |ctx, input| day_of_month_with(ctx)(input))
+        .with("ordinal_date", |ctx, input| {
+            weekday_prefixed(named_weekday, ordinal_date_with(ctx))(input)
+        })
+        .with("dmy_named_month", |ctx, input| {
+            weekday_prefixed(named_weekday, dmy_named_month_with(ctx, named_month))(input)
+        })
+        .with("month_dd_y4", |_, input| {
+            weekday_prefixed(named_weekday, month_dd_y4)(input)
+        })
+        .with("yesterday", |ctx, input| yesterday_with(ctx)(input))
+        .with("today", |ctx, input| today_with(ctx)(input))
+        .with("tomorrow", |ctx, input| tomorrow_with(ctx)(input))
+        .with("in_duration", |ctx, input| in_duration_with(ctx)(input))
+        .with("last_named_weekday", |ctx, input| {
+            last_named_weekday_with(ctx)(input)
+        })
+        .with("this_named_weekday", |ctx, input| {
+            this_named_weekday_with(ctx)(input)
+        })
+        .with("next_named_weekday", |ctx, input| {
+            next_named_weekday_with(ctx)(input)
+        })
+        .with("current_named_weekday_only", |ctx, input| {
+            current_named_weekday_only_with(ctx)(input)
+        })
+}
+
+/// Creates the [`BundleBuilder`] prefilled with the components of the
+/// [`bundle_mdy`] parser in the same order, so they can be excluded or
+/// reordered. The components are named after the parsers: `duration_ago`,
+/// `calendar_week`, `day_of_year`, `month_part`, `relative_period`,
+/// `nth_weekday_of_month`, `day_of_month`, `ordinal_date`, `mdy_named_month`
+/// (the numeric and named month dates, see [`mdy_named_month_with`]),
+/// `month_dd_y4`, `yesterday`, `today`, `tomorrow`, `in_duration`,
+/// `last_named_weekday`, `this_named_weekday`, `next_named_weekday` and
+/// `current_named_weekday_only`.
+pub fn bundle_mdy_builder(ctx: ParserContext) -> BundleBuilder {
+    ctx.bundle_builder()
+        .with("duration_ago", |ctx, input| duration_ago_with(ctx)(input))
+        .with("calendar_week", |ctx, input| calendar_week_with(ctx)(input))
+        .with("day_of_year", |ctx, input| day_of_year_with(ctx)(input))
+        .with("month_part", |ctx, input| month_part_with(ctx)(input))
+        .with("relative_period", |ctx, input| {
+            relative_period_with(ctx)(input)
+        })
+        .with("nth_weekday_of_month", |ctx, input| {
+            nth_weekday_of_month_with(ctx)(input)
+        })
+        .with("day_of_month", |ctx, input| day_of_month_with(ctx)(input))
+        .with("ordinal_date", |ctx, input| {
+            weekday_prefixed(named_weekday, ordinal_date_with(ctx))(input)
+        })
+        .with("mdy_named_month", |ctx, input| {
+            weekday_prefixed(named_weekday, mdy_named_month_with(ctx, named_month))(input)
+        })
+        .with("month_dd_y4", |_, input| {
+            weekday_prefixed(named_weekday, month_dd_y4)(input)
+        })
+        .with("yesterday", |ctx, input| yesterday_with(ctx)(input))
+        .with("today", |ctx, input| today_with(ctx)(input))
+        .with("tomorrow", |ctx, input| tomorrow_with(ctx)(input))
+        .with("in_duration", |ctx, input| in_duration_with(ctx)(input))
+        .with("last_named_weekday", |ctx, input| {
+            last_named_weekday_with(ctx)(input)
+        })
+        .with("this_named_weekday", |ctx, input| {
+            this_named_weekday_with(ctx)(input)
+        })
+        .with("next_named_weekday", |ctx, input| {
+            next_named_weekday_with(ctx)(input)
+        })
+        .with("current_named_weekday_only", |ctx, input| {
+            current_named_weekday_only_with(ctx)(input)
+        })
+}
+
+/// Recognizes the date in `English` using the [`ordinal_date`], [`month_dd_y4`]
+/// and the numeric and named month parsers of the [`bundle_dmy`] (see
+/// [`dmy_named_month_with`]), optionally prefixed with the weekday (e.g. `Fri
+/// 12 Jul 2024`, `Friday, 12/07` or `13 Jul`).
+///
+/// Unlike the [`bundle_dmy`], the weekday is validated: returns
+/// [`Error::WeekdayMismatch`](crate::error::Error::WeekdayMismatch) if it
+/// doesn't match the recognized date, see [`weekday_prefixed_checked`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{error::Error, i18n::en::weekday_date_dmy};
+///
+/// assert_eq!(
+///     weekday_date_dmy("Sat 13 Jul 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     weekday_date_dmy("Fri 13 Jul 2024"),
+///     Err(nom::Err::Error(Error::WeekdayMismatch))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn weekday_date_dmy(input: &str) -> IResult<&str, NaiveDate> {
+    weekday_date_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`weekday_date_dmy`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn weekday_date_dmy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(weekday_prefixed_checked(
+            weekday::context_named_weekday(ctx),
+            alt((
+                ordinal_date_with(ctx),
+                dmy_named_month_with(ctx, named_month),
+                month_dd_y4,
+            )),
+        ))(input)
+    }
+}
+
+/// Same as [`weekday_date_dmy`], but uses the `month-day-year` sequence of the
+/// numeric dates (see [`mdy_named_month_with`]), e.g. `Fri, 07/13/2024`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{error::Error, i18n::en::weekday_date_mdy};
+///
+/// assert_eq!(
+///     weekday_date_mdy("Sat, 07/13/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     weekday_date_mdy("Sun, July 13th, 2024"),
+///     Err(nom::Err::Error(Error::WeekdayMismatch))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn weekday_date_mdy(input: &str) -> IResult<&str, NaiveDate> {
+    weekday_date_mdy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`weekday_date_mdy`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn weekday_date_mdy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(weekday_prefixed_checked(
+            weekday::context_named_weekday(ctx),
+            alt((
+                ordinal_date_with(ctx),
+                mdy_named_month_with(ctx, named_month),
+                month_dd_y4,
+            )),
+        ))(input)
+    }
+}
+
+/// Uses the [`bundle_dmy`] parser to recognize the date, optionally followed by
+/// the time (see [`trailing_time`](crate::time::trailing_time)), and returns
+/// the corresponding [`NaiveDateTime`]. If the time is absent, uses midnight.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::en::date_time_bundle_dmy;
+///
+/// assert_eq!(
+///     date_time_bundle_dmy("13/07/2024 2:30 pm")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(14, 30, 0)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn date_time_bundle_dmy(input: &str) -> IResult<&str, NaiveDateTime> {
+    date_time_bundle_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`date_time_bundle_dmy`] parser which uses the reference date
+/// and the trailing input handling of the specified [`ParserContext`].
+pub fn date_time_bundle_dmy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDateTime> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| ctx.trailing(date_time(bundle_dmy_with(&date_ctx)))(input)
+}
+
+/// Uses the [`bundle_mdy`] parser to recognize the date, optionally followed by
+/// the time (see [`trailing_time`](crate::time::trailing_time)), and returns
+/// the corresponding [`NaiveDateTime`]. If the time is absent, uses midnight.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::en::date_time_bundle_mdy;
+///
+/// assert_eq!(
+///     date_time_bundle_mdy("Jul 13, 2024 14:30")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(14, 30, 0)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn date_time_bundle_mdy(input: &str) -> IResult<&str, NaiveDateTime> {
+    date_time_bundle_mdy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`date_time_bundle_mdy`] parser which uses the reference date
+/// and the trailing input handling of the specified [`ParserContext`].
+pub fn date_time_bundle_mdy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDateTime> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| ctx.trailing(date_time(bundle_mdy_with(&date_ctx)))(input)
+}
+
+/// Recognizes a separator between the dates of the range: either the
+/// [`range_separator`](range::range_separator) or the `case insensitive` words
+/// `to`, `until`, `till` or `through` surrounded by spaces.
+pub fn range_separator(input: &str) -> IResult<&str, ()> {
     alt((
-        mm_dd_y4,
-        mm_dd_only,
-        dd_only,
-        yesterday,
-        today,
-        tomorrow,
-        current_named_weekday_only,
+        range::range_separator,
+        value(
+            (),
+            tuple((
+                space1,
+                alt((
+                    tag_no_case("to"),
+                    tag_no_case("until"),
+                    tag_no_case("till"),
+                    tag_no_case("through"),
+                )),
+                space1,
+            )),
+        ),
     ))(input)
 }
 
+/// Recognizes the range of dates in `English` and returns the corresponding
+/// [`DateRange`]. Uses the `day-month-year` sequence:
+/// - the dates with the shared named month and year (e.g. `13-15 July` or `13
+///   July - 20 August 2024`), see [`shared_month_range_with`]
+/// - two dates recognized by the [`bundle_dmy`] parser (e.g. `13/07/2024 -
+///   20/07/2024` or `monday to friday`), see [`date_range`]
+///
+/// The range may be prefixed with the `case insensitive` word `from` (e.g.
+/// `from monday to friday`), the dates are separated by the
+/// [`range_separator`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{i18n::en::date_range_dmy, range::DateRange};
+///
+/// assert_eq!(
+///     date_range_dmy("13/07/2024 - 20/07/2024")?.1,
+///     DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn date_range_dmy(input: &str) -> IResult<&str, DateRange> {
+    date_range_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`date_range_dmy`] parser which uses the reference date and the
+/// trailing input handling of the specified [`ParserContext`].
+pub fn date_range_dmy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, DateRange> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| {
+        ctx.trailing(optional_prefix(
+            pair(tag_no_case("from"), space1),
+            alt((
+                shared_month_range_with(ctx, range_separator, named_month),
+                date_range(range_separator, bundle_dmy_with(&date_ctx)),
+            )),
+        ))(input)
+    }
+}
+
+/// Same as [`date_range_dmy`], but the dates are recognized by the
+/// [`bundle_mdy`] parser.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{i18n::en::date_range_mdy, range::DateRange};
+///
+/// assert_eq!(
+///     date_range_mdy("from 07/13/2024 to 07/20/2024")?.1,
+///     DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn date_range_mdy(input: &str) -> IResult<&str, DateRange> {
+    date_range_mdy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`date_range_mdy`] parser which uses the reference date and the
+/// trailing input handling of the specified [`ParserContext`].
+pub fn date_range_mdy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, DateRange> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| {
+        ctx.trailing(optional_prefix(
+            pair(tag_no_case("from"), space1),
+            alt((
+                shared_month_range_with(ctx, range_separator, named_month),
+                date_range(range_separator, bundle_mdy_with(&date_ctx)),
+            )),
+        ))(input)
+    }
+}
+
+/// Recognizes the `case insensitive` words starting the range in `English`
+/// followed by the spaces: `from`, `since`, `starting` or `starting from`.
+fn open_range_start(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        terminated(
+            alt((
+                tag_no_case("from"),
+                tag_no_case("since"),
+                tag_no_case("starting from"),
+                tag_no_case("starting"),
+            )),
+            space1,
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` words ending the range in `English`
+/// followed by the spaces: `until`, `till`, `through` or `by`.
+fn open_range_end(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        terminated(
+            alt((
+                tag_no_case("until"),
+                tag_no_case("till"),
+                tag_no_case("through"),
+                tag_no_case("by"),
+            )),
+            space1,
+        ),
+    )(input)
+}
+
+/// Recognizes the half-open range of dates in `English` and returns the
+/// corresponding [`OpenDateRange`], see [`open_date_range`]:
+/// - the words `from`, `since`, `starting` or `starting from` followed by the
+///   date start the range, e.g. `from monday`
+/// - the words `until`, `till`, `through` or `by` followed by the date end the
+///   range, e.g. `until the end of the month`
+///
+/// The dates are recognized by the [`end_of`] and [`bundle_dmy`] parsers. The
+/// input with both dates (e.g. `from monday to friday`) should be recognized by
+/// the [`date_range_dmy`] parser first.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     i18n::en::open_date_range_dmy,
+///     range::{OpenDateRange, RangeBound},
+/// };
+///
+/// assert_eq!(
+///     open_date_range_dmy("since 13/07/2024")?.1,
+///     OpenDateRange {
+///         start: RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()),
+///         end: RangeBound::Unbounded,
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn open_date_range_dmy(input: &str) -> IResult<&str, OpenDateRange> {
+    open_date_range_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`open_date_range_dmy`] parser which uses the reference date and
+/// the trailing input handling of the specified [`ParserContext`].
+pub fn open_date_range_dmy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, OpenDateRange> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| {
+        ctx.trailing(open_date_range(
+            open_range_start,
+            open_range_end,
+            alt((end_of_with(ctx), bundle_dmy_with(&date_ctx))),
+        ))(input)
+    }
+}
+
+/// Same as [`open_date_range_dmy`], but the dates are recognized by the
+/// [`end_of`] and [`bundle_mdy`] parsers.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     i18n::en::open_date_range_mdy,
+///     range::{OpenDateRange, RangeBound},
+/// };
+///
+/// assert_eq!(
+///     open_date_range_mdy("until 07/20/2024")?.1,
+///     OpenDateRange {
+///         start: RangeBound::Unbounded,
+///         end: RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 20).unwrap()),
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn open_date_range_mdy(input: &str) -> IResult<&str, OpenDateRange> {
+    open_date_range_mdy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`open_date_range_mdy`] parser which uses the reference date and
+/// the trailing input handling of the specified [`ParserContext`].
+pub fn open_date_range_mdy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, OpenDateRange> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| {
+        ctx.trailing(open_date_range(
+            open_range_start,
+            open_range_end,
+            alt((end_of_with(ctx), bundle_mdy_with(&date_ctx))),
+        ))(input)
+    }
+}
+
+/// Recognizes a separator between the dates of the list: either the
+/// `case insensitive` word `and` surrounded by spaces and optionally preceded
+/// by the comma (e.g. `13, 15, and 20 July`) or the
+/// [`list_separator`](list::list_separator).
+pub fn list_separator(input: &str) -> IResult<&str, ()> {
+    alt((
+        value(
+            (),
+            tuple((
+                opt(preceded(space0, char(','))),
+                space1,
+                tag_no_case("and"),
+                space1,
+            )),
+        ),
+        list::list_separator,
+    ))(input)
+}
+
+/// Recognizes the list of dates in `English` (e.g. `13/07, 15/07 and 20/07` or
+/// `monday, wednesday and friday`) and returns the dates in the order of the
+/// input. Uses the `day-month-year` sequence:
+/// - the dates with the shared named month and year (e.g. `13, 15 and 20 July`
+///   or `30 June, 2 and 5 July 2024`), see [`shared_month_list_with`]
+/// - the dates recognized by the [`bundle_dmy`] parser, see [`date_list`]
+///
+/// The dates are separated by the [`list_separator`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::en::dates_list_dmy;
+///
+/// assert_eq!(
+///     dates_list_dmy("13, 15 and 20 July 2024")?.1,
+///     vec![
+///         NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dates_list_dmy(input: &str) -> IResult<&str, Vec<NaiveDate>> {
+    dates_list_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dates_list_dmy`] parser which uses the reference date and the
+/// trailing input handling of the specified [`ParserContext`].
+pub fn dates_list_dmy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, Vec<NaiveDate>> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| {
+        ctx.trailing(alt((
+            shared_month_list_with(ctx, list_separator, named_month),
+            date_list(list_separator, bundle_dmy_with(&date_ctx)),
+        )))(input)
+    }
+}
+
+/// Same as [`dates_list_dmy`], but the dates are recognized by the
+/// [`bundle_mdy`] parser.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::en::dates_list_mdy;
+///
+/// assert_eq!(
+///     dates_list_mdy("07/13/2024, 07/15/2024")?.1,
+///     vec![
+///         NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dates_list_mdy(input: &str) -> IResult<&str, Vec<NaiveDate>> {
+    dates_list_mdy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dates_list_mdy`] parser which uses the reference date and the
+/// trailing input handling of the specified [`ParserContext`].
+pub fn dates_list_mdy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, Vec<NaiveDate>> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| {
+        ctx.trailing(alt((
+            shared_month_list_with(ctx, list_separator, named_month),
+            date_list(list_separator, bundle_mdy_with(&date_ctx)),
+        )))(input)
+    }
+}
+
+/// Finds all the dates in the free-form `English` text (e.g. `let's meet
+/// tomorrow or on 15/08`) and returns them with their byte ranges in the order
+/// of appearance. Uses the language-independent parsers (`iso8601`, `numeric`
+/// in the `day-month-year` order and `quick`) and the [`bundle_dmy`] parser.
+///
+/// The dates must start and end at the word boundaries, the unrelated words
+/// and the bare numbers (e.g. `3` in the `3 people`) are skipped. To find the
+/// single most likely date use the
+/// [`extract_best_date`](crate::extract_best_date) function.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, i18n::en::find_dates_with};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     find_dates_with(&ctx)("let's meet tomorrow or on 15/08"),
+///     vec![
+///         (11..19, NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()),
+///         (26..31, NaiveDate::from_ymd_opt(2024, 8, 15).unwrap()),
+///     ]
+/// );
+/// ```
+#[cfg(feature = "clock")]
+pub fn find_dates(text: &str) -> Vec<(Range<usize>, NaiveDate)> {
+    find_dates_with(&ParserContext::default())(text)
+}
+
+/// Creates the [`find_dates`] function which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn find_dates_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> Vec<(Range<usize>, NaiveDate)> + '_ {
+    move |text: &str| {
+        let mut parsers = common_parsers();
+        parsers.push(|ctx, input| bundle_dmy_with(ctx)(input));
+
+        find_all(ctx, &parsers, text)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::ops::{Add, Sub};
 
     use chrono::{Datelike, Days, Local};
+    use nom::error::ErrorKind;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::{context::TrailingInput, error::Error, range::RangeBound};
+
     use super::*;
 
     #[rstest]
     #[case("09", Ok(("", Local::now().date_naive().with_day(9).unwrap())))]
     #[case("03/12", Ok(("", Local::now().date_naive().with_day(3).unwrap().with_month(12).unwrap())))]
     #[case("13    06\t2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("13 July 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("Jul 13, 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 jul", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
     #[case("Yesterday", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
     #[case("Today", Ok(("", Local::now().date_naive())))]
     #[case("Tomorrow", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
@@ -86,10 +862,369 @@ mod tests {
     #[case("09", Ok(("", Local::now().date_naive().with_day(9).unwrap())))]
     #[case("12/03", Ok(("", Local::now().date_naive().with_day(3).unwrap().with_month(12).unwrap())))]
     #[case("06    13\t2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("July 13, 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 Jul 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 july", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("May 3rd, 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 5, 3).unwrap())))]
+    #[case("1st of August 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 1).unwrap())))]
     #[case("Yesterday", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
     #[case("Today", Ok(("", Local::now().date_naive())))]
     #[case("Tomorrow", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
     fn test_bundle_mdy(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
         assert_eq!(bundle_mdy(input), expected)
     }
+
+    #[rstest]
+    #[case("09", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 9).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 3).unwrap())))]
+    #[case("Yesterday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("Today", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("Tomorrow", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case(" tomorrow\n", Ok(("\n", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("Friday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("Fri 13/09", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("next friday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap())))]
+    #[case("in 2 weeks", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap())))]
+    #[case("3 days ago", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("last monday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 8).unwrap())))]
+    #[case("this sat", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("Friday, 13 September 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("3rd of May 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 5, 3).unwrap())))]
+    #[case("Fri, July 19th", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("the 21st", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("13th next month", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case("the 1st of last month", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())))]
+    #[case("42", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42" })))]
+    #[case("31 February 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("lorem", Err(nom::Err::Error(Error::Nom("lorem", ErrorKind::Tag))))]
+    fn test_bundle_dmy_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_dmy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("Fri 12 Jul 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 12).unwrap())))]
+    #[case("Friday, 12/07", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 12).unwrap())))]
+    #[case("13 Jul", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("sat, July 13th, 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("Sat Jul 13, 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("Fri 13 Jul 2024", Err(nom::Err::Error(Error::WeekdayMismatch)))]
+    #[case("Mon, the 21st", Err(nom::Err::Error(Error::WeekdayMismatch)))]
+    #[case("Sun, the 21st", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("Fri 31 February 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(
+        "Friday",
+        Err(nom::Err::Error(Error::Context(
+            "month",
+            Box::new(Error::Nom("Friday", ErrorKind::Tag))
+        )))
+    )]
+    fn test_weekday_date_dmy_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(weekday_date_dmy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("Fri 07/12", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 12).unwrap())))]
+    #[case("Sat, 07/13/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("Fri 13 Jul 2024", Err(nom::Err::Error(Error::WeekdayMismatch)))]
+    #[case("thurs. 12 Jul 2024", Err(nom::Err::Error(Error::WeekdayMismatch)))]
+    fn test_weekday_date_mdy_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(weekday_date_mdy_with(&ctx)(input), expected)
+    }
+
+    #[test]
+    fn test_weekday_date_dmy_with_weekday_prefixes() {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .with_weekday_prefixes(true);
+
+        assert_eq!(
+            weekday_date_dmy_with(&ctx)("Weds 17 Jul"),
+            Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()))
+        );
+        assert_eq!(
+            weekday_date_dmy_with(&ctx)("Tu 17 Jul"),
+            Err(nom::Err::Error(Error::WeekdayMismatch))
+        );
+    }
+
+    #[rstest]
+    #[case("03/12")]
+    #[case(" tomorrow\n")]
+    #[case("Fri 13/09")]
+    #[case("next friday")]
+    #[case("3 days ago")]
+    #[case("Friday, 13 September 2024")]
+    #[case("Fri, July 19th")]
+    #[case("42")]
+    #[case("31/02/2024")]
+    #[case("lorem")]
+    fn test_bundle_builders(#[case] input: &str) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(
+            bundle_dmy_builder(ctx.clone()).build()(input),
+            bundle_dmy_with(&ctx)(input)
+        );
+        assert_eq!(
+            bundle_mdy_builder(ctx.clone()).build()(input),
+            bundle_mdy_with(&ctx)(input)
+        );
+    }
+
+    #[rstest]
+    #[case("13", Err(nom::Err::Error(Error::Nom("13", ErrorKind::Tag))))]
+    #[case("Friday", Err(nom::Err::Error(Error::Nom("Friday", ErrorKind::Tag))))]
+    #[case("tomorrow", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    fn test_bundle_dmy_builder(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+        let parser = bundle_dmy_builder(ctx)
+            .without("dmy_named_month")
+            .without("current_named_weekday_only")
+            .build();
+
+        assert_eq!(parser(input), expected)
+    }
+
+    #[rstest]
+    #[case(TrailingInput::Keep, "13/07/2024 14:00", Ok((" 14:00", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(TrailingInput::RequireEnd, "tomorrow ", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case(
+        TrailingInput::RequireEnd,
+        "13/07/2024 14:00",
+        Err(nom::Err::Error(Error::Nom("14:00", ErrorKind::Eof)))
+    )]
+    #[case(TrailingInput::IgnoreTime, "13/07/2024 14:00", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(TrailingInput::IgnoreTime, "Jul 13, 2024, 14:00", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    fn test_bundle_dmy_with_trailing_input(
+        #[case] trailing_input: TrailingInput,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .with_trailing_input(trailing_input);
+
+        assert_eq!(bundle_dmy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("13/07/2024 14:30", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 30, 0).unwrap())))]
+    #[case("13 July 2024, 2:30 pm", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 30, 0).unwrap())))]
+    #[case("tomorrow 9:00 am", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap().and_hms_opt(9, 0, 0).unwrap())))]
+    #[case("13/07/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(0, 0, 0).unwrap())))]
+    #[case("today", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap().and_hms_opt(0, 0, 0).unwrap())))]
+    fn test_date_time_bundle_dmy_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDateTime>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(date_time_bundle_dmy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case(TrailingInput::Keep, "07/13/2024 14:30 sharp", Ok((" sharp", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 30, 0).unwrap())))]
+    #[case(TrailingInput::RequireEnd, "07/13/2024 14:30 ", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 30, 0).unwrap())))]
+    #[case(TrailingInput::RequireEnd, "07/13/2024 ", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(0, 0, 0).unwrap())))]
+    #[case(
+        TrailingInput::RequireEnd,
+        "07/13/2024 14:30 sharp",
+        Err(nom::Err::Error(Error::Nom("sharp", ErrorKind::Eof)))
+    )]
+    fn test_date_time_bundle_mdy_with_trailing_input(
+        #[case] trailing_input: TrailingInput,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDateTime>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .with_trailing_input(trailing_input);
+
+        assert_eq!(date_time_bundle_mdy_with(&ctx)(input), expected)
+    }
+
+    fn range(start: (i32, u32, u32), end: (i32, u32, u32)) -> DateRange {
+        DateRange {
+            start: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            end: NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap(),
+        }
+    }
+
+    #[rstest]
+    #[case("13/07/2024 - 20/07/2024", Ok(("", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case("13/07/2024 to 20/07/2024", Ok(("", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case("from monday to friday", Ok(("", range((2024, 7, 15), (2024, 7, 19)))))]
+    #[case("Mon—Fri", Ok(("", range((2024, 7, 15), (2024, 7, 19)))))]
+    #[case("13-15 July", Ok(("", range((2024, 7, 13), (2024, 7, 15)))))]
+    #[case("13 July until 2 August 2025", Ok(("", range((2025, 7, 13), (2025, 8, 2)))))]
+    #[case("today..tomorrow", Ok(("", range((2024, 7, 16), (2024, 7, 17)))))]
+    #[case("13.07 .. 20.07", Ok(("", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case("20/07/2024 - 13/07/2024", Err(nom::Err::Error(Error::ReversedRange)))]
+    fn test_date_range_dmy_with(#[case] input: &str, #[case] expected: IResult<&str, DateRange>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(date_range_dmy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("07/13/2024 - 07/20/2024", Ok(("", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case("From Jul 13, 2024 through Jul 20, 2024", Ok(("", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case("13-15 July", Ok(("", range((2024, 7, 13), (2024, 7, 15)))))]
+    fn test_date_range_mdy_with(#[case] input: &str, #[case] expected: IResult<&str, DateRange>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(date_range_mdy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("13/07/2024 - 20/07/2024 later", TrailingInput::Keep, Ok((" later", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case("13/07/2024 - 20/07/2024 ", TrailingInput::RequireEnd, Ok(("", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case(
+        "13/07/2024 - 20/07/2024 later",
+        TrailingInput::RequireEnd,
+        Err(nom::Err::Error(Error::Nom("later", ErrorKind::Eof)))
+    )]
+    fn test_date_range_dmy_with_trailing_input(
+        #[case] input: &str,
+        #[case] trailing_input: TrailingInput,
+        #[case] expected: IResult<&str, DateRange>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .with_trailing_input(trailing_input);
+
+        assert_eq!(date_range_dmy_with(&ctx)(input), expected)
+    }
+
+    fn since(date: (i32, u32, u32)) -> OpenDateRange {
+        OpenDateRange {
+            start: RangeBound::Date(NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap()),
+            end: RangeBound::Unbounded,
+        }
+    }
+
+    fn until(date: (i32, u32, u32)) -> OpenDateRange {
+        OpenDateRange {
+            start: RangeBound::Unbounded,
+            end: RangeBound::Date(NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap()),
+        }
+    }
+
+    #[rstest]
+    #[case("from Monday", Ok(("", since((2024, 7, 15)))))]
+    #[case("since 13/07/2024", Ok(("", since((2024, 7, 13)))))]
+    #[case("starting from tomorrow", Ok(("", since((2024, 7, 17)))))]
+    #[case("until the end of the month", Ok(("", until((2024, 7, 31)))))]
+    #[case("by Friday", Ok(("", until((2024, 7, 19)))))]
+    #[case("till 20 August", Ok(("", until((2024, 8, 20)))))]
+    #[case("from 31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(
+        "13/07/2024",
+        Err(nom::Err::Error(Error::Nom("13/07/2024", ErrorKind::Tag)))
+    )]
+    fn test_open_date_range_dmy_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, OpenDateRange>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(open_date_range_dmy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("until 07/20/2024", Ok(("", until((2024, 7, 20)))))]
+    #[case("Starting Jul 13, 2024", Ok(("", since((2024, 7, 13)))))]
+    fn test_open_date_range_mdy_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, OpenDateRange>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(open_date_range_mdy_with(&ctx)(input), expected)
+    }
+
+    fn dates(dates: &[(i32, u32, u32)]) -> Vec<NaiveDate> {
+        dates
+            .iter()
+            .map(|&(year, month, day)| NaiveDate::from_ymd_opt(year, month, day).unwrap())
+            .collect()
+    }
+
+    #[rstest]
+    #[case(" and ", Ok(("", ())))]
+    #[case(", AND 20", Ok(("20", ())))]
+    #[case(" ,  and 20", Ok(("20", ())))]
+    #[case(", 20", Ok(("20", ())))]
+    #[case(" andrew", Err(nom::Err::Error(Error::Nom("andrew", ErrorKind::Char))))]
+    fn test_list_separator(#[case] input: &str, #[case] expected: IResult<&str, ()>) {
+        assert_eq!(list_separator(input), expected)
+    }
+
+    #[rstest]
+    #[case("13/07, 15/07 and 20/07", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15), (2024, 7, 20)]))))]
+    #[case("13, 15, and 20 July", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15), (2024, 7, 20)]))))]
+    #[case("30 June, 2 and 5 July 2025", Ok(("", dates(&[(2025, 6, 30), (2025, 7, 2), (2025, 7, 5)]))))]
+    #[case("13 July 2024 and 15 August 2025", Ok(("", dates(&[(2024, 7, 13), (2025, 8, 15)]))))]
+    #[case("monday, wednesday and friday", Ok(("", dates(&[(2024, 7, 15), (2024, 7, 17), (2024, 7, 19)]))))]
+    #[case("tomorrow and 20/07", Ok(("", dates(&[(2024, 7, 17), (2024, 7, 20)]))))]
+    #[case("13 and 15", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15)]))))]
+    #[case("13/07 and later", Ok((" and later", dates(&[(2024, 7, 13)]))))]
+    #[case("13, 31 June", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dates_list_dmy_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, Vec<NaiveDate>>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(dates_list_dmy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("07/13, 07/15 and 07/20", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15), (2024, 7, 20)]))))]
+    #[case("13 and 15 July", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15)]))))]
+    #[case("Jul 13, 2024 and Jul 15, 2024", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15)]))))]
+    fn test_dates_list_mdy_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, Vec<NaiveDate>>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(dates_list_mdy_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case(
+        "let's meet tomorrow or on 15/08",
+        vec![(11..19, (2024, 7, 17)), (26..31, (2024, 8, 15))]
+    )]
+    #[case(
+        "3 people, Fri 13 September 2024 at 10",
+        vec![(10..31, (2024, 9, 13))]
+    )]
+    #[case(
+        "from 2024-07-20 till next friday",
+        vec![(5..15, (2024, 7, 20)), (21..32, (2024, 7, 26))]
+    )]
+    #[case("room a13b, see you", vec![])]
+    #[case("31/02/2024", vec![])]
+    #[case("", vec![])]
+    fn test_find_dates_with(
+        #[case] text: &str,
+        #[case] expected: Vec<(Range<usize>, (i32, u32, u32))>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(span, (y, m, d))| (span, NaiveDate::from_ymd_opt(y, m, d).unwrap()))
+            .collect();
+
+        assert_eq!(find_dates_with(&ctx)(text), expected)
+    }
 }
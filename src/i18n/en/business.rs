@@ -0,0 +1,223 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    character::complete::{digit1, space1},
+    combinator::{map_res, opt, value},
+    sequence::{pair, preceded, terminated, tuple},
+};
+
+use crate::{
+    combinators::{tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n,
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` business day in `English`: `business
+/// day`, `working day`, `work day` or `weekday`, or the plural forms of them
+/// if `plural` is `true`.
+fn business_day_word(plural: bool) -> impl Fn(&str) -> IResult<&str, ()> {
+    move |input: &str| {
+        let day = |input| {
+            if plural {
+                alt((tag_no_case("days"), tag_no_case("day")))(input)
+            } else {
+                tag_no_case("day")(input)
+            }
+        };
+
+        value(
+            (),
+            terminated(
+                alt((
+                    value(
+                        (),
+                        tuple((
+                            alt((
+                                tag_no_case("business"),
+                                tag_no_case("working"),
+                                tag_no_case("work"),
+                            )),
+                            space1,
+                            day,
+                        )),
+                    ),
+                    value((), pair(tag_no_case("week"), day)),
+                )),
+                word_boundary,
+            ),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` relative business day in `English`: the
+/// `next`, `last` or `previous` modifier with the optional `the` followed by
+/// the singular [`business_day_word`]. Returns the number of business days
+/// (`1` or `-1`).
+fn modified_business_day(input: &str) -> IResult<&str, i64> {
+    preceded(
+        opt(pair(tag_no_case("the"), space1)),
+        terminated(
+            alt((
+                value(1, tag_no_case("next")),
+                value(-1, alt((tag_no_case("last"), tag_no_case("previous")))),
+            )),
+            pair(space1, business_day_word(false)),
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` relative business day in `English` (e.g.
+/// `next business day`, `the next working day` or `previous weekday`) and
+/// returns the nearest business day after or before the reference date,
+/// skipping the weekends and the holidays of the
+/// [`BusinessCalendar`](crate::calendar::BusinessCalendar) of the context, see
+/// [`business_day`](i18n::business_day).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Weekday};
+/// use nom_date_parsers::i18n::en::business_day;
+///
+/// assert!(!matches!(
+///     business_day("next business day")?.1.weekday(),
+///     Weekday::Sat | Weekday::Sun
+/// ));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn business_day(input: &str) -> IResult<&str, NaiveDate> {
+    business_day_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`business_day`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn business_day_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::business_day(ctx, modified_business_day)(input)
+}
+
+/// Recognizes the `case insensitive` word `in` followed by the number of
+/// business days in `English`: the number (or the `a` or `an` article meaning
+/// one) followed by the [`business_day_word`], e.g. `in 3 business days`.
+fn business_days_duration(input: &str) -> IResult<&str, i64> {
+    preceded(
+        pair(tag_no_case("in"), space1),
+        terminated(
+            alt((
+                map_res(digit1, |s: &str| s.parse()),
+                value(1, tag_no_case("an")),
+                value(1, tag_no_case("a")),
+            )),
+            pair(space1, business_day_word(true)),
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` word `in` followed by the number of
+/// business days in `English` (e.g. `in 3 business days` or `in a working
+/// day`) and returns the reference date shifted by this number of business
+/// days, skipping the weekends and the holidays of the
+/// [`BusinessCalendar`](crate::calendar::BusinessCalendar) of the context, see
+/// [`business_day`](i18n::business_day).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, i18n::en::in_business_days_with};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap());
+///
+/// assert_eq!(
+///     in_business_days_with(&ctx)("in 3 business days")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 24).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn in_business_days(input: &str) -> IResult<&str, NaiveDate> {
+    in_business_days_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`in_business_days`] parser which uses the reference date and
+/// the business calendar of the specified [`ParserContext`].
+pub fn in_business_days_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::business_day(ctx, business_days_duration)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::calendar::BusinessCalendar;
+
+    #[rstest]
+    #[case("next business day", (2024, 7, 16), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("Next Business Day", (2024, 7, 19), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap())))]
+    #[case("the next working day", (2024, 7, 20), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap())))]
+    #[case("next weekday", (2024, 7, 21), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap())))]
+    #[case("previous work day", (2024, 7, 22), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("last business day, ok", (2024, 7, 17), Ok((", ok", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    fn test_business_day_with(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap());
+
+        assert_eq!(business_day_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("next day")]
+    #[case("next weekdays")]
+    #[case("business day")]
+    fn test_business_day_with_error(#[case] input: &str) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert!(business_day_with(&ctx)(input).is_err());
+    }
+
+    #[rstest]
+    #[case("in 3 business days", (2024, 7, 19), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 24).unwrap())))]
+    #[case("In 1 Working Day", (2024, 7, 16), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("in a business day", (2024, 7, 20), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap())))]
+    #[case("in 5 weekdays", (2024, 7, 16), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("in 10 work days, ok", (2024, 7, 16), Ok((", ok", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap())))]
+    #[case("in 0 business days", (2024, 7, 20), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    fn test_in_business_days_with(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap());
+
+        assert_eq!(in_business_days_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_in_business_days_with_holidays() {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 12, 23).unwrap())
+            .with_business_calendar(BusinessCalendar::new().with_holidays([
+                NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(),
+            ]));
+
+        assert_eq!(
+            in_business_days_with(&ctx)("in 2 business days"),
+            Ok(("", NaiveDate::from_ymd_opt(2024, 12, 27).unwrap()))
+        );
+        assert_eq!(
+            business_day_with(&ctx)("next business day"),
+            Ok(("", NaiveDate::from_ymd_opt(2024, 12, 24).unwrap()))
+        );
+    }
+}
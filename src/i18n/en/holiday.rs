@@ -0,0 +1,245 @@
+use chrono::{Month, NaiveDate, Weekday};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{space0, space1},
+    combinator::{map, value},
+    sequence::{pair, preceded},
+};
+
+use crate::{
+    context::ParserContext,
+    holiday::{self, HolidayRule, HolidayTable},
+    numeric::{y4, y4_with},
+    types::IResult,
+};
+
+/// Returns the table of the holidays in `English`.
+///
+/// The following names are accepted:
+/// - `New Year's Day` | `New Year's` | `New Year` -> `January 1`
+/// - `Valentine's Day` -> `February 14`
+/// - `St. Patrick's Day` | `Saint Patrick's Day` -> `March 17`
+/// - `April Fools' Day` -> `April 1`
+/// - `Independence Day` | `Fourth of July` -> `July 4`
+/// - `Halloween` -> `October 31`
+/// - `Veterans Day` -> `November 11`
+/// - `Christmas Eve` -> `December 24`
+/// - `Christmas Day` | `Christmas` | `Xmas` -> `December 25`
+/// - `Boxing Day` -> `December 26`
+/// - `New Year's Eve` -> `December 31`
+/// - `Martin Luther King Jr. Day` | `MLK Day` -> the 3rd Monday of January
+/// - `Presidents' Day` -> the 3rd Monday of February
+/// - `Mother's Day` -> the 2nd Sunday of May
+/// - `Memorial Day` -> the last Monday of May
+/// - `Father's Day` -> the 3rd Sunday of June
+/// - `Labor Day` -> the 1st Monday of September
+/// - `Columbus Day` -> the 2nd Monday of October
+/// - `Thanksgiving` | `Thanksgiving Day` -> the 4th Thursday of November
+/// - `Ash Wednesday` -> 46 days before the Easter
+/// - `Palm Sunday` -> 7 days before the Easter
+/// - `Good Friday` -> 2 days before the Easter
+/// - `Easter` | `Easter Sunday` -> the Easter Sunday, see [`holiday::easter`]
+/// - `Easter Monday` -> 1 day after the Easter
+/// - `Pentecost` -> 49 days after the Easter
+///
+/// The apostrophes and the dot after `St` may be omitted (e.g. `New Years Eve`
+/// or `St Patricks Day`).
+///
+/// Register the custom named days of the application using the
+/// [`HolidayTable::register`] method and recognize them with the
+/// [`holiday::holiday`] parser generator and the [`year`] parser.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate, Weekday};
+/// use nom_date_parsers::{context::ParserContext, holiday::holiday, i18n::en};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let table = en::holiday::table().with("Founders Day", Month::March, 3);
+///
+/// assert_eq!(
+///     holiday(&ctx, &table, en::holiday::year)("founders day 2025")?.1,
+///     NaiveDate::from_ymd_opt(2025, 3, 3).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn table() -> HolidayTable {
+    HolidayTable::new()
+        .with("new year's day", Month::January, 1)
+        .with("new years day", Month::January, 1)
+        .with("new year's", Month::January, 1)
+        .with("new years", Month::January, 1)
+        .with("new year", Month::January, 1)
+        .with("valentine's day", Month::February, 14)
+        .with("valentines day", Month::February, 14)
+        .with("st. patrick's day", Month::March, 17)
+        .with("st. patricks day", Month::March, 17)
+        .with("st patrick's day", Month::March, 17)
+        .with("st patricks day", Month::March, 17)
+        .with("saint patrick's day", Month::March, 17)
+        .with("saint patricks day", Month::March, 17)
+        .with("april fools' day", Month::April, 1)
+        .with("april fools day", Month::April, 1)
+        .with("independence day", Month::July, 4)
+        .with("fourth of july", Month::July, 4)
+        .with("halloween", Month::October, 31)
+        .with("veterans' day", Month::November, 11)
+        .with("veterans day", Month::November, 11)
+        .with("christmas eve", Month::December, 24)
+        .with("christmas day", Month::December, 25)
+        .with("christmas", Month::December, 25)
+        .with("xmas", Month::December, 25)
+        .with("boxing day", Month::December, 26)
+        .with("new year's eve", Month::December, 31)
+        .with("new years eve", Month::December, 31)
+        .with_rule(
+            "martin luther king jr. day",
+            nth_weekday(3, Weekday::Mon, Month::January),
+        )
+        .with_rule(
+            "martin luther king jr day",
+            nth_weekday(3, Weekday::Mon, Month::January),
+        )
+        .with_rule("mlk day", nth_weekday(3, Weekday::Mon, Month::January))
+        .with_rule(
+            "presidents' day",
+            nth_weekday(3, Weekday::Mon, Month::February),
+        )
+        .with_rule(
+            "presidents day",
+            nth_weekday(3, Weekday::Mon, Month::February),
+        )
+        .with_rule("mother's day", nth_weekday(2, Weekday::Sun, Month::May))
+        .with_rule("mothers day", nth_weekday(2, Weekday::Sun, Month::May))
+        .with_rule("memorial day", nth_weekday(-1, Weekday::Mon, Month::May))
+        .with_rule("father's day", nth_weekday(3, Weekday::Sun, Month::June))
+        .with_rule("fathers day", nth_weekday(3, Weekday::Sun, Month::June))
+        .with_rule("labor day", nth_weekday(1, Weekday::Mon, Month::September))
+        .with_rule("columbus day", nth_weekday(2, Weekday::Mon, Month::October))
+        .with_rule(
+            "thanksgiving day",
+            nth_weekday(4, Weekday::Thu, Month::November),
+        )
+        .with_rule(
+            "thanksgiving",
+            nth_weekday(4, Weekday::Thu, Month::November),
+        )
+        .with_rule("ash wednesday", HolidayRule::EasterOffset(-46))
+        .with_rule("palm sunday", HolidayRule::EasterOffset(-7))
+        .with_rule("good friday", HolidayRule::EasterOffset(-2))
+        .with_rule("easter sunday", HolidayRule::EasterOffset(0))
+        .with_rule("easter", HolidayRule::EasterOffset(0))
+        .with_rule("easter monday", HolidayRule::EasterOffset(1))
+        .with_rule("pentecost", HolidayRule::EasterOffset(49))
+}
+
+/// Creates the [`HolidayRule::NthWeekdayOfMonth`] rule.
+fn nth_weekday(n: i8, weekday: Weekday, month: Month) -> HolidayRule {
+    HolidayRule::NthWeekdayOfMonth { n, weekday, month }
+}
+
+/// Recognizes the explicit year following the name of the holiday, either
+/// after the spaces or after the `comma` symbol, e.g. ` 2025` in the
+/// `Christmas 2025` or `, 2025` in the `Halloween, 2025`.
+pub fn year(input: &str) -> IResult<&str, i32> {
+    year_by(y4)(input)
+}
+
+/// Creates the [`year`] parser which uses the
+/// [`YearRange`](crate::context::YearRange) of the specified [`ParserContext`].
+pub fn year_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, i32> + '_ {
+    year_by(y4_with(ctx))
+}
+
+/// Creates the [`year`] parser which uses the specified four-digit year parser.
+fn year_by<Y>(y4: Y) -> impl Fn(&str) -> IResult<&str, i32>
+where
+    Y: Fn(&str) -> IResult<&str, u32>,
+{
+    move |input: &str| {
+        map(
+            preceded(
+                alt((value((), pair(tag(","), space0)), value((), space1))),
+                &y4,
+            ),
+            |year| year as i32,
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` name of the holiday in `English` from the
+/// [`table`] followed by the optional [`year`] and returns the corresponding
+/// [`NaiveDate`]. If the year is omitted, the current year is used.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::en::holiday::holiday;
+///
+/// assert_eq!(
+///     holiday("Christmas")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 12, 25).unwrap()
+/// );
+/// assert_eq!(
+///     holiday("New Year's Eve 2025")?.1,
+///     NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn holiday(input: &str) -> IResult<&str, NaiveDate> {
+    holiday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`holiday()`] parser which uses the reference date and the
+/// [`YearRange`](crate::context::YearRange) of the specified [`ParserContext`].
+pub fn holiday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    let table = table();
+
+    move |input: &str| holiday::holiday(ctx, &table, year_with(ctx))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::error::Error;
+
+    #[rstest]
+    #[case("Christmas", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 25).unwrap())))]
+    #[case("christmas eve", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())))]
+    #[case("Xmas 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 12, 25).unwrap())))]
+    #[case("New Year's Day", Ok(("", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())))]
+    #[case("new year's eve", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())))]
+    #[case("Halloween, 2023", Ok(("", NaiveDate::from_ymd_opt(2023, 10, 31).unwrap())))]
+    #[case("St. Patrick's Day!", Ok(("!", NaiveDate::from_ymd_opt(2024, 3, 17).unwrap())))]
+    #[case("Fourth of July party", Ok((" party", NaiveDate::from_ymd_opt(2024, 7, 4).unwrap())))]
+    #[case("Thanksgiving", Ok(("", NaiveDate::from_ymd_opt(2024, 11, 28).unwrap())))]
+    #[case("thanksgiving 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 11, 27).unwrap())))]
+    #[case("Mother's Day", Ok(("", NaiveDate::from_ymd_opt(2024, 5, 12).unwrap())))]
+    #[case("Memorial Day", Ok(("", NaiveDate::from_ymd_opt(2024, 5, 27).unwrap())))]
+    #[case("Labor Day", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 2).unwrap())))]
+    #[case("MLK Day 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 20).unwrap())))]
+    #[case("Easter", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 31).unwrap())))]
+    #[case("Easter Monday 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 4, 21).unwrap())))]
+    #[case("Good Friday", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 29).unwrap())))]
+    #[case(
+        "Christmastime",
+        Err(nom::Err::Error(Error::Nom("Christmastime", ErrorKind::Tag)))
+    )]
+    #[case(
+        "Christmas 1800",
+        Err(nom::Err::Error(Error::YearOutOfRange { value: 1800, input: "1800" }))
+    )]
+    fn test_holiday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::fixed(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(holiday_with(&ctx)(input), expected);
+    }
+}
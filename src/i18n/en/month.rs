@@ -0,0 +1,445 @@
+use chrono::{Month, NaiveDate};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{space0, space1},
+    combinator::{map, opt, value},
+    sequence::{delimited, pair, preceded, terminated},
+};
+
+use crate::{
+    combinators::{lookup_no_case, optional_prefix, ordinal_suffix, tag_no_case},
+    context::ParserContext,
+    error::Error,
+    i18n::{day_month_separator, dd_named_month_only_with, dd_named_month_y4, named_month_dd_y4},
+    numeric::{dd, numeric_date_parts_separator, opt_year, y4_with},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `English`.
+///
+/// The following words are accepted:
+/// - `jan` -> [`Month::January`]
+/// - `feb` -> [`Month::February`]
+/// - `mar` -> [`Month::March`]
+/// - `apr` -> [`Month::April`]
+/// - `may` -> [`Month::May`]
+/// - `jun` -> [`Month::June`]
+/// - `jul` -> [`Month::July`]
+/// - `aug` -> [`Month::August`]
+/// - `sep` | `sept` -> [`Month::September`]
+/// - `oct` -> [`Month::October`]
+/// - `nov` -> [`Month::November`]
+/// - `dec` -> [`Month::December`]
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::en::short_named_month;
+///
+/// assert_eq!(short_named_month("jul")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    lookup_no_case(short_month)(input)
+}
+
+/// Returns the month of the lowercase short month name.
+fn short_month(word: &str) -> Option<Month> {
+    Some(match word {
+        "jan" => Month::January,
+        "feb" => Month::February,
+        "mar" => Month::March,
+        "apr" => Month::April,
+        "may" => Month::May,
+        "jun" => Month::June,
+        "jul" => Month::July,
+        "aug" => Month::August,
+        "sep" | "sept" => Month::September,
+        "oct" => Month::October,
+        "nov" => Month::November,
+        "dec" => Month::December,
+        _ => return None,
+    })
+}
+
+/// Recognizes the `case insensitive` short-named month in `English` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `English`.
+///
+/// The following words are accepted:
+/// - `january` -> [`Month::January`]
+/// - `february` -> [`Month::February`]
+/// - `march` -> [`Month::March`]
+/// - `april` -> [`Month::April`]
+/// - `may` -> [`Month::May`]
+/// - `june` -> [`Month::June`]
+/// - `july` -> [`Month::July`]
+/// - `august` -> [`Month::August`]
+/// - `september` -> [`Month::September`]
+/// - `october` -> [`Month::October`]
+/// - `november` -> [`Month::November`]
+/// - `december` -> [`Month::December`]
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::en::full_named_month;
+///
+/// assert_eq!(full_named_month("July")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    lookup_no_case(full_month)(input)
+}
+
+/// Returns the month of the lowercase full month name.
+fn full_month(word: &str) -> Option<Month> {
+    Some(match word {
+        "january" => Month::January,
+        "february" => Month::February,
+        "march" => Month::March,
+        "april" => Month::April,
+        "may" => Month::May,
+        "june" => Month::June,
+        "july" => Month::July,
+        "august" => Month::August,
+        "september" => Month::September,
+        "october" => Month::October,
+        "november" => Month::November,
+        "december" => Month::December,
+        _ => return None,
+    })
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `English`, the words of the [`full_named_month`] and
+/// [`short_named_month`] parsers are looked up at once. The short name may be
+/// followed by the `dot` symbol (see [`short_named_month_dot`]).
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::en::named_month;
+///
+/// assert_eq!(named_month("jul")?.1, Month::July);
+/// assert_eq!(named_month("july")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    let (input, (month, short)) = lookup_no_case(|word| {
+        full_month(word)
+            .map(|month| (month, false))
+            .or_else(|| short_month(word).map(|month| (month, true)))
+    })(input)?;
+
+    if short {
+        value(month, opt(tag(".")))(input)
+    } else {
+        Ok((input, month))
+    }
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `English` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::en::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 july 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(named_month)(input)
+}
+
+/// Recognizes the `month`, `day` and `year` parts in `English` using the
+/// [`named_month_dd_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::en::month_dd_y4;
+///
+/// assert_eq!(
+///     month_dd_y4("Jul 13, 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn month_dd_y4(input: &str) -> IResult<&str, NaiveDate> {
+    named_month_dd_y4(named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `English` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::en::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 july")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+/// Recognizes the `day` part followed by the `English` ordinal suffix (see
+/// [`ordinal_suffix`]), e.g. `1st`, `22nd`, `3rd` or `13th`.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::i18n::en::ordinal_dd;
+///
+/// assert_eq!(ordinal_dd("21st of July")?, (" of July", 21));
+/// assert!(ordinal_dd("21 July").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn ordinal_dd(input: &str) -> IResult<&str, u32> {
+    terminated(dd, ordinal_suffix)(input)
+}
+
+/// Recognizes the date with the ordinal `day` part (see [`ordinal_dd`]) in
+/// `English` and returns the corresponding [`NaiveDate`]. The following forms
+/// are accepted, the missing `month` and `year` parts are taken from the
+/// current date:
+/// - `[the] <day> [of] <month> [year]`, e.g. `3rd of May 2025` or `the 13th
+///   July`
+/// - `<month> <day>[,] [year]`, e.g. `May 3rd` or `July 13th, 2024`
+/// - `[the] <day>`, e.g. `the 21st`
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::en::ordinal_date;
+///
+/// assert_eq!(
+///     ordinal_date("3rd of May 2025")?.1,
+///     NaiveDate::from_ymd_opt(2025, 5, 3).unwrap()
+/// );
+/// assert_eq!(
+///     ordinal_date("May 3rd")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 5, 3).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn ordinal_date(input: &str) -> IResult<&str, NaiveDate> {
+    ordinal_date_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`ordinal_date`] parser which takes the missing parts from the
+/// reference date of the specified [`ParserContext`].
+pub fn ordinal_date_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let year = || {
+            preceded(
+                alt((
+                    value((), pair(tag(","), space0)),
+                    numeric_date_parts_separator,
+                )),
+                y4_with(ctx),
+            )
+        };
+        let (input, (day, month_y4)) = alt((
+            map(
+                pair(
+                    named_month,
+                    pair(preceded(space1, ordinal_dd), opt_year(year())),
+                ),
+                |(month, (day, y4))| (day, Some((month, y4))),
+            ),
+            pair(
+                optional_prefix(pair(tag_no_case("the"), space1), ordinal_dd),
+                opt_year(pair(
+                    preceded(
+                        alt((
+                            value((), delimited(space1, tag_no_case("of"), space1)),
+                            day_month_separator,
+                        )),
+                        named_month,
+                    ),
+                    opt_year(preceded(numeric_date_parts_separator, y4_with(ctx))),
+                )),
+            ),
+        ))(input)?;
+        let date = match month_y4 {
+            Some((month, Some(y4))) => {
+                NaiveDate::from_ymd_opt(y4 as i32, month.number_from_month(), day)
+            }
+            Some((month, None)) => ctx.resolve(Some(month.number_from_month()), day),
+            None => ctx.resolve(None, day),
+        };
+
+        Ok((input, date.ok_or(nom::Err::Error(Error::NonExistentDate))?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::{combinators::assert_lookup_words, error::Error};
+
+    use super::*;
+
+    #[rstest]
+    #[case("jan", Ok(("", Month::January)))]
+    #[case("FEB", Ok(("", Month::February)))]
+    #[case("Dec", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("jan.", Ok(("", Month::January)))]
+    #[case("Dec.", Ok(("", Month::December)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("january", Ok(("", Month::January)))]
+    #[case("May", Ok(("", Month::May)))]
+    #[case("SEPTEMBER", Ok(("", Month::September)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("mar", Ok(("", Month::March)))]
+    #[case("march", Ok(("", Month::March)))]
+    #[case("Sept. 1", Ok((" 1", Month::September)))]
+    #[case("may.", Ok((".", Month::May)))]
+    #[case("julyish", Ok(("ish", Month::July)))]
+    #[case("Jul2024", Ok(("2024", Month::July)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 july 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1-jan-2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 february 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("Jul 13, 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("July 13 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("sept. 1,2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 1).unwrap())))]
+    #[case("Feb 30, 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_month_dd_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(month_dd_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 july", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 july", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 july" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+
+    #[rstest]
+    #[case("1st", Ok(("", 1)))]
+    #[case("22ND of May", Ok((" of May", 22)))]
+    #[case("13th, 2024", Ok((", 2024", 13)))]
+    #[case(
+        "13 July",
+        Err(nom::Err::Error(Error::Nom(" July", nom::error::ErrorKind::Tag)))
+    )]
+    #[case("32nd", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32nd" })))]
+    fn test_ordinal_dd(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
+        assert_eq!(ordinal_dd(input), expected);
+    }
+
+    #[rstest]
+    #[case("3rd of May 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 5, 3).unwrap())))]
+    #[case("1st of may", Ok(("", NaiveDate::from_ymd_opt(2024, 5, 1).unwrap())))]
+    #[case("the 13th July", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13th-Jul-2023", Ok(("", NaiveDate::from_ymd_opt(2023, 7, 13).unwrap())))]
+    #[case("May 3rd", Ok(("", NaiveDate::from_ymd_opt(2024, 5, 3).unwrap())))]
+    #[case("July 13th, 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 13).unwrap())))]
+    #[case("the 21st", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("21st at noon", Ok((" at noon", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("30th of February", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("May 32nd", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32nd" })))]
+    fn test_ordinal_date_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(ordinal_date_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_lookup_words() {
+        assert_lookup_words(
+            short_named_month,
+            &[
+                "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "sept", "oct",
+                "nov", "dec",
+            ],
+        );
+        assert_lookup_words(
+            full_named_month,
+            &[
+                "january",
+                "february",
+                "march",
+                "april",
+                "may",
+                "june",
+                "july",
+                "august",
+                "september",
+                "october",
+                "november",
+                "december",
+            ],
+        );
+    }
+}
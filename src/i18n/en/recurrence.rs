@@ -0,0 +1,216 @@
+use chrono::Weekday;
+use nom::{
+    branch::alt,
+    character::complete::{digit1, space1},
+    combinator::{map, map_res, opt, value, verify},
+    sequence::{pair, preceded, terminated, tuple},
+};
+
+use super::{named_weekday, ordinal_dd};
+use crate::{
+    combinators::{tag_no_case, word_boundary},
+    recurrence::{Frequency, Recurrence},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` number of the units between the
+/// occurrences in `English`: either the number greater than zero or the word
+/// `other` (`2`).
+fn interval(input: &str) -> IResult<&str, u32> {
+    alt((
+        value(2, terminated(tag_no_case("other"), word_boundary)),
+        verify(map_res(digit1, |s: &str| s.parse()), |&interval| {
+            interval > 0
+        }),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday of the weekly recurrence in
+/// `English` preceded by the word `on`, e.g. ` on monday`.
+fn on_weekday(input: &str) -> IResult<&str, Weekday> {
+    preceded(tuple((space1, tag_no_case("on"), space1)), named_weekday)(input)
+}
+
+/// Recognizes the `case insensitive` day of the monthly recurrence in `English`
+/// preceded by the words `on` or `on the`, e.g. ` on the 15th`.
+fn on_day(input: &str) -> IResult<&str, u32> {
+    preceded(
+        tuple((
+            space1,
+            tag_no_case("on"),
+            space1,
+            opt(pair(tag_no_case("the"), space1)),
+        )),
+        ordinal_dd,
+    )(input)
+}
+
+/// Recognizes the `case insensitive` unit of the recurrence in `English`
+/// following the word `every` and the optional [`interval`]:
+/// - `day` | `days` -> [`Frequency::Daily`]
+/// - `week` | `weeks` with the optional [`on_weekday`] -> [`Frequency::Weekly`]
+/// - `month` | `months` with the optional [`on_day`] -> [`Frequency::Monthly`]
+/// - the [`named_weekday`] -> [`Frequency::Weekly`]
+fn unit(input: &str) -> IResult<&str, Frequency> {
+    alt((
+        value(
+            Frequency::Daily,
+            terminated(
+                alt((tag_no_case("days"), tag_no_case("day"))),
+                word_boundary,
+            ),
+        ),
+        map(
+            preceded(
+                terminated(
+                    alt((tag_no_case("weeks"), tag_no_case("week"))),
+                    word_boundary,
+                ),
+                opt(on_weekday),
+            ),
+            Frequency::Weekly,
+        ),
+        map(
+            preceded(
+                terminated(
+                    alt((tag_no_case("months"), tag_no_case("month"))),
+                    word_boundary,
+                ),
+                opt(on_day),
+            ),
+            Frequency::Monthly,
+        ),
+        map(terminated(named_weekday, word_boundary), |weekday| {
+            Frequency::Weekly(Some(weekday))
+        }),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` recurring date in `English` and returns
+/// the corresponding [`Recurrence`]. The following phrases are accepted:
+/// - `daily`, `weekly [on <weekday>]` or `monthly [on [the] <day>]` with the
+///   ordinal `day` (see [`ordinal_dd`]), e.g. `weekly on friday` or `monthly on
+///   the 15th`
+/// - `every [<interval>] <unit>` with the number or the word `other` as the
+///   `interval`, where the `unit` is either the [`named_weekday`] or the
+///   `day(s)`, `week(s) [on <weekday>]` or `month(s) [on [the] <day>]`, e.g.
+///   `every monday`, `every other friday`, `every 3 days` or `every 2 weeks on
+///   tuesday`
+/// - `every <day> [of the month]` or `every <day> of every month`, e.g. `every
+///   15th`
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::{
+///     i18n::en::recurrence,
+///     recurrence::{Frequency, Recurrence},
+/// };
+///
+/// assert_eq!(
+///     recurrence("every other friday")?.1,
+///     Recurrence::new(Frequency::Weekly(Some(Weekday::Fri))).with_interval(2)
+/// );
+/// assert_eq!(
+///     recurrence("monthly on the 15th")?.1,
+///     Recurrence::new(Frequency::Monthly(Some(15)))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn recurrence(input: &str) -> IResult<&str, Recurrence> {
+    alt((
+        value(
+            Recurrence::new(Frequency::Daily),
+            terminated(tag_no_case("daily"), word_boundary),
+        ),
+        map(
+            preceded(
+                terminated(tag_no_case("weekly"), word_boundary),
+                opt(on_weekday),
+            ),
+            |weekday| Recurrence::new(Frequency::Weekly(weekday)),
+        ),
+        map(
+            preceded(
+                terminated(tag_no_case("monthly"), word_boundary),
+                opt(on_day),
+            ),
+            |day| Recurrence::new(Frequency::Monthly(day)),
+        ),
+        preceded(
+            pair(tag_no_case("every"), space1),
+            alt((
+                map(
+                    terminated(
+                        ordinal_dd,
+                        opt(tuple((
+                            space1,
+                            tag_no_case("of"),
+                            space1,
+                            alt((tag_no_case("the"), tag_no_case("every"))),
+                            space1,
+                            tag_no_case("month"),
+                            word_boundary,
+                        ))),
+                    ),
+                    |day| Recurrence::new(Frequency::Monthly(Some(day))),
+                ),
+                map(
+                    pair(opt(terminated(interval, space1)), unit),
+                    |(interval, frequency)| {
+                        Recurrence::new(frequency).with_interval(interval.unwrap_or(1))
+                    },
+                ),
+            )),
+        ),
+    ))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::error::Error;
+
+    fn every(frequency: Frequency, interval: u32) -> Recurrence {
+        Recurrence::new(frequency).with_interval(interval)
+    }
+
+    #[rstest]
+    #[case("daily", Ok(("", every(Frequency::Daily, 1))))]
+    #[case("Weekly", Ok(("", every(Frequency::Weekly(None), 1))))]
+    #[case("weekly on Friday", Ok(("", every(Frequency::Weekly(Some(Weekday::Fri)), 1))))]
+    #[case("monthly", Ok(("", every(Frequency::Monthly(None), 1))))]
+    #[case("monthly on the 15th", Ok(("", every(Frequency::Monthly(Some(15)), 1))))]
+    #[case("monthly on 1st", Ok(("", every(Frequency::Monthly(Some(1)), 1))))]
+    #[case("every day", Ok(("", every(Frequency::Daily, 1))))]
+    #[case("every other day", Ok(("", every(Frequency::Daily, 2))))]
+    #[case("every 3 days", Ok(("", every(Frequency::Daily, 3))))]
+    #[case("every monday", Ok(("", every(Frequency::Weekly(Some(Weekday::Mon)), 1))))]
+    #[case("Every Mon", Ok(("", every(Frequency::Weekly(Some(Weekday::Mon)), 1))))]
+    #[case("every other friday", Ok(("", every(Frequency::Weekly(Some(Weekday::Fri)), 2))))]
+    #[case("every week", Ok(("", every(Frequency::Weekly(None), 1))))]
+    #[case("every 2 weeks", Ok(("", every(Frequency::Weekly(None), 2))))]
+    #[case("every 2 weeks on tue", Ok(("", every(Frequency::Weekly(Some(Weekday::Tue)), 2))))]
+    #[case("every month", Ok(("", every(Frequency::Monthly(None), 1))))]
+    #[case("every 3 months on the 1st", Ok(("", every(Frequency::Monthly(Some(1)), 3))))]
+    #[case("every 15th", Ok(("", every(Frequency::Monthly(Some(15)), 1))))]
+    #[case("every 15th of the month", Ok(("", every(Frequency::Monthly(Some(15)), 1))))]
+    #[case("every 31st of every month", Ok(("", every(Frequency::Monthly(Some(31)), 1))))]
+    #[case("every monday at 10:00", Ok((" at 10:00", every(Frequency::Weekly(Some(Weekday::Mon)), 1))))]
+    #[case("every 32nd", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32nd" })))]
+    #[case("every 0 days", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "0 days" })))]
+    #[case(
+        "every monthly",
+        Err(nom::Err::Error(Error::Nom("thly", ErrorKind::Not)))
+    )]
+    #[case("every dayz", Err(nom::Err::Error(Error::Nom("dayz", ErrorKind::Tag))))]
+    #[case("everyday", Err(nom::Err::Error(Error::Nom("day", ErrorKind::Space))))]
+    fn test_recurrence(#[case] input: &str, #[case] expected: IResult<&str, Recurrence>) {
+        assert_eq!(recurrence(input), expected);
+    }
+}
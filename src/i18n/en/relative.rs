@@ -1,9 +1,25 @@
-use std::ops::{Add, Sub};
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, space1},
+    combinator::{map, map_res, opt, value},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+};
 
-use chrono::{Days, Local, NaiveDate};
-use nom::{bytes::complete::tag_no_case, combinator::value};
-
-use crate::types::IResult;
+use super::{named_month, named_weekday, ordinal_dd};
+use crate::{
+    combinators::{optional_prefix, tag_no_case, word_boundary},
+    context::ParserContext,
+    error::Error,
+    i18n::{
+        self, end_of_period, relative_day, relative_duration, week_of_year, y4_mm_month_with,
+        MonthPart, MonthRef, Period,
+    },
+    numeric::{ddd, opt_year, ww, y4, y4_with},
+    range::DateRange,
+    types::IResult,
+};
 
 /// Recognizes the `case insensitive` word `yesterday` in `English` and returns
 /// the corresponding [`NaiveDate`] for it.
@@ -22,11 +38,15 @@ use crate::types::IResult;
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
-    value(
-        Local::now().sub(Days::new(1)).date_naive(),
-        tag_no_case("yesterday"),
-    )(input)
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -1, tag_no_case("yesterday"))(input)
 }
 
 /// Recognizes the `case insensitive` word `today` in `English` and returns
@@ -41,8 +61,15 @@ pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
 /// assert_eq!(today("Today")?.1, Local::now().date_naive());
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn today(input: &str) -> IResult<&str, NaiveDate> {
-    value(Local::now().date_naive(), tag_no_case("today"))(input)
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| value(ctx.today(), tag_no_case("today"))(input)
 }
 
 /// Recognizes the `case insensitive` word `tomorrow` in `English` and returns
@@ -62,19 +89,552 @@ pub fn today(input: &str) -> IResult<&str, NaiveDate> {
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
-    value(
-        Local::now().add(Days::new(1)).date_naive(),
-        tag_no_case("tomorrow"),
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 1, tag_no_case("tomorrow"))(input)
+}
+
+/// Recognizes the `case insensitive` unit of the duration in `English`.
+///
+/// The following words are accepted:
+/// - `day` | `days` -> [`Period::Day`]
+/// - `week` | `weeks` -> [`Period::Week`]
+/// - `month` | `months` -> [`Period::Month`]
+/// - `year` | `years` -> [`Period::Year`]
+///
+/// The unit must be followed by the [`word_boundary`], so `weekend` isn't
+/// recognized as `week`.
+pub fn period(input: &str) -> IResult<&str, Period> {
+    terminated(
+        alt((
+            value(Period::Day, tag_no_case("days")),
+            value(Period::Day, tag_no_case("day")),
+            value(Period::Week, tag_no_case("weeks")),
+            value(Period::Week, tag_no_case("week")),
+            value(Period::Month, tag_no_case("months")),
+            value(Period::Month, tag_no_case("month")),
+            value(Period::Year, tag_no_case("years")),
+            value(Period::Year, tag_no_case("year")),
+        )),
+        word_boundary,
+    )(input)
+}
+
+/// Recognizes the duration in `English`: the number (or the `case insensitive`
+/// `a` or `an` article meaning one) followed by the [`period`], e.g. `3 days`
+/// or `a week`.
+pub fn duration(input: &str) -> IResult<&str, (u32, Period)> {
+    separated_pair(
+        alt((
+            map_res(digit1, |s: &str| s.parse()),
+            value(1, tag_no_case("an")),
+            value(1, tag_no_case("a")),
+        )),
+        space1,
+        period,
+    )(input)
+}
+
+/// Recognizes the `case insensitive` relative period in `English`: the `next`,
+/// `last` or `previous` modifier followed by the [`period`], e.g. `next week`
+/// or `last month`. Returns the number of periods (`1` or `-1`) and the
+/// [`Period`].
+fn modified_period(input: &str) -> IResult<&str, (i64, Period)> {
+    separated_pair(
+        alt((
+            value(1, tag_no_case("next")),
+            value(-1, alt((tag_no_case("last"), tag_no_case("previous")))),
+        )),
+        space1,
+        period,
+    )(input)
+}
+
+/// Recognizes the `case insensitive` relative period in `English` (e.g. `next
+/// week`, `last month` or `next year`) and returns the reference date shifted
+/// by the period (e.g. the same weekday of the next week), see
+/// [`relative_period`](i18n::relative_period).
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local};
+/// use nom_date_parsers::i18n::en::relative_period;
+///
+/// assert_eq!(
+///     relative_period("next week")?.1,
+///     Local::now().add(Days::new(7)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn relative_period(input: &str) -> IResult<&str, NaiveDate> {
+    relative_period_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`relative_period`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn relative_period_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::relative_period(ctx, modified_period)(input)
+}
+
+/// Recognizes the same phrases as the [`relative_period`] parser, but returns
+/// the whole period as the [`DateRange`] (e.g. from Monday to Sunday of the
+/// next week), see [`relative_period_range`](i18n::relative_period_range).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::en::relative_period_range;
+///
+/// let range = relative_period_range("next year")?.1;
+///
+/// assert_eq!(
+///     range.start,
+///     NaiveDate::from_ymd_opt(Local::now().year() + 1, 1, 1).unwrap()
+/// );
+/// assert_eq!(
+///     range.end,
+///     NaiveDate::from_ymd_opt(Local::now().year() + 1, 12, 31).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn relative_period_range(input: &str) -> IResult<&str, DateRange> {
+    relative_period_range_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`relative_period_range`] parser which uses the reference date
+/// of the specified [`ParserContext`].
+pub fn relative_period_range_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, DateRange> + '_ {
+    move |input: &str| i18n::relative_period_range(ctx, modified_period)(input)
+}
+
+/// Recognizes the `case insensitive` word `in` followed by the [`duration`] in
+/// `English` (e.g. `in 3 days` or `in a month`) and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local};
+/// use nom_date_parsers::i18n::en::in_duration;
+///
+/// assert_eq!(
+///     in_duration("in 2 weeks")?.1,
+///     Local::now().add(Days::new(14)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn in_duration(input: &str) -> IResult<&str, NaiveDate> {
+    in_duration_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`in_duration`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn in_duration_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_duration(ctx, 1, preceded(pair(tag_no_case("in"), space1), duration))(input)
+    }
+}
+
+/// Recognizes the [`duration`] followed by the `case insensitive` word `ago` in
+/// `English` (e.g. `4 months ago`) and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local};
+/// use nom_date_parsers::i18n::en::duration_ago;
+///
+/// assert_eq!(
+///     duration_ago("3 days ago")?.1,
+///     Local::now().sub(Days::new(3)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn duration_ago(input: &str) -> IResult<&str, NaiveDate> {
+    duration_ago_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`duration_ago`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn duration_ago_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_duration(
+            ctx,
+            -1,
+            terminated(duration, pair(space1, tag_no_case("ago"))),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` phrase `end of` followed by the
+/// [`period`] in `English` (e.g. `the end of the month` or `end of this week`)
+/// and returns the last day of the period containing the current date, see
+/// [`end_of_period`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::en::end_of;
+///
+/// assert_eq!(
+///     end_of("the end of the year")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 12, 31).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn end_of(input: &str) -> IResult<&str, NaiveDate> {
+    end_of_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`end_of`] parser which uses the reference date of the specified
+/// [`ParserContext`].
+pub fn end_of_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, period) = preceded(
+            tuple((
+                optional_prefix(pair(tag_no_case("the"), space1), tag_no_case("end")),
+                space1,
+                tag_no_case("of"),
+                space1,
+                opt(pair(alt((tag_no_case("the"), tag_no_case("this"))), space1)),
+            )),
+            period,
+        )(input)?;
+
+        Ok((
+            input,
+            end_of_period(ctx.today(), period).ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Recognizes the `case insensitive` part of the month phrase in `English`
+/// (e.g. `the beginning of`, `start of`, `middle of`, `mid-` or `end of`).
+fn month_part_prefix(input: &str) -> IResult<&str, MonthPart> {
+    alt((
+        optional_prefix(
+            pair(tag_no_case("the"), space1),
+            terminated(
+                alt((
+                    value(
+                        MonthPart::Beginning,
+                        alt((tag_no_case("beginning"), tag_no_case("start"))),
+                    ),
+                    value(MonthPart::Middle, tag_no_case("middle")),
+                    value(MonthPart::End, tag_no_case("end")),
+                )),
+                tuple((space1, tag_no_case("of"), space1)),
+            ),
+        ),
+        value(
+            MonthPart::Middle,
+            pair(tag_no_case("mid"), alt((tag("-"), space1))),
+        ),
+    ))(input)
+}
+
+/// Recognizes one of the `this`, `the`, `next`, `last` and `previous` modifiers
+/// and returns the corresponding number of periods from the reference date.
+pub(super) fn modifier(input: &str) -> IResult<&str, i64> {
+    alt((
+        value(0, alt((tag_no_case("this"), tag_no_case("the")))),
+        value(1, tag_no_case("next")),
+        value(-1, alt((tag_no_case("last"), tag_no_case("previous")))),
+    ))(input)
+}
+
+/// Recognizes the [`named_month`] followed by one of the `this year`, `next
+/// year` and `last year` phrases.
+fn named_month_of_year(input: &str) -> IResult<&str, MonthRef> {
+    map(
+        separated_pair(
+            named_month,
+            space1,
+            terminated(modifier, pair(space1, tag_no_case("year"))),
+        ),
+        |(month, years)| MonthRef::NamedRelative(month, years),
     )(input)
 }
 
+/// Recognizes one of the `this month`, `the month`, `next month`, `last month`
+/// and `previous month` phrases.
+fn relative_month(input: &str) -> IResult<&str, MonthRef> {
+    map(
+        terminated(modifier, pair(space1, tag_no_case("month"))),
+        MonthRef::Relative,
+    )(input)
+}
+
+/// Recognizes the month relative to the reference date, either the
+/// [`named_month_of_year`] or the [`relative_month`].
+fn relative_month_ref(input: &str) -> IResult<&str, MonthRef> {
+    alt((named_month_of_year, relative_month))(input)
+}
+
+/// Recognizes the [`named_month_of_year`], the [`named_month`] with the
+/// optional year or the [`relative_month`].
+fn month_ref(input: &str) -> IResult<&str, MonthRef> {
+    alt((
+        named_month_of_year,
+        map(
+            pair(named_month, opt(preceded(space1, y4))),
+            |(month, year)| MonthRef::Named(month, year.map(|year| year as i32)),
+        ),
+        relative_month,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` phrases referring to the beginning, the
+/// middle or the end of the month in `English` (e.g. `end of July`,
+/// `mid-August 2024` or `the beginning of next month`) and returns the first,
+/// the 15th or the last day of the month, see [`month_part`].
+///
+/// The month is either the [`named_month`] with the optional year (the year of
+/// the reference date is used if it is omitted) or followed by the `this
+/// year`, `next year` or `last year`, or one of the `this month`, `the month`,
+/// `next month`, `last month` and `previous month`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::en::month_part;
+///
+/// assert_eq!(
+///     month_part("mid-August")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 8, 15).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn month_part(input: &str) -> IResult<&str, NaiveDate> {
+    month_part_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`month_part`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn month_part_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::month_part(ctx, pair(month_part_prefix, month_ref))(input)
+}
+
+/// Recognizes the `case insensitive` informal week notation in `English` (e.g.
+/// `week 29`) and returns the Monday of the ISO 8601 week in the current year,
+/// see [`week_of_year`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Weekday};
+/// use nom_date_parsers::i18n::en::calendar_week;
+///
+/// let date = calendar_week("Week 29")?.1;
+///
+/// assert_eq!(date.iso_week().week(), 29);
+/// assert_eq!(date.weekday(), Weekday::Mon);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn calendar_week(input: &str) -> IResult<&str, NaiveDate> {
+    calendar_week_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`calendar_week`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn calendar_week_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| week_of_year(ctx, preceded(pair(tag_no_case("week"), space1), ww))(input)
+}
+
+/// Recognizes the `case insensitive` day of the year in `English` followed by
+/// the optional year (e.g. `day 195` or `day 195 of 2024`) and returns the
+/// corresponding [`NaiveDate`], see [`i18n::day_of_year`]. If the year is
+/// omitted, the current year is used.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{error::Error, i18n::en::day_of_year};
+///
+/// assert_eq!(
+///     day_of_year("day 195 of 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     day_of_year("Day 400"),
+///     Err(nom::Err::Error(Error::DayOfYearOutOfRange))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_of_year(input: &str) -> IResult<&str, NaiveDate> {
+    day_of_year_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_of_year`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn day_of_year_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        i18n::day_of_year(
+            ctx,
+            pair(
+                preceded(pair(tag_no_case("day"), space1), ddd),
+                opt_year(map(
+                    preceded(tuple((space1, tag_no_case("of"), space1)), y4_with(ctx)),
+                    |year| year as i32,
+                )),
+            ),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` ordinal number of the weekday in the month
+/// in `English`: `first` | `1st` .. `fifth` | `5th` or `last` (`-1`).
+fn weekday_ordinal(input: &str) -> IResult<&str, i8> {
+    terminated(
+        alt((
+            value(1, alt((tag_no_case("first"), tag_no_case("1st")))),
+            value(2, alt((tag_no_case("second"), tag_no_case("2nd")))),
+            value(3, alt((tag_no_case("third"), tag_no_case("3rd")))),
+            value(4, alt((tag_no_case("fourth"), tag_no_case("4th")))),
+            value(5, alt((tag_no_case("fifth"), tag_no_case("5th")))),
+            value(-1, tag_no_case("last")),
+        )),
+        word_boundary,
+    )(input)
+}
+
+/// Recognizes the `case insensitive` phrases referring to the n-th weekday of
+/// the month in `English` (e.g. `first monday of september`, `the last Friday
+/// of 2024-10` or `2nd tue in next month`) and returns the corresponding
+/// [`NaiveDate`], see [`nth_weekday_of_month`](i18n::nth_weekday_of_month).
+///
+/// The month is either the [`named_month`] with the optional year (the year of
+/// the reference date is used if it is omitted), the year and month separated
+/// by the `dash` symbol or one of the `this month`, `the month`, `next month`,
+/// `last month` and `previous month`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use nom_date_parsers::{error::Error, i18n::en::nth_weekday_of_month};
+///
+/// let date = nth_weekday_of_month("first monday of september")?.1;
+///
+/// assert_eq!(date.weekday(), Weekday::Mon);
+/// assert!(date.day() <= 7);
+/// assert_eq!(
+///     nth_weekday_of_month("last friday of 2024-10")?.1,
+///     NaiveDate::from_ymd_opt(2024, 10, 25).unwrap()
+/// );
+/// assert_eq!(
+///     nth_weekday_of_month("fifth friday of 2024-10"),
+///     Err(nom::Err::Error(Error::NonExistentDate))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn nth_weekday_of_month(input: &str) -> IResult<&str, NaiveDate> {
+    nth_weekday_of_month_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`nth_weekday_of_month`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn nth_weekday_of_month_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        i18n::nth_weekday_of_month(
+            ctx,
+            tuple((
+                optional_prefix(pair(tag_no_case("the"), space1), weekday_ordinal),
+                preceded(space1, named_weekday),
+                preceded(
+                    tuple((space1, alt((tag_no_case("of"), tag_no_case("in"))), space1)),
+                    alt((y4_mm_month_with(ctx), month_ref)),
+                ),
+            )),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` phrases referring to the day of the
+/// relative month in `English`: `[the] <day> [of] <month>` with the ordinal
+/// `day` (see [`ordinal_dd`]), e.g. `13th next month`, `the 1st of last month`
+/// or `5th of July next year`, and returns the corresponding [`NaiveDate`], see
+/// [`day_of_month`](i18n::day_of_month).
+///
+/// The month is one of the `this month`, `the month`, `next month`, `last
+/// month` and `previous month` or the [`named_month`] followed by the `this
+/// year`, `next year` or `last year`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local};
+/// use nom_date_parsers::i18n::en::day_of_month;
+///
+/// assert_eq!(day_of_month("1st of next month")?.1.day(), 1);
+/// assert_eq!(
+///     day_of_month("5th of July next year")?.1.year(),
+///     Local::now().year() + 1
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_of_month(input: &str) -> IResult<&str, NaiveDate> {
+    day_of_month_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_of_month`] parser which uses the reference date and the
+/// [`MonthOverflow`](crate::calendar::MonthOverflow) policy of the specified
+/// [`ParserContext`].
+pub fn day_of_month_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        i18n::day_of_month(
+            ctx,
+            separated_pair(
+                optional_prefix(pair(tag_no_case("the"), space1), ordinal_dd),
+                alt((delimited(space1, tag_no_case("of"), space1), space1)),
+                relative_month_ref,
+            ),
+        )(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use chrono::NaiveDate;
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local, NaiveDate};
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::{calendar::MonthOverflow, error::Error};
+
     use super::*;
 
     #[rstest]
@@ -94,4 +654,237 @@ mod tests {
     fn test_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
         assert_eq!(tomorrow(input), expected);
     }
+
+    #[test]
+    fn test_date_out_of_range() {
+        let ctx = ParserContext::new().with_today(NaiveDate::MAX);
+
+        assert_eq!(
+            tomorrow_with(&ctx)("tomorrow"),
+            Err(nom::Err::Error(Error::DateOutOfRange))
+        );
+
+        let ctx = ParserContext::new().with_today(NaiveDate::MIN);
+
+        assert_eq!(
+            yesterday_with(&ctx)("yesterday"),
+            Err(nom::Err::Error(Error::DateOutOfRange))
+        );
+    }
+
+    #[rstest]
+    #[case(NaiveDate::MAX, "in 2 days")]
+    #[case(NaiveDate::MAX, "in 999999 years")]
+    #[case(NaiveDate::MIN, "999999 months ago")]
+    #[case(NaiveDate::MAX, "next week")]
+    #[case(NaiveDate::MIN, "last year")]
+    #[case(NaiveDate::MAX, "end of next month")]
+    #[case(NaiveDate::MIN, "start of last month")]
+    #[case(NaiveDate::MAX, "first monday of next month")]
+    #[case(NaiveDate::MAX, "13th next month")]
+    #[case(NaiveDate::MIN, "1st of June last year")]
+    fn test_relative_date_out_of_range(#[case] today: NaiveDate, #[case] input: &str) {
+        let ctx = ParserContext::new().with_today(today);
+
+        assert_eq!(
+            alt((
+                in_duration_with(&ctx),
+                duration_ago_with(&ctx),
+                relative_period_with(&ctx),
+                month_part_with(&ctx),
+                nth_weekday_of_month_with(&ctx),
+                day_of_month_with(&ctx),
+            ))(input),
+            Err(nom::Err::Error(Error::DateOutOfRange))
+        );
+    }
+
+    #[rstest]
+    #[case("in 3 days", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("In a week", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("in 2 weeks", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap())))]
+    #[case("in a year", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 16).unwrap())))]
+    #[case("in 1 month", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 16).unwrap())))]
+    fn test_in_duration_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(in_duration_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("1 day ago", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("4 Months Ago", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 16).unwrap())))]
+    #[case("a year ago", Ok(("", NaiveDate::from_ymd_opt(2023, 7, 16).unwrap())))]
+    #[case("999999999 years ago", Err(nom::Err::Error(Error::DateOutOfRange)))]
+    fn test_duration_ago_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(duration_ago_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("end of the month", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 31).unwrap())))]
+    #[case("The End of This Week", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("the end of year", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())))]
+    #[case("end of the day", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case(
+        "the end",
+        Err(nom::Err::Error(Error::Nom("", nom::error::ErrorKind::Space)))
+    )]
+    fn test_end_of_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(end_of_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("week 29", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("Week 1", Ok(("", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())))]
+    #[case("week 53", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("week 54", Err(nom::Err::Error(Error::WeekOutOfRange)))]
+    fn test_calendar_week_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(calendar_week_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("day 195", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("Day 1 of 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("day 366 of 2020", Ok(("", NaiveDate::from_ymd_opt(2020, 12, 31).unwrap())))]
+    #[case("day 60 of the year", Ok((" of the year", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case("day 366 of 2023", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("day 0", Err(nom::Err::Error(Error::DayOfYearOutOfRange)))]
+    fn test_day_of_year_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(day_of_year_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("end of July", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 31).unwrap())))]
+    #[case("The Beginning of next month", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 1).unwrap())))]
+    #[case("start of last month", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())))]
+    #[case("mid-August", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 15).unwrap())))]
+    #[case("mid Feb 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 2, 15).unwrap())))]
+    #[case("end of February next year", Ok(("", NaiveDate::from_ymd_opt(2025, 2, 28).unwrap())))]
+    #[case("the middle of the month", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("end of February 2023, ok", Ok((", ok", NaiveDate::from_ymd_opt(2023, 2, 28).unwrap())))]
+    #[case(
+        "end of the year",
+        Err(nom::Err::Error(Error::Nom("year", nom::error::ErrorKind::Tag)))
+    )]
+    fn test_month_part_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(month_part_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("first monday of september", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 2).unwrap())))]
+    #[case("The last Friday of 2024-10", Ok(("", NaiveDate::from_ymd_opt(2024, 10, 25).unwrap())))]
+    #[case("2nd tue in next month", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case("third Wednesday of the month", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("4th thursday of November 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 11, 27).unwrap())))]
+    #[case("last sunday of last month", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 30).unwrap())))]
+    #[case(
+        "fifth friday of 2024-10",
+        Err(nom::Err::Error(Error::NonExistentDate))
+    )]
+    #[case(
+        "first monday of 2024-13",
+        Err(nom::Err::Error(Error::MonthOutOfRange {
+            value: 13,
+            input: "13"
+        }))
+    )]
+    #[case(
+        "last friday of 1800-10",
+        Err(nom::Err::Error(Error::YearOutOfRange {
+            value: 1800,
+            input: "1800-10"
+        }))
+    )]
+    #[case(
+        "firstly monday of july",
+        Err(nom::Err::Error(Error::Nom("ly monday of july", nom::error::ErrorKind::Not)))
+    )]
+    fn test_nth_weekday_of_month_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(nth_weekday_of_month_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("13th next month", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case("1st of last month", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())))]
+    #[case("The 20th of this month", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("5th of July next year", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 5).unwrap())))]
+    #[case("31st of September last year", Ok(("", NaiveDate::from_ymd_opt(2023, 9, 30).unwrap())))]
+    #[case("31st of next month, ok", Ok((", ok", NaiveDate::from_ymd_opt(2024, 8, 31).unwrap())))]
+    #[case(
+        "13th of July",
+        Err(nom::Err::Error(Error::Nom("July", nom::error::ErrorKind::Tag)))
+    )]
+    fn test_day_of_month_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(day_of_month_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case(MonthOverflow::Clamp, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())]
+    #[case(MonthOverflow::Roll, NaiveDate::from_ymd_opt(2024, 3, 2).unwrap())]
+    fn test_day_of_month_overflow(
+        #[case] month_overflow: MonthOverflow,
+        #[case] expected: NaiveDate,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())
+            .with_month_overflow(month_overflow);
+
+        assert_eq!(
+            day_of_month_with(&ctx)("31st of next month"),
+            Ok(("", expected))
+        );
+    }
+
+    #[rstest]
+    #[case("next week", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("Last Month", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 16).unwrap())))]
+    #[case("previous year", Ok(("", NaiveDate::from_ymd_opt(2023, 7, 16).unwrap())))]
+    #[case("next day", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case(
+        "next weekend",
+        Err(nom::Err::Error(Error::Nom("end", nom::error::ErrorKind::Not)))
+    )]
+    #[case(
+        "last weekend",
+        Err(nom::Err::Error(Error::Nom("end", nom::error::ErrorKind::Not)))
+    )]
+    fn test_relative_period_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(relative_period_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("next week", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap(), NaiveDate::from_ymd_opt(2024, 7, 28).unwrap())]
+    #[case("last month", NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(), NaiveDate::from_ymd_opt(2024, 6, 30).unwrap())]
+    #[case("next year", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), NaiveDate::from_ymd_opt(2025, 12, 31).unwrap())]
+    fn test_relative_period_range_with(
+        #[case] input: &str,
+        #[case] start: NaiveDate,
+        #[case] end: NaiveDate,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(
+            relative_period_range_with(&ctx)(input),
+            Ok(("", DateRange { start, end }))
+        );
+    }
 }
@@ -0,0 +1,223 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    character::complete::space1,
+    combinator::{map, opt},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+};
+
+use super::relative::modifier;
+use crate::{
+    combinators::{lookup_no_case, tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n::{self, Season, SeasonRef},
+    numeric::y4,
+    range::DateRange,
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` season in `English`.
+///
+/// The following words are accepted:
+/// - `spring` -> [`Season::Spring`]
+/// - `summer` -> [`Season::Summer`]
+/// - `autumn` | `fall` -> [`Season::Autumn`]
+/// - `winter` -> [`Season::Winter`]
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::i18n::{en::named_season, Season};
+///
+/// assert_eq!(named_season("Fall 2024")?, (" 2024", Season::Autumn));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_season(input: &str) -> IResult<&str, Season> {
+    terminated(
+        lookup_no_case(|word| match word {
+            "spring" => Some(Season::Spring),
+            "summer" => Some(Season::Summer),
+            "autumn" | "fall" => Some(Season::Autumn),
+            "winter" => Some(Season::Winter),
+            _ => None,
+        }),
+        word_boundary,
+    )(input)
+}
+
+/// Recognizes the `case insensitive` season phrase in `English` with the
+/// optional `in` or `during` preposition:
+/// - `[the] <season> [of] <year>` -> [`SeasonRef::Named`] with the year
+/// - `this` | `the` | `next` | `last` | `previous` followed by the `<season>`
+///   -> [`SeasonRef::Relative`]
+/// - `<season>` -> [`SeasonRef::Named`] without the year
+fn season_ref(input: &str) -> IResult<&str, SeasonRef> {
+    preceded(
+        opt(pair(
+            alt((tag_no_case("in"), tag_no_case("during"))),
+            space1,
+        )),
+        alt((
+            map(
+                separated_pair(
+                    preceded(opt(pair(tag_no_case("the"), space1)), named_season),
+                    tuple((space1, opt(pair(tag_no_case("of"), space1)))),
+                    y4,
+                ),
+                |(season, year)| SeasonRef::Named(season, Some(year as i32)),
+            ),
+            map(
+                separated_pair(modifier, space1, named_season),
+                |(years, season)| SeasonRef::Relative(season, years),
+            ),
+            map(named_season, |season| SeasonRef::Named(season, None)),
+        )),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` season phrase in `English` (e.g. `this
+/// winter`, `next spring`, `in the summer of 2025` or `fall`) and returns the
+/// first day of the season, see [`season`](i18n::season). The current season
+/// is the one containing the reference date or the one starting in its year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::en::season;
+///
+/// assert_eq!(
+///     season("summer 2025")?.1,
+///     NaiveDate::from_ymd_opt(2025, 6, 1).unwrap()
+/// );
+/// assert_eq!(
+///     season("next spring")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year() + 1, 3, 1).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn season(input: &str) -> IResult<&str, NaiveDate> {
+    season_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`season`] parser which uses the reference date and the
+/// hemisphere of the specified [`ParserContext`].
+pub fn season_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::season(ctx, season_ref)(input)
+}
+
+/// Recognizes the same phrases as the [`season`] parser, but returns the whole
+/// season as the [`DateRange`], see [`season_range`](i18n::season_range).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::en::season_range;
+///
+/// let range = season_range("winter 2024")?.1;
+///
+/// assert_eq!(range.start, NaiveDate::from_ymd_opt(2024, 12, 1).unwrap());
+/// assert_eq!(range.end, NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn season_range(input: &str) -> IResult<&str, DateRange> {
+    season_range_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`season_range`] parser which uses the reference date and the
+/// hemisphere of the specified [`ParserContext`].
+pub fn season_range_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, DateRange> + '_ {
+    move |input: &str| i18n::season_range(ctx, season_ref)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{calendar::Hemisphere, combinators::assert_lookup_words, error::Error};
+
+    fn range(start: (i32, u32, u32), end: (i32, u32, u32)) -> DateRange {
+        DateRange {
+            start: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            end: NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap(),
+        }
+    }
+
+    #[rstest]
+    #[case("Spring", Ok(("", Season::Spring)))]
+    #[case("autumn", Ok(("", Season::Autumn)))]
+    #[case("fall, 2024", Ok((", 2024", Season::Autumn)))]
+    #[case(
+        "falls",
+        Err(nom::Err::Error(Error::Nom("s", nom::error::ErrorKind::Not)))
+    )]
+    fn test_named_season(#[case] input: &str, #[case] expected: IResult<&str, Season>) {
+        assert_eq!(named_season(input), expected);
+    }
+
+    #[rstest]
+    #[case("summer", (2024, 1, 16), Ok(("", range((2024, 6, 1), (2024, 8, 31)))))]
+    #[case("this winter", (2024, 1, 16), Ok(("", range((2023, 12, 1), (2024, 2, 29)))))]
+    #[case("this winter", (2024, 7, 16), Ok(("", range((2024, 12, 1), (2025, 2, 28)))))]
+    #[case("the winter", (2024, 12, 16), Ok(("", range((2024, 12, 1), (2025, 2, 28)))))]
+    #[case("next spring", (2024, 7, 16), Ok(("", range((2025, 3, 1), (2025, 5, 31)))))]
+    #[case("last fall", (2024, 7, 16), Ok(("", range((2023, 9, 1), (2023, 11, 30)))))]
+    #[case("previous winter", (2024, 1, 16), Ok(("", range((2022, 12, 1), (2023, 2, 28)))))]
+    #[case("in Summer 2025", (2024, 7, 16), Ok(("", range((2025, 6, 1), (2025, 8, 31)))))]
+    #[case("during the summer of 2023", (2024, 7, 16), Ok(("", range((2023, 6, 1), (2023, 8, 31)))))]
+    #[case("in the summer", (2024, 7, 16), Ok(("", range((2024, 6, 1), (2024, 8, 31)))))]
+    #[case("next summer, ok", (2024, 7, 16), Ok((", ok", range((2025, 6, 1), (2025, 8, 31)))))]
+    #[case("next year", (2024, 7, 16), Err(nom::Err::Error(Error::Nom("next year", nom::error::ErrorKind::Tag))))]
+    fn test_season_range_with(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: IResult<&str, DateRange>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap());
+
+        assert_eq!(season_range_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("summer", (2024, 7, 16), NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())]
+    #[case("this summer", (2024, 1, 16), NaiveDate::from_ymd_opt(2023, 12, 1).unwrap())]
+    #[case("winter 2024", (2024, 7, 16), NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())]
+    fn test_season_with_southern_hemisphere(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: NaiveDate,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap())
+            .with_hemisphere(Hemisphere::Southern);
+
+        assert_eq!(season_with(&ctx)(input), Ok(("", expected)));
+    }
+
+    #[test]
+    fn test_date_out_of_range() {
+        let ctx = ParserContext::new().with_today(NaiveDate::MAX);
+
+        assert_eq!(
+            season_with(&ctx)("next winter"),
+            Err(nom::Err::Error(Error::DateOutOfRange))
+        );
+    }
+
+    #[test]
+    fn test_lookup_words() {
+        assert_lookup_words(
+            named_season,
+            &["spring", "summer", "autumn", "fall", "winter"],
+        );
+    }
+}
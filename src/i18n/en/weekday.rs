@@ -1,12 +1,18 @@
 use chrono::{NaiveDate, Weekday};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, tag_no_case},
-    combinator::{map_res, value},
+    bytes::complete::tag,
+    combinator::{opt, value},
     sequence::terminated,
 };
 
-use crate::{i18n::naive_date_for_weekday, types::IResult};
+use crate::{
+    combinators::{lookup_no_case, lookup_prefix_no_case, tag_no_case, word_boundary},
+    context::ParserContext,
+    error::Error,
+    i18n::{naive_date_for_weekday_at, relative_weekday},
+    types::IResult,
+};
 
 /// Recognizes the `case insensitive` short-named weekday in `English`.
 ///
@@ -19,6 +25,9 @@ use crate::{i18n::naive_date_for_weekday, types::IResult};
 /// - `sat` -> [`Weekday::Sat`]
 /// - `sun` -> [`Weekday::Sun`]
 ///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
 /// # Examples
 ///
 /// ```
@@ -29,18 +38,21 @@ use crate::{i18n::naive_date_for_weekday, types::IResult};
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
-    alt((
-        value(Weekday::Mon, tag_no_case("mon")),
-        value(Weekday::Tue, tag_no_case("tue")),
-        value(Weekday::Tue, tag_no_case("tues")),
-        value(Weekday::Wed, tag_no_case("wed")),
-        value(Weekday::Thu, tag_no_case("thu")),
-        value(Weekday::Thu, tag_no_case("thur")),
-        value(Weekday::Thu, tag_no_case("thurs")),
-        value(Weekday::Fri, tag_no_case("fri")),
-        value(Weekday::Sat, tag_no_case("sat")),
-        value(Weekday::Sun, tag_no_case("sun")),
-    ))(input)
+    lookup_no_case(short_weekday)(input)
+}
+
+/// Returns the weekday of the lowercase short weekday name.
+fn short_weekday(word: &str) -> Option<Weekday> {
+    Some(match word {
+        "mon" => Weekday::Mon,
+        "tue" | "tues" => Weekday::Tue,
+        "wed" => Weekday::Wed,
+        "thu" | "thur" | "thurs" => Weekday::Thu,
+        "fri" => Weekday::Fri,
+        "sat" => Weekday::Sat,
+        "sun" => Weekday::Sun,
+        _ => return None,
+    })
 }
 
 /// Recognizes the `case insensitive` short-named weekday in `English` which
@@ -60,6 +72,9 @@ pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
 /// - `saturday` -> [`Weekday::Sat`]
 /// - `sunday` -> [`Weekday::Sun`]
 ///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
 /// # Examples
 ///
 /// ```
@@ -70,20 +85,30 @@ pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
-    alt((
-        value(Weekday::Mon, tag_no_case("monday")),
-        value(Weekday::Tue, tag_no_case("tuesday")),
-        value(Weekday::Wed, tag_no_case("wednesday")),
-        value(Weekday::Thu, tag_no_case("thursday")),
-        value(Weekday::Fri, tag_no_case("friday")),
-        value(Weekday::Sat, tag_no_case("saturday")),
-        value(Weekday::Sun, tag_no_case("sunday")),
-    ))(input)
+    lookup_no_case(full_weekday)(input)
+}
+
+/// Returns the weekday of the lowercase full weekday name.
+fn full_weekday(word: &str) -> Option<Weekday> {
+    Some(match word {
+        "monday" => Weekday::Mon,
+        "tuesday" => Weekday::Tue,
+        "wednesday" => Weekday::Wed,
+        "thursday" => Weekday::Thu,
+        "friday" => Weekday::Fri,
+        "saturday" => Weekday::Sat,
+        "sunday" => Weekday::Sun,
+        _ => return None,
+    })
 }
 
 /// Recognizes either the `case insensitive` short-named or full-named weekday
-/// in `English`. Uses the [`short_named_weekday`] and [`full_named_weekday`]
-/// parsers.
+/// in `English`, the words of the [`full_named_weekday`] and
+/// [`short_named_weekday`] parsers are looked up at once. The short name may be
+/// followed by the `dot` symbol (see [`short_named_weekday_dot`]).
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
 ///
 /// # Examples
 ///
@@ -96,13 +121,74 @@ pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    let (input, (weekday, short)) = lookup_no_case(|word| {
+        full_weekday(word)
+            .map(|weekday| (weekday, false))
+            .or_else(|| short_weekday(word).map(|weekday| (weekday, true)))
+    })(input)?;
+
+    if short {
+        value(weekday, opt(tag(".")))(input)
+    } else {
+        Ok((input, weekday))
+    }
+}
+
+/// The words abbreviated by the [`named_weekday_prefix`] parser.
+const WEEKDAY_WORDS: [(&str, Weekday); 8] = [
+    ("monday", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("weds", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+];
+
+/// Recognizes the `case insensitive` weekday in `English` like the
+/// [`named_weekday`] parser, but also accepts any unambiguous prefix of at
+/// least two letters of the full name (or of the `weds` abbreviation)
+/// followed by the optional `dot` symbol, e.g. `tu`, `thurs.` or `weds`, see
+/// [`lookup_prefix_no_case`].
+///
+/// Used by the weekday parsers of the [`ParserContext`] with the
+/// [`ParserContext::with_weekday_prefixes`] enabled.
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::en::named_weekday_prefix;
+///
+/// assert_eq!(named_weekday_prefix("Tu")?.1, Weekday::Tue);
+/// assert_eq!(named_weekday_prefix("weds")?.1, Weekday::Wed);
+/// assert_eq!(named_weekday_prefix("thurs.")?, ("", Weekday::Thu));
+/// assert!(named_weekday_prefix("s").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday_prefix(input: &str) -> IResult<&str, Weekday> {
     alt((
-        full_named_weekday,
-        short_named_weekday_dot,
-        short_named_weekday,
+        terminated(full_named_weekday, word_boundary),
+        terminated(lookup_prefix_no_case(&WEEKDAY_WORDS, 2), opt(tag("."))),
+        named_weekday,
     ))(input)
 }
 
+/// Returns the weekday parser of the [`ParserContext`]: the
+/// [`named_weekday_prefix`] if the [`ParserContext::with_weekday_prefixes`] is
+/// enabled, otherwise the [`named_weekday`].
+pub(super) fn context_named_weekday(ctx: &ParserContext) -> fn(&str) -> IResult<&str, Weekday> {
+    if ctx.weekday_prefixes() {
+        named_weekday_prefix
+    } else {
+        named_weekday
+    }
+}
+
 /// Recognizes the `case insensitive` weekday in `English` using the
 /// [`named_weekday`] function and returns the corresponding [`NaiveDate`]
 /// for the current week.
@@ -115,28 +201,141 @@ pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
 ///
 /// assert_eq!(
 ///     current_named_weekday_only("Wednesday")?.1,
-///     naive_date_for_weekday(Weekday::Wed)
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
-    map_res(named_weekday, |weekday: Weekday| {
-        Ok(naive_date_for_weekday(weekday))
-    })(input)
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = context_named_weekday(ctx)(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Recognizes the `case insensitive` word `last` followed by the weekday in
+/// `English` (e.g. `last friday`) using the [`named_weekday`] parser and
+/// returns the corresponding [`NaiveDate`] for the previous week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Days, NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{en::last_named_weekday, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     last_named_weekday("Last friday")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap() - Days::new(7)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn last_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    last_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`last_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn last_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(ctx, -1, tag_no_case("last"), context_named_weekday(ctx))(input)
+    }
+}
+
+/// Recognizes the `case insensitive` word `this` followed by the weekday in
+/// `English` (e.g. `this friday`) using the [`named_weekday`] parser and
+/// returns the corresponding [`NaiveDate`] for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Days, NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{en::this_named_weekday, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     this_named_weekday("This friday")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn this_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    this_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`this_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn this_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(ctx, 0, tag_no_case("this"), context_named_weekday(ctx))(input)
+    }
+}
+
+/// Recognizes the `case insensitive` word `next` followed by the weekday in
+/// `English` (e.g. `next friday`) using the [`named_weekday`] parser and
+/// returns the corresponding [`NaiveDate`] for the next week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Days, NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{en::next_named_weekday, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     next_named_weekday("Next friday")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap() + Days::new(7)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn next_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    next_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`next_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn next_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(ctx, 1, tag_no_case("next"), context_named_weekday(ctx))(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use chrono::Weekday;
+    use nom::error::ErrorKind;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::{combinators::assert_lookup_words, i18n::naive_date_for_weekday};
+
     use super::*;
 
     #[rstest]
     #[case("mon", Ok(("", Weekday::Mon)))]
     #[case("tue", Ok(("", Weekday::Tue)))]
     #[case("Wed", Ok(("", Weekday::Wed)))]
+    #[case("Tues", Ok(("", Weekday::Tue)))]
+    #[case("thurs.", Ok((".", Weekday::Thu)))]
     fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
         assert_eq!(short_named_weekday(input), expected);
     }
@@ -160,17 +359,135 @@ mod tests {
     #[rstest]
     #[case("mon", Ok(("", Weekday::Mon)))]
     #[case("Tuesday", Ok(("", Weekday::Tue)))]
+    #[case("Thurs. 13", Ok((" 13", Weekday::Thu)))]
+    #[case("SUNDAY.", Ok((".", Weekday::Sun)))]
+    #[case(
+        "13 friday",
+        Err(nom::Err::Error(Error::Nom("13 friday", ErrorKind::Tag)))
+    )]
     fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
         assert_eq!(named_weekday(input), expected)
     }
 
     #[rstest]
-    #[case("mon", Ok(("", naive_date_for_weekday(Weekday::Mon))))]
-    #[case("Tuesday", Ok(("", naive_date_for_weekday(Weekday::Tue))))]
+    #[case("tu", Ok(("", Weekday::Tue)))]
+    #[case("Thurs.", Ok(("", Weekday::Thu)))]
+    #[case("weds 13", Ok((" 13", Weekday::Wed)))]
+    #[case("Wednes", Ok(("", Weekday::Wed)))]
+    #[case("sa", Ok(("", Weekday::Sat)))]
+    #[case("friday.", Ok((".", Weekday::Fri)))]
+    #[case("fridays", Ok(("s", Weekday::Fri)))]
+    #[case("t", Err(nom::Err::Error(Error::Nom("t", ErrorKind::Tag))))]
+    #[case("s", Err(nom::Err::Error(Error::Nom("s", ErrorKind::Tag))))]
+    #[case("week", Err(nom::Err::Error(Error::Nom("week", ErrorKind::Tag))))]
+    fn test_named_weekday_prefix(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday_prefix(input), expected)
+    }
+
+    #[rstest]
+    #[case("mon", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Tuesday", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
     fn test_current_named_weekday_only(
         #[case] input: &str,
         #[case] expected: IResult<&str, NaiveDate>,
     ) {
         assert_eq!(current_named_weekday_only(input), expected)
     }
+
+    #[rstest]
+    #[case("last friday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 12).unwrap())))]
+    #[case("Last   Mon", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 8).unwrap())))]
+    #[case(
+        "lastfriday",
+        Err(nom::Err::Error(Error::Nom("friday", ErrorKind::Space)))
+    )]
+    fn test_last_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(last_named_weekday_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("this monday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("This Sat", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    fn test_this_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(this_named_weekday_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("next friday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap())))]
+    #[case("Next Sunday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 28).unwrap())))]
+    #[case("next tue.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    fn test_next_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(next_named_weekday_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("next tu", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("last thurs.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 11).unwrap())))]
+    #[case("this weds", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("sund", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    fn test_named_weekday_with_prefixes(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .with_weekday_prefixes(true);
+        let parser = |input| {
+            alt((
+                last_named_weekday_with(&ctx),
+                this_named_weekday_with(&ctx),
+                next_named_weekday_with(&ctx),
+                current_named_weekday_only_with(&ctx),
+            ))(input)
+        };
+
+        assert_eq!(parser(input), expected)
+    }
+
+    #[test]
+    fn test_next_named_weekday_out_of_range() {
+        let ctx = ParserContext::new().with_today(NaiveDate::MAX);
+
+        assert_eq!(
+            next_named_weekday_with(&ctx)("next monday"),
+            Err(nom::Err::Error(Error::DateOutOfRange))
+        )
+    }
+
+    #[test]
+    fn test_lookup_words() {
+        assert_lookup_words(
+            short_named_weekday,
+            &[
+                "mon", "tue", "tues", "wed", "thu", "thur", "thurs", "fri", "sat", "sun",
+            ],
+        );
+        assert_lookup_words(
+            full_named_weekday,
+            &[
+                "monday",
+                "tuesday",
+                "wednesday",
+                "thursday",
+                "friday",
+                "saturday",
+                "sunday",
+            ],
+        );
+    }
 }
@@ -0,0 +1,151 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    character::complete::space1,
+    combinator::{opt, value},
+    sequence::{pair, preceded, terminated},
+};
+
+use super::relative::modifier;
+use crate::{
+    combinators::{tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n,
+    range::DateRange,
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` word `weekend` or `week-end` in `English`.
+fn weekend_word(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        terminated(
+            alt((tag_no_case("weekend"), tag_no_case("week-end"))),
+            word_boundary,
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` weekend phrase in `English` with the
+/// optional `on`, `over`, `at` or `during` preposition: the [`weekend_word`]
+/// optionally preceded by one of the `this`, `the`, `next`, `last` and
+/// `previous` modifiers. Returns the number of weeks from the reference date.
+fn weekend_ref(input: &str) -> IResult<&str, i64> {
+    preceded(
+        opt(pair(
+            alt((
+                tag_no_case("on"),
+                tag_no_case("over"),
+                tag_no_case("at"),
+                tag_no_case("during"),
+            )),
+            space1,
+        )),
+        alt((
+            terminated(modifier, pair(space1, weekend_word)),
+            value(0, weekend_word),
+        )),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` weekend phrase in `English` (e.g. `this
+/// weekend`, `next weekend`, `on the weekend` or `over last weekend`) and
+/// returns the Saturday of the weekend, see [`weekend`](i18n::weekend). The
+/// weekend of the Sunday is the one which began the day before.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Weekday};
+/// use nom_date_parsers::i18n::en::weekend;
+///
+/// assert_eq!(weekend("next weekend")?.1.weekday(), Weekday::Sat);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn weekend(input: &str) -> IResult<&str, NaiveDate> {
+    weekend_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`weekend`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn weekend_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::weekend(ctx, weekend_ref)(input)
+}
+
+/// Recognizes the same phrases as the [`weekend`] parser, but returns the
+/// Saturday and the Sunday as the [`DateRange`], see
+/// [`weekend_range`](i18n::weekend_range).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Weekday};
+/// use nom_date_parsers::i18n::en::weekend_range;
+///
+/// let range = weekend_range("this weekend")?.1;
+///
+/// assert_eq!(range.start.weekday(), Weekday::Sat);
+/// assert_eq!(range.end.weekday(), Weekday::Sun);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn weekend_range(input: &str) -> IResult<&str, DateRange> {
+    weekend_range_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`weekend_range`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn weekend_range_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, DateRange> + '_ {
+    move |input: &str| i18n::weekend_range(ctx, weekend_ref)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::error::Error;
+
+    fn range(start: (i32, u32, u32), end: (i32, u32, u32)) -> DateRange {
+        DateRange {
+            start: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            end: NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap(),
+        }
+    }
+
+    #[rstest]
+    #[case("weekend", (2024, 7, 16), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("This Weekend", (2024, 7, 16), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("this weekend", (2024, 7, 21), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("next weekend", (2024, 7, 16), Ok(("", range((2024, 7, 27), (2024, 7, 28)))))]
+    #[case("next week-end", (2024, 7, 20), Ok(("", range((2024, 7, 27), (2024, 7, 28)))))]
+    #[case("last weekend", (2024, 7, 16), Ok(("", range((2024, 7, 13), (2024, 7, 14)))))]
+    #[case("on the weekend", (2024, 7, 16), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("over previous weekend", (2024, 7, 16), Ok(("", range((2024, 7, 13), (2024, 7, 14)))))]
+    #[case("at the weekend, ok", (2024, 7, 16), Ok((", ok", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("weekends", (2024, 7, 16), Err(nom::Err::Error(Error::Nom("s", ErrorKind::Not))))]
+    #[case("next week", (2024, 7, 16), Err(nom::Err::Error(Error::Nom("next week", ErrorKind::Tag))))]
+    fn test_weekend_range_with(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: IResult<&str, DateRange>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap());
+
+        assert_eq!(weekend_range_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_weekend_with() {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(
+            weekend_with(&ctx)("next weekend"),
+            Ok(("", NaiveDate::from_ymd_opt(2024, 7, 27).unwrap()))
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use chrono::{Month, NaiveDate};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{opt, value},
+    sequence::{delimited, pair, terminated},
+};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `Spanish`.
+///
+/// The following words are accepted:
+/// - `ene` -> [`Month::January`]
+/// - `feb` -> [`Month::February`]
+/// - `mar` -> [`Month::March`]
+/// - `abr` -> [`Month::April`]
+/// - `may` -> [`Month::May`]
+/// - `jun` -> [`Month::June`]
+/// - `jul` -> [`Month::July`]
+/// - `ago` -> [`Month::August`]
+/// - `sept` | `sep` | `set` -> [`Month::September`]
+/// - `oct` -> [`Month::October`]
+/// - `nov` -> [`Month::November`]
+/// - `dic` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::es::short_named_month;
+///
+/// assert_eq!(short_named_month("jul")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("ene")),
+        value(Month::February, tag_no_case("feb")),
+        value(Month::March, tag_no_case("mar")),
+        value(Month::April, tag_no_case("abr")),
+        value(Month::May, tag_no_case("may")),
+        value(Month::June, tag_no_case("jun")),
+        value(Month::July, tag_no_case("jul")),
+        value(Month::August, tag_no_case("ago")),
+        value(Month::September, tag_no_case("sept")),
+        value(Month::September, tag_no_case("sep")),
+        value(Month::September, tag_no_case("set")),
+        value(Month::October, tag_no_case("oct")),
+        value(Month::November, tag_no_case("nov")),
+        value(Month::December, tag_no_case("dic")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `Spanish` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `Spanish`.
+///
+/// The following words are accepted:
+/// - `enero` -> [`Month::January`]
+/// - `febrero` -> [`Month::February`]
+/// - `marzo` -> [`Month::March`]
+/// - `abril` -> [`Month::April`]
+/// - `mayo` -> [`Month::May`]
+/// - `junio` -> [`Month::June`]
+/// - `julio` -> [`Month::July`]
+/// - `agosto` -> [`Month::August`]
+/// - `septiembre` | `setiembre` -> [`Month::September`]
+/// - `octubre` -> [`Month::October`]
+/// - `noviembre` -> [`Month::November`]
+/// - `diciembre` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::es::full_named_month;
+///
+/// assert_eq!(full_named_month("julio")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("enero")),
+        value(Month::February, tag_no_case("febrero")),
+        value(Month::March, tag_no_case("marzo")),
+        value(Month::April, tag_no_case("abril")),
+        value(Month::May, tag_no_case("mayo")),
+        value(Month::June, tag_no_case("junio")),
+        value(Month::July, tag_no_case("julio")),
+        value(Month::August, tag_no_case("agosto")),
+        value(Month::September, tag_no_case("septiembre")),
+        value(Month::September, tag_no_case("setiembre")),
+        value(Month::October, tag_no_case("octubre")),
+        value(Month::November, tag_no_case("noviembre")),
+        value(Month::December, tag_no_case("diciembre")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `Spanish`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::es::named_month;
+///
+/// assert_eq!(named_month("jul.")?.1, Month::July);
+/// assert_eq!(named_month("julio")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the named month optionally preceded and followed by the `case
+/// insensitive` preposition `de` (e.g. `de julio de`) using the [`named_month`]
+/// parser.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::es::prepositional_named_month;
+///
+/// assert_eq!(prepositional_named_month("de julio")?.1, Month::July);
+/// assert_eq!(
+///     prepositional_named_month("julio de 2024")?,
+///     (" 2024", Month::July)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn prepositional_named_month(input: &str) -> IResult<&str, Month> {
+    delimited(
+        opt(pair(tag_no_case("de"), space1)),
+        named_month,
+        opt(pair(space1, tag_no_case("de"))),
+    )(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `Spanish` using the
+/// [`dd_named_month_y4`] parser with the [`prepositional_named_month`] parser
+/// and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::es::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 de julio de 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(prepositional_named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `Spanish` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`prepositional_named_month`] parser and returns the corresponding
+/// [`NaiveDate`] for the current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::es::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 de julio")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, prepositional_named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("ene", Ok(("", Month::January)))]
+    #[case("SEPT", Ok(("", Month::September)))]
+    #[case("set", Ok(("", Month::September)))]
+    #[case("Dic", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("ene.", Ok(("", Month::January)))]
+    #[case("Oct.", Ok(("", Month::October)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("enero", Ok(("", Month::January)))]
+    #[case("Marzo", Ok(("", Month::March)))]
+    #[case("SETIEMBRE", Ok(("", Month::September)))]
+    #[case("diciembre", Ok(("", Month::December)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("may", Ok(("", Month::May)))]
+    #[case("mayo", Ok(("", Month::May)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("julio", Ok(("", Month::July)))]
+    #[case("de julio", Ok(("", Month::July)))]
+    #[case("de julio de 2024", Ok((" 2024", Month::July)))]
+    #[case("julio de 2024", Ok((" 2024", Month::July)))]
+    fn test_prepositional_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(prepositional_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 de julio de 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 julio 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1 ENE. 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 de febrero de 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 de julio", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 de julio", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 de julio" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
@@ -0,0 +1,200 @@
+use chrono::NaiveDate;
+use nom::{branch::alt, character::complete::space1, combinator::value, sequence::tuple};
+
+use crate::{combinators::tag_no_case, context::ParserContext, i18n::relative_day, types::IResult};
+
+/// Recognizes the `case insensitive` words `anteayer` or `antier` in `Spanish`
+/// and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::es::day_before_yesterday;
+///
+/// assert_eq!(
+///     day_before_yesterday("anteayer")?.1,
+///     Local::now().sub(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    day_before_yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_before_yesterday`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_before_yesterday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_day(
+            ctx,
+            -2,
+            alt((tag_no_case("anteayer"), tag_no_case("antier"))),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` words `mañana` or `manana`.
+fn manana(input: &str) -> IResult<&str, &str> {
+    alt((tag_no_case("mañana"), tag_no_case("manana")))(input)
+}
+
+/// Recognizes the `case insensitive` word `ayer` in `Spanish` and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::es::yesterday;
+///
+/// assert_eq!(
+///     yesterday("ayer")?.1,
+///     Local::now().sub(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -1, tag_no_case("ayer"))(input)
+}
+
+/// Recognizes the `case insensitive` word `hoy` in `Spanish` and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use nom_date_parsers::i18n::es::today;
+///
+/// assert_eq!(today("hoy")?.1, Local::now().date_naive());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn today(input: &str) -> IResult<&str, NaiveDate> {
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| value(ctx.today(), tag_no_case("hoy"))(input)
+}
+
+/// Recognizes the `case insensitive` words `mañana` or `manana` in `Spanish`
+/// and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::es::tomorrow;
+///
+/// assert_eq!(
+///     tomorrow("mañana")?.1,
+///     Local::now().add(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 1, manana)(input)
+}
+
+/// Recognizes the `case insensitive` words `pasado mañana` (or `pasado
+/// manana`) in `Spanish` and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::es::day_after_tomorrow;
+///
+/// assert_eq!(
+///     day_after_tomorrow("pasado mañana")?.1,
+///     Local::now().add(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_after_tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    day_after_tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_after_tomorrow`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_after_tomorrow_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 2, tuple((tag_no_case("pasado"), space1, manana)))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Anteayer", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("antier", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    fn test_day_before_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_before_yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("Ayer", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    fn test_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("hoy", Ok(("", Local::now().date_naive())))]
+    #[case("HOY", Ok(("", Local::now().date_naive())))]
+    fn test_today(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(today(input), expected);
+    }
+
+    #[rstest]
+    #[case("Mañana", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("MAÑANA", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("manana", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    fn test_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(tomorrow(input), expected);
+    }
+
+    #[rstest]
+    #[case("pasado mañana", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    #[case("Pasado  Manana", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_day_after_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_after_tomorrow(input), expected);
+    }
+}
@@ -0,0 +1,196 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Spanish`.
+///
+/// The following words are accepted:
+/// - `lun` -> [`Weekday::Mon`]
+/// - `mar` -> [`Weekday::Tue`]
+/// - `mié` | `mie` -> [`Weekday::Wed`]
+/// - `jue` -> [`Weekday::Thu`]
+/// - `vie` -> [`Weekday::Fri`]
+/// - `sáb` | `sab` -> [`Weekday::Sat`]
+/// - `dom` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::es::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("vie")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("lun")),
+        value(Weekday::Tue, tag_no_case("mar")),
+        value(Weekday::Wed, tag_no_case("mié")),
+        value(Weekday::Wed, tag_no_case("mie")),
+        value(Weekday::Thu, tag_no_case("jue")),
+        value(Weekday::Fri, tag_no_case("vie")),
+        value(Weekday::Sat, tag_no_case("sáb")),
+        value(Weekday::Sat, tag_no_case("sab")),
+        value(Weekday::Sun, tag_no_case("dom")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Spanish` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Spanish`.
+///
+/// The following words are accepted:
+/// - `lunes` -> [`Weekday::Mon`]
+/// - `martes` -> [`Weekday::Tue`]
+/// - `miércoles` | `miercoles` -> [`Weekday::Wed`]
+/// - `jueves` -> [`Weekday::Thu`]
+/// - `viernes` -> [`Weekday::Fri`]
+/// - `sábado` | `sabado` -> [`Weekday::Sat`]
+/// - `domingo` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::es::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("miércoles")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("lunes")),
+        value(Weekday::Tue, tag_no_case("martes")),
+        value(Weekday::Wed, tag_no_case("miércoles")),
+        value(Weekday::Wed, tag_no_case("miercoles")),
+        value(Weekday::Thu, tag_no_case("jueves")),
+        value(Weekday::Fri, tag_no_case("viernes")),
+        value(Weekday::Sat, tag_no_case("sábado")),
+        value(Weekday::Sat, tag_no_case("sabado")),
+        value(Weekday::Sun, tag_no_case("domingo")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Spanish`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::es::named_weekday;
+///
+/// assert_eq!(named_weekday("vie.")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Viernes")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Spanish` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{es::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("miércoles")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("lun", Ok(("", Weekday::Mon)))]
+    #[case("MIÉ", Ok(("", Weekday::Wed)))]
+    #[case("mie", Ok(("", Weekday::Wed)))]
+    #[case("Sáb", Ok(("", Weekday::Sat)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("lun.", Ok(("", Weekday::Mon)))]
+    #[case("DOM.", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("lunes", Ok(("", Weekday::Mon)))]
+    #[case("Martes", Ok(("", Weekday::Tue)))]
+    #[case("MIÉRCOLES", Ok(("", Weekday::Wed)))]
+    #[case("miercoles", Ok(("", Weekday::Wed)))]
+    #[case("Sábado", Ok(("", Weekday::Sat)))]
+    #[case("sabado", Ok(("", Weekday::Sat)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("dom", Ok(("", Weekday::Sun)))]
+    #[case("Domingo", Ok(("", Weekday::Sun)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("lun", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("martes", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
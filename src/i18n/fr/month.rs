@@ -0,0 +1,238 @@
+use chrono::{Month, NaiveDate};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `French`.
+///
+/// The following words are accepted:
+/// - `janv` | `jan` -> [`Month::January`]
+/// - `févr` | `fevr` | `fév` | `fev` -> [`Month::February`]
+/// - `mar` -> [`Month::March`]
+/// - `avr` -> [`Month::April`]
+/// - `mai` -> [`Month::May`]
+/// - `juin` -> [`Month::June`]
+/// - `juil` -> [`Month::July`]
+/// - `août` | `aout` -> [`Month::August`]
+/// - `sept` | `sep` -> [`Month::September`]
+/// - `oct` -> [`Month::October`]
+/// - `nov` -> [`Month::November`]
+/// - `déc` | `dec` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::fr::short_named_month;
+///
+/// assert_eq!(short_named_month("juil")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("janv")),
+        value(Month::January, tag_no_case("jan")),
+        value(Month::February, tag_no_case("févr")),
+        value(Month::February, tag_no_case("fevr")),
+        value(Month::February, tag_no_case("fév")),
+        value(Month::February, tag_no_case("fev")),
+        value(Month::March, tag_no_case("mar")),
+        value(Month::April, tag_no_case("avr")),
+        value(Month::May, tag_no_case("mai")),
+        value(Month::June, tag_no_case("juin")),
+        value(Month::July, tag_no_case("juil")),
+        value(Month::August, tag_no_case("août")),
+        value(Month::August, tag_no_case("aout")),
+        value(Month::September, tag_no_case("sept")),
+        value(Month::September, tag_no_case("sep")),
+        value(Month::October, tag_no_case("oct")),
+        value(Month::November, tag_no_case("nov")),
+        value(Month::December, tag_no_case("déc")),
+        value(Month::December, tag_no_case("dec")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `French` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `French`.
+///
+/// The following words are accepted:
+/// - `janvier` -> [`Month::January`]
+/// - `février` | `fevrier` -> [`Month::February`]
+/// - `mars` -> [`Month::March`]
+/// - `avril` -> [`Month::April`]
+/// - `mai` -> [`Month::May`]
+/// - `juin` -> [`Month::June`]
+/// - `juillet` -> [`Month::July`]
+/// - `août` | `aout` -> [`Month::August`]
+/// - `septembre` -> [`Month::September`]
+/// - `octobre` -> [`Month::October`]
+/// - `novembre` -> [`Month::November`]
+/// - `décembre` | `decembre` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::fr::full_named_month;
+///
+/// assert_eq!(full_named_month("juillet")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("janvier")),
+        value(Month::February, tag_no_case("février")),
+        value(Month::February, tag_no_case("fevrier")),
+        value(Month::March, tag_no_case("mars")),
+        value(Month::April, tag_no_case("avril")),
+        value(Month::May, tag_no_case("mai")),
+        value(Month::June, tag_no_case("juin")),
+        value(Month::July, tag_no_case("juillet")),
+        value(Month::August, tag_no_case("août")),
+        value(Month::August, tag_no_case("aout")),
+        value(Month::September, tag_no_case("septembre")),
+        value(Month::October, tag_no_case("octobre")),
+        value(Month::November, tag_no_case("novembre")),
+        value(Month::December, tag_no_case("décembre")),
+        value(Month::December, tag_no_case("decembre")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `French`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::fr::named_month;
+///
+/// assert_eq!(named_month("juil.")?.1, Month::July);
+/// assert_eq!(named_month("juillet")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `French` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::fr::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 juillet 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `French` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::fr::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 juillet")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("janv", Ok(("", Month::January)))]
+    #[case("Jan", Ok(("", Month::January)))]
+    #[case("FÉVR", Ok(("", Month::February)))]
+    #[case("fev", Ok(("", Month::February)))]
+    #[case("Déc", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("janv.", Ok(("", Month::January)))]
+    #[case("Oct.", Ok(("", Month::October)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("janvier", Ok(("", Month::January)))]
+    #[case("Février", Ok(("", Month::February)))]
+    #[case("AOÛT", Ok(("", Month::August)))]
+    #[case("aout", Ok(("", Month::August)))]
+    #[case("decembre", Ok(("", Month::December)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("mar", Ok(("", Month::March)))]
+    #[case("mars", Ok(("", Month::March)))]
+    #[case("juin", Ok(("", Month::June)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 juillet 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1 janv. 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 février 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 juillet", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 juillet", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 juillet" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
@@ -0,0 +1,225 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{one_of, space1},
+    combinator::value,
+    sequence::tuple,
+};
+
+use crate::{combinators::tag_no_case, context::ParserContext, i18n::relative_day, types::IResult};
+
+/// Recognizes the `hyphen` symbol or at least one space between the parts of
+/// the compound words (e.g. `avant-hier` or `avant hier`).
+fn hyphen(input: &str) -> IResult<&str, ()> {
+    value((), alt((tag("-"), space1)))(input)
+}
+
+/// Recognizes the `case insensitive` words `avant-hier` or `avant hier` in
+/// `French` and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::fr::day_before_yesterday;
+///
+/// assert_eq!(
+///     day_before_yesterday("avant-hier")?.1,
+///     Local::now().sub(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    day_before_yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_before_yesterday`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_before_yesterday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_day(
+            ctx,
+            -2,
+            tuple((tag_no_case("avant"), hyphen, tag_no_case("hier"))),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` word `hier` in `French` and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::fr::yesterday;
+///
+/// assert_eq!(
+///     yesterday("hier")?.1,
+///     Local::now().sub(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -1, tag_no_case("hier"))(input)
+}
+
+/// Recognizes the `case insensitive` word `aujourd'hui` in `French` with
+/// either the straight (`'`) or the typographic (`’`) apostrophe and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use nom_date_parsers::i18n::fr::today;
+///
+/// assert_eq!(today("aujourd'hui")?.1, Local::now().date_naive());
+/// assert_eq!(today("aujourd’hui")?.1, Local::now().date_naive());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn today(input: &str) -> IResult<&str, NaiveDate> {
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        value(
+            ctx.today(),
+            tuple((tag_no_case("aujourd"), one_of("'’"), tag_no_case("hui"))),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` word `demain` in `French` and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::fr::tomorrow;
+///
+/// assert_eq!(
+///     tomorrow("demain")?.1,
+///     Local::now().add(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 1, tag_no_case("demain"))(input)
+}
+
+/// Recognizes the `case insensitive` words `après-demain`, `apres-demain` (or
+/// the same words separated by a space) in `French` and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::fr::day_after_tomorrow;
+///
+/// assert_eq!(
+///     day_after_tomorrow("après-demain")?.1,
+///     Local::now().add(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_after_tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    day_after_tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_after_tomorrow`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_after_tomorrow_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_day(
+            ctx,
+            2,
+            tuple((
+                alt((tag_no_case("après"), tag_no_case("apres"))),
+                hyphen,
+                tag_no_case("demain"),
+            )),
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Avant-hier", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("avant hier", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    fn test_day_before_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_before_yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("Hier", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    fn test_yesterday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(yesterday(input), expected);
+    }
+
+    #[rstest]
+    #[case("aujourd'hui", Ok(("", Local::now().date_naive())))]
+    #[case("Aujourd’hui", Ok(("", Local::now().date_naive())))]
+    #[case("AUJOURD'HUI", Ok(("", Local::now().date_naive())))]
+    fn test_today(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(today(input), expected);
+    }
+
+    #[rstest]
+    #[case("Demain", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    fn test_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(tomorrow(input), expected);
+    }
+
+    #[rstest]
+    #[case("après-demain", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    #[case("APRÈS-DEMAIN", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    #[case("apres demain", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_day_after_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(day_after_tomorrow(input), expected);
+    }
+}
@@ -0,0 +1,189 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `French`.
+///
+/// The following words are accepted:
+/// - `lun` -> [`Weekday::Mon`]
+/// - `mar` -> [`Weekday::Tue`]
+/// - `mer` -> [`Weekday::Wed`]
+/// - `jeu` -> [`Weekday::Thu`]
+/// - `ven` -> [`Weekday::Fri`]
+/// - `sam` -> [`Weekday::Sat`]
+/// - `dim` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::fr::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("ven")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("lun")),
+        value(Weekday::Tue, tag_no_case("mar")),
+        value(Weekday::Wed, tag_no_case("mer")),
+        value(Weekday::Thu, tag_no_case("jeu")),
+        value(Weekday::Fri, tag_no_case("ven")),
+        value(Weekday::Sat, tag_no_case("sam")),
+        value(Weekday::Sun, tag_no_case("dim")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `French` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `French`.
+///
+/// The following words are accepted:
+/// - `lundi` -> [`Weekday::Mon`]
+/// - `mardi` -> [`Weekday::Tue`]
+/// - `mercredi` -> [`Weekday::Wed`]
+/// - `jeudi` -> [`Weekday::Thu`]
+/// - `vendredi` -> [`Weekday::Fri`]
+/// - `samedi` -> [`Weekday::Sat`]
+/// - `dimanche` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::fr::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("mercredi")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("lundi")),
+        value(Weekday::Tue, tag_no_case("mardi")),
+        value(Weekday::Wed, tag_no_case("mercredi")),
+        value(Weekday::Thu, tag_no_case("jeudi")),
+        value(Weekday::Fri, tag_no_case("vendredi")),
+        value(Weekday::Sat, tag_no_case("samedi")),
+        value(Weekday::Sun, tag_no_case("dimanche")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `French`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::fr::named_weekday;
+///
+/// assert_eq!(named_weekday("ven.")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Vendredi")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `French` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{fr::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("mercredi")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("lun", Ok(("", Weekday::Mon)))]
+    #[case("MAR", Ok(("", Weekday::Tue)))]
+    #[case("Mer", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("mar.", Ok(("", Weekday::Tue)))]
+    #[case("DIM.", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("lundi", Ok(("", Weekday::Mon)))]
+    #[case("Mardi", Ok(("", Weekday::Tue)))]
+    #[case("JEUDI", Ok(("", Weekday::Thu)))]
+    #[case("samedi", Ok(("", Weekday::Sat)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("dim", Ok(("", Weekday::Sun)))]
+    #[case("Dimanche", Ok(("", Weekday::Sun)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("lun", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("mardi", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
@@ -0,0 +1,110 @@
+mod relative;
+mod weekday;
+
+use chrono::NaiveDate;
+use nom::{branch::alt, sequence::delimited};
+
+use crate::{
+    combinators::bidi_marks, context::ParserContext, i18n::weekday_prefixed, numeric::dmy_with,
+    types::IResult,
+};
+
+pub use self::{relative::*, weekday::*};
+
+/// Uses the following parsers to recognize the `numeric` and
+/// `language-specific` dates in `Hebrew`. Uses the `day-month-year` sequence:
+/// - Numeric date parsers:
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
+/// - Language-specific
+///     - [`day_before_yesterday`]
+///     - [`yesterday`]
+///     - [`today`]
+///     - [`day_after_tomorrow`]
+///     - [`tomorrow`]
+///     - [`current_named_weekday_only`]
+///
+/// The Unicode bidirectional formatting characters (e.g. the `RIGHT-TO-LEFT
+/// MARK`, embeddings and isolates) around the date are skipped, so the
+/// numeric dates wrapped into the right-to-left text are recognized as well.
+///
+/// The numeric dates may be prefixed with the weekday (e.g. `ו׳ 13.09`),
+/// which is consumed but not validated, see [`weekday_prefixed`].
+///
+/// If the specified date doesn't exist, returns `nom::Err::Error`
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::he::bundle_dmy;
+///
+/// assert_eq!(
+///     bundle_dmy("\u{200F}13.06.2024\u{200F}")?.1,
+///     NaiveDate::from_ymd_opt(2024, 6, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn bundle_dmy(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle_dmy`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_dmy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(delimited(
+            bidi_marks,
+            alt((
+                weekday_prefixed(named_weekday, dmy_with(ctx)),
+                day_before_yesterday_with(ctx),
+                yesterday_with(ctx),
+                today_with(ctx),
+                day_after_tomorrow_with(ctx),
+                tomorrow_with(ctx),
+                current_named_weekday_only_with(ctx),
+            )),
+            bidi_marks,
+        ))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Datelike, Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1", Ok(("", Local::now().date_naive().with_day(1).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 12, 3).unwrap())))]
+    #[case("13.06.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("\u{200F}13.06.2024\u{200F}", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("\u{2066}13.06.2024\u{2069} ", Ok((" ", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("שלשום", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("אתמול", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("\u{202B}היום\u{202C}", Ok(("", Local::now().date_naive())))]
+    #[case("מחר", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("מחרתיים", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_bundle_dmy(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(bundle_dmy(input), expected)
+    }
+
+    #[rstest]
+    #[case("\u{200F}13/07", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("שלשום", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 14).unwrap())))]
+    #[case("מחרתיים", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap())))]
+    #[case("יום שישי", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("ו׳", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    fn test_bundle_dmy_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_dmy_with(&ctx)(input), expected)
+    }
+}
@@ -0,0 +1,179 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, combinator::value};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the short-named weekday in `Hebrew`.
+///
+/// The following words are accepted:
+/// - `ב׳` | `ב'` -> [`Weekday::Mon`]
+/// - `ג׳` | `ג'` -> [`Weekday::Tue`]
+/// - `ד׳` | `ד'` -> [`Weekday::Wed`]
+/// - `ה׳` | `ה'` -> [`Weekday::Thu`]
+/// - `ו׳` | `ו'` -> [`Weekday::Fri`]
+/// - `ש׳` | `ש'` -> [`Weekday::Sat`]
+/// - `א׳` | `א'` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::he::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("ו׳")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("ב׳")),
+        value(Weekday::Mon, tag_no_case("ב'")),
+        value(Weekday::Tue, tag_no_case("ג׳")),
+        value(Weekday::Tue, tag_no_case("ג'")),
+        value(Weekday::Wed, tag_no_case("ד׳")),
+        value(Weekday::Wed, tag_no_case("ד'")),
+        value(Weekday::Thu, tag_no_case("ה׳")),
+        value(Weekday::Thu, tag_no_case("ה'")),
+        value(Weekday::Fri, tag_no_case("ו׳")),
+        value(Weekday::Fri, tag_no_case("ו'")),
+        value(Weekday::Sat, tag_no_case("ש׳")),
+        value(Weekday::Sat, tag_no_case("ש'")),
+        value(Weekday::Sun, tag_no_case("א׳")),
+        value(Weekday::Sun, tag_no_case("א'")),
+    ))(input)
+}
+
+/// Recognizes the full-named weekday in `Hebrew`.
+///
+/// The following words are accepted:
+/// - `יום שני` -> [`Weekday::Mon`]
+/// - `יום שלישי` -> [`Weekday::Tue`]
+/// - `יום רביעי` -> [`Weekday::Wed`]
+/// - `יום חמישי` -> [`Weekday::Thu`]
+/// - `יום שישי` -> [`Weekday::Fri`]
+/// - `יום שבת` | `שבת` -> [`Weekday::Sat`]
+/// - `יום ראשון` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::he::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("יום רביעי")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("יום שני")),
+        value(Weekday::Tue, tag_no_case("יום שלישי")),
+        value(Weekday::Wed, tag_no_case("יום רביעי")),
+        value(Weekday::Thu, tag_no_case("יום חמישי")),
+        value(Weekday::Fri, tag_no_case("יום שישי")),
+        value(Weekday::Sat, tag_no_case("יום שבת")),
+        value(Weekday::Sat, tag_no_case("שבת")),
+        value(Weekday::Sun, tag_no_case("יום ראשון")),
+    ))(input)
+}
+
+/// Recognizes either the short-named or full-named weekday
+/// in `Hebrew`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::he::named_weekday;
+///
+/// assert_eq!(named_weekday("ו׳")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("יום שישי")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((full_named_weekday, short_named_weekday))(input)
+}
+
+/// Recognizes the weekday in `Hebrew` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{he::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("יום רביעי")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("ב׳", Ok(("", Weekday::Mon)))]
+    #[case("ג׳", Ok(("", Weekday::Tue)))]
+    #[case("ד׳", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("יום שני", Ok(("", Weekday::Mon)))]
+    #[case("יום שלישי", Ok(("", Weekday::Tue)))]
+    #[case("יום רביעי", Ok(("", Weekday::Wed)))]
+    #[case("שבת", Ok(("", Weekday::Sat)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("ב׳", Ok(("", Weekday::Mon)))]
+    #[case("יום שלישי", Ok(("", Weekday::Tue)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("ב׳", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("יום שלישי", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
@@ -0,0 +1,69 @@
+mod relative;
+mod weekday;
+
+use chrono::NaiveDate;
+use nom::branch::alt;
+
+use crate::{context::ParserContext, i18n::weekday_prefixed, numeric::dmy_with, types::IResult};
+
+pub use self::{relative::*, weekday::*};
+
+/// Uses the following parsers to recognize the `numeric` and
+/// `language-specific` dates in `Armenian`:
+/// - Numeric date parsers:
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
+/// - Language-specific
+///     - [`yesterday`]
+///     - [`today`]
+///     - [`day_after_tomorrow`]
+///     - [`tomorrow`]
+///     - [`current_named_weekday_only`]
+///
+/// The numeric dates may be prefixed with the weekday (e.g. `ուր 13.09`),
+/// which is consumed but not validated, see [`weekday_prefixed`].
+///
+/// If the specified date doesn't exist, returns `nom::Err::Error`
+#[cfg(feature = "clock")]
+pub fn bundle(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(alt((
+            weekday_prefixed(named_weekday, dmy_with(ctx)),
+            yesterday_with(ctx),
+            today_with(ctx),
+            day_after_tomorrow_with(ctx),
+            tomorrow_with(ctx),
+            current_named_weekday_only_with(ctx),
+        )))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Datelike, Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1", Ok(("", Local::now().date_naive().with_day(1).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 12, 3).unwrap())))]
+    #[case("13.06.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("երեկ", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("Այսօր", Ok(("", Local::now().date_naive())))]
+    #[case("Վաղը", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("վաղը չէ մյուս օրը", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_bundle(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(bundle(input), expected)
+    }
+}
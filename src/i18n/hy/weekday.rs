@@ -0,0 +1,190 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Armenian`.
+///
+/// The following words are accepted:
+/// - `երկ` -> [`Weekday::Mon`]
+/// - `երք` -> [`Weekday::Tue`]
+/// - `չրք` -> [`Weekday::Wed`]
+/// - `հնգ` -> [`Weekday::Thu`]
+/// - `ուր` -> [`Weekday::Fri`]
+/// - `շբթ` -> [`Weekday::Sat`]
+/// - `կիր` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::hy::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("ուր")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("երկ")),
+        value(Weekday::Tue, tag_no_case("երք")),
+        value(Weekday::Wed, tag_no_case("չրք")),
+        value(Weekday::Thu, tag_no_case("հնգ")),
+        value(Weekday::Fri, tag_no_case("ուր")),
+        value(Weekday::Sat, tag_no_case("շբթ")),
+        value(Weekday::Sun, tag_no_case("կիր")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Armenian` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Armenian`.
+///
+/// The following words are accepted:
+/// - `երկուշաբթի` -> [`Weekday::Mon`]
+/// - `երեքշաբթի` -> [`Weekday::Tue`]
+/// - `չորեքշաբթի` -> [`Weekday::Wed`]
+/// - `հինգշաբթի` -> [`Weekday::Thu`]
+/// - `ուրբաթ` -> [`Weekday::Fri`]
+/// - `շաբաթ` -> [`Weekday::Sat`]
+/// - `կիրակի` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::hy::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("չորեքշաբթի")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("երկուշաբթի")),
+        value(Weekday::Tue, tag_no_case("երեքշաբթի")),
+        value(Weekday::Wed, tag_no_case("չորեքշաբթի")),
+        value(Weekday::Thu, tag_no_case("հինգշաբթի")),
+        value(Weekday::Fri, tag_no_case("ուրբաթ")),
+        value(Weekday::Sat, tag_no_case("շաբաթ")),
+        value(Weekday::Sun, tag_no_case("կիրակի")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Armenian`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::hy::named_weekday;
+///
+/// assert_eq!(named_weekday("ուր")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Ուրբաթ")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Armenian` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{hy::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Չորեքշաբթի")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("երկ", Ok(("", Weekday::Mon)))]
+    #[case("ԵՐՔ", Ok(("", Weekday::Tue)))]
+    #[case("Չրք", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("երկ.", Ok(("", Weekday::Mon)))]
+    #[case("ԵՐՔ.", Ok(("", Weekday::Tue)))]
+    #[case("Չրք.", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("երկուշաբթի", Ok(("", Weekday::Mon)))]
+    #[case("Երեքշաբթի", Ok(("", Weekday::Tue)))]
+    #[case("ՉՈՐԵՔՇԱԲԹԻ", Ok(("", Weekday::Wed)))]
+    #[case("Շաբաթ", Ok(("", Weekday::Sat)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("երկ", Ok(("", Weekday::Mon)))]
+    #[case("երեքշաբթի", Ok(("", Weekday::Tue)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("երկ", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Երեքշաբթի", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
@@ -0,0 +1,225 @@
+use chrono::{Month, NaiveDate};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `Italian`.
+///
+/// The following words are accepted:
+/// - `gen` -> [`Month::January`]
+/// - `feb` -> [`Month::February`]
+/// - `mar` -> [`Month::March`]
+/// - `apr` -> [`Month::April`]
+/// - `mag` -> [`Month::May`]
+/// - `giu` -> [`Month::June`]
+/// - `lug` -> [`Month::July`]
+/// - `ago` -> [`Month::August`]
+/// - `set` -> [`Month::September`]
+/// - `ott` -> [`Month::October`]
+/// - `nov` -> [`Month::November`]
+/// - `dic` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::it::short_named_month;
+///
+/// assert_eq!(short_named_month("lug")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("gen")),
+        value(Month::February, tag_no_case("feb")),
+        value(Month::March, tag_no_case("mar")),
+        value(Month::April, tag_no_case("apr")),
+        value(Month::May, tag_no_case("mag")),
+        value(Month::June, tag_no_case("giu")),
+        value(Month::July, tag_no_case("lug")),
+        value(Month::August, tag_no_case("ago")),
+        value(Month::September, tag_no_case("set")),
+        value(Month::October, tag_no_case("ott")),
+        value(Month::November, tag_no_case("nov")),
+        value(Month::December, tag_no_case("dic")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `Italian` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `Italian`.
+///
+/// The following words are accepted:
+/// - `gennaio` -> [`Month::January`]
+/// - `febbraio` -> [`Month::February`]
+/// - `marzo` -> [`Month::March`]
+/// - `aprile` -> [`Month::April`]
+/// - `maggio` -> [`Month::May`]
+/// - `giugno` -> [`Month::June`]
+/// - `luglio` -> [`Month::July`]
+/// - `agosto` -> [`Month::August`]
+/// - `settembre` -> [`Month::September`]
+/// - `ottobre` -> [`Month::October`]
+/// - `novembre` -> [`Month::November`]
+/// - `dicembre` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::it::full_named_month;
+///
+/// assert_eq!(full_named_month("luglio")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("gennaio")),
+        value(Month::February, tag_no_case("febbraio")),
+        value(Month::March, tag_no_case("marzo")),
+        value(Month::April, tag_no_case("aprile")),
+        value(Month::May, tag_no_case("maggio")),
+        value(Month::June, tag_no_case("giugno")),
+        value(Month::July, tag_no_case("luglio")),
+        value(Month::August, tag_no_case("agosto")),
+        value(Month::September, tag_no_case("settembre")),
+        value(Month::October, tag_no_case("ottobre")),
+        value(Month::November, tag_no_case("novembre")),
+        value(Month::December, tag_no_case("dicembre")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `Italian`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::it::named_month;
+///
+/// assert_eq!(named_month("lug.")?.1, Month::July);
+/// assert_eq!(named_month("luglio")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `Italian` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::it::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 luglio 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `Italian` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::it::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 luglio")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("gen", Ok(("", Month::January)))]
+    #[case("MAG", Ok(("", Month::May)))]
+    #[case("Dic", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("gen.", Ok(("", Month::January)))]
+    #[case("Ott.", Ok(("", Month::October)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("gennaio", Ok(("", Month::January)))]
+    #[case("Febbraio", Ok(("", Month::February)))]
+    #[case("AGOSTO", Ok(("", Month::August)))]
+    #[case("dicembre", Ok(("", Month::December)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("mar", Ok(("", Month::March)))]
+    #[case("marzo", Ok(("", Month::March)))]
+    #[case("giu", Ok(("", Month::June)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 luglio 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1 gen. 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 febbraio 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 luglio", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 luglio", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 luglio" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
@@ -0,0 +1,199 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Italian`.
+///
+/// The following words are accepted:
+/// - `lun` -> [`Weekday::Mon`]
+/// - `mar` -> [`Weekday::Tue`]
+/// - `mer` -> [`Weekday::Wed`]
+/// - `gio` -> [`Weekday::Thu`]
+/// - `ven` -> [`Weekday::Fri`]
+/// - `sab` -> [`Weekday::Sat`]
+/// - `dom` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::it::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("ven")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("lun")),
+        value(Weekday::Tue, tag_no_case("mar")),
+        value(Weekday::Wed, tag_no_case("mer")),
+        value(Weekday::Thu, tag_no_case("gio")),
+        value(Weekday::Fri, tag_no_case("ven")),
+        value(Weekday::Sat, tag_no_case("sab")),
+        value(Weekday::Sun, tag_no_case("dom")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Italian` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Italian`.
+///
+/// The following words are accepted:
+/// - `lunedì` | `lunedi` -> [`Weekday::Mon`]
+/// - `martedì` | `martedi` -> [`Weekday::Tue`]
+/// - `mercoledì` | `mercoledi` -> [`Weekday::Wed`]
+/// - `giovedì` | `giovedi` -> [`Weekday::Thu`]
+/// - `venerdì` | `venerdi` -> [`Weekday::Fri`]
+/// - `sabato` -> [`Weekday::Sat`]
+/// - `domenica` -> [`Weekday::Sun`]
+///
+/// The accented `ì` may be replaced with the plain `i`, e.g. `lunedi`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::it::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("mercoledì")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("lunedì")),
+        value(Weekday::Mon, tag_no_case("lunedi")),
+        value(Weekday::Tue, tag_no_case("martedì")),
+        value(Weekday::Tue, tag_no_case("martedi")),
+        value(Weekday::Wed, tag_no_case("mercoledì")),
+        value(Weekday::Wed, tag_no_case("mercoledi")),
+        value(Weekday::Thu, tag_no_case("giovedì")),
+        value(Weekday::Thu, tag_no_case("giovedi")),
+        value(Weekday::Fri, tag_no_case("venerdì")),
+        value(Weekday::Fri, tag_no_case("venerdi")),
+        value(Weekday::Sat, tag_no_case("sabato")),
+        value(Weekday::Sun, tag_no_case("domenica")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Italian`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::it::named_weekday;
+///
+/// assert_eq!(named_weekday("ven.")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Venerdì")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Italian` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{it::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("mercoledì")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("lun", Ok(("", Weekday::Mon)))]
+    #[case("GIO", Ok(("", Weekday::Thu)))]
+    #[case("Dom", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("lun.", Ok(("", Weekday::Mon)))]
+    #[case("SAB.", Ok(("", Weekday::Sat)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("lunedì", Ok(("", Weekday::Mon)))]
+    #[case("LUNEDÌ", Ok(("", Weekday::Mon)))]
+    #[case("Martedì", Ok(("", Weekday::Tue)))]
+    #[case("mercoledi", Ok(("", Weekday::Wed)))]
+    #[case("VENERDI", Ok(("", Weekday::Fri)))]
+    #[case("Sabato", Ok(("", Weekday::Sat)))]
+    #[case("domenica", Ok(("", Weekday::Sun)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("ven", Ok(("", Weekday::Fri)))]
+    #[case("Venerdì", Ok(("", Weekday::Fri)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("lun", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("martedì", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
@@ -0,0 +1,111 @@
+mod date;
+mod era;
+mod relative;
+mod weekday;
+
+use chrono::NaiveDate;
+use nom::{branch::alt, character::complete::space0, sequence::preceded};
+
+use crate::{combinators::optional_suffix, context::ParserContext, types::IResult};
+
+pub use self::{date::*, era::*, relative::*, weekday::*};
+
+/// Uses the following parsers to recognize the `numeric` and
+/// `language-specific` dates in `Japanese`. Uses the `year-month-day` sequence:
+/// - Numeric date parsers:
+///     - [`y4_mm_dd`](crate::numeric::y4_mm_dd)
+/// - Language-specific
+///     - [`era_y_mm_dd`]
+///     - [`y4_mm_dd`]
+///     - [`mm_dd_only`]
+///     - [`dd_only`]
+///     - [`day_before_yesterday`]
+///     - [`yesterday`]
+///     - [`today`]
+///     - [`tomorrow`]
+///     - [`day_after_tomorrow`]
+///     - [`current_named_weekday_only`]
+///
+/// The dates may be followed by the weekday (e.g. `7月13日(土)`), which is
+/// consumed but not validated.
+///
+/// If the specified date doesn't exist, returns `nom::Err::Error`
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ja::bundle;
+///
+/// assert_eq!(
+///     bundle("2024年7月13日(土)")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     bundle("令和6年7月13日")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn bundle(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(alt((
+            optional_suffix(
+                alt((
+                    era_y_mm_dd,
+                    y4_mm_dd_with(ctx),
+                    crate::numeric::y4_mm_dd_with(ctx),
+                    mm_dd_only_with(ctx),
+                    dd_only_with(ctx),
+                )),
+                preceded(space0, named_weekday),
+            ),
+            day_before_yesterday_with(ctx),
+            yesterday_with(ctx),
+            today_with(ctx),
+            tomorrow_with(ctx),
+            day_after_tomorrow_with(ctx),
+            current_named_weekday_only_with(ctx),
+        )))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::error::Error;
+
+    #[rstest]
+    #[case("2024年7月13日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024年7月13日(土)", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024年7月13日 土曜日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("令和6年7月13日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("平成元年1月8日（日）", Ok(("", NaiveDate::from_ymd_opt(1989, 1, 8).unwrap())))]
+    #[case("2023/07/13", Ok(("", NaiveDate::from_ymd_opt(2023, 7, 13).unwrap())))]
+    #[case("8月1日", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 1).unwrap())))]
+    #[case("20日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("一昨日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 14).unwrap())))]
+    #[case("昨日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("今日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("明日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("明後日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap())))]
+    #[case("金曜日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("(月)", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("2月30日", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("平成31年5月1日", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_bundle_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_with(&ctx)(input), expected)
+    }
+}
@@ -0,0 +1,206 @@
+use chrono::{Datelike, NaiveDate};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, space0},
+    combinator::{map_res, value},
+    sequence::separated_pair,
+};
+
+use crate::{
+    error::Error,
+    i18n::{ja::date::DAY_SUFFIXES, y_mm_dd_suffixed},
+    types::IResult,
+};
+
+/// The era (`元号`) of the Japanese calendar, which counts the years from the
+/// accession of the emperor, e.g. `令和6年` is `2024`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Era {
+    /// `明治`, from October 23, 1868
+    Meiji,
+    /// `大正`, from July 30, 1912
+    Taisho,
+    /// `昭和`, from December 25, 1926
+    Showa,
+    /// `平成`, from January 8, 1989
+    Heisei,
+    /// `令和`, from May 1, 2019
+    Reiwa,
+}
+
+impl Era {
+    /// Returns the `year`, `month` and `day` of the first day of the era in the
+    /// Gregorian calendar.
+    fn first_day(self) -> (i32, u32, u32) {
+        match self {
+            Era::Meiji => (1868, 10, 23),
+            Era::Taisho => (1912, 7, 30),
+            Era::Showa => (1926, 12, 25),
+            Era::Heisei => (1989, 1, 8),
+            Era::Reiwa => (2019, 5, 1),
+        }
+    }
+
+    /// Returns the era which follows this one or [`None`] for the current era.
+    fn next(self) -> Option<Era> {
+        match self {
+            Era::Meiji => Some(Era::Taisho),
+            Era::Taisho => Some(Era::Showa),
+            Era::Showa => Some(Era::Heisei),
+            Era::Heisei => Some(Era::Reiwa),
+            Era::Reiwa => None,
+        }
+    }
+
+    /// Returns the Gregorian [`NaiveDate`] of the `day` of the `month` of the
+    /// `year` of the era (the first year, `元年`, is `1`) or [`None`] if the
+    /// date doesn't exist or falls outside the era (e.g. `平成31年5月1日`,
+    /// which is already `令和元年`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::i18n::ja::Era;
+    ///
+    /// assert_eq!(
+    ///     Era::Reiwa.date(6, 7, 13),
+    ///     NaiveDate::from_ymd_opt(2024, 7, 13)
+    /// );
+    /// assert_eq!(
+    ///     Era::Heisei.date(31, 4, 30),
+    ///     NaiveDate::from_ymd_opt(2019, 4, 30)
+    /// );
+    /// assert_eq!(Era::Heisei.date(31, 5, 1), None);
+    /// ```
+    pub fn date(self, year: u32, month: u32, day: u32) -> Option<NaiveDate> {
+        let first_day = self.first_day();
+        let year = first_day
+            .0
+            .checked_add(i32::try_from(year).ok()?.checked_sub(1)?)?;
+        let date = NaiveDate::from_ymd_opt(year, month, day)?;
+
+        let ymd = (date.year(), date.month(), date.day());
+        if ymd < first_day || self.next().is_some_and(|next| ymd >= next.first_day()) {
+            return None;
+        }
+        Some(date)
+    }
+}
+
+/// Recognizes the name of the era in `Japanese`.
+///
+/// The following words are accepted:
+/// - `明治` -> [`Era::Meiji`]
+/// - `大正` -> [`Era::Taisho`]
+/// - `昭和` -> [`Era::Showa`]
+/// - `平成` -> [`Era::Heisei`]
+/// - `令和` -> [`Era::Reiwa`]
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::i18n::ja::{era, Era};
+///
+/// assert_eq!(era("令和")?.1, Era::Reiwa);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn era(input: &str) -> IResult<&str, Era> {
+    alt((
+        value(Era::Meiji, tag("明治")),
+        value(Era::Taisho, tag("大正")),
+        value(Era::Showa, tag("昭和")),
+        value(Era::Heisei, tag("平成")),
+        value(Era::Reiwa, tag("令和")),
+    ))(input)
+}
+
+/// Recognizes the year of the era: either the number or the `元` character of
+/// the first year.
+fn era_year(input: &str) -> IResult<&str, u32> {
+    alt((value(1, tag("元")), map_res(digit1, str::parse)))(input)
+}
+
+/// Recognizes the date in `Japanese` with the [`era`], the `year` of the era,
+/// `month` and `day` parts followed by the `年`, `月` and `日` suffixes (e.g.
+/// `令和6年7月13日` or `平成元年1月8日`) and returns the corresponding
+/// Gregorian [`NaiveDate`] if the date exists within the era (see
+/// [`Era::date`]), otherwise returns [`Error::NonExistentDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ja::era_y_mm_dd;
+///
+/// assert_eq!(
+///     era_y_mm_dd("令和6年7月13日")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     era_y_mm_dd("平成元年1月8日")?.1,
+///     NaiveDate::from_ymd_opt(1989, 1, 8).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn era_y_mm_dd(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, ((era, year), mm, dd)) = y_mm_dd_suffixed(
+        |input| separated_pair(era, space0, era_year)(input),
+        DAY_SUFFIXES,
+    )(input)?;
+
+    Ok((
+        input,
+        era.date(year, mm, dd)
+            .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Era::Meiji, 45, 7, 29, NaiveDate::from_ymd_opt(1912, 7, 29))]
+    #[case(Era::Meiji, 45, 7, 30, None)]
+    #[case(Era::Taisho, 1, 7, 30, NaiveDate::from_ymd_opt(1912, 7, 30))]
+    #[case(Era::Showa, 64, 1, 7, NaiveDate::from_ymd_opt(1989, 1, 7))]
+    #[case(Era::Heisei, 1, 1, 7, None)]
+    #[case(Era::Heisei, 1, 1, 8, NaiveDate::from_ymd_opt(1989, 1, 8))]
+    #[case(Era::Heisei, 31, 4, 30, NaiveDate::from_ymd_opt(2019, 4, 30))]
+    #[case(Era::Reiwa, 1, 4, 30, None)]
+    #[case(Era::Reiwa, 6, 2, 29, NaiveDate::from_ymd_opt(2024, 2, 29))]
+    #[case(Era::Reiwa, 7, 2, 29, None)]
+    #[case(Era::Reiwa, 0, 7, 13, None)]
+    #[case(Era::Reiwa, u32::MAX, 7, 13, None)]
+    fn test_date(
+        #[case] era: Era,
+        #[case] year: u32,
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        assert_eq!(era.date(year, month, day), expected);
+    }
+
+    #[rstest]
+    #[case("令和6年7月13日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("令和元年5月1日", Ok(("", NaiveDate::from_ymd_opt(2019, 5, 1).unwrap())))]
+    #[case("令和 6 年 07 月 13 日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("昭和64年1月7日", Ok(("", NaiveDate::from_ymd_opt(1989, 1, 7).unwrap())))]
+    #[case("大正元年8月1日", Ok(("", NaiveDate::from_ymd_opt(1912, 8, 1).unwrap())))]
+    #[case("明治45年7月29日", Ok(("", NaiveDate::from_ymd_opt(1912, 7, 29).unwrap())))]
+    #[case("平成31年5月1日", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("令和6年13月1日", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13月1日" })))]
+    #[case(
+        "2024年7月13日",
+        Err(nom::Err::Error(Error::Nom("2024年7月13日", nom::error::ErrorKind::Tag)))
+    )]
+    fn test_era_y_mm_dd(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(era_y_mm_dd(input), expected);
+    }
+}
@@ -0,0 +1,171 @@
+use chrono::NaiveDate;
+use nom::{branch::alt, bytes::complete::tag, combinator::value};
+
+use crate::{context::ParserContext, i18n::relative_day, types::IResult};
+
+/// Recognizes the word `一昨日` in `Japanese` and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ja::day_before_yesterday;
+///
+/// assert_eq!(
+///     day_before_yesterday("一昨日")?.1,
+///     Local::now().sub(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    day_before_yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_before_yesterday`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_before_yesterday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -2, tag("一昨日"))(input)
+}
+
+/// Recognizes the word `昨日` in `Japanese` and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ja::yesterday;
+///
+/// assert_eq!(
+///     yesterday("昨日")?.1,
+///     Local::now().sub(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -1, tag("昨日"))(input)
+}
+
+/// Recognizes either the word `今日` or the formal `本日` in `Japanese` and
+/// returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use nom_date_parsers::i18n::ja::today;
+///
+/// assert_eq!(today("今日")?.1, Local::now().date_naive());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn today(input: &str) -> IResult<&str, NaiveDate> {
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| value(ctx.today(), alt((tag("今日"), tag("本日"))))(input)
+}
+
+/// Recognizes the word `明日` in `Japanese` and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ja::tomorrow;
+///
+/// assert_eq!(
+///     tomorrow("明日")?.1,
+///     Local::now().add(Days::new(1)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 1, tag("明日"))(input)
+}
+
+/// Recognizes the word `明後日` in `Japanese` and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ja::day_after_tomorrow;
+///
+/// assert_eq!(
+///     day_after_tomorrow("明後日")?.1,
+///     Local::now().add(Days::new(2)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_after_tomorrow(input: &str) -> IResult<&str, NaiveDate> {
+    day_after_tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_after_tomorrow`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_after_tomorrow_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 2, tag("明後日"))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("一昨日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 14).unwrap())))]
+    #[case("昨日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("今日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("本日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("明日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("明後日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap())))]
+    fn test_relative_days(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+        let mut relative_day = alt((
+            day_before_yesterday_with(&ctx),
+            yesterday_with(&ctx),
+            today_with(&ctx),
+            tomorrow_with(&ctx),
+            day_after_tomorrow_with(&ctx),
+        ));
+
+        assert_eq!(relative_day(input), expected);
+    }
+}
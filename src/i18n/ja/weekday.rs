@@ -0,0 +1,203 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::value,
+    sequence::{delimited, terminated},
+};
+
+use crate::{
+    context::ParserContext, error::Error, i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the character of the weekday in `Japanese`.
+///
+/// The following characters are accepted:
+/// - `月` -> [`Weekday::Mon`]
+/// - `火` -> [`Weekday::Tue`]
+/// - `水` -> [`Weekday::Wed`]
+/// - `木` -> [`Weekday::Thu`]
+/// - `金` -> [`Weekday::Fri`]
+/// - `土` -> [`Weekday::Sat`]
+/// - `日` -> [`Weekday::Sun`]
+fn weekday_character(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag("月")),
+        value(Weekday::Tue, tag("火")),
+        value(Weekday::Wed, tag("水")),
+        value(Weekday::Thu, tag("木")),
+        value(Weekday::Fri, tag("金")),
+        value(Weekday::Sat, tag("土")),
+        value(Weekday::Sun, tag("日")),
+    ))(input)
+}
+
+/// Recognizes the short-named weekday in `Japanese`: the character of the
+/// weekday followed by the `曜` suffix (e.g. `月曜` -> [`Weekday::Mon`]).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ja::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("金曜")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    terminated(weekday_character, tag("曜"))(input)
+}
+
+/// Recognizes the full-named weekday in `Japanese`: the character of the
+/// weekday followed by the `曜日` suffix (e.g. `月曜日` -> [`Weekday::Mon`]).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ja::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("水曜日")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    terminated(weekday_character, tag("曜日"))(input)
+}
+
+/// Recognizes the character of the weekday in `Japanese` in either the ASCII
+/// or the full-width parentheses (e.g. `(月)` or `（月）` ->
+/// [`Weekday::Mon`]), which usually follows the date, e.g. `7月13日(土)`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ja::parenthesized_weekday;
+///
+/// assert_eq!(parenthesized_weekday("(土)")?.1, Weekday::Sat);
+/// assert_eq!(parenthesized_weekday("（土）")?.1, Weekday::Sat);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn parenthesized_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        delimited(tag("("), weekday_character, tag(")")),
+        delimited(tag("（"), weekday_character, tag("）")),
+    ))(input)
+}
+
+/// Recognizes either the short-named, full-named or parenthesized weekday in
+/// `Japanese`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday`]
+/// - [`parenthesized_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ja::named_weekday;
+///
+/// assert_eq!(named_weekday("金曜日")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("(金)")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday,
+        parenthesized_weekday,
+    ))(input)
+}
+
+/// Recognizes the weekday in `Japanese` using the [`named_weekday`] parser and
+/// returns the corresponding [`NaiveDate`] for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{ja::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("水曜日")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::{error::Error, i18n::naive_date_for_weekday};
+
+    use super::*;
+
+    #[rstest]
+    #[case("月曜", Ok(("", Weekday::Mon)))]
+    #[case("土曜", Ok(("", Weekday::Sat)))]
+    #[case("日曜", Ok(("", Weekday::Sun)))]
+    #[case("月", Err(nom::Err::Error(Error::Nom("", nom::error::ErrorKind::Tag))))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("月曜日", Ok(("", Weekday::Mon)))]
+    #[case("火曜日", Ok(("", Weekday::Tue)))]
+    #[case("木曜日", Ok(("", Weekday::Thu)))]
+    #[case("日曜日", Ok(("", Weekday::Sun)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("(月)", Ok(("", Weekday::Mon)))]
+    #[case("（水）", Ok(("", Weekday::Wed)))]
+    #[case(
+        "(月）",
+        Err(nom::Err::Error(Error::Nom("(月）", nom::error::ErrorKind::Tag)))
+    )]
+    fn test_parenthesized_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(parenthesized_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("金曜", Ok(("", Weekday::Fri)))]
+    #[case("金曜日", Ok(("", Weekday::Fri)))]
+    #[case("(金)", Ok(("", Weekday::Fri)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("月曜日", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("（火）", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Georgian`.
+///
+/// The following words are accepted:
+/// - `ორშ` -> [`Weekday::Mon`]
+/// - `სამ` -> [`Weekday::Tue`]
+/// - `ოთხ` -> [`Weekday::Wed`]
+/// - `ხუთ` -> [`Weekday::Thu`]
+/// - `პარ` -> [`Weekday::Fri`]
+/// - `შაბ` -> [`Weekday::Sat`]
+/// - `კვი` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ka::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("პარ")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("ორშ")),
+        value(Weekday::Tue, tag_no_case("სამ")),
+        value(Weekday::Wed, tag_no_case("ოთხ")),
+        value(Weekday::Thu, tag_no_case("ხუთ")),
+        value(Weekday::Fri, tag_no_case("პარ")),
+        value(Weekday::Sat, tag_no_case("შაბ")),
+        value(Weekday::Sun, tag_no_case("კვი")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Georgian` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Georgian`.
+///
+/// The following words are accepted:
+/// - `ორშაბათი` -> [`Weekday::Mon`]
+/// - `სამშაბათი` -> [`Weekday::Tue`]
+/// - `ოთხშაბათი` -> [`Weekday::Wed`]
+/// - `ხუთშაბათი` -> [`Weekday::Thu`]
+/// - `პარასკევი` -> [`Weekday::Fri`]
+/// - `შაბათი` -> [`Weekday::Sat`]
+/// - `კვირა` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ka::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("ოთხშაბათი")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("ორშაბათი")),
+        value(Weekday::Tue, tag_no_case("სამშაბათი")),
+        value(Weekday::Wed, tag_no_case("ოთხშაბათი")),
+        value(Weekday::Thu, tag_no_case("ხუთშაბათი")),
+        value(Weekday::Fri, tag_no_case("პარასკევი")),
+        value(Weekday::Sat, tag_no_case("შაბათი")),
+        value(Weekday::Sun, tag_no_case("კვირა")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Georgian`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ka::named_weekday;
+///
+/// assert_eq!(named_weekday("პარ")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("ᲞᲐᲠᲐᲡᲙᲔᲕᲘ")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Georgian` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{ka::current_named_weekday_only, naive_date_for_weekday};
+///
+/// assert_eq!(
+///     current_named_weekday_only("ოთხშაბათი")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("ორშ", Ok(("", Weekday::Mon)))]
+    #[case("ᲡᲐᲛ", Ok(("", Weekday::Tue)))]
+    #[case("ოთხ", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("ორშ.", Ok(("", Weekday::Mon)))]
+    #[case("ᲡᲐᲛ.", Ok(("", Weekday::Tue)))]
+    #[case("ოთხ.", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("ორშაბათი", Ok(("", Weekday::Mon)))]
+    #[case("ᲡᲐᲛᲨᲐᲑᲐᲗᲘ", Ok(("", Weekday::Tue)))]
+    #[case("ოთხშაბათი", Ok(("", Weekday::Wed)))]
+    #[case("შაბათი", Ok(("", Weekday::Sat)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("ორშ", Ok(("", Weekday::Mon)))]
+    #[case("სამშაბათი", Ok(("", Weekday::Tue)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("ორშ", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("ᲡᲐᲛᲨᲐᲑᲐᲗᲘ", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
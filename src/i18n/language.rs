@@ -0,0 +1,395 @@
+use alloc::{vec, vec::Vec};
+
+use chrono::NaiveDate;
+
+use crate::{context::ParserContext, numeric::DateOrder, parser::ContextFn, types::IResult};
+
+/// The language of the bundle parser selected at runtime with the
+/// [`bundle_for`] function or detected by the
+/// [`extract_best_date`](crate::extract_best_date) function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    /// English, recognized by the
+    /// [`en::bundle_dmy`](crate::i18n::en::bundle_dmy) or the
+    /// [`en::bundle_mdy`](crate::i18n::en::bundle_mdy)
+    #[cfg(feature = "en")]
+    En,
+    /// Russian, recognized by the [`ru::bundle`](crate::i18n::ru::bundle)
+    #[cfg(feature = "ru")]
+    Ru,
+    /// German, recognized by the [`de::bundle`](crate::i18n::de::bundle)
+    #[cfg(feature = "de")]
+    De,
+    /// Spanish, recognized by the
+    /// [`es::bundle_dmy`](crate::i18n::es::bundle_dmy)
+    #[cfg(feature = "es")]
+    Es,
+    /// French, recognized by the [`fr::bundle`](crate::i18n::fr::bundle)
+    #[cfg(feature = "fr")]
+    Fr,
+    /// Chinese (Simplified), recognized by the
+    /// [`zh::bundle`](crate::i18n::zh::bundle)
+    #[cfg(feature = "zh")]
+    Zh,
+    /// Armenian, recognized by the [`hy::bundle`](crate::i18n::hy::bundle)
+    #[cfg(feature = "hy")]
+    Hy,
+    /// Georgian, recognized by the [`ka::bundle`](crate::i18n::ka::bundle)
+    #[cfg(feature = "ka")]
+    Ka,
+    /// Azerbaijani, recognized by the [`az::bundle`](crate::i18n::az::bundle)
+    #[cfg(feature = "az")]
+    Az,
+    /// Uzbek (Latin script), recognized by the
+    /// [`uz::bundle`](crate::i18n::uz::bundle)
+    #[cfg(feature = "uz")]
+    Uz,
+    /// Uzbek (Cyrillic script), recognized by the
+    /// [`uz::cyrl::bundle`](crate::i18n::uz::cyrl::bundle)
+    #[cfg(feature = "uz-cyrl")]
+    UzCyrl,
+    /// Romanian, recognized by the
+    /// [`ro::bundle_dmy`](crate::i18n::ro::bundle_dmy)
+    #[cfg(feature = "ro")]
+    Ro,
+    /// Bulgarian, recognized by the
+    /// [`bg::bundle_dmy`](crate::i18n::bg::bundle_dmy)
+    #[cfg(feature = "bg")]
+    Bg,
+    /// Hebrew, recognized by the
+    /// [`he::bundle_dmy`](crate::i18n::he::bundle_dmy)
+    #[cfg(feature = "he")]
+    He,
+    /// Ukrainian, recognized by the [`uk::bundle`](crate::i18n::uk::bundle)
+    #[cfg(feature = "uk")]
+    Uk,
+    /// Polish, recognized by the
+    /// [`pl::bundle_dmy`](crate::i18n::pl::bundle_dmy)
+    #[cfg(feature = "pl")]
+    Pl,
+    /// Japanese, recognized by the [`ja::bundle`](crate::i18n::ja::bundle)
+    #[cfg(feature = "ja")]
+    Ja,
+    /// Portuguese, recognized by the
+    /// [`pt::bundle_dmy`](crate::i18n::pt::bundle_dmy)
+    #[cfg(feature = "pt")]
+    Pt,
+    /// Italian, recognized by the
+    /// [`it::bundle_dmy`](crate::i18n::it::bundle_dmy)
+    #[cfg(feature = "it")]
+    It,
+    /// Turkish, recognized by the
+    /// [`tr::bundle_dmy`](crate::i18n::tr::bundle_dmy)
+    #[cfg(feature = "tr")]
+    Tr,
+    /// Arabic, recognized by the
+    /// [`ar::bundle_dmy`](crate::i18n::ar::bundle_dmy)
+    #[cfg(feature = "ar")]
+    Ar,
+}
+
+impl Language {
+    /// Returns all the languages enabled by the features.
+    pub fn all() -> Vec<Language> {
+        vec![
+            #[cfg(feature = "en")]
+            Language::En,
+            #[cfg(feature = "ru")]
+            Language::Ru,
+            #[cfg(feature = "de")]
+            Language::De,
+            #[cfg(feature = "es")]
+            Language::Es,
+            #[cfg(feature = "fr")]
+            Language::Fr,
+            #[cfg(feature = "zh")]
+            Language::Zh,
+            #[cfg(feature = "hy")]
+            Language::Hy,
+            #[cfg(feature = "ka")]
+            Language::Ka,
+            #[cfg(feature = "az")]
+            Language::Az,
+            #[cfg(feature = "uz")]
+            Language::Uz,
+            #[cfg(feature = "uz-cyrl")]
+            Language::UzCyrl,
+            #[cfg(feature = "ro")]
+            Language::Ro,
+            #[cfg(feature = "bg")]
+            Language::Bg,
+            #[cfg(feature = "he")]
+            Language::He,
+            #[cfg(feature = "uk")]
+            Language::Uk,
+            #[cfg(feature = "pl")]
+            Language::Pl,
+            #[cfg(feature = "ja")]
+            Language::Ja,
+            #[cfg(feature = "pt")]
+            Language::Pt,
+            #[cfg(feature = "it")]
+            Language::It,
+            #[cfg(feature = "tr")]
+            Language::Tr,
+            #[cfg(feature = "ar")]
+            Language::Ar,
+        ]
+    }
+
+    /// Returns the language with the specified `ISO 639-1` code (e.g. `en` or
+    /// `RU`), the region and script subtags are ignored except the `Cyrl`
+    /// script of `Uzbek` (e.g. `en-US` is `En` and `uz-Cyrl` is `UzCyrl`).
+    /// Returns [`None`] if the language isn't supported or its feature isn't
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nom_date_parsers::i18n::Language;
+    ///
+    /// assert_eq!(Language::from_code("en-GB"), Some(Language::En));
+    /// assert_eq!(Language::from_code("xx"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<Language> {
+        let mut subtags = code.split(['-', '_']);
+        let language = subtags.next()?;
+        let candidates: Vec<(Language, &str)> = Language::all()
+            .into_iter()
+            .filter_map(|candidate| {
+                let (candidate_language, script) = candidate
+                    .code()
+                    .split_once('-')
+                    .unwrap_or((candidate.code(), ""));
+
+                candidate_language
+                    .eq_ignore_ascii_case(language)
+                    .then_some((candidate, script))
+            })
+            .collect();
+
+        // The language with the requested script wins over the default one
+        candidates
+            .iter()
+            .find(|(_, script)| {
+                !script.is_empty()
+                    && subtags
+                        .clone()
+                        .any(|subtag| subtag.eq_ignore_ascii_case(script))
+            })
+            .or_else(|| candidates.iter().find(|(_, script)| script.is_empty()))
+            .map(|&(candidate, _)| candidate)
+    }
+
+    /// Returns the `ISO 639-1` code of the language with the script subtag
+    /// if the language is written in several scripts (e.g. `uz-Cyrl`).
+    pub fn code(self) -> &'static str {
+        match self {
+            #[cfg(feature = "en")]
+            Language::En => "en",
+            #[cfg(feature = "ru")]
+            Language::Ru => "ru",
+            #[cfg(feature = "de")]
+            Language::De => "de",
+            #[cfg(feature = "es")]
+            Language::Es => "es",
+            #[cfg(feature = "fr")]
+            Language::Fr => "fr",
+            #[cfg(feature = "zh")]
+            Language::Zh => "zh",
+            #[cfg(feature = "hy")]
+            Language::Hy => "hy",
+            #[cfg(feature = "ka")]
+            Language::Ka => "ka",
+            #[cfg(feature = "az")]
+            Language::Az => "az",
+            #[cfg(feature = "uz")]
+            Language::Uz => "uz",
+            #[cfg(feature = "uz-cyrl")]
+            Language::UzCyrl => "uz-Cyrl",
+            #[cfg(feature = "ro")]
+            Language::Ro => "ro",
+            #[cfg(feature = "bg")]
+            Language::Bg => "bg",
+            #[cfg(feature = "he")]
+            Language::He => "he",
+            #[cfg(feature = "uk")]
+            Language::Uk => "uk",
+            #[cfg(feature = "pl")]
+            Language::Pl => "pl",
+            #[cfg(feature = "ja")]
+            Language::Ja => "ja",
+            #[cfg(feature = "pt")]
+            Language::Pt => "pt",
+            #[cfg(feature = "it")]
+            Language::It => "it",
+            #[cfg(feature = "tr")]
+            Language::Tr => "tr",
+            #[cfg(feature = "ar")]
+            Language::Ar => "ar",
+        }
+    }
+
+    /// Returns the bundle parser of the language for the specified
+    /// [`DateOrder`], see [`bundle_for`].
+    #[cfg_attr(not(feature = "en"), allow(unused_variables))]
+    pub(crate) fn bundle(self, order: DateOrder) -> ContextFn {
+        #[allow(unused_imports)]
+        use crate::i18n::*;
+
+        match self {
+            #[cfg(feature = "en")]
+            Language::En if order == DateOrder::Mdy => |ctx, input| en::bundle_mdy_with(ctx)(input),
+            #[cfg(feature = "en")]
+            Language::En => |ctx, input| en::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "ru")]
+            Language::Ru => |ctx, input| ru::bundle_with(ctx)(input),
+            #[cfg(feature = "de")]
+            Language::De => |ctx, input| de::bundle_with(ctx)(input),
+            #[cfg(feature = "es")]
+            Language::Es => |ctx, input| es::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "fr")]
+            Language::Fr => |ctx, input| fr::bundle_with(ctx)(input),
+            #[cfg(feature = "zh")]
+            Language::Zh => |ctx, input| zh::bundle_with(ctx)(input),
+            #[cfg(feature = "hy")]
+            Language::Hy => |ctx, input| hy::bundle_with(ctx)(input),
+            #[cfg(feature = "ka")]
+            Language::Ka => |ctx, input| ka::bundle_with(ctx)(input),
+            #[cfg(feature = "az")]
+            Language::Az => |ctx, input| az::bundle_with(ctx)(input),
+            #[cfg(feature = "uz")]
+            Language::Uz => |ctx, input| uz::bundle_with(ctx)(input),
+            #[cfg(feature = "uz-cyrl")]
+            Language::UzCyrl => |ctx, input| uz::cyrl::bundle_with(ctx)(input),
+            #[cfg(feature = "ro")]
+            Language::Ro => |ctx, input| ro::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "bg")]
+            Language::Bg => |ctx, input| bg::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "he")]
+            Language::He => |ctx, input| he::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "uk")]
+            Language::Uk => |ctx, input| uk::bundle_with(ctx)(input),
+            #[cfg(feature = "pl")]
+            Language::Pl => |ctx, input| pl::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "ja")]
+            Language::Ja => |ctx, input| ja::bundle_with(ctx)(input),
+            #[cfg(feature = "pt")]
+            Language::Pt => |ctx, input| pt::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "it")]
+            Language::It => |ctx, input| it::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "tr")]
+            Language::Tr => |ctx, input| tr::bundle_dmy_with(ctx)(input),
+            #[cfg(feature = "ar")]
+            Language::Ar => |ctx, input| ar::bundle_dmy_with(ctx)(input),
+        }
+    }
+}
+
+/// Returns the bundle parser of the specified [`Language`] selected at runtime,
+/// so the applications which receive the locale at runtime (e.g. chat bots)
+/// don't need to import the language modules.
+///
+/// The [`DateOrder`] selects the bundle of the languages which support several
+/// orders of the numeric dates (`English`: [`DateOrder::Mdy`] selects the
+/// [`en::bundle_mdy`](crate::i18n::en::bundle_mdy), the other orders select the
+/// [`en::bundle_dmy`](crate::i18n::en::bundle_dmy)), the other languages
+/// always use their own bundle.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::{bundle_for_with, Language},
+///     numeric::DateOrder,
+/// };
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+/// let language = Language::from_code("en-US").unwrap();
+///
+/// assert_eq!(
+///     bundle_for_with(&ctx, language, DateOrder::Mdy)("07/13/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn bundle_for(
+    language: Language,
+    order: DateOrder,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    let ctx = ParserContext::default();
+    let bundle = language.bundle(order);
+
+    move |input: &str| bundle(&ctx, input)
+}
+
+/// Creates the [`bundle_for`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_for_with(
+    ctx: &ParserContext,
+    language: Language,
+    order: DateOrder,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    let bundle = language.bundle(order);
+
+    move |input: &str| bundle(ctx, input)
+}
+
+#[cfg(all(test, feature = "en"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("en", Some(Language::En))]
+    #[case("EN", Some(Language::En))]
+    #[case("en-US", Some(Language::En))]
+    #[case("en_GB", Some(Language::En))]
+    #[case("english", None)]
+    #[case("", None)]
+    fn test_language_from_code(#[case] code: &str, #[case] expected: Option<Language>) {
+        assert_eq!(Language::from_code(code), expected);
+    }
+
+    #[cfg(all(feature = "uz", feature = "uz-cyrl"))]
+    #[rstest]
+    #[case("uz", Some(Language::Uz))]
+    #[case("uz-Latn", Some(Language::Uz))]
+    #[case("uz-Cyrl-UZ", Some(Language::UzCyrl))]
+    fn test_language_from_code_script(#[case] code: &str, #[case] expected: Option<Language>) {
+        assert_eq!(Language::from_code(code), expected);
+    }
+
+    #[test]
+    fn test_language_code() {
+        for language in Language::all() {
+            assert_eq!(Language::from_code(language.code()), Some(language));
+        }
+    }
+
+    #[rstest]
+    #[case(Language::En, DateOrder::Dmy, "03/04/2024", (2024, 4, 3))]
+    #[case(Language::En, DateOrder::Mdy, "03/04/2024", (2024, 3, 4))]
+    #[case(Language::En, DateOrder::Ymd, "tomorrow", (2024, 7, 17))]
+    #[cfg_attr(feature = "ru", case(Language::Ru, DateOrder::Mdy, "13.07.2024", (2024, 7, 13)))]
+    fn test_bundle_for_with(
+        #[case] language: Language,
+        #[case] order: DateOrder,
+        #[case] input: &str,
+        #[case] expected: (i32, u32, u32),
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+        let (year, month, day) = expected;
+
+        assert_eq!(
+            bundle_for_with(&ctx, language, order)(input),
+            Ok(("", NaiveDate::from_ymd_opt(year, month, day).unwrap()))
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use chrono::{Month, NaiveDate};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `Polish`.
+///
+/// The following words are accepted:
+/// - `sty` -> [`Month::January`]
+/// - `lut` -> [`Month::February`]
+/// - `mar` -> [`Month::March`]
+/// - `kwi` -> [`Month::April`]
+/// - `maj` -> [`Month::May`]
+/// - `cze` -> [`Month::June`]
+/// - `lip` -> [`Month::July`]
+/// - `sie` -> [`Month::August`]
+/// - `wrz` -> [`Month::September`]
+/// - `paź` -> [`Month::October`]
+/// - `lis` -> [`Month::November`]
+/// - `gru` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::pl::short_named_month;
+///
+/// assert_eq!(short_named_month("paź")?.1, Month::October);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("sty")),
+        value(Month::February, tag_no_case("lut")),
+        value(Month::March, tag_no_case("mar")),
+        value(Month::April, tag_no_case("kwi")),
+        value(Month::May, tag_no_case("maj")),
+        value(Month::June, tag_no_case("cze")),
+        value(Month::July, tag_no_case("lip")),
+        value(Month::August, tag_no_case("sie")),
+        value(Month::September, tag_no_case("wrz")),
+        value(Month::October, tag_no_case("paź")),
+        value(Month::November, tag_no_case("lis")),
+        value(Month::December, tag_no_case("gru")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `Polish` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `Polish` in either
+/// the nominative or the genitive case.
+///
+/// The following words are accepted:
+/// - `styczeń` | `stycznia` -> [`Month::January`]
+/// - `luty` | `lutego` -> [`Month::February`]
+/// - `marzec` | `marca` -> [`Month::March`]
+/// - `kwiecień` | `kwietnia` -> [`Month::April`]
+/// - `maj` | `maja` -> [`Month::May`]
+/// - `czerwiec` | `czerwca` -> [`Month::June`]
+/// - `lipiec` | `lipca` -> [`Month::July`]
+/// - `sierpień` | `sierpnia` -> [`Month::August`]
+/// - `wrzesień` | `września` -> [`Month::September`]
+/// - `październik` | `października` -> [`Month::October`]
+/// - `listopad` | `listopada` -> [`Month::November`]
+/// - `grudzień` | `grudnia` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::pl::full_named_month;
+///
+/// assert_eq!(full_named_month("Lipiec")?.1, Month::July);
+/// assert_eq!(full_named_month("stycznia")?.1, Month::January);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        alt((
+            value(Month::January, tag_no_case("styczeń")),
+            value(Month::January, tag_no_case("stycznia")),
+            value(Month::February, tag_no_case("luty")),
+            value(Month::February, tag_no_case("lutego")),
+            value(Month::March, tag_no_case("marzec")),
+            value(Month::March, tag_no_case("marca")),
+            value(Month::April, tag_no_case("kwiecień")),
+            value(Month::April, tag_no_case("kwietnia")),
+            value(Month::May, tag_no_case("maja")),
+            value(Month::May, tag_no_case("maj")),
+            value(Month::June, tag_no_case("czerwiec")),
+            value(Month::June, tag_no_case("czerwca")),
+        )),
+        alt((
+            value(Month::July, tag_no_case("lipiec")),
+            value(Month::July, tag_no_case("lipca")),
+            value(Month::August, tag_no_case("sierpień")),
+            value(Month::August, tag_no_case("sierpnia")),
+            value(Month::September, tag_no_case("wrzesień")),
+            value(Month::September, tag_no_case("września")),
+            value(Month::October, tag_no_case("października")),
+            value(Month::October, tag_no_case("październik")),
+            value(Month::November, tag_no_case("listopada")),
+            value(Month::November, tag_no_case("listopad")),
+            value(Month::December, tag_no_case("grudzień")),
+            value(Month::December, tag_no_case("grudnia")),
+        )),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `Polish`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::pl::named_month;
+///
+/// assert_eq!(named_month("lip.")?.1, Month::July);
+/// assert_eq!(named_month("lipiec")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `Polish` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::pl::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 lipca 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `Polish` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::pl::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 lipca")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("sty", Ok(("", Month::January)))]
+    #[case("PAŹ", Ok(("", Month::October)))]
+    #[case("Gru", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("lut.", Ok(("", Month::February)))]
+    #[case("Wrz.", Ok(("", Month::September)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("styczeń", Ok(("", Month::January)))]
+    #[case("Stycznia", Ok(("", Month::January)))]
+    #[case("maj", Ok(("", Month::May)))]
+    #[case("maja", Ok(("", Month::May)))]
+    #[case("PAŹDZIERNIKA", Ok(("", Month::October)))]
+    #[case("listopad", Ok(("", Month::November)))]
+    #[case("listopada", Ok(("", Month::November)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("mar", Ok(("", Month::March)))]
+    #[case("marca", Ok(("", Month::March)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 lipca 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1-sty-2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 lutego 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 lipca", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 lipca", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 lipca" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
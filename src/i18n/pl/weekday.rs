@@ -0,0 +1,224 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{opt, value},
+    sequence::{preceded, terminated},
+};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Polish`.
+///
+/// The following words are accepted:
+/// - `pon` | `pn` -> [`Weekday::Mon`]
+/// - `wt` -> [`Weekday::Tue`]
+/// - `śr` -> [`Weekday::Wed`]
+/// - `czw` | `cz` -> [`Weekday::Thu`]
+/// - `pt` -> [`Weekday::Fri`]
+/// - `sob` | `sb` -> [`Weekday::Sat`]
+/// - `niedz` | `nd` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::pl::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("pon")?.1, Weekday::Mon);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("pon")),
+        value(Weekday::Mon, tag_no_case("pn")),
+        value(Weekday::Tue, tag_no_case("wt")),
+        value(Weekday::Wed, tag_no_case("śr")),
+        value(Weekday::Thu, tag_no_case("czw")),
+        value(Weekday::Thu, tag_no_case("cz")),
+        value(Weekday::Fri, tag_no_case("pt")),
+        value(Weekday::Sat, tag_no_case("sob")),
+        value(Weekday::Sat, tag_no_case("sb")),
+        value(Weekday::Sun, tag_no_case("niedz")),
+        value(Weekday::Sun, tag_no_case("nd")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Polish` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Polish`.
+///
+/// The following words are accepted:
+/// - `poniedziałek` -> [`Weekday::Mon`]
+/// - `wtorek` -> [`Weekday::Tue`]
+/// - `środa` | `środę` -> [`Weekday::Wed`]
+/// - `czwartek` -> [`Weekday::Thu`]
+/// - `piątek` -> [`Weekday::Fri`]
+/// - `sobota` | `sobotę` -> [`Weekday::Sat`]
+/// - `niedziela` | `niedzielę` -> [`Weekday::Sun`]
+///
+/// The accusative forms are used after the `w` preposition, e.g. `w środę`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::pl::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("poniedziałek")?.1, Weekday::Mon);
+/// assert_eq!(full_named_weekday("Środę")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("poniedziałek")),
+        value(Weekday::Tue, tag_no_case("wtorek")),
+        value(Weekday::Wed, tag_no_case("środa")),
+        value(Weekday::Wed, tag_no_case("środę")),
+        value(Weekday::Thu, tag_no_case("czwartek")),
+        value(Weekday::Fri, tag_no_case("piątek")),
+        value(Weekday::Sat, tag_no_case("sobota")),
+        value(Weekday::Sat, tag_no_case("sobotę")),
+        value(Weekday::Sun, tag_no_case("niedziela")),
+        value(Weekday::Sun, tag_no_case("niedzielę")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Polish`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::pl::named_weekday;
+///
+/// assert_eq!(named_weekday("pt.")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Piątek")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Polish` using the
+/// [`named_weekday`] parser, optionally preceded by the `w` or `we`
+/// preposition (e.g. `w środę`), and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, pl::current_named_weekday_only};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Środa")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// assert_eq!(
+///     current_named_weekday_only("w środę")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = preceded(
+            opt(terminated(
+                alt((tag_no_case("we"), tag_no_case("w"))),
+                space1,
+            )),
+            named_weekday,
+        )(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("pon", Ok(("", Weekday::Mon)))]
+    #[case("WT", Ok(("", Weekday::Tue)))]
+    #[case("Śr", Ok(("", Weekday::Wed)))]
+    #[case("czw", Ok(("", Weekday::Thu)))]
+    #[case("niedz", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("pon.", Ok(("", Weekday::Mon)))]
+    #[case("Sob.", Ok(("", Weekday::Sat)))]
+    #[case("niedz.", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("poniedziałek", Ok(("", Weekday::Mon)))]
+    #[case("Wtorek", Ok(("", Weekday::Tue)))]
+    #[case("ŚRODA", Ok(("", Weekday::Wed)))]
+    #[case("środę", Ok(("", Weekday::Wed)))]
+    #[case("Piątek", Ok(("", Weekday::Fri)))]
+    #[case("niedzielę", Ok(("", Weekday::Sun)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("pon.", Ok(("", Weekday::Mon)))]
+    #[case("sobota", Ok(("", Weekday::Sat)))]
+    #[case("sob", Ok(("", Weekday::Sat)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("pon", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Wtorek", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    #[case("w środę", Ok(("", naive_date_for_weekday(Weekday::Wed).unwrap())))]
+    #[case("we wtorek", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
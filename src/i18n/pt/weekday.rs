@@ -0,0 +1,216 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{opt, value},
+    sequence::{pair, terminated},
+};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Portuguese`.
+///
+/// The following words are accepted:
+/// - `seg` -> [`Weekday::Mon`]
+/// - `ter` -> [`Weekday::Tue`]
+/// - `qua` -> [`Weekday::Wed`]
+/// - `qui` -> [`Weekday::Thu`]
+/// - `sex` -> [`Weekday::Fri`]
+/// - `sáb` | `sab` -> [`Weekday::Sat`]
+/// - `dom` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::pt::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("seg")?.1, Weekday::Mon);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("seg")),
+        value(Weekday::Tue, tag_no_case("ter")),
+        value(Weekday::Wed, tag_no_case("qua")),
+        value(Weekday::Thu, tag_no_case("qui")),
+        value(Weekday::Fri, tag_no_case("sex")),
+        value(Weekday::Sat, tag_no_case("sáb")),
+        value(Weekday::Sat, tag_no_case("sab")),
+        value(Weekday::Sun, tag_no_case("dom")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Portuguese` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` name of the weekday from Monday to Friday
+/// optionally followed by the `-feira` (or ` feira`) suffix, e.g. both
+/// `segunda-feira` and `segunda`.
+fn feira<'a>(name: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    terminated(
+        tag_no_case(name),
+        opt(pair(alt((tag("-"), space1)), tag_no_case("feira"))),
+    )
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Portuguese`.
+///
+/// The following words are accepted:
+/// - `segunda-feira` -> [`Weekday::Mon`]
+/// - `terça-feira` | `terca-feira` -> [`Weekday::Tue`]
+/// - `quarta-feira` -> [`Weekday::Wed`]
+/// - `quinta-feira` -> [`Weekday::Thu`]
+/// - `sexta-feira` -> [`Weekday::Fri`]
+/// - `sábado` | `sabado` -> [`Weekday::Sat`]
+/// - `domingo` -> [`Weekday::Sun`]
+///
+/// The `-feira` suffix may be omitted or separated by the space, e.g.
+/// `segunda` or `segunda feira`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::pt::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("segunda-feira")?.1, Weekday::Mon);
+/// assert_eq!(full_named_weekday("Terça")?.1, Weekday::Tue);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, feira("segunda")),
+        value(Weekday::Tue, feira("terça")),
+        value(Weekday::Tue, feira("terca")),
+        value(Weekday::Wed, feira("quarta")),
+        value(Weekday::Thu, feira("quinta")),
+        value(Weekday::Fri, feira("sexta")),
+        value(Weekday::Sat, tag_no_case("sábado")),
+        value(Weekday::Sat, tag_no_case("sabado")),
+        value(Weekday::Sun, tag_no_case("domingo")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Portuguese`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::pt::named_weekday;
+///
+/// assert_eq!(named_weekday("sex.")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Sexta-feira")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Portuguese` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, pt::current_named_weekday_only};
+///
+/// assert_eq!(
+///     current_named_weekday_only("quarta-feira")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("seg", Ok(("", Weekday::Mon)))]
+    #[case("QUA", Ok(("", Weekday::Wed)))]
+    #[case("Sáb", Ok(("", Weekday::Sat)))]
+    #[case("sab", Ok(("", Weekday::Sat)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("seg.", Ok(("", Weekday::Mon)))]
+    #[case("DOM.", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("segunda-feira", Ok(("", Weekday::Mon)))]
+    #[case("Terça-Feira", Ok(("", Weekday::Tue)))]
+    #[case("terca feira", Ok(("", Weekday::Tue)))]
+    #[case("QUARTA", Ok(("", Weekday::Wed)))]
+    #[case("sexta-feira, 13", Ok((", 13", Weekday::Fri)))]
+    #[case("Sábado", Ok(("", Weekday::Sat)))]
+    #[case("domingo", Ok(("", Weekday::Sun)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("qui", Ok(("", Weekday::Thu)))]
+    #[case("Quinta-feira", Ok(("", Weekday::Thu)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("seg", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("terça-feira", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
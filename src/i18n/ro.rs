@@ -0,0 +1,113 @@
+mod month;
+mod relative;
+mod weekday;
+
+use chrono::NaiveDate;
+
+use crate::{context::ParserContext, i18n::LanguageSpec, types::IResult};
+
+pub use self::{month::*, relative::*, weekday::*};
+
+/// The weekday and month names and the relative words in `Romanian` recognized
+/// by the [`bundle_dmy`] parser, see [`LanguageSpec`].
+///
+/// The forms are the same as the ones of the [`named_weekday`],
+/// [`named_month`] and relative word parsers (e.g. [`yesterday`]).
+pub const SPEC: LanguageSpec<'static> = LanguageSpec::new(
+    [
+        &["luni", "lun", "lun."],
+        &["marți", "marţi", "marti", "mar", "mar."],
+        &["miercuri", "mie", "mie."],
+        &["joi", "joi."],
+        &["vineri", "vin", "vin."],
+        &["sâmbătă", "sambata", "sâm", "sâm.", "sam", "sam."],
+        &["duminică", "duminica", "dum", "dum."],
+    ],
+    [
+        &["ianuarie", "ian", "ian."],
+        &["februarie", "feb", "feb."],
+        &["martie", "mar", "mar."],
+        &["aprilie", "apr", "apr."],
+        &["mai", "mai."],
+        &["iunie", "iun", "iun."],
+        &["iulie", "iul", "iul."],
+        &["august", "aug", "aug."],
+        &["septembrie", "sept", "sept.", "sep", "sep."],
+        &["octombrie", "oct", "oct."],
+        &["noiembrie", "noi", "noi."],
+        &["decembrie", "dec", "dec."],
+    ],
+)
+.with_relative_days(&[
+    ("alaltăieri", -2),
+    ("alaltaieri", -2),
+    ("ieri", -1),
+    ("azi", 0),
+    ("astăzi", 0),
+    ("astazi", 0),
+    ("mâine", 1),
+    ("maine", 1),
+    ("poimâine", 2),
+    ("poimaine", 2),
+]);
+
+/// Recognizes the `numeric` and `language-specific` dates in `Romanian` using
+/// the [`LanguageSpec::bundle_with`] parser with the [`SPEC`] tables. Uses the
+/// `day-month-year` sequence, so the same dates as the following parsers are
+/// recognized:
+/// - Numeric date parsers:
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
+/// - Language-specific
+///     - [`dd_month_y4`]
+///     - [`dd_month_only`]
+///     - [`day_before_yesterday`]
+///     - [`yesterday`]
+///     - [`today`]
+///     - [`tomorrow`]
+///     - [`day_after_tomorrow`]
+///     - [`current_named_weekday_only`]
+///
+/// The numeric and named month dates may be prefixed with the weekday (e.g.
+/// `vin 13.09`), which is consumed but not validated, see
+/// [`weekday_prefixed`](crate::i18n::weekday_prefixed).
+///
+/// If the specified date doesn't exist, returns `nom::Err::Error`
+#[cfg(feature = "clock")]
+pub fn bundle_dmy(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle_dmy`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_dmy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| SPEC.bundle_with(ctx)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Datelike, Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1", Ok(("", Local::now().date_naive().with_day(1).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 12, 3).unwrap())))]
+    #[case("13.06.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("13 iulie 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 iulie", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("vin. 13 sept. 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("alaltăieri", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("Ieri", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("azi", Ok(("", Local::now().date_naive())))]
+    #[case("Mâine", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("poimâine", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_bundle_dmy(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(bundle_dmy(input), expected)
+    }
+}
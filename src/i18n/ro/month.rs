@@ -0,0 +1,224 @@
+use chrono::{Month, NaiveDate};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `Romanian`.
+///
+/// The following words are accepted:
+/// - `ian` -> [`Month::January`]
+/// - `feb` -> [`Month::February`]
+/// - `mar` -> [`Month::March`]
+/// - `apr` -> [`Month::April`]
+/// - `mai` -> [`Month::May`]
+/// - `iun` -> [`Month::June`]
+/// - `iul` -> [`Month::July`]
+/// - `aug` -> [`Month::August`]
+/// - `sep` | `sept` -> [`Month::September`]
+/// - `oct` -> [`Month::October`]
+/// - `noi` -> [`Month::November`]
+/// - `dec` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::ro::short_named_month;
+///
+/// assert_eq!(short_named_month("iul")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("ian")),
+        value(Month::February, tag_no_case("feb")),
+        value(Month::March, tag_no_case("mar")),
+        value(Month::April, tag_no_case("apr")),
+        value(Month::May, tag_no_case("mai")),
+        value(Month::June, tag_no_case("iun")),
+        value(Month::July, tag_no_case("iul")),
+        value(Month::August, tag_no_case("aug")),
+        value(Month::September, tag_no_case("sept")),
+        value(Month::September, tag_no_case("sep")),
+        value(Month::October, tag_no_case("oct")),
+        value(Month::November, tag_no_case("noi")),
+        value(Month::December, tag_no_case("dec")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `Romanian` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `Romanian`.
+///
+/// The following words are accepted:
+/// - `ianuarie` -> [`Month::January`]
+/// - `februarie` -> [`Month::February`]
+/// - `martie` -> [`Month::March`]
+/// - `aprilie` -> [`Month::April`]
+/// - `mai` -> [`Month::May`]
+/// - `iunie` -> [`Month::June`]
+/// - `iulie` -> [`Month::July`]
+/// - `august` -> [`Month::August`]
+/// - `septembrie` -> [`Month::September`]
+/// - `octombrie` -> [`Month::October`]
+/// - `noiembrie` -> [`Month::November`]
+/// - `decembrie` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::ro::full_named_month;
+///
+/// assert_eq!(full_named_month("Iulie")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("ianuarie")),
+        value(Month::February, tag_no_case("februarie")),
+        value(Month::March, tag_no_case("martie")),
+        value(Month::April, tag_no_case("aprilie")),
+        value(Month::May, tag_no_case("mai")),
+        value(Month::June, tag_no_case("iunie")),
+        value(Month::July, tag_no_case("iulie")),
+        value(Month::August, tag_no_case("august")),
+        value(Month::September, tag_no_case("septembrie")),
+        value(Month::October, tag_no_case("octombrie")),
+        value(Month::November, tag_no_case("noiembrie")),
+        value(Month::December, tag_no_case("decembrie")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `Romanian`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::ro::named_month;
+///
+/// assert_eq!(named_month("iul")?.1, Month::July);
+/// assert_eq!(named_month("iulie")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `Romanian` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ro::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 iulie 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `Romanian` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ro::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 iulie")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("ian", Ok(("", Month::January)))]
+    #[case("FEB", Ok(("", Month::February)))]
+    #[case("Dec", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("ian.", Ok(("", Month::January)))]
+    #[case("Dec.", Ok(("", Month::December)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("ianuarie", Ok(("", Month::January)))]
+    #[case("Mai", Ok(("", Month::May)))]
+    #[case("SEPTEMBRIE", Ok(("", Month::September)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("mar", Ok(("", Month::March)))]
+    #[case("martie", Ok(("", Month::March)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 iulie 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1-ian-2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 februarie 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 iulie", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 iulie", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 iulie" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
@@ -0,0 +1,196 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Romanian`.
+///
+/// The following words are accepted:
+/// - `lun` -> [`Weekday::Mon`]
+/// - `mar` -> [`Weekday::Tue`]
+/// - `mie` -> [`Weekday::Wed`]
+/// - `joi` -> [`Weekday::Thu`]
+/// - `vin` -> [`Weekday::Fri`]
+/// - `sâm` | `sam` -> [`Weekday::Sat`]
+/// - `dum` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ro::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("vin")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("lun")),
+        value(Weekday::Tue, tag_no_case("mar")),
+        value(Weekday::Wed, tag_no_case("mie")),
+        value(Weekday::Thu, tag_no_case("joi")),
+        value(Weekday::Fri, tag_no_case("vin")),
+        value(Weekday::Sat, tag_no_case("sâm")),
+        value(Weekday::Sat, tag_no_case("sam")),
+        value(Weekday::Sun, tag_no_case("dum")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Romanian` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Romanian`.
+///
+/// The following words are accepted:
+/// - `luni` -> [`Weekday::Mon`]
+/// - `marți` | `marţi` | `marti` -> [`Weekday::Tue`]
+/// - `miercuri` -> [`Weekday::Wed`]
+/// - `joi` -> [`Weekday::Thu`]
+/// - `vineri` -> [`Weekday::Fri`]
+/// - `sâmbătă` | `sambata` -> [`Weekday::Sat`]
+/// - `duminică` | `duminica` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ro::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("miercuri")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("luni")),
+        value(Weekday::Tue, tag_no_case("marți")),
+        value(Weekday::Tue, tag_no_case("marţi")),
+        value(Weekday::Tue, tag_no_case("marti")),
+        value(Weekday::Wed, tag_no_case("miercuri")),
+        value(Weekday::Thu, tag_no_case("joi")),
+        value(Weekday::Fri, tag_no_case("vineri")),
+        value(Weekday::Sat, tag_no_case("sâmbătă")),
+        value(Weekday::Sat, tag_no_case("sambata")),
+        value(Weekday::Sun, tag_no_case("duminică")),
+        value(Weekday::Sun, tag_no_case("duminica")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Romanian`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ro::named_weekday;
+///
+/// assert_eq!(named_weekday("vin")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Vineri")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Romanian` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, ro::current_named_weekday_only};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Miercuri")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("lun", Ok(("", Weekday::Mon)))]
+    #[case("MAR", Ok(("", Weekday::Tue)))]
+    #[case("Mie", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("lun.", Ok(("", Weekday::Mon)))]
+    #[case("MAR.", Ok(("", Weekday::Tue)))]
+    #[case("Mie.", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("luni", Ok(("", Weekday::Mon)))]
+    #[case("Marți", Ok(("", Weekday::Tue)))]
+    #[case("MIERCURI", Ok(("", Weekday::Wed)))]
+    #[case("sambata", Ok(("", Weekday::Sat)))]
+    #[case("Marţi", Ok(("", Weekday::Tue)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("lun", Ok(("", Weekday::Mon)))]
+    #[case("marți", Ok(("", Weekday::Tue)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("lun", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Marți", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
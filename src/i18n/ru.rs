@@ -1,45 +1,197 @@
+mod business;
+#[cfg(feature = "holidays")]
+pub mod holiday;
+mod month;
+mod recurrence;
 mod relative;
+mod season;
 mod weekday;
+mod weekend;
 
-use chrono::NaiveDate;
-use nom::branch::alt;
+use alloc::vec::Vec;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use nom::{branch::alt, character::complete::space1, combinator::value, sequence::tuple};
 
 use crate::{
-    numeric::{dd_mm_only, dd_mm_y4, dd_only},
+    combinators::{optional_suffix, tag_no_case},
+    context::{ParserContext, TrailingInput},
+    i18n::{dmy_named_month_with, weekday_prefixed},
+    list::{self, date_list, shared_month_list_with},
+    numeric::dd_mm_y2_with,
+    time::date_time,
     types::IResult,
 };
 
-pub use self::{relative::*, weekday::*};
+pub use self::{
+    business::*, month::*, recurrence::*, relative::*, season::*, weekday::*, weekend::*,
+};
 
 /// Uses the following parsers to recognize the `numeric` and
 /// `language-specific` dates in `Russian`:
 /// - Numeric date parsers:
-///     - [`dd_mm_y4`]
-///     - [`dd_mm_only`]
-///     - [`dd_only`]
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_y2`](crate::numeric::dd_mm_y2)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
 /// - Language-specific
+///     - [`dd_month_y4`]
+///     - [`dd_month_only`]
+///     - [`dd_month_current_year`]
 ///     - [`day_before_yesterday`]
 ///     - [`yesterday`]
 ///     - [`today`]
 ///     - [`tomorrow`]
 ///     - [`day_after_tomorrow`]
+///     - [`in_duration`]
+///     - [`duration_ago`]
+///     - [`calendar_week`]
+///     - [`month_part`]
+///     - [`relative_period`]
+///     - [`nth_weekday_of_month`]
+///     - [`day_of_month`]
+///     - [`last_named_weekday`]
+///     - [`this_named_weekday`]
+///     - [`next_named_weekday`]
 ///     - [`current_named_weekday_only`]
 ///
+/// The numeric and named month dates may be prefixed with the weekday (e.g.
+/// `пт 13.09`), which is consumed but not validated, see
+/// [`weekday_prefixed`], and followed by the [`year_suffix`] (e.g.
+/// `13 июля 2024 года`).
+///
 /// If the specified date doesn't exist, returns `nom::Err::Error`
+#[cfg(feature = "clock")]
 pub fn bundle(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(alt((
+            duration_ago_with(ctx),
+            calendar_week_with(ctx),
+            month_part_with(ctx),
+            relative_period_with(ctx),
+            nth_weekday_of_month_with(ctx),
+            day_of_month_with(ctx),
+            optional_suffix(
+                weekday_prefixed(
+                    named_weekday,
+                    alt((
+                        dd_month_current_year_with(ctx),
+                        dd_mm_y2_with(ctx),
+                        dmy_named_month_with(ctx, named_month),
+                    )),
+                ),
+                year_suffix,
+            ),
+            day_before_yesterday_with(ctx),
+            yesterday_with(ctx),
+            today_with(ctx),
+            tomorrow_with(ctx),
+            day_after_tomorrow_with(ctx),
+            in_duration_with(ctx),
+            last_named_weekday_with(ctx),
+            this_named_weekday_with(ctx),
+            next_named_weekday_with(ctx),
+            current_named_weekday_only_with(ctx),
+        )))(input)
+    }
+}
+
+/// Uses the [`bundle`] parser to recognize the date, optionally followed by
+/// the time (see [`trailing_time`](crate::time::trailing_time)), and returns
+/// the corresponding [`NaiveDateTime`]. If the time is absent, uses midnight.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ru::date_time_bundle;
+///
+/// assert_eq!(
+///     date_time_bundle("13 июля 2024 14:30")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(14, 30, 0)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn date_time_bundle(input: &str) -> IResult<&str, NaiveDateTime> {
+    date_time_bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`date_time_bundle`] parser which uses the reference date and
+/// the trailing input handling of the specified [`ParserContext`].
+pub fn date_time_bundle_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDateTime> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| ctx.trailing(date_time(bundle_with(&date_ctx)))(input)
+}
+
+/// Recognizes a separator between the dates of the list: either the
+/// `case insensitive` word `и` surrounded by spaces or the
+/// [`list_separator`](list::list_separator).
+pub fn list_separator(input: &str) -> IResult<&str, ()> {
     alt((
-        dd_mm_y4,
-        dd_mm_only,
-        dd_only,
-        day_before_yesterday,
-        yesterday,
-        today,
-        tomorrow,
-        day_after_tomorrow,
-        current_named_weekday_only,
+        value((), tuple((space1, tag_no_case("и"), space1))),
+        list::list_separator,
     ))(input)
 }
 
+/// Recognizes the list of dates in `Russian` (e.g. `13.07, 15.07 и 20.07` or
+/// `пн, ср и пт`) and returns the dates in the order of the input:
+/// - the dates with the shared named month and year (e.g. `13, 15 и 20 июля` or
+///   `30 июня, 2 и 5 июля 2024 года`), see [`shared_month_list_with`]
+/// - the dates recognized by the [`bundle`] parser, see [`date_list`]
+///
+/// The dates are separated by the [`list_separator`], the shared year may be
+/// followed by the [`year_suffix`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ru::dates_list;
+///
+/// assert_eq!(
+///     dates_list("13, 15 и 20 июля 2024 года")?.1,
+///     vec![
+///         NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dates_list(input: &str) -> IResult<&str, Vec<NaiveDate>> {
+    dates_list_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dates_list`] parser which uses the reference date and the
+/// trailing input handling of the specified [`ParserContext`].
+pub fn dates_list_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, Vec<NaiveDate>> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| {
+        ctx.trailing(alt((
+            optional_suffix(
+                shared_month_list_with(ctx, list_separator, named_month),
+                year_suffix,
+            ),
+            date_list(list_separator, bundle_with(&date_ctx)),
+        )))(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::ops::{Add, Sub};
@@ -55,6 +207,8 @@ mod tests {
     #[case("09", Ok(("", Local::now().date_naive().with_day(9).unwrap())))]
     #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 12, 3).unwrap())))]
     #[case("13    06\t2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("13 июля 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 июля", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
     #[case("позавчера", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
     #[case("Вчера", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
     #[case("Сегодня", Ok(("", Local::now().date_naive())))]
@@ -63,4 +217,89 @@ mod tests {
     fn test_bundle(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
         assert_eq!(bundle(input), expected)
     }
+
+    #[rstest]
+    #[case("09", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 9).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 3).unwrap())))]
+    #[case("позавчера", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 14).unwrap())))]
+    #[case("Сегодня", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("послезавтра", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap())))]
+    #[case("Пятница", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("пт 13.09", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("Пт. 13.09.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("в следующую пятницу", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap())))]
+    #[case("через 3 дня", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("5 дней назад", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 11).unwrap())))]
+    #[case("прошлый вторник", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 9).unwrap())))]
+    #[case("в эту субботу", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("13 июля 2024 года", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1 сентября 2024 г.", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 1).unwrap())))]
+    #[case("пятница, 13 сентября 2024 г.", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("13.09.2024 г.", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("13 июля 2024 г., Москва", Ok((", Москва", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("29-я неделя", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("13.07.24г", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07.24 г.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07.24", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07.2024г.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07с.г.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("5 сентября с. г.", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 5).unwrap())))]
+    #[case("пт, 13.09 с.г.", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("5 числа следующего месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 5).unwrap())))]
+    #[case("13 июля следующего года", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 13).unwrap())))]
+    fn test_bundle_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("13.07.2024 14:30", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 30, 0).unwrap())))]
+    #[case("послезавтра 09:00:15", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap().and_hms_opt(9, 0, 15).unwrap())))]
+    #[case("13 июля", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(0, 0, 0).unwrap())))]
+    #[case("13 июля 2024 г. 14:30", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 30, 0).unwrap())))]
+    fn test_date_time_bundle_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDateTime>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(date_time_bundle_with(&ctx)(input), expected)
+    }
+
+    fn dates(dates: &[(i32, u32, u32)]) -> Vec<NaiveDate> {
+        dates
+            .iter()
+            .map(|&(year, month, day)| NaiveDate::from_ymd_opt(year, month, day).unwrap())
+            .collect()
+    }
+
+    #[rstest]
+    #[case(" и ", Ok(("", ())))]
+    #[case(" И 20", Ok(("20", ())))]
+    #[case(", 20", Ok(("20", ())))]
+    #[case(
+        " или ",
+        Err(nom::Err::Error(crate::error::Error::Nom("или ", nom::error::ErrorKind::Char)))
+    )]
+    fn test_list_separator(#[case] input: &str, #[case] expected: IResult<&str, ()>) {
+        assert_eq!(list_separator(input), expected)
+    }
+
+    #[rstest]
+    #[case("13.07, 15.07 и 20.07", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15), (2024, 7, 20)]))))]
+    #[case("пн, ср и пт", Ok(("", dates(&[(2024, 7, 15), (2024, 7, 17), (2024, 7, 19)]))))]
+    #[case("13, 15 и 20 июля", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15), (2024, 7, 20)]))))]
+    #[case("30 июня, 2 и 5 июля 2025 года", Ok(("", dates(&[(2025, 6, 30), (2025, 7, 2), (2025, 7, 5)]))))]
+    #[case("сегодня и завтра", Ok(("", dates(&[(2024, 7, 16), (2024, 7, 17)]))))]
+    #[case("13 июля, Москва", Ok((", Москва", dates(&[(2024, 7, 13)]))))]
+    #[case(
+        "13 и 31 июня",
+        Err(nom::Err::Error(crate::error::Error::NonExistentDate))
+    )]
+    fn test_dates_list_with(#[case] input: &str, #[case] expected: IResult<&str, Vec<NaiveDate>>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(dates_list_with(&ctx)(input), expected)
+    }
 }
@@ -0,0 +1,191 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    character::complete::{digit1, space1},
+    combinator::{map, map_res, opt, value},
+    sequence::{pair, preceded, terminated, tuple},
+};
+
+use crate::{
+    combinators::{tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n,
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` relative business day in `Russian` in
+/// the nominative or accusative case with the optional preposition `в`: the
+/// `следующий`, `ближайший`, `предыдущий` or `прошлый` modifier followed by
+/// the words `рабочий день`. Returns the number of business days (`1` or
+/// `-1`).
+fn modified_business_day(input: &str) -> IResult<&str, i64> {
+    preceded(
+        opt(pair(tag_no_case("в"), space1)),
+        terminated(
+            alt((
+                value(1, alt((tag_no_case("следующий"), tag_no_case("ближайший")))),
+                value(-1, alt((tag_no_case("предыдущий"), tag_no_case("прошлый")))),
+            )),
+            tuple((
+                space1,
+                tag_no_case("рабочий"),
+                space1,
+                tag_no_case("день"),
+                word_boundary,
+            )),
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` relative business day in `Russian` (e.g.
+/// `следующий рабочий день` or `в предыдущий рабочий день`) and returns the
+/// nearest business day after or before the reference date, skipping the
+/// weekends and the holidays of the
+/// [`BusinessCalendar`](crate::calendar::BusinessCalendar) of the context, see
+/// [`business_day`](i18n::business_day).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Weekday};
+/// use nom_date_parsers::i18n::ru::business_day;
+///
+/// assert!(!matches!(
+///     business_day("следующий рабочий день")?.1.weekday(),
+///     Weekday::Sat | Weekday::Sun
+/// ));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn business_day(input: &str) -> IResult<&str, NaiveDate> {
+    business_day_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`business_day`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn business_day_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::business_day(ctx, modified_business_day)(input)
+}
+
+/// Recognizes the `case insensitive` word `через` followed by the number of
+/// business days in `Russian`: the optional number (one by default) followed
+/// by the words `рабочий день`, `рабочих дня` or `рабочих дней`, e.g. `через 2
+/// рабочих дня`.
+fn business_days_duration(input: &str) -> IResult<&str, i64> {
+    preceded(
+        pair(tag_no_case("через"), space1),
+        map(
+            terminated(
+                opt(terminated(map_res(digit1, |s: &str| s.parse()), space1)),
+                tuple((
+                    alt((tag_no_case("рабочий"), tag_no_case("рабочих"))),
+                    space1,
+                    alt((tag_no_case("дней"), tag_no_case("дня"), tag_no_case("день"))),
+                    word_boundary,
+                )),
+            ),
+            |days| days.unwrap_or(1),
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` word `через` followed by the number of
+/// business days in `Russian` (e.g. `через 2 рабочих дня` or `через рабочий
+/// день`) and returns the reference date shifted by this number of business
+/// days, skipping the weekends and the holidays of the
+/// [`BusinessCalendar`](crate::calendar::BusinessCalendar) of the context, see
+/// [`business_day`](i18n::business_day).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, i18n::ru::in_business_days_with};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap());
+///
+/// assert_eq!(
+///     in_business_days_with(&ctx)("через 2 рабочих дня")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 23).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn in_business_days(input: &str) -> IResult<&str, NaiveDate> {
+    in_business_days_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`in_business_days`] parser which uses the reference date and
+/// the business calendar of the specified [`ParserContext`].
+pub fn in_business_days_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::business_day(ctx, business_days_duration)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::calendar::BusinessCalendar;
+
+    #[rstest]
+    #[case("следующий рабочий день", (2024, 7, 16), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("Следующий рабочий день", (2024, 7, 19), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap())))]
+    #[case("в ближайший рабочий день", (2024, 7, 20), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap())))]
+    #[case("предыдущий рабочий день", (2024, 7, 22), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("в прошлый рабочий день, ok", (2024, 7, 17), Ok((", ok", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    fn test_business_day_with(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap());
+
+        assert_eq!(business_day_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("рабочий день")]
+    #[case("следующий день")]
+    #[case("следующий рабочий денёк")]
+    fn test_business_day_with_error(#[case] input: &str) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert!(business_day_with(&ctx)(input).is_err());
+    }
+
+    #[rstest]
+    #[case("через 2 рабочих дня", (2024, 7, 19), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("Через 5 рабочих дней", (2024, 7, 16), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("через рабочий день", (2024, 7, 20), Ok(("", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap())))]
+    #[case("через 1 рабочий день, ok", (2024, 7, 16), Ok((", ok", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    fn test_in_business_days_with(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap());
+
+        assert_eq!(in_business_days_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_in_business_days_with_holidays() {
+        let ctx =
+            ParserContext::new()
+                .with_today(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap())
+                .with_business_calendar(BusinessCalendar::new().with_holidays(
+                    (1..=8).map(|day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap()),
+                ));
+
+        assert_eq!(
+            in_business_days_with(&ctx)("через 2 рабочих дня"),
+            Ok(("", NaiveDate::from_ymd_opt(2025, 1, 9).unwrap()))
+        );
+    }
+}
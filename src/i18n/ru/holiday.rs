@@ -0,0 +1,147 @@
+use chrono::{Month, NaiveDate, Weekday};
+use nom::{
+    character::complete::space1,
+    combinator::{map, opt},
+    sequence::{preceded, terminated},
+};
+
+use super::year_suffix;
+use crate::{
+    context::ParserContext,
+    holiday::{self, HolidayRule, HolidayTable},
+    numeric::{y4, y4_with},
+    types::IResult,
+};
+
+/// Returns the table of the holidays in `Russian`.
+///
+/// The following names are accepted:
+/// - `Новый год` | `Нового года` -> `1 января`
+/// - `Рождество` | `Рождества` -> `7 января`
+/// - `Старый Новый год` -> `14 января`
+/// - `День защитника Отечества` | `23 февраля` -> `23 февраля`
+/// - `Международный женский день` | `8 марта` -> `8 марта`
+/// - `День космонавтики` -> `12 апреля`
+/// - `Праздник Весны и Труда` | `1 мая` -> `1 мая`
+/// - `День Победы` | `9 мая` -> `9 мая`
+/// - `День России` -> `12 июня`
+/// - `День знаний` -> `1 сентября`
+/// - `День народного единства` -> `4 ноября`
+/// - `День матери` -> the last Sunday of November
+///
+/// Register the custom named days of the application using the
+/// [`HolidayTable::register`] method and recognize them with the
+/// [`holiday::holiday`] parser generator and the [`year`] parser.
+pub fn table() -> HolidayTable {
+    HolidayTable::new()
+        .with("новый год", Month::January, 1)
+        .with("нового года", Month::January, 1)
+        .with("рождество", Month::January, 7)
+        .with("рождества", Month::January, 7)
+        .with("старый новый год", Month::January, 14)
+        .with("день защитника отечества", Month::February, 23)
+        .with("23 февраля", Month::February, 23)
+        .with("международный женский день", Month::March, 8)
+        .with("8 марта", Month::March, 8)
+        .with("день космонавтики", Month::April, 12)
+        .with("праздник весны и труда", Month::May, 1)
+        .with("1 мая", Month::May, 1)
+        .with("день победы", Month::May, 9)
+        .with("9 мая", Month::May, 9)
+        .with("день россии", Month::June, 12)
+        .with("день знаний", Month::September, 1)
+        .with("день народного единства", Month::November, 4)
+        .with_rule(
+            "день матери",
+            HolidayRule::NthWeekdayOfMonth {
+                n: -1,
+                weekday: Weekday::Sun,
+                month: Month::November,
+            },
+        )
+}
+
+/// Recognizes the explicit year following the name of the holiday with the
+/// optional [`year_suffix`], e.g. ` 2025 года` in the `9 мая 2025 года`.
+pub fn year(input: &str) -> IResult<&str, i32> {
+    year_by(y4)(input)
+}
+
+/// Creates the [`year`] parser which uses the
+/// [`YearRange`](crate::context::YearRange) of the specified [`ParserContext`].
+pub fn year_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, i32> + '_ {
+    year_by(y4_with(ctx))
+}
+
+/// Creates the [`year`] parser which uses the specified four-digit year parser.
+fn year_by<Y>(y4: Y) -> impl Fn(&str) -> IResult<&str, i32>
+where
+    Y: Fn(&str) -> IResult<&str, u32>,
+{
+    move |input: &str| {
+        map(
+            terminated(preceded(space1, &y4), opt(year_suffix)),
+            |year| year as i32,
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` name of the holiday in `Russian` from the
+/// [`table`] followed by the optional [`year`] and returns the corresponding
+/// [`NaiveDate`]. If the year is omitted, the current year is used.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ru::holiday::holiday;
+///
+/// assert_eq!(
+///     holiday("День Победы")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 5, 9).unwrap()
+/// );
+/// assert_eq!(
+///     holiday("Новый год 2025")?.1,
+///     NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn holiday(input: &str) -> IResult<&str, NaiveDate> {
+    holiday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`holiday()`] parser which uses the reference date and the
+/// [`YearRange`](crate::context::YearRange) of the specified [`ParserContext`].
+pub fn holiday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    let table = table();
+
+    move |input: &str| holiday::holiday(ctx, &table, year_with(ctx))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Новый год", Ok(("", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())))]
+    #[case("старый новый год", Ok(("", NaiveDate::from_ymd_opt(2024, 1, 14).unwrap())))]
+    #[case("Рождество 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 7).unwrap())))]
+    #[case("9 мая", Ok(("", NaiveDate::from_ymd_opt(2024, 5, 9).unwrap())))]
+    #[case("9 мая 2025 года", Ok(("", NaiveDate::from_ymd_opt(2025, 5, 9).unwrap())))]
+    #[case("День народного единства 2023 г.", Ok(("", NaiveDate::from_ymd_opt(2023, 11, 4).unwrap())))]
+    #[case("День матери", Ok(("", NaiveDate::from_ymd_opt(2024, 11, 24).unwrap())))]
+    #[case("8 марта!", Ok(("!", NaiveDate::from_ymd_opt(2024, 3, 8).unwrap())))]
+    #[case(
+        "Рождество 1800",
+        Err(nom::Err::Error(crate::error::Error::YearOutOfRange { value: 1800, input: "1800" }))
+    )]
+    fn test_holiday(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::fixed(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(holiday_with(&ctx)(input), expected);
+    }
+}
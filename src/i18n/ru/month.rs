@@ -0,0 +1,455 @@
+use chrono::{Month, NaiveDate};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space0,
+    combinator::{opt, value},
+    sequence::{preceded, terminated, tuple},
+};
+
+use crate::{
+    combinators::{lookup_no_case, optional_suffix, tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    numeric::dd_mm_only_with,
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `Russian`.
+///
+/// The following words are accepted:
+/// - `янв` -> [`Month::January`]
+/// - `фев` -> [`Month::February`]
+/// - `мар` -> [`Month::March`]
+/// - `апр` -> [`Month::April`]
+/// - `май` | `мая` -> [`Month::May`]
+/// - `июн` -> [`Month::June`]
+/// - `июл` -> [`Month::July`]
+/// - `авг` -> [`Month::August`]
+/// - `сен` | `сент` -> [`Month::September`]
+/// - `окт` -> [`Month::October`]
+/// - `ноя` -> [`Month::November`]
+/// - `дек` -> [`Month::December`]
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::ru::short_named_month;
+///
+/// assert_eq!(short_named_month("июл")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    lookup_no_case(short_month)(input)
+}
+
+/// Returns the month of the lowercase short month name.
+fn short_month(word: &str) -> Option<Month> {
+    Some(match word {
+        "янв" => Month::January,
+        "фев" => Month::February,
+        "мар" => Month::March,
+        "апр" => Month::April,
+        "май" | "мая" => Month::May,
+        "июн" => Month::June,
+        "июл" => Month::July,
+        "авг" => Month::August,
+        "сен" | "сент" => Month::September,
+        "окт" => Month::October,
+        "ноя" => Month::November,
+        "дек" => Month::December,
+        _ => return None,
+    })
+}
+
+/// Recognizes the `case insensitive` short-named month in `Russian` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `Russian`.
+///
+/// The following words are accepted:
+/// - `январь` | `января` -> [`Month::January`]
+/// - `февраль` | `февраля` -> [`Month::February`]
+/// - `март` | `марта` -> [`Month::March`]
+/// - `апрель` | `апреля` -> [`Month::April`]
+/// - `май` | `мая` -> [`Month::May`]
+/// - `июнь` | `июня` -> [`Month::June`]
+/// - `июль` | `июля` -> [`Month::July`]
+/// - `август` | `августа` -> [`Month::August`]
+/// - `сентябрь` | `сентября` -> [`Month::September`]
+/// - `октябрь` | `октября` -> [`Month::October`]
+/// - `ноябрь` | `ноября` -> [`Month::November`]
+/// - `декабрь` | `декабря` -> [`Month::December`]
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::ru::full_named_month;
+///
+/// assert_eq!(full_named_month("Июль")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    lookup_no_case(full_month)(input)
+}
+
+/// Returns the month of the lowercase full month name in the nominative or
+/// genitive case.
+fn full_month(word: &str) -> Option<Month> {
+    Some(match word {
+        "январь" | "января" => Month::January,
+        "февраль" | "февраля" => Month::February,
+        "март" | "марта" => Month::March,
+        "апрель" | "апреля" => Month::April,
+        "май" | "мая" => Month::May,
+        "июнь" | "июня" => Month::June,
+        "июль" | "июля" => Month::July,
+        "август" | "августа" => Month::August,
+        "сентябрь" | "сентября" => Month::September,
+        "октябрь" | "октября" => Month::October,
+        "ноябрь" | "ноября" => Month::November,
+        "декабрь" | "декабря" => Month::December,
+        _ => return None,
+    })
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `Russian`, the words of the [`full_named_month`] and
+/// [`short_named_month`] parsers are looked up at once. The short name may be
+/// followed by the `dot` symbol (see [`short_named_month_dot`]).
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::ru::named_month;
+///
+/// assert_eq!(named_month("июл")?.1, Month::July);
+/// assert_eq!(named_month("июль")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    let (input, (month, short)) = lookup_no_case(|word| {
+        full_month(word)
+            .map(|month| (month, false))
+            .or_else(|| short_month(word).map(|month| (month, true)))
+    })(input)?;
+
+    if short {
+        value(month, opt(tag(".")))(input)
+    } else {
+        Ok((input, month))
+    }
+}
+
+/// Recognizes the `case insensitive` genitive year suffix in `Russian`
+/// preceded by any number of spaces: `года`, `г.` or `г` (e.g. the suffix of
+/// `13 июля 2024 года`).
+///
+/// The suffix must not be followed by a letter or a digit, so the `г` of the
+/// next word (e.g. `где`) isn't consumed.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::i18n::ru::year_suffix;
+///
+/// assert_eq!(year_suffix(" года")?, ("", ()));
+/// assert_eq!(year_suffix(" г., Москва")?, (", Москва", ()));
+/// assert!(year_suffix(" где").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn year_suffix(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        preceded(
+            space0,
+            alt((
+                terminated(tag_no_case("года"), word_boundary),
+                tag_no_case("г."),
+                terminated(tag_no_case("г"), word_boundary),
+            )),
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` abbreviation `с.г.` (`сего года`, i.e.
+/// the current year) in `Russian` preceded by any number of spaces. The space
+/// between the letters and the last dot are optional (e.g. `с. г.` or `с.г`).
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::i18n::ru::current_year_suffix;
+///
+/// assert_eq!(current_year_suffix("с.г.")?, ("", ()));
+/// assert_eq!(current_year_suffix(" С. Г.")?, ("", ()));
+/// assert!(current_year_suffix(" с.").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn current_year_suffix(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        tuple((
+            space0,
+            tag_no_case("с."),
+            space0,
+            tag_no_case("г"),
+            alt((tag("."), value("", word_boundary))),
+        )),
+    )(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `Russian` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser, followed by
+/// the optional [`year_suffix`], and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ru::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 июля 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     dd_month_y4("13 июля 2024 года")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    optional_suffix(dd_named_month_y4(named_month), year_suffix)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `Russian` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ru::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 июля")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+/// Recognizes the `day` and `month` parts in `Russian` (either the named month
+/// or the numeric one, see [`dd_mm_only`](crate::numeric::dd_mm_only))
+/// followed by the [`current_year_suffix`] (e.g. `13 июля с.г.` or
+/// `13.07с.г.`), and returns the corresponding [`NaiveDate`] for the current
+/// year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ru::dd_month_current_year;
+///
+/// assert_eq!(
+///     dd_month_current_year("13.07 с.г.")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_current_year(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_current_year_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_current_year`] parser which takes the year from the
+/// reference date of the specified [`ParserContext`].
+pub fn dd_month_current_year_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        terminated(
+            alt((dd_month_only_with(ctx), dd_mm_only_with(ctx))),
+            current_year_suffix,
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::{combinators::assert_lookup_words, error::Error};
+
+    use super::*;
+
+    #[rstest]
+    #[case("янв", Ok(("", Month::January)))]
+    #[case("ФЕВ", Ok(("", Month::February)))]
+    #[case("Дек", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("янв.", Ok(("", Month::January)))]
+    #[case("Дек.", Ok(("", Month::December)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("январь", Ok(("", Month::January)))]
+    #[case("Май", Ok(("", Month::May)))]
+    #[case("СЕНТЯБРЬ", Ok(("", Month::September)))]
+    #[case("Января", Ok(("", Month::January)))]
+    #[case("МАРТА", Ok(("", Month::March)))]
+    #[case("мая", Ok(("", Month::May)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("мар", Ok(("", Month::March)))]
+    #[case("март", Ok(("", Month::March)))]
+    #[case("СЕНТ. 2024", Ok((" 2024", Month::September)))]
+    #[case("августа", Ok(("", Month::August)))]
+    #[case("мая.", Ok((".", Month::May)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case(" года", Ok(("", ())))]
+    #[case("ГОДА", Ok(("", ())))]
+    #[case(" г.", Ok(("", ())))]
+    #[case(" г", Ok(("", ())))]
+    #[case(" г. в 14:00", Ok((" в 14:00", ())))]
+    #[case(
+        " годах",
+        Err(nom::Err::Error(Error::Nom("одах", nom::error::ErrorKind::Not)))
+    )]
+    #[case(
+        " где",
+        Err(nom::Err::Error(Error::Nom("де", nom::error::ErrorKind::Not)))
+    )]
+    fn test_year_suffix(#[case] input: &str, #[case] expected: IResult<&str, ()>) {
+        assert_eq!(year_suffix(input), expected);
+    }
+
+    #[rstest]
+    #[case("с.г.", Ok(("", ())))]
+    #[case(" С.Г.", Ok(("", ())))]
+    #[case(" с. г.", Ok(("", ())))]
+    #[case(" с.г, в 14:00", Ok((", в 14:00", ())))]
+    #[case(
+        " с.годом",
+        Err(nom::Err::Error(Error::Nom("одом", nom::error::ErrorKind::Not)))
+    )]
+    fn test_current_year_suffix(#[case] input: &str, #[case] expected: IResult<&str, ()>) {
+        assert_eq!(current_year_suffix(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 июля 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 июля 2024 года", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 июля 2024 г.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 июля 2024г", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 июля 2024 где", Ok((" где", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1-янв-2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 февраля 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 июля", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 июля", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 июля" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+
+    #[rstest]
+    #[case("13.07с.г.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13/07 с. г.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 июля с.г.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("29 февраля с.г.", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case("31.02 с.г.", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_current_year_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(dd_month_current_year_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_lookup_words() {
+        assert_lookup_words(
+            short_named_month,
+            &[
+                "янв", "фев", "мар", "апр", "май", "мая", "июн", "июл", "авг", "сен", "сент",
+                "окт", "ноя", "дек",
+            ],
+        );
+        assert_lookup_words(
+            full_named_month,
+            &[
+                "январь",
+                "января",
+                "февраль",
+                "февраля",
+                "март",
+                "марта",
+                "апрель",
+                "апреля",
+                "май",
+                "мая",
+                "июнь",
+                "июня",
+                "июль",
+                "июля",
+                "август",
+                "августа",
+                "сентябрь",
+                "сентября",
+                "октябрь",
+                "октября",
+                "ноябрь",
+                "ноября",
+                "декабрь",
+                "декабря",
+            ],
+        );
+    }
+}
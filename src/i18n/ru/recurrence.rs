@@ -0,0 +1,297 @@
+use chrono::Weekday;
+use nom::{
+    branch::alt,
+    character::complete::{digit1, space1},
+    combinator::{map, map_opt, map_res, opt, value, verify},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+};
+
+use super::{full_named_weekday_accusative, named_weekday, period};
+use crate::{
+    combinators::{lookup_no_case, tag_no_case, word_boundary},
+    i18n::Period,
+    numeric::dd,
+    recurrence::{Frequency, Recurrence},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` full-named weekday in `Russian` in the
+/// plural dative case used after the preposition `по`.
+///
+/// The following words are accepted:
+/// - `понедельникам` -> [`Weekday::Mon`]
+/// - `вторникам` -> [`Weekday::Tue`]
+/// - `средам` -> [`Weekday::Wed`]
+/// - `четвергам` -> [`Weekday::Thu`]
+/// - `пятницам` -> [`Weekday::Fri`]
+/// - `субботам` -> [`Weekday::Sat`]
+/// - `воскресеньям` -> [`Weekday::Sun`]
+fn full_named_weekday_dative_plural(input: &str) -> IResult<&str, Weekday> {
+    lookup_no_case(|word| match word {
+        "понедельникам" => Some(Weekday::Mon),
+        "вторникам" => Some(Weekday::Tue),
+        "средам" => Some(Weekday::Wed),
+        "четвергам" => Some(Weekday::Thu),
+        "пятницам" => Some(Weekday::Fri),
+        "субботам" => Some(Weekday::Sat),
+        "воскресеньям" => Some(Weekday::Sun),
+        _ => None,
+    })(input)
+}
+
+/// Recognizes the `case insensitive` weekday of the weekly recurrence in
+/// `Russian` preceded by the preposition `по`, e.g. `по понедельникам`.
+fn on_weekdays(input: &str) -> IResult<&str, Weekday> {
+    preceded(
+        pair(tag_no_case("по"), space1),
+        full_named_weekday_dative_plural,
+    )(input)
+}
+
+/// Recognizes the `case insensitive` word `каждый` in `Russian` in any gender
+/// and number in the nominative or accusative case followed by the spaces.
+fn every(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        terminated(
+            alt((
+                tag_no_case("каждый"),
+                tag_no_case("каждая"),
+                tag_no_case("каждое"),
+                tag_no_case("каждую"),
+                tag_no_case("каждые"),
+            )),
+            space1,
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` day of the month in `Russian`: the number
+/// with the optional `-е` or `-го` ending, e.g. `15` or `15-е`.
+fn day(input: &str) -> IResult<&str, u32> {
+    terminated(dd, opt(alt((tag_no_case("-е"), tag_no_case("-го")))))(input)
+}
+
+/// Recognizes the `case insensitive` unit of the recurrence in `Russian`
+/// following the word `каждый` (see [`every`]) and the optional word `второй`:
+/// - `день` -> [`Frequency::Daily`]
+/// - `неделю` with the optional [`on_weekdays`] -> [`Frequency::Weekly`]
+/// - `месяц` -> [`Frequency::Monthly`]
+/// - the [`full_named_weekday_accusative`] or [`named_weekday`] ->
+///   [`Frequency::Weekly`]
+/// - the [`day`] followed by the word `число` -> [`Frequency::Monthly`]
+fn unit(input: &str) -> IResult<&str, Frequency> {
+    alt((
+        value(
+            Frequency::Daily,
+            terminated(tag_no_case("день"), word_boundary),
+        ),
+        map(
+            preceded(
+                terminated(tag_no_case("неделю"), word_boundary),
+                opt(preceded(space1, on_weekdays)),
+            ),
+            Frequency::Weekly,
+        ),
+        value(
+            Frequency::Monthly(None),
+            terminated(tag_no_case("месяц"), word_boundary),
+        ),
+        map(
+            terminated(
+                alt((full_named_weekday_accusative, named_weekday)),
+                word_boundary,
+            ),
+            |weekday| Frequency::Weekly(Some(weekday)),
+        ),
+        map(
+            terminated(day, pair(space1, tag_no_case("число"))),
+            |day| Frequency::Monthly(Some(day)),
+        ),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` word `второй` in `Russian` in any gender
+/// in the nominative or accusative case followed by the spaces, which means
+/// every other unit.
+fn second(input: &str) -> IResult<&str, u32> {
+    value(
+        2,
+        terminated(
+            alt((
+                tag_no_case("второй"),
+                tag_no_case("вторая"),
+                tag_no_case("второе"),
+                tag_no_case("вторую"),
+            )),
+            space1,
+        ),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` number of the periods between the
+/// occurrences in `Russian`, e.g. `3 дня` or `2 недели`, and returns the
+/// corresponding [`Recurrence`].
+fn periods(input: &str) -> IResult<&str, Recurrence> {
+    map_opt(
+        separated_pair(
+            verify(map_res(digit1, |s: &str| s.parse()), |&interval| {
+                interval > 0
+            }),
+            space1,
+            period,
+        ),
+        |(interval, period)| {
+            let frequency = match period {
+                Period::Day => Frequency::Daily,
+                Period::Week => Frequency::Weekly(None),
+                Period::Month => Frequency::Monthly(None),
+                _ => return None,
+            };
+            Some(Recurrence::new(frequency).with_interval(interval))
+        },
+    )(input)
+}
+
+/// Recognizes the `case insensitive` recurring date in `Russian` and returns
+/// the corresponding [`Recurrence`]. The following phrases are accepted:
+/// - `ежедневно`, `еженедельно [по <weekday>]` or `ежемесячно`, e.g.
+///   `еженедельно по пятницам`
+/// - `по <weekday>`, e.g. `по понедельникам`
+/// - `каждый [второй] <unit>` in any gender, where the `unit` is either the
+///   weekday or the `день`, `неделю [по <weekday>]`, `месяц` or `<day> число`,
+///   e.g. `каждый вторник`, `каждую вторую пятницу` or `каждое 15 число`
+/// - `каждые <number> <period>` with the `дней`, `недель` or `месяцев`
+///   [`period`] in any form, e.g. `каждые 3 дня`
+/// - `<day> [числа] каждого месяца`, e.g. `15-го числа каждого месяца`
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::{
+///     i18n::ru::recurrence,
+///     recurrence::{Frequency, Recurrence},
+/// };
+///
+/// assert_eq!(
+///     recurrence("каждый вторник")?.1,
+///     Recurrence::new(Frequency::Weekly(Some(Weekday::Tue)))
+/// );
+/// assert_eq!(
+///     recurrence("каждое 15 число")?.1,
+///     Recurrence::new(Frequency::Monthly(Some(15)))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn recurrence(input: &str) -> IResult<&str, Recurrence> {
+    alt((
+        value(
+            Recurrence::new(Frequency::Daily),
+            terminated(tag_no_case("ежедневно"), word_boundary),
+        ),
+        map(
+            preceded(
+                terminated(tag_no_case("еженедельно"), word_boundary),
+                opt(preceded(space1, on_weekdays)),
+            ),
+            |weekday| Recurrence::new(Frequency::Weekly(weekday)),
+        ),
+        value(
+            Recurrence::new(Frequency::Monthly(None)),
+            terminated(tag_no_case("ежемесячно"), word_boundary),
+        ),
+        map(on_weekdays, |weekday| {
+            Recurrence::new(Frequency::Weekly(Some(weekday)))
+        }),
+        preceded(
+            every,
+            alt((
+                periods,
+                map(pair(opt(second), unit), |(interval, frequency)| {
+                    Recurrence::new(frequency).with_interval(interval.unwrap_or(1))
+                }),
+            )),
+        ),
+        map(
+            terminated(
+                day,
+                tuple((
+                    space1,
+                    opt(pair(tag_no_case("числа"), space1)),
+                    tag_no_case("каждого"),
+                    space1,
+                    tag_no_case("месяца"),
+                )),
+            ),
+            |day| Recurrence::new(Frequency::Monthly(Some(day))),
+        ),
+    ))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{combinators::assert_lookup_words, error::Error};
+
+    fn every(frequency: Frequency, interval: u32) -> Recurrence {
+        Recurrence::new(frequency).with_interval(interval)
+    }
+
+    #[rstest]
+    #[case("ежедневно", Ok(("", every(Frequency::Daily, 1))))]
+    #[case("Еженедельно", Ok(("", every(Frequency::Weekly(None), 1))))]
+    #[case("еженедельно по пятницам", Ok(("", every(Frequency::Weekly(Some(Weekday::Fri)), 1))))]
+    #[case("ежемесячно", Ok(("", every(Frequency::Monthly(None), 1))))]
+    #[case("по понедельникам", Ok(("", every(Frequency::Weekly(Some(Weekday::Mon)), 1))))]
+    #[case("каждый день", Ok(("", every(Frequency::Daily, 1))))]
+    #[case("каждый второй день", Ok(("", every(Frequency::Daily, 2))))]
+    #[case("каждые 3 дня", Ok(("", every(Frequency::Daily, 3))))]
+    #[case("Каждый вторник", Ok(("", every(Frequency::Weekly(Some(Weekday::Tue)), 1))))]
+    #[case("каждую пятницу", Ok(("", every(Frequency::Weekly(Some(Weekday::Fri)), 1))))]
+    #[case("каждое воскресенье", Ok(("", every(Frequency::Weekly(Some(Weekday::Sun)), 1))))]
+    #[case("каждую вторую среду", Ok(("", every(Frequency::Weekly(Some(Weekday::Wed)), 2))))]
+    #[case("каждый пн", Ok(("", every(Frequency::Weekly(Some(Weekday::Mon)), 1))))]
+    #[case("каждую неделю", Ok(("", every(Frequency::Weekly(None), 1))))]
+    #[case("каждую неделю по средам", Ok(("", every(Frequency::Weekly(Some(Weekday::Wed)), 1))))]
+    #[case("каждые 2 недели", Ok(("", every(Frequency::Weekly(None), 2))))]
+    #[case("каждый месяц", Ok(("", every(Frequency::Monthly(None), 1))))]
+    #[case("каждые 3 месяца", Ok(("", every(Frequency::Monthly(None), 3))))]
+    #[case("каждое 15 число", Ok(("", every(Frequency::Monthly(Some(15)), 1))))]
+    #[case("каждое 15-е число", Ok(("", every(Frequency::Monthly(Some(15)), 1))))]
+    #[case("15 числа каждого месяца", Ok(("", every(Frequency::Monthly(Some(15)), 1))))]
+    #[case("1-го числа каждого месяца", Ok(("", every(Frequency::Monthly(Some(1)), 1))))]
+    #[case("31 каждого месяца", Ok(("", every(Frequency::Monthly(Some(31)), 1))))]
+    #[case("каждое 32 число", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 число" })))]
+    fn test_recurrence(#[case] input: &str, #[case] expected: IResult<&str, Recurrence>) {
+        assert_eq!(recurrence(input), expected);
+    }
+
+    #[rstest]
+    #[case("каждые 2 года")]
+    #[case("каждые 0 дней")]
+    #[case("каждый второй")]
+    #[case("по пятницу")]
+    fn test_recurrence_error(#[case] input: &str) {
+        assert!(recurrence(input).is_err());
+    }
+
+    #[test]
+    fn test_lookup_words() {
+        assert_lookup_words(
+            full_named_weekday_dative_plural,
+            &[
+                "понедельникам",
+                "вторникам",
+                "средам",
+                "четвергам",
+                "пятницам",
+                "субботам",
+                "воскресеньям",
+            ],
+        );
+    }
+}
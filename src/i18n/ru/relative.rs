@@ -1,9 +1,23 @@
-use std::ops::{Add, Sub};
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    character::complete::{digit1, space1},
+    combinator::{map, map_res, opt, value},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+};
 
-use chrono::{Days, Local, NaiveDate};
-use nom::{bytes::complete::tag_no_case, combinator::value};
-
-use crate::types::IResult;
+use super::{full_named_weekday_accusative, named_month, named_weekday, year_suffix};
+use crate::{
+    combinators::{optional_prefix, tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n::{
+        self, relative_day, relative_duration, week_of_year, y4_mm_month_with, MonthPart, MonthRef,
+        Period,
+    },
+    numeric::{dd, ww, y4},
+    range::DateRange,
+    types::IResult,
+};
 
 /// Recognizes the `case insensitive` word `позавчера` in `Russian` and returns
 /// the corresponding [`NaiveDate`].
@@ -22,11 +36,17 @@ use crate::types::IResult;
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
-    value(
-        Local::now().sub(Days::new(2)).date_naive(),
-        tag_no_case("позавчера"),
-    )(input)
+    day_before_yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_before_yesterday`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_before_yesterday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -2, tag_no_case("позавчера"))(input)
 }
 
 /// Recognizes the `case insensitive` word `вчера` in `Russian` and returns
@@ -46,11 +66,15 @@ pub fn day_before_yesterday(input: &str) -> IResult<&str, NaiveDate> {
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
-    value(
-        Local::now().sub(Days::new(1)).date_naive(),
-        tag_no_case("вчера"),
-    )(input)
+    yesterday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`yesterday`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn yesterday_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, -1, tag_no_case("вчера"))(input)
 }
 
 /// Recognizes the `case insensitive` word `today` in `Russian` and returns
@@ -65,8 +89,15 @@ pub fn yesterday(input: &str) -> IResult<&str, NaiveDate> {
 /// assert_eq!(today("сегодня")?.1, Local::now().date_naive());
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn today(input: &str) -> IResult<&str, NaiveDate> {
-    value(Local::now().date_naive(), tag_no_case("сегодня"))(input)
+    today_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`today`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn today_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| value(ctx.today(), tag_no_case("сегодня"))(input)
 }
 
 /// Recognizes the `case insensitive` word `завтра` in `Russian` and returns the
@@ -86,11 +117,15 @@ pub fn today(input: &str) -> IResult<&str, NaiveDate> {
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
-    value(
-        Local::now().add(Days::new(1)).date_naive(),
-        tag_no_case("завтра"),
-    )(input)
+    tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`tomorrow`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn tomorrow_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 1, tag_no_case("завтра"))(input)
 }
 
 /// Recognizes the `case insensitive` word `послезавтра` in `Russian` and
@@ -110,20 +145,516 @@ pub fn tomorrow(input: &str) -> IResult<&str, NaiveDate> {
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn day_after_tomorrow(input: &str) -> IResult<&str, NaiveDate> {
-    value(
-        Local::now().add(Days::new(2)).date_naive(),
-        tag_no_case("послезавтра"),
+    day_after_tomorrow_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_after_tomorrow`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn day_after_tomorrow_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| relative_day(ctx, 2, tag_no_case("послезавтра"))(input)
+}
+
+/// Recognizes the `case insensitive` unit of the duration in `Russian` in any
+/// form used after the numbers.
+///
+/// The following words are accepted:
+/// - `день` | `дня` | `дней` -> [`Period::Day`]
+/// - `неделя` | `неделю` | `недели` | `недель` -> [`Period::Week`]
+/// - `месяц` | `месяца` | `месяцев` -> [`Period::Month`]
+/// - `год` | `года` | `лет` -> [`Period::Year`]
+///
+/// The unit must be followed by the [`word_boundary`], so `неделями` isn't
+/// recognized as `неделя`.
+pub fn period(input: &str) -> IResult<&str, Period> {
+    terminated(
+        alt((
+            value(Period::Day, tag_no_case("дней")),
+            value(Period::Day, tag_no_case("дня")),
+            value(Period::Day, tag_no_case("день")),
+            value(Period::Week, tag_no_case("недели")),
+            value(Period::Week, tag_no_case("недель")),
+            value(Period::Week, tag_no_case("неделю")),
+            value(Period::Week, tag_no_case("неделя")),
+            value(Period::Month, tag_no_case("месяцев")),
+            value(Period::Month, tag_no_case("месяца")),
+            value(Period::Month, tag_no_case("месяц")),
+            value(Period::Year, tag_no_case("года")),
+            value(Period::Year, tag_no_case("год")),
+            value(Period::Year, tag_no_case("лет")),
+        )),
+        word_boundary,
+    )(input)
+}
+
+/// Recognizes the duration in `Russian`: the number followed by the [`period`]
+/// (e.g. `3 дня`) or the single [`period`] meaning one (e.g. `неделю`).
+pub fn duration(input: &str) -> IResult<&str, (u32, Period)> {
+    alt((
+        separated_pair(map_res(digit1, |s: &str| s.parse()), space1, period),
+        map(period, |period| (1, period)),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` word `через` followed by the [`duration`]
+/// in `Russian` (e.g. `через 3 дня` or `через неделю`) and returns the
+/// corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local};
+/// use nom_date_parsers::i18n::ru::in_duration;
+///
+/// assert_eq!(
+///     in_duration("через 3 дня")?.1,
+///     Local::now().add(Days::new(3)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn in_duration(input: &str) -> IResult<&str, NaiveDate> {
+    in_duration_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`in_duration`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn in_duration_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_duration(
+            ctx,
+            1,
+            preceded(pair(tag_no_case("через"), space1), duration),
+        )(input)
+    }
+}
+
+/// Recognizes the [`duration`] followed by the `case insensitive` word `назад`
+/// in `Russian` (e.g. `5 дней назад`) and returns the corresponding
+/// [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use chrono::{Days, Local};
+/// use nom_date_parsers::i18n::ru::duration_ago;
+///
+/// assert_eq!(
+///     duration_ago("5 дней назад")?.1,
+///     Local::now().sub(Days::new(5)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn duration_ago(input: &str) -> IResult<&str, NaiveDate> {
+    duration_ago_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`duration_ago`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn duration_ago_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_duration(
+            ctx,
+            -1,
+            terminated(duration, pair(space1, tag_no_case("назад"))),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` relative period in `Russian` in the
+/// prepositional case: `на следующей неделе`, `на прошлой неделе` or the
+/// `в следующем`, `в прошлом` modifiers followed by `месяце` or `году`. The
+/// `предыдущей` and `предыдущем` are accepted as well. Returns the number of
+/// periods (`1` or `-1`) and the [`Period`].
+fn modified_period(input: &str) -> IResult<&str, (i64, Period)> {
+    alt((
+        preceded(
+            pair(tag_no_case("на"), space1),
+            separated_pair(
+                alt((
+                    value(1, tag_no_case("следующей")),
+                    value(-1, alt((tag_no_case("прошлой"), tag_no_case("предыдущей")))),
+                )),
+                space1,
+                value(Period::Week, tag_no_case("неделе")),
+            ),
+        ),
+        preceded(
+            pair(tag_no_case("в"), space1),
+            separated_pair(
+                alt((
+                    value(1, tag_no_case("следующем")),
+                    value(-1, alt((tag_no_case("прошлом"), tag_no_case("предыдущем")))),
+                )),
+                space1,
+                alt((
+                    value(Period::Month, tag_no_case("месяце")),
+                    value(Period::Year, tag_no_case("году")),
+                )),
+            ),
+        ),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` relative period in `Russian` (e.g. `на
+/// следующей неделе`, `в прошлом месяце` or `в следующем году`) and returns
+/// the reference date shifted by the period (e.g. the same weekday of the next
+/// week), see [`relative_period`](i18n::relative_period).
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local};
+/// use nom_date_parsers::i18n::ru::relative_period;
+///
+/// assert_eq!(
+///     relative_period("на следующей неделе")?.1,
+///     Local::now().add(Days::new(7)).date_naive()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn relative_period(input: &str) -> IResult<&str, NaiveDate> {
+    relative_period_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`relative_period`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn relative_period_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::relative_period(ctx, modified_period)(input)
+}
+
+/// Recognizes the same phrases as the [`relative_period`] parser, but returns
+/// the whole period as the [`DateRange`] (e.g. from Monday to Sunday of the
+/// next week), see [`relative_period_range`](i18n::relative_period_range).
+#[cfg(feature = "clock")]
+pub fn relative_period_range(input: &str) -> IResult<&str, DateRange> {
+    relative_period_range_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`relative_period_range`] parser which uses the reference date
+/// of the specified [`ParserContext`].
+pub fn relative_period_range_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, DateRange> + '_ {
+    move |input: &str| i18n::relative_period_range(ctx, modified_period)(input)
+}
+
+/// Recognizes the `case insensitive` calendar week notation in `Russian` (e.g.
+/// `29-я неделя`, `29 неделя` or `неделя 29`) and returns the Monday of the
+/// ISO 8601 week in the current year, see [`week_of_year`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Weekday};
+/// use nom_date_parsers::i18n::ru::calendar_week;
+///
+/// let date = calendar_week("29-я неделя")?.1;
+///
+/// assert_eq!(date.iso_week().week(), 29);
+/// assert_eq!(date.weekday(), Weekday::Mon);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn calendar_week(input: &str) -> IResult<&str, NaiveDate> {
+    calendar_week_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`calendar_week`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn calendar_week_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        week_of_year(
+            ctx,
+            alt((
+                preceded(pair(tag_no_case("неделя"), space1), ww),
+                terminated(
+                    ww,
+                    pair(
+                        opt(tag_no_case("-я")),
+                        preceded(space1, tag_no_case("неделя")),
+                    ),
+                ),
+            )),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` part of the month phrase in `Russian`
+/// with the optional preposition `в` (e.g. `начало`, `в середине` or
+/// `конец`).
+fn month_part_prefix(input: &str) -> IResult<&str, MonthPart> {
+    optional_prefix(
+        pair(tag_no_case("в"), space1),
+        terminated(
+            alt((
+                value(
+                    MonthPart::Beginning,
+                    alt((tag_no_case("начало"), tag_no_case("начале"))),
+                ),
+                value(
+                    MonthPart::Middle,
+                    alt((tag_no_case("середина"), tag_no_case("середине"))),
+                ),
+                value(
+                    MonthPart::End,
+                    alt((tag_no_case("конец"), tag_no_case("конце"))),
+                ),
+            )),
+            space1,
+        ),
     )(input)
 }
 
+/// Recognizes one of the `этого`, `текущего`, `следующего`, `прошлого` and
+/// `предыдущего` modifiers and returns the corresponding number of periods from
+/// the reference date.
+fn modifier(input: &str) -> IResult<&str, i64> {
+    alt((
+        value(0, alt((tag_no_case("этого"), tag_no_case("текущего")))),
+        value(1, tag_no_case("следующего")),
+        value(
+            -1,
+            alt((tag_no_case("прошлого"), tag_no_case("предыдущего"))),
+        ),
+    ))(input)
+}
+
+/// Recognizes the [`named_month`] in the genitive case followed by one of the
+/// `этого года`, `следующего года` and `прошлого года` phrases.
+fn named_month_of_year(input: &str) -> IResult<&str, MonthRef> {
+    map(
+        separated_pair(
+            named_month,
+            space1,
+            terminated(modifier, pair(space1, tag_no_case("года"))),
+        ),
+        |(month, years)| MonthRef::NamedRelative(month, years),
+    )(input)
+}
+
+/// Recognizes one of the `этого месяца`, `текущего месяца`, `следующего
+/// месяца`, `прошлого месяца` and `предыдущего месяца` phrases.
+fn relative_month(input: &str) -> IResult<&str, MonthRef> {
+    map(
+        terminated(modifier, pair(space1, tag_no_case("месяца"))),
+        MonthRef::Relative,
+    )(input)
+}
+
+/// Recognizes the month relative to the reference date, either the
+/// [`named_month_of_year`] or the [`relative_month`].
+fn relative_month_ref(input: &str) -> IResult<&str, MonthRef> {
+    alt((named_month_of_year, relative_month))(input)
+}
+
+/// Recognizes the [`named_month_of_year`], the [`named_month`] in the genitive
+/// case with the optional year or the [`relative_month`].
+fn month_ref(input: &str) -> IResult<&str, MonthRef> {
+    alt((
+        named_month_of_year,
+        map(
+            pair(
+                named_month,
+                opt(preceded(space1, terminated(y4, opt(year_suffix)))),
+            ),
+            |(month, year)| MonthRef::Named(month, year.map(|year| year as i32)),
+        ),
+        relative_month,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` phrases referring to the beginning, the
+/// middle or the end of the month in `Russian` (e.g. `конец июля`, `в середине
+/// августа 2024 года` or `начало следующего месяца`) and returns the first, the
+/// 15th or the last day of the month, see [`month_part`].
+///
+/// The month is either the [`named_month`] with the optional year (the year of
+/// the reference date is used if it is omitted) or followed by the `этого
+/// года`, `следующего года` or `прошлого года`, or one of the `этого месяца`,
+/// `текущего месяца`, `следующего месяца`, `прошлого месяца` and `предыдущего
+/// месяца`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ru::month_part;
+///
+/// assert_eq!(
+///     month_part("конец июля")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 31).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn month_part(input: &str) -> IResult<&str, NaiveDate> {
+    month_part_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`month_part`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn month_part_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::month_part(ctx, pair(month_part_prefix, month_ref))(input)
+}
+
+/// Recognizes the `case insensitive` phrases referring to the day of the
+/// relative month in `Russian`: `<day>[-го] [числа] <month>`, e.g. `5 числа
+/// следующего месяца`, `1-го прошлого месяца` or `13 июля следующего года`, and
+/// returns the corresponding [`NaiveDate`], see
+/// [`day_of_month`](i18n::day_of_month).
+///
+/// The month is one of the `этого месяца`, `текущего месяца`, `следующего
+/// месяца`, `прошлого месяца` and `предыдущего месяца` or the [`named_month`]
+/// in the genitive case followed by the `этого года`, `следующего года` or
+/// `прошлого года`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local};
+/// use nom_date_parsers::i18n::ru::day_of_month;
+///
+/// assert_eq!(day_of_month("5 числа следующего месяца")?.1.day(), 5);
+/// assert_eq!(
+///     day_of_month("13 июля следующего года")?.1.year(),
+///     Local::now().year() + 1
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn day_of_month(input: &str) -> IResult<&str, NaiveDate> {
+    day_of_month_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`day_of_month`] parser which uses the reference date and the
+/// [`MonthOverflow`](crate::calendar::MonthOverflow) policy of the specified
+/// [`ParserContext`].
+pub fn day_of_month_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        i18n::day_of_month(
+            ctx,
+            separated_pair(
+                terminated(dd, opt(tag_no_case("-го"))),
+                pair(space1, opt(pair(tag_no_case("числа"), space1))),
+                relative_month_ref,
+            ),
+        )(input)
+    }
+}
+
+/// Creates a parser which recognizes the `case insensitive` ordinal number in
+/// `Russian` in any of the specified gender and case `forms` followed by the
+/// word boundary.
+fn ordinal(forms: [&'static str; 4]) -> impl Fn(&str) -> IResult<&str, ()> {
+    move |input: &str| {
+        value(
+            (),
+            terminated(
+                alt((
+                    tag_no_case(forms[0]),
+                    tag_no_case(forms[1]),
+                    tag_no_case(forms[2]),
+                    tag_no_case(forms[3]),
+                )),
+                word_boundary,
+            ),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` ordinal number of the weekday in the month
+/// in `Russian` in any gender in the nominative or accusative case: `первый`
+/// .. `пятый` or `последний` (`-1`).
+fn weekday_ordinal(input: &str) -> IResult<&str, i8> {
+    alt((
+        value(1, ordinal(["первый", "первая", "первое", "первую"])),
+        value(2, ordinal(["второй", "вторая", "второе", "вторую"])),
+        value(3, ordinal(["третий", "третья", "третье", "третью"])),
+        value(
+            4,
+            alt((
+                ordinal(["четвёртый", "четвёртая", "четвёртое", "четвёртую"]),
+                ordinal(["четвертый", "четвертая", "четвертое", "четвертую"]),
+            )),
+        ),
+        value(5, ordinal(["пятый", "пятая", "пятое", "пятую"])),
+        value(
+            -1,
+            ordinal(["последний", "последняя", "последнее", "последнюю"]),
+        ),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` phrases referring to the n-th weekday of
+/// the month in `Russian` with the optional preposition `в`/`во` (e.g.
+/// `третий вторник января`, `в последнюю пятницу 2024-10` or `во вторую среду
+/// следующего месяца`) and returns the corresponding [`NaiveDate`], see
+/// [`nth_weekday_of_month`](i18n::nth_weekday_of_month).
+///
+/// The month is either the [`named_month`] with the optional year (the year of
+/// the reference date is used if it is omitted), the year and month separated
+/// by the `dash` symbol or one of the `этого месяца`, `текущего месяца`,
+/// `следующего месяца`, `прошлого месяца` and `предыдущего месяца`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ru::nth_weekday_of_month;
+///
+/// assert_eq!(
+///     nth_weekday_of_month("третий вторник января 2025 года")?.1,
+///     NaiveDate::from_ymd_opt(2025, 1, 21).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn nth_weekday_of_month(input: &str) -> IResult<&str, NaiveDate> {
+    nth_weekday_of_month_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`nth_weekday_of_month`] parser which uses the reference date of
+/// the specified [`ParserContext`].
+pub fn nth_weekday_of_month_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        i18n::nth_weekday_of_month(
+            ctx,
+            tuple((
+                optional_prefix(
+                    pair(alt((tag_no_case("во"), tag_no_case("в"))), space1),
+                    weekday_ordinal,
+                ),
+                preceded(space1, alt((full_named_weekday_accusative, named_weekday))),
+                preceded(space1, alt((y4_mm_month_with(ctx), month_ref))),
+            )),
+        )(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use chrono::Local;
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local, NaiveDate};
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
     use super::*;
+    use crate::error::Error;
 
     #[rstest]
     #[case("позавчера", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
@@ -154,4 +685,123 @@ mod tests {
     fn test_day_after_tomorrow(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
         assert_eq!(day_after_tomorrow(input), expected);
     }
+
+    #[rstest]
+    #[case("через 3 дня", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("Через неделю", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("через 2 недели", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap())))]
+    #[case("через 5 месяцев", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 16).unwrap())))]
+    #[case("через год", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 16).unwrap())))]
+    #[case(
+        "через неделями",
+        Err(nom::Err::Error(crate::error::Error::Nom("ми", nom::error::ErrorKind::Not)))
+    )]
+    fn test_in_duration_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(in_duration_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("5 дней назад", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 11).unwrap())))]
+    #[case("1 день назад", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("месяц назад", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 16).unwrap())))]
+    #[case("10 лет назад", Ok(("", NaiveDate::from_ymd_opt(2014, 7, 16).unwrap())))]
+    fn test_duration_ago_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(duration_ago_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("29-я неделя", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("29 Неделя", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("неделя 1", Ok(("", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())))]
+    #[case("53-я неделя", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("54-я неделя", Err(nom::Err::Error(Error::WeekOutOfRange)))]
+    fn test_calendar_week_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(calendar_week_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("5 числа следующего месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 5).unwrap())))]
+    #[case("1-го прошлого месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())))]
+    #[case("20-го числа этого месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("13 июля следующего года", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 13).unwrap())))]
+    #[case("31 числа следующего месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 31).unwrap())))]
+    #[case("31 сентября прошлого года", Ok(("", NaiveDate::from_ymd_opt(2023, 9, 30).unwrap())))]
+    #[case(
+        "13 июля",
+        Err(nom::Err::Error(Error::Nom("июля", nom::error::ErrorKind::Tag)))
+    )]
+    fn test_day_of_month_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(day_of_month_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("конец июля", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 31).unwrap())))]
+    #[case("Начало следующего месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 1).unwrap())))]
+    #[case("в середине августа", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 15).unwrap())))]
+    #[case("в конце февраля 2023 года", Ok(("", NaiveDate::from_ymd_opt(2023, 2, 28).unwrap())))]
+    #[case("конец прошлого месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 30).unwrap())))]
+    #[case("начале этого месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 1).unwrap())))]
+    #[case("конец февраля следующего года", Ok(("", NaiveDate::from_ymd_opt(2025, 2, 28).unwrap())))]
+    fn test_month_part_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(month_part_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("третий вторник января", Ok(("", NaiveDate::from_ymd_opt(2024, 1, 16).unwrap())))]
+    #[case("Первый понедельник сентября", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 2).unwrap())))]
+    #[case("в последнюю пятницу 2024-10", Ok(("", NaiveDate::from_ymd_opt(2024, 10, 25).unwrap())))]
+    #[case("во вторую среду следующего месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 14).unwrap())))]
+    #[case("четвёртый четверг ноября 2025 г.", Ok(("", NaiveDate::from_ymd_opt(2025, 11, 27).unwrap())))]
+    #[case("последнее воскресенье прошлого месяца", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 30).unwrap())))]
+    #[case("пятая пятница октября", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(
+        "в последнюю пятницу 1800-10",
+        Err(nom::Err::Error(Error::YearOutOfRange { value: 1800, input: "1800-10" }))
+    )]
+    fn test_nth_weekday_of_month_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(nth_weekday_of_month_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("на следующей неделе", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("На прошлой неделе", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 9).unwrap())))]
+    #[case("в прошлом месяце", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 16).unwrap())))]
+    #[case("в следующем году", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 16).unwrap())))]
+    #[case("в предыдущем году", Ok(("", NaiveDate::from_ymd_opt(2023, 7, 16).unwrap())))]
+    fn test_relative_period_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(relative_period_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_relative_period_range_with() {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(
+            relative_period_range_with(&ctx)("на следующей неделе"),
+            Ok((
+                "",
+                DateRange {
+                    start: NaiveDate::from_ymd_opt(2024, 7, 22).unwrap(),
+                    end: NaiveDate::from_ymd_opt(2024, 7, 28).unwrap(),
+                }
+            ))
+        );
+    }
 }
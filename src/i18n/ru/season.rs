@@ -0,0 +1,229 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    character::complete::space1,
+    combinator::{map, opt, value},
+    sequence::{separated_pair, terminated},
+};
+
+use super::year_suffix;
+use crate::{
+    combinators::{lookup_no_case, tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n::{self, Season, SeasonRef},
+    numeric::y4,
+    range::DateRange,
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` season in `Russian` in the nominative or
+/// instrumental case.
+///
+/// The following words are accepted:
+/// - `весна` | `весной` -> [`Season::Spring`]
+/// - `лето` | `летом` -> [`Season::Summer`]
+/// - `осень` | `осенью` -> [`Season::Autumn`]
+/// - `зима` | `зимой` -> [`Season::Winter`]
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::i18n::{ru::named_season, Season};
+///
+/// assert_eq!(named_season("Летом 2025")?, (" 2025", Season::Summer));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_season(input: &str) -> IResult<&str, Season> {
+    terminated(
+        lookup_no_case(|word| match word {
+            "весна" | "весной" => Some(Season::Spring),
+            "лето" | "летом" => Some(Season::Summer),
+            "осень" | "осенью" => Some(Season::Autumn),
+            "зима" | "зимой" => Some(Season::Winter),
+            _ => None,
+        }),
+        word_boundary,
+    )(input)
+}
+
+/// Recognizes the `case insensitive` modifier of the season in `Russian` in
+/// the nominative or instrumental case in any gender (e.g. `этой` in `этой
+/// зимой` or `следующее` in `следующее лето`) and returns the corresponding
+/// number of years from the current season.
+fn modifier(input: &str) -> IResult<&str, i64> {
+    alt((
+        value(
+            0,
+            alt((
+                tag_no_case("этим"),
+                tag_no_case("этой"),
+                tag_no_case("это"),
+                tag_no_case("эта"),
+            )),
+        ),
+        value(
+            1,
+            alt((
+                tag_no_case("следующим"),
+                tag_no_case("следующей"),
+                tag_no_case("следующее"),
+                tag_no_case("следующая"),
+                tag_no_case("будущим"),
+                tag_no_case("будущей"),
+            )),
+        ),
+        value(
+            -1,
+            alt((
+                tag_no_case("прошлым"),
+                tag_no_case("прошлой"),
+                tag_no_case("прошлое"),
+                tag_no_case("прошлая"),
+            )),
+        ),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` season phrase in `Russian`:
+/// - `<season> <year> [года]` -> [`SeasonRef::Named`] with the year
+/// - `<modifier> <season>` -> [`SeasonRef::Relative`]
+/// - `<season>` -> [`SeasonRef::Named`] without the year
+fn season_ref(input: &str) -> IResult<&str, SeasonRef> {
+    alt((
+        map(
+            separated_pair(named_season, space1, terminated(y4, opt(year_suffix))),
+            |(season, year)| SeasonRef::Named(season, Some(year as i32)),
+        ),
+        map(
+            separated_pair(modifier, space1, named_season),
+            |(years, season)| SeasonRef::Relative(season, years),
+        ),
+        map(named_season, |season| SeasonRef::Named(season, None)),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` season phrase in `Russian` (e.g. `летом
+/// 2025`, `осенью 2024 года`, `этой зимой` or `следующей весной`) and returns
+/// the first day of the season, see [`season`](i18n::season). The current
+/// season is the one containing the reference date or the one starting in its
+/// year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::ru::season;
+///
+/// assert_eq!(
+///     season("летом 2025")?.1,
+///     NaiveDate::from_ymd_opt(2025, 6, 1).unwrap()
+/// );
+/// assert_eq!(
+///     season("следующей весной")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year() + 1, 3, 1).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn season(input: &str) -> IResult<&str, NaiveDate> {
+    season_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`season`] parser which uses the reference date and the
+/// hemisphere of the specified [`ParserContext`].
+pub fn season_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::season(ctx, season_ref)(input)
+}
+
+/// Recognizes the same phrases as the [`season`] parser, but returns the whole
+/// season as the [`DateRange`], see [`season_range`](i18n::season_range).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::ru::season_range;
+///
+/// let range = season_range("осенью 2024 года")?.1;
+///
+/// assert_eq!(range.start, NaiveDate::from_ymd_opt(2024, 9, 1).unwrap());
+/// assert_eq!(range.end, NaiveDate::from_ymd_opt(2024, 11, 30).unwrap());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn season_range(input: &str) -> IResult<&str, DateRange> {
+    season_range_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`season_range`] parser which uses the reference date and the
+/// hemisphere of the specified [`ParserContext`].
+pub fn season_range_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, DateRange> + '_ {
+    move |input: &str| i18n::season_range(ctx, season_ref)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::assert_lookup_words;
+
+    fn range(start: (i32, u32, u32), end: (i32, u32, u32)) -> DateRange {
+        DateRange {
+            start: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            end: NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap(),
+        }
+    }
+
+    #[rstest]
+    #[case("летом", (2024, 1, 16), Ok(("", range((2024, 6, 1), (2024, 8, 31)))))]
+    #[case("Зима", (2024, 1, 16), Ok(("", range((2023, 12, 1), (2024, 2, 29)))))]
+    #[case("этой зимой", (2024, 7, 16), Ok(("", range((2024, 12, 1), (2025, 2, 28)))))]
+    #[case("следующей весной", (2024, 7, 16), Ok(("", range((2025, 3, 1), (2025, 5, 31)))))]
+    #[case("следующее лето", (2024, 7, 16), Ok(("", range((2025, 6, 1), (2025, 8, 31)))))]
+    #[case("прошлой осенью", (2024, 7, 16), Ok(("", range((2023, 9, 1), (2023, 11, 30)))))]
+    #[case("летом 2025", (2024, 7, 16), Ok(("", range((2025, 6, 1), (2025, 8, 31)))))]
+    #[case("осенью 2024 года", (2024, 7, 16), Ok(("", range((2024, 9, 1), (2024, 11, 30)))))]
+    #[case("зима 2024 г., ok", (2024, 7, 16), Ok((", ok", range((2024, 12, 1), (2025, 2, 28)))))]
+    fn test_season_range_with(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: IResult<&str, DateRange>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap());
+
+        assert_eq!(season_range_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("летний")]
+    #[case("следующим годом")]
+    #[case("этом лете")]
+    fn test_season_range_with_error(#[case] input: &str) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert!(season_range_with(&ctx)(input).is_err());
+    }
+
+    #[test]
+    fn test_lookup_words() {
+        assert_lookup_words(
+            named_season,
+            &[
+                "весна",
+                "весной",
+                "лето",
+                "летом",
+                "осень",
+                "осенью",
+                "зима",
+                "зимой",
+            ],
+        );
+    }
+}
@@ -1,12 +1,19 @@
 use chrono::{NaiveDate, Weekday};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, tag_no_case},
-    combinator::{map_res, value},
-    sequence::terminated,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{opt, value},
+    sequence::{preceded, terminated},
 };
 
-use crate::{i18n::naive_date_for_weekday, types::IResult};
+use crate::{
+    combinators::{lookup_no_case, lookup_prefix_no_case, tag_no_case, word_boundary},
+    context::ParserContext,
+    error::Error,
+    i18n::{naive_date_for_weekday_at, relative_weekday},
+    types::IResult,
+};
 
 /// Recognizes the `case insensitive` short-named weekday in `Russian`.
 ///
@@ -19,6 +26,9 @@ use crate::{i18n::naive_date_for_weekday, types::IResult};
 /// - `сб` -> [`Weekday::Sat`]
 /// - `вс` -> [`Weekday::Sun`]
 ///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
 /// # Examples
 ///
 /// ```
@@ -29,15 +39,21 @@ use crate::{i18n::naive_date_for_weekday, types::IResult};
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
-    alt((
-        value(Weekday::Mon, tag_no_case("пн")),
-        value(Weekday::Tue, tag_no_case("вт")),
-        value(Weekday::Wed, tag_no_case("ср")),
-        value(Weekday::Thu, tag_no_case("чт")),
-        value(Weekday::Fri, tag_no_case("пт")),
-        value(Weekday::Sat, tag_no_case("сб")),
-        value(Weekday::Sun, tag_no_case("вс")),
-    ))(input)
+    lookup_no_case(short_weekday)(input)
+}
+
+/// Returns the weekday of the lowercase short weekday name.
+fn short_weekday(word: &str) -> Option<Weekday> {
+    Some(match word {
+        "пн" => Weekday::Mon,
+        "вт" => Weekday::Tue,
+        "ср" => Weekday::Wed,
+        "чт" => Weekday::Thu,
+        "пт" => Weekday::Fri,
+        "сб" => Weekday::Sat,
+        "вс" => Weekday::Sun,
+        _ => return None,
+    })
 }
 
 /// Recognizes the `case insensitive` short-named weekday in `Russian` which
@@ -57,6 +73,9 @@ pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
 /// - `суббота` -> [`Weekday::Sat`]
 /// - `воскресенье` -> [`Weekday::Sun`]
 ///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
 /// # Examples
 ///
 /// ```
@@ -67,22 +86,68 @@ pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
-    alt((
-        value(Weekday::Mon, tag_no_case("понедельник")),
-        value(Weekday::Tue, tag_no_case("вторник")),
-        value(Weekday::Wed, tag_no_case("среда")),
-        value(Weekday::Thu, tag_no_case("четверг")),
-        value(Weekday::Fri, tag_no_case("пятница")),
-        value(Weekday::Sat, tag_no_case("суббота")),
-        value(Weekday::Sun, tag_no_case("воскресенье")),
-    ))(input)
+    lookup_no_case(full_weekday)(input)
+}
+
+/// Returns the weekday of the lowercase full weekday name.
+fn full_weekday(word: &str) -> Option<Weekday> {
+    Some(match word {
+        "понедельник" => Weekday::Mon,
+        "вторник" => Weekday::Tue,
+        "среда" => Weekday::Wed,
+        "четверг" => Weekday::Thu,
+        "пятница" => Weekday::Fri,
+        "суббота" => Weekday::Sat,
+        "воскресенье" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Russian` in the
+/// accusative case, which is used after the prepositions and modifiers (e.g.
+/// `в следующую пятницу`).
+///
+/// The following words are accepted:
+/// - `понедельник` -> [`Weekday::Mon`]
+/// - `вторник` -> [`Weekday::Tue`]
+/// - `среду` -> [`Weekday::Wed`]
+/// - `четверг` -> [`Weekday::Thu`]
+/// - `пятницу` -> [`Weekday::Fri`]
+/// - `субботу` -> [`Weekday::Sat`]
+/// - `воскресенье` -> [`Weekday::Sun`]
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ru::full_named_weekday_accusative;
+///
+/// assert_eq!(full_named_weekday_accusative("пятницу")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday_accusative(input: &str) -> IResult<&str, Weekday> {
+    lookup_no_case(|word| match word {
+        "понедельник" => Some(Weekday::Mon),
+        "вторник" => Some(Weekday::Tue),
+        "среду" => Some(Weekday::Wed),
+        "четверг" => Some(Weekday::Thu),
+        "пятницу" => Some(Weekday::Fri),
+        "субботу" => Some(Weekday::Sat),
+        "воскресенье" => Some(Weekday::Sun),
+        _ => None,
+    })(input)
 }
 
 /// Recognizes either the `case insensitive` short-named or full-named weekday
-/// in `Russian`. Uses the following parsers:
-/// - [`full_named_weekday`]
-/// - [`short_named_weekday_dot`]
-/// - [`short_named_weekday`]
+/// in `Russian`, the words of the [`full_named_weekday`] and
+/// [`short_named_weekday`] parsers are looked up at once. The short name may be
+/// followed by the `dot` symbol (see [`short_named_weekday_dot`]).
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
 ///
 /// # Examples
 ///
@@ -95,13 +160,75 @@ pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    let (input, (weekday, short)) = lookup_no_case(|word| {
+        full_weekday(word)
+            .map(|weekday| (weekday, false))
+            .or_else(|| short_weekday(word).map(|weekday| (weekday, true)))
+    })(input)?;
+
+    if short {
+        value(weekday, opt(tag(".")))(input)
+    } else {
+        Ok((input, weekday))
+    }
+}
+
+/// The words abbreviated by the [`named_weekday_prefix`] parser.
+const WEEKDAY_WORDS: [(&str, Weekday); 10] = [
+    ("понедельник", Weekday::Mon),
+    ("вторник", Weekday::Tue),
+    ("среда", Weekday::Wed),
+    ("среду", Weekday::Wed),
+    ("четверг", Weekday::Thu),
+    ("пятница", Weekday::Fri),
+    ("пятницу", Weekday::Fri),
+    ("суббота", Weekday::Sat),
+    ("субботу", Weekday::Sat),
+    ("воскресенье", Weekday::Sun),
+];
+
+/// Recognizes the `case insensitive` weekday in `Russian` like the
+/// [`named_weekday`] parser, but also accepts any unambiguous prefix of at
+/// least two letters of the full name (in the nominative or the accusative
+/// case) followed by the optional `dot` symbol, e.g. `воскр`, `суб.` or
+/// `пятн`, see [`lookup_prefix_no_case`].
+///
+/// Used by the weekday parsers of the [`ParserContext`] with the
+/// [`ParserContext::with_weekday_prefixes`] enabled.
+///
+/// The words are looked up with the [`lookup_no_case`] combinator, which
+/// lowercases at most 32 bytes of the leading letters of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::ru::named_weekday_prefix;
+///
+/// assert_eq!(named_weekday_prefix("воскр")?.1, Weekday::Sun);
+/// assert_eq!(named_weekday_prefix("Суб.")?, ("", Weekday::Sat));
+/// assert!(named_weekday_prefix("п").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday_prefix(input: &str) -> IResult<&str, Weekday> {
     alt((
-        full_named_weekday,
-        short_named_weekday_dot,
-        short_named_weekday,
+        terminated(full_named_weekday, word_boundary),
+        terminated(lookup_prefix_no_case(&WEEKDAY_WORDS, 2), opt(tag("."))),
+        named_weekday,
     ))(input)
 }
 
+/// Returns the weekday parser of the [`ParserContext`]: the
+/// [`named_weekday_prefix`] if the [`ParserContext::with_weekday_prefixes`] is
+/// enabled, otherwise the [`named_weekday`].
+fn context_named_weekday(ctx: &ParserContext) -> fn(&str) -> IResult<&str, Weekday> {
+    if ctx.weekday_prefixes() {
+        named_weekday_prefix
+    } else {
+        named_weekday
+    }
+}
+
 /// Recognizes the `case insensitive` weekday in `Russian` using the
 /// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
 /// for the current week.
@@ -114,14 +241,179 @@ pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
 ///
 /// assert_eq!(
 ///     current_named_weekday_only("Среда")?.1,
-///     naive_date_for_weekday(Weekday::Wed)
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
-    map_res(named_weekday, |weekday: Weekday| {
-        Ok(naive_date_for_weekday(weekday))
-    })(input)
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = context_named_weekday(ctx)(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Recognizes the weekday after the modifier in either the nominative or the
+/// accusative case.
+fn modified_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((full_named_weekday_accusative, named_weekday))(input)
+}
+
+/// Recognizes the weekday after the modifier like the
+/// [`modified_named_weekday`] parser, but also accepts the prefixes of the
+/// [`named_weekday_prefix`] parser.
+fn modified_named_weekday_prefix(input: &str) -> IResult<&str, Weekday> {
+    alt((full_named_weekday_accusative, named_weekday_prefix))(input)
+}
+
+/// Returns the weekday parser after the modifier of the [`ParserContext`], see
+/// [`context_named_weekday`].
+fn context_modified_named_weekday(ctx: &ParserContext) -> fn(&str) -> IResult<&str, Weekday> {
+    if ctx.weekday_prefixes() {
+        modified_named_weekday_prefix
+    } else {
+        modified_named_weekday
+    }
+}
+
+/// Recognizes the weekday modifier specified by its gender forms, optionally
+/// preceded by the `в` preposition.
+fn weekday_modifier<'a>(
+    forms: [&'static str; 4],
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    preceded(
+        opt(terminated(tag_no_case("в"), space1)),
+        alt((
+            tag_no_case(forms[0]),
+            tag_no_case(forms[1]),
+            tag_no_case(forms[2]),
+            tag_no_case(forms[3]),
+        )),
+    )
+}
+
+/// Recognizes the `case insensitive` words `прошлый`, `прошлая`, `прошлое` or
+/// `прошлую` (optionally preceded by the `в` preposition) followed by the
+/// weekday in `Russian` (e.g. `в прошлую пятницу`) and returns the
+/// corresponding [`NaiveDate`] for the previous week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Days, NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, ru::last_named_weekday};
+///
+/// assert_eq!(
+///     last_named_weekday("в прошлую пятницу")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap() - Days::new(7)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn last_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    last_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`last_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn last_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(
+            ctx,
+            -1,
+            weekday_modifier(["прошлый", "прошлая", "прошлое", "прошлую"]),
+            context_modified_named_weekday(ctx),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` words `этот`, `эта`, `это` or `эту`
+/// (optionally preceded by the `в` preposition) followed by the weekday in
+/// `Russian` (e.g. `в эту пятницу`) and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Days, NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, ru::this_named_weekday};
+///
+/// assert_eq!(
+///     this_named_weekday("в эту пятницу")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn this_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    this_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`this_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn this_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(
+            ctx,
+            0,
+            weekday_modifier(["этот", "эта", "это", "эту"]),
+            context_modified_named_weekday(ctx),
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` words `следующий`, `следующая`,
+/// `следующее` or `следующую` (optionally preceded by the `в` preposition)
+/// followed by the weekday in `Russian` (e.g. `в следующую пятницу`) and
+/// returns the corresponding [`NaiveDate`] for the next week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Days, NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, ru::next_named_weekday};
+///
+/// assert_eq!(
+///     next_named_weekday("в следующую пятницу")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap() + Days::new(7)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn next_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    next_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`next_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn next_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(
+            ctx,
+            1,
+            weekday_modifier(["следующий", "следующая", "следующее", "следующую"]),
+            context_modified_named_weekday(ctx),
+        )(input)
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +421,8 @@ mod tests {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::{combinators::assert_lookup_words, i18n::naive_date_for_weekday};
+
     use super::*;
 
     #[rstest]
@@ -151,6 +445,8 @@ mod tests {
     #[case("понедельник", Ok(("", Weekday::Mon)))]
     #[case("Вторник", Ok(("", Weekday::Tue)))]
     #[case("СРЕДА", Ok(("", Weekday::Wed)))]
+    #[case("срЕдА", Ok(("", Weekday::Wed)))]
+    #[case("ВоСкРеСеНьЕ", Ok(("", Weekday::Sun)))]
     fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
         assert_eq!(full_named_weekday(input), expected);
     }
@@ -158,17 +454,145 @@ mod tests {
     #[rstest]
     #[case("пн", Ok(("", Weekday::Mon)))]
     #[case("вторник", Ok(("", Weekday::Tue)))]
+    #[case("Пт. 13", Ok((" 13", Weekday::Fri)))]
+    #[case(
+        "пятницу",
+        Err(nom::Err::Error(Error::Nom("пятницу", nom::error::ErrorKind::Tag)))
+    )]
     fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
         assert_eq!(named_weekday(input), expected)
     }
 
     #[rstest]
-    #[case("пн", Ok(("", naive_date_for_weekday(Weekday::Mon))))]
-    #[case("Вторник", Ok(("", naive_date_for_weekday(Weekday::Tue))))]
+    #[case("воскр", Ok(("", Weekday::Sun)))]
+    #[case("Суб.", Ok(("", Weekday::Sat)))]
+    #[case("пятн 13", Ok((" 13", Weekday::Fri)))]
+    #[case("пон", Ok(("", Weekday::Mon)))]
+    #[case("Пт. 13", Ok((" 13", Weekday::Fri)))]
+    #[case("вс", Ok(("", Weekday::Sun)))]
+    #[case("п", Err(nom::Err::Error(Error::Nom("п", nom::error::ErrorKind::Tag))))]
+    #[case(
+        "субтитры",
+        Err(nom::Err::Error(Error::Nom("субтитры", nom::error::ErrorKind::Tag)))
+    )]
+    fn test_named_weekday_prefix(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday_prefix(input), expected)
+    }
+
+    #[rstest]
+    #[case("пн", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Вторник", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
     fn test_current_named_weekday_only(
         #[case] input: &str,
         #[case] expected: IResult<&str, NaiveDate>,
     ) {
         assert_eq!(current_named_weekday_only(input), expected)
     }
+
+    #[rstest]
+    #[case("пятницу", Ok(("", Weekday::Fri)))]
+    #[case("Среду", Ok(("", Weekday::Wed)))]
+    #[case("воскресенье", Ok(("", Weekday::Sun)))]
+    fn test_full_named_weekday_accusative(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, Weekday>,
+    ) {
+        assert_eq!(full_named_weekday_accusative(input), expected)
+    }
+
+    #[rstest]
+    #[case("прошлая пятница", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 12).unwrap())))]
+    #[case("в прошлый понедельник", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 8).unwrap())))]
+    #[case("В прошлую среду", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 10).unwrap())))]
+    fn test_last_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(last_named_weekday_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("эта суббота", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("в этот понедельник", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("это воскресенье", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("в эту пт", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    fn test_this_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(this_named_weekday_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("следующий вторник", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("в следующую пятницу", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap())))]
+    #[case("Следующее воскресенье", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 28).unwrap())))]
+    fn test_next_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(next_named_weekday_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("в следующую пятн", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap())))]
+    #[case("в прошлую суб.", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("это воскр", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("четв", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap())))]
+    fn test_named_weekday_with_prefixes(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .with_weekday_prefixes(true);
+        let parser = |input| {
+            alt((
+                last_named_weekday_with(&ctx),
+                this_named_weekday_with(&ctx),
+                next_named_weekday_with(&ctx),
+                current_named_weekday_only_with(&ctx),
+            ))(input)
+        };
+
+        assert_eq!(parser(input), expected)
+    }
+
+    #[test]
+    fn test_lookup_words() {
+        assert_lookup_words(
+            short_named_weekday,
+            &["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+        );
+        assert_lookup_words(
+            full_named_weekday,
+            &[
+                "понедельник",
+                "вторник",
+                "среда",
+                "четверг",
+                "пятница",
+                "суббота",
+                "воскресенье",
+            ],
+        );
+        assert_lookup_words(
+            full_named_weekday_accusative,
+            &[
+                "понедельник",
+                "вторник",
+                "среду",
+                "четверг",
+                "пятницу",
+                "субботу",
+                "воскресенье",
+            ],
+        );
+    }
 }
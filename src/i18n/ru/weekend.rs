@@ -0,0 +1,180 @@
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    character::complete::space1,
+    combinator::{map, opt, value},
+    sequence::{pair, preceded, terminated},
+};
+
+use crate::{
+    combinators::{tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n,
+    range::DateRange,
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` weekend phrase in `Russian` in the
+/// nominative or accusative case with the optional preposition `в`: the word
+/// `выходные` optionally preceded by one of the `эти`, `ближайшие`,
+/// `следующие` and `прошлые` modifiers (e.g. `в следующие выходные`).
+fn weekend_accusative(input: &str) -> IResult<&str, i64> {
+    map(
+        preceded(
+            opt(pair(tag_no_case("в"), space1)),
+            terminated(
+                opt(terminated(
+                    alt((
+                        value(0, alt((tag_no_case("эти"), tag_no_case("ближайшие")))),
+                        value(1, tag_no_case("следующие")),
+                        value(-1, tag_no_case("прошлые")),
+                    )),
+                    space1,
+                )),
+                terminated(tag_no_case("выходные"), word_boundary),
+            ),
+        ),
+        |weeks| weeks.unwrap_or(0),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` weekend phrase in `Russian` in the
+/// prepositional case: the preposition `на` followed by the word `выходных`
+/// optionally preceded by one of the `этих`, `ближайших`, `следующих` and
+/// `прошлых` modifiers (e.g. `на прошлых выходных`).
+fn weekend_prepositional(input: &str) -> IResult<&str, i64> {
+    map(
+        preceded(
+            pair(tag_no_case("на"), space1),
+            terminated(
+                opt(terminated(
+                    alt((
+                        value(0, alt((tag_no_case("этих"), tag_no_case("ближайших")))),
+                        value(1, tag_no_case("следующих")),
+                        value(-1, tag_no_case("прошлых")),
+                    )),
+                    space1,
+                )),
+                terminated(tag_no_case("выходных"), word_boundary),
+            ),
+        ),
+        |weeks| weeks.unwrap_or(0),
+    )(input)
+}
+
+/// Recognizes the `case insensitive` weekend phrase in `Russian`, either the
+/// [`weekend_accusative`] or the [`weekend_prepositional`]. Returns the number
+/// of weeks from the reference date.
+fn weekend_ref(input: &str) -> IResult<&str, i64> {
+    alt((weekend_prepositional, weekend_accusative))(input)
+}
+
+/// Recognizes the `case insensitive` weekend phrase in `Russian` (e.g. `в
+/// выходные`, `в эти выходные`, `на следующих выходных` or `прошлые
+/// выходные`) and returns the Saturday of the weekend, see
+/// [`weekend`](i18n::weekend). The weekend of the Sunday is the one which
+/// began the day before.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Weekday};
+/// use nom_date_parsers::i18n::ru::weekend;
+///
+/// assert_eq!(weekend("в выходные")?.1.weekday(), Weekday::Sat);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn weekend(input: &str) -> IResult<&str, NaiveDate> {
+    weekend_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`weekend`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn weekend_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| i18n::weekend(ctx, weekend_ref)(input)
+}
+
+/// Recognizes the same phrases as the [`weekend`] parser, but returns the
+/// Saturday and the Sunday as the [`DateRange`], see
+/// [`weekend_range`](i18n::weekend_range).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Weekday};
+/// use nom_date_parsers::i18n::ru::weekend_range;
+///
+/// let range = weekend_range("на этих выходных")?.1;
+///
+/// assert_eq!(range.start.weekday(), Weekday::Sat);
+/// assert_eq!(range.end.weekday(), Weekday::Sun);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn weekend_range(input: &str) -> IResult<&str, DateRange> {
+    weekend_range_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`weekend_range`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn weekend_range_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, DateRange> + '_ {
+    move |input: &str| i18n::weekend_range(ctx, weekend_ref)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn range(start: (i32, u32, u32), end: (i32, u32, u32)) -> DateRange {
+        DateRange {
+            start: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            end: NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap(),
+        }
+    }
+
+    #[rstest]
+    #[case("в выходные", (2024, 7, 16), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("Выходные", (2024, 7, 21), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("в эти выходные", (2024, 7, 16), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("в ближайшие выходные", (2024, 7, 16), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("в следующие выходные", (2024, 7, 16), Ok(("", range((2024, 7, 27), (2024, 7, 28)))))]
+    #[case("прошлые выходные", (2024, 7, 16), Ok(("", range((2024, 7, 13), (2024, 7, 14)))))]
+    #[case("на выходных", (2024, 7, 16), Ok(("", range((2024, 7, 20), (2024, 7, 21)))))]
+    #[case("на следующих выходных", (2024, 7, 16), Ok(("", range((2024, 7, 27), (2024, 7, 28)))))]
+    #[case("на прошлых выходных, ok", (2024, 7, 16), Ok((", ok", range((2024, 7, 13), (2024, 7, 14)))))]
+    fn test_weekend_range_with(
+        #[case] input: &str,
+        #[case] today: (i32, u32, u32),
+        #[case] expected: IResult<&str, DateRange>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap());
+
+        assert_eq!(weekend_range_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("на выходные")]
+    #[case("в выходных")]
+    #[case("в следующих выходных")]
+    #[case("выходной")]
+    fn test_weekend_range_with_error(#[case] input: &str) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert!(weekend_range_with(&ctx)(input).is_err());
+    }
+
+    #[test]
+    fn test_weekend_with() {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(
+            weekend_with(&ctx)("в следующие выходные"),
+            Ok(("", NaiveDate::from_ymd_opt(2024, 7, 27).unwrap()))
+        );
+    }
+}
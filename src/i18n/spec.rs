@@ -0,0 +1,365 @@
+use chrono::{Month, NaiveDate, Weekday};
+use nom::{
+    branch::alt,
+    combinator::opt,
+    error::{ErrorKind, ParseError},
+    sequence::terminated,
+};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    error::Error,
+    i18n::{
+        dmy_named_month_by, mdy_named_month_by, naive_date_for_weekday_at, shift_date,
+        weekday_separator, Period,
+    },
+    numeric::{y4_mm_dd_with, DateOrder},
+    types::IResult,
+};
+
+/// The vocabulary of a language described with the tables of words instead of
+/// the language-specific parser code: the names of the weekdays and months, the
+/// relative words and the preferred order of the numeric date parts.
+///
+/// The generic parsers of the spec (e.g. [`LanguageSpec::bundle_with`]) match
+/// the words `case insensitively` (see
+/// [`tag_no_case`](crate::combinators::tag_no_case)) and prefer the longest
+/// matching word, so the order of the forms in the tables doesn't matter (e.g.
+/// both `mar` and `marzo` may be listed for March).
+///
+/// The tables are borrowed, so the spec can be either a `const` or built at
+/// runtime from the words loaded from a file.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, i18n::LanguageSpec};
+///
+/// const DUTCH: LanguageSpec<'static> = LanguageSpec::new(
+///     [
+///         &["maandag", "ma"],
+///         &["dinsdag", "di"],
+///         &["woensdag", "wo"],
+///         &["donderdag", "do"],
+///         &["vrijdag", "vr"],
+///         &["zaterdag", "za"],
+///         &["zondag", "zo"],
+///     ],
+///     [
+///         &["januari", "jan"],
+///         &["februari", "feb"],
+///         &["maart", "mrt"],
+///         &["april", "apr"],
+///         &["mei"],
+///         &["juni", "jun"],
+///         &["juli", "jul"],
+///         &["augustus", "aug"],
+///         &["september", "sep"],
+///         &["oktober", "okt"],
+///         &["november", "nov"],
+///         &["december", "dec"],
+///     ],
+/// )
+/// .with_relative_days(&[("gisteren", -1), ("vandaag", 0), ("morgen", 1)]);
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     DUTCH.bundle_with(&ctx)("vrijdag 13 september 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 9, 13).unwrap()
+/// );
+/// assert_eq!(
+///     DUTCH.bundle_with(&ctx)("morgen")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LanguageSpec<'a> {
+    weekdays: [&'a [&'a str]; 7],
+    months: [&'a [&'a str]; 12],
+    relative_days: &'a [(&'a str, i64)],
+    order: DateOrder,
+}
+
+impl<'a> LanguageSpec<'a> {
+    /// Creates the spec with the specified forms of the weekday names (starting
+    /// from Monday) and the month names (starting from January), without the
+    /// relative words and with the [`DateOrder::Dmy`] order.
+    pub const fn new(weekdays: [&'a [&'a str]; 7], months: [&'a [&'a str]; 12]) -> Self {
+        Self {
+            weekdays,
+            months,
+            relative_days: &[],
+            order: DateOrder::Dmy,
+        }
+    }
+
+    /// Sets the relative words with the number of days from the reference date,
+    /// e.g. `("yesterday", -1)` or `("today", 0)`.
+    #[must_use]
+    pub const fn with_relative_days(mut self, relative_days: &'a [(&'a str, i64)]) -> Self {
+        self.relative_days = relative_days;
+        self
+    }
+
+    /// Sets the order of the numeric date parts used by the
+    /// [`LanguageSpec::bundle_with`] parser.
+    #[must_use]
+    pub const fn with_order(mut self, order: DateOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Returns the order of the numeric date parts.
+    pub const fn order(&self) -> DateOrder {
+        self.order
+    }
+
+    /// Recognizes the longest of the weekday names of the spec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use nom_date_parsers::i18n::LanguageSpec;
+    ///
+    /// let spec = LanguageSpec::new(
+    ///     [
+    ///         &["mon"],
+    ///         &["tue"],
+    ///         &["wed"],
+    ///         &["thu"],
+    ///         &["fri"],
+    ///         &["sat"],
+    ///         &["sun"],
+    ///     ],
+    ///     [&[]; 12],
+    /// );
+    ///
+    /// assert_eq!(spec.named_weekday("Fri")?.1, Weekday::Fri);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn named_weekday<'i>(&self, input: &'i str) -> IResult<&'i str, Weekday> {
+        longest(
+            input,
+            self.weekdays
+                .iter()
+                .zip(0_u8..)
+                .flat_map(|(forms, n)| forms.iter().map(move |form| (*form, n))),
+        )
+        .map(|(input, n)| {
+            (
+                input,
+                Weekday::try_from(n).expect("the spec has exactly seven weekdays"),
+            )
+        })
+    }
+
+    /// Recognizes the longest of the month names of the spec.
+    pub fn named_month<'i>(&self, input: &'i str) -> IResult<&'i str, Month> {
+        longest(
+            input,
+            self.months
+                .iter()
+                .zip(0_u8..)
+                .flat_map(|(forms, n)| forms.iter().map(move |form| (*form, n))),
+        )
+        .map(|(input, n)| {
+            (
+                input,
+                Month::try_from(n + 1).expect("the spec has exactly twelve months"),
+            )
+        })
+    }
+
+    /// Creates a parser which recognizes the longest of the relative words of
+    /// the spec and returns the reference date of the specified
+    /// [`ParserContext`] shifted by the corresponding number of days.
+    pub fn relative_day_with<'s>(
+        &'s self,
+        ctx: &'s ParserContext,
+    ) -> impl Fn(&str) -> IResult<&str, NaiveDate> + 's {
+        move |input: &str| {
+            let (input, days) = longest(input, self.relative_days.iter().copied())?;
+
+            Ok((
+                input,
+                shift_date(ctx.today(), days, Period::Day)
+                    .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+            ))
+        }
+    }
+
+    /// Creates a parser which recognizes the weekday using the
+    /// [`LanguageSpec::named_weekday`] parser and returns the corresponding
+    /// [`NaiveDate`] for the week of the reference date of the specified
+    /// [`ParserContext`].
+    pub fn current_named_weekday_only_with<'s>(
+        &'s self,
+        ctx: &'s ParserContext,
+    ) -> impl Fn(&str) -> IResult<&str, NaiveDate> + 's {
+        move |input: &str| {
+            let (input, weekday) = self.named_weekday(input)?;
+
+            Ok((
+                input,
+                naive_date_for_weekday_at(ctx.today(), weekday)
+                    .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+            ))
+        }
+    }
+
+    /// Uses the words of the spec to recognize the `numeric` and the named
+    /// month dates in the [`order`](LanguageSpec::order) of the spec (the named
+    /// month dates always use the `day-month-year` sequence, e.g. `13 July
+    /// 2024`), optionally prefixed with the weekday, as well as the relative
+    /// words and the weekdays of the current week.
+    ///
+    /// If the specified date doesn't exist, returns `nom::Err::Error`
+    #[cfg(feature = "clock")]
+    pub fn bundle<'i>(&self, input: &'i str) -> IResult<&'i str, NaiveDate> {
+        self.bundle_with(&ParserContext::default())(input)
+    }
+
+    /// Creates the [`LanguageSpec::bundle`] parser which uses the reference
+    /// date of the specified [`ParserContext`].
+    pub fn bundle_with<'s>(
+        &'s self,
+        ctx: &'s ParserContext,
+    ) -> impl Fn(&str) -> IResult<&str, NaiveDate> + 's {
+        move |input: &str| {
+            ctx.trailing(alt((
+                |input| {
+                    let (input, _) = opt(terminated(
+                        |input| self.named_weekday(input),
+                        weekday_separator,
+                    ))(input)?;
+
+                    match self.order {
+                        DateOrder::Dmy => dmy_named_month_by(ctx, month_parser(self))(input),
+                        DateOrder::Mdy => mdy_named_month_by(ctx, month_parser(self))(input),
+                        DateOrder::Ymd => alt((
+                            y4_mm_dd_with(ctx),
+                            dmy_named_month_by(ctx, month_parser(self)),
+                        ))(input),
+                    }
+                },
+                self.relative_day_with(ctx),
+                self.current_named_weekday_only_with(ctx),
+            )))(input)
+        }
+    }
+}
+
+/// Creates the [`LanguageSpec::named_month`] parser of the specified `spec`
+/// (unlike the closure, it accepts the input of any lifetime).
+fn month_parser<'s>(spec: &'s LanguageSpec) -> impl Fn(&str) -> IResult<&str, Month> + 's {
+    move |input: &str| spec.named_month(input)
+}
+
+/// Recognizes the longest of the specified `words` at the start of the input
+/// `case insensitively` and returns the value of the word.
+fn longest<'i, 'w, T>(
+    input: &'i str,
+    words: impl Iterator<Item = (&'w str, T)>,
+) -> IResult<&'i str, T> {
+    words
+        .filter(|(word, _)| !word.is_empty())
+        .filter_map(|(word, value)| tag_no_case(word)(input).ok().map(|(rest, _)| (rest, value)))
+        .min_by_key(|(rest, _)| rest.len())
+        .ok_or(nom::Err::Error(Error::from_error_kind(
+            input,
+            ErrorKind::Tag,
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const ITALIAN: LanguageSpec<'static> = LanguageSpec::new(
+        [
+            &["lunedì", "lunedi", "lun"],
+            &["martedì", "martedi", "mar"],
+            &["mercoledì", "mercoledi", "mer"],
+            &["giovedì", "giovedi", "gio"],
+            &["venerdì", "venerdi", "ven"],
+            &["sabato", "sab"],
+            &["domenica", "dom"],
+        ],
+        [
+            &["gen", "gennaio"],
+            &["feb", "febbraio"],
+            &["mar", "marzo"],
+            &["apr", "aprile"],
+            &["mag", "maggio"],
+            &["giu", "giugno"],
+            &["lug", "luglio"],
+            &["ago", "agosto"],
+            &["set", "settembre"],
+            &["ott", "ottobre"],
+            &["nov", "novembre"],
+            &["dic", "dicembre"],
+        ],
+    )
+    .with_relative_days(&[("ieri", -1), ("oggi", 0), ("domani", 1), ("dopodomani", 2)]);
+
+    #[rstest]
+    #[case("lun", Ok(("", Weekday::Mon)))]
+    #[case("Martedì", Ok(("", Weekday::Tue)))]
+    #[case("VENERDI 13", Ok((" 13", Weekday::Fri)))]
+    #[case("luglio", Err(nom::Err::Error(Error::Nom("luglio", ErrorKind::Tag))))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(ITALIAN.named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("mar", Ok(("", Month::March)))]
+    #[case("marzo", Ok(("", Month::March)))]
+    #[case("Dicembre 2024", Ok((" 2024", Month::December)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(ITALIAN.named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024", DateOrder::Dmy, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("07/13/2024", DateOrder::Mdy, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024-07-13", DateOrder::Ymd, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 luglio 2024", DateOrder::Dmy, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 luglio 2024", DateOrder::Ymd, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("ven, 13 set", DateOrder::Dmy, Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("domani", DateOrder::Dmy, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("Dopodomani", DateOrder::Dmy, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap())))]
+    #[case("ieri", DateOrder::Dmy, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("venerdì", DateOrder::Dmy, Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case(
+        "31 febbraio 2024",
+        DateOrder::Dmy,
+        Err(nom::Err::Error(Error::NonExistentDate))
+    )]
+    fn test_bundle_with(
+        #[case] input: &str,
+        #[case] order: DateOrder,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(ITALIAN.with_order(order).bundle_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_runtime_spec() {
+        let words = "lundi mardi mercredi jeudi vendredi samedi dimanche".to_string();
+        let forms: Vec<Vec<&str>> = words.split(' ').map(|word| vec![word]).collect();
+        let weekdays: Vec<&[&str]> = forms.iter().map(Vec::as_slice).collect();
+        let spec = LanguageSpec::new(weekdays.try_into().unwrap(), [&[]; 12]);
+
+        assert_eq!(spec.named_weekday("Samedi"), Ok(("", Weekday::Sat)));
+    }
+}
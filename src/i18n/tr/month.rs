@@ -0,0 +1,232 @@
+use chrono::{Month, NaiveDate};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case_turkic,
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `Turkish`.
+///
+/// The following words are accepted:
+/// - `oca` -> [`Month::January`]
+/// - `şub` -> [`Month::February`]
+/// - `mar` -> [`Month::March`]
+/// - `nis` -> [`Month::April`]
+/// - `may` -> [`Month::May`]
+/// - `haz` -> [`Month::June`]
+/// - `tem` -> [`Month::July`]
+/// - `ağu` -> [`Month::August`]
+/// - `eyl` -> [`Month::September`]
+/// - `eki` -> [`Month::October`]
+/// - `kas` -> [`Month::November`]
+/// - `ara` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::tr::short_named_month;
+///
+/// assert_eq!(short_named_month("tem")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case_turkic("oca")),
+        value(Month::February, tag_no_case_turkic("şub")),
+        value(Month::March, tag_no_case_turkic("mar")),
+        value(Month::April, tag_no_case_turkic("nis")),
+        value(Month::May, tag_no_case_turkic("may")),
+        value(Month::June, tag_no_case_turkic("haz")),
+        value(Month::July, tag_no_case_turkic("tem")),
+        value(Month::August, tag_no_case_turkic("ağu")),
+        value(Month::September, tag_no_case_turkic("eyl")),
+        value(Month::October, tag_no_case_turkic("eki")),
+        value(Month::November, tag_no_case_turkic("kas")),
+        value(Month::December, tag_no_case_turkic("ara")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `Turkish` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `Turkish`.
+///
+/// The following words are accepted:
+/// - `ocak` -> [`Month::January`]
+/// - `şubat` -> [`Month::February`]
+/// - `mart` -> [`Month::March`]
+/// - `nisan` -> [`Month::April`]
+/// - `mayıs` -> [`Month::May`]
+/// - `haziran` -> [`Month::June`]
+/// - `temmuz` -> [`Month::July`]
+/// - `ağustos` -> [`Month::August`]
+/// - `eylül` -> [`Month::September`]
+/// - `ekim` -> [`Month::October`]
+/// - `kasım` -> [`Month::November`]
+/// - `aralık` -> [`Month::December`]
+///
+/// The dotted and dotless `i` are matched using the Turkish case mapping, see
+/// [`tag_no_case_turkic`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::tr::full_named_month;
+///
+/// assert_eq!(full_named_month("Temmuz")?.1, Month::July);
+/// assert_eq!(full_named_month("KASIM")?.1, Month::November);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case_turkic("ocak")),
+        value(Month::February, tag_no_case_turkic("şubat")),
+        value(Month::March, tag_no_case_turkic("mart")),
+        value(Month::April, tag_no_case_turkic("nisan")),
+        value(Month::May, tag_no_case_turkic("mayıs")),
+        value(Month::June, tag_no_case_turkic("haziran")),
+        value(Month::July, tag_no_case_turkic("temmuz")),
+        value(Month::August, tag_no_case_turkic("ağustos")),
+        value(Month::September, tag_no_case_turkic("eylül")),
+        value(Month::October, tag_no_case_turkic("ekim")),
+        value(Month::November, tag_no_case_turkic("kasım")),
+        value(Month::December, tag_no_case_turkic("aralık")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `Turkish`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::tr::named_month;
+///
+/// assert_eq!(named_month("tem.")?.1, Month::July);
+/// assert_eq!(named_month("temmuz")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `Turkish` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser and returns
+/// the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::tr::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 Temmuz 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    dd_named_month_y4(named_month)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `Turkish` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::tr::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 Temmuz")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("oca", Ok(("", Month::January)))]
+    #[case("ŞUB", Ok(("", Month::February)))]
+    #[case("Ağu", Ok(("", Month::August)))]
+    #[case("EKİ", Ok(("", Month::October)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("oca.", Ok(("", Month::January)))]
+    #[case("Ara.", Ok(("", Month::December)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("ocak", Ok(("", Month::January)))]
+    #[case("MAYIS", Ok(("", Month::May)))]
+    #[case("Haziran", Ok(("", Month::June)))]
+    #[case("EYLÜL", Ok(("", Month::September)))]
+    #[case("EKİM", Ok(("", Month::October)))]
+    #[case("ARALIK", Ok(("", Month::December)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("may", Ok(("", Month::May)))]
+    #[case("mayıs", Ok(("", Month::May)))]
+    #[case("mart", Ok(("", Month::March)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 Temmuz 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1 oca. 2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 Şubat 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 Temmuz", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 Temmuz", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 Temmuz" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
@@ -0,0 +1,198 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case_turkic, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Turkish`.
+///
+/// The following words are accepted:
+/// - `pzt` -> [`Weekday::Mon`]
+/// - `sal` -> [`Weekday::Tue`]
+/// - `çar` -> [`Weekday::Wed`]
+/// - `per` -> [`Weekday::Thu`]
+/// - `cum` -> [`Weekday::Fri`]
+/// - `cmt` -> [`Weekday::Sat`]
+/// - `paz` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::tr::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("cum")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case_turkic("pzt")),
+        value(Weekday::Tue, tag_no_case_turkic("sal")),
+        value(Weekday::Wed, tag_no_case_turkic("çar")),
+        value(Weekday::Thu, tag_no_case_turkic("per")),
+        value(Weekday::Fri, tag_no_case_turkic("cum")),
+        value(Weekday::Sat, tag_no_case_turkic("cmt")),
+        value(Weekday::Sun, tag_no_case_turkic("paz")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Turkish` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Turkish`.
+///
+/// The following words are accepted:
+/// - `pazartesi` -> [`Weekday::Mon`]
+/// - `salı` -> [`Weekday::Tue`]
+/// - `çarşamba` -> [`Weekday::Wed`]
+/// - `perşembe` -> [`Weekday::Thu`]
+/// - `cuma` -> [`Weekday::Fri`]
+/// - `cumartesi` -> [`Weekday::Sat`]
+/// - `pazar` -> [`Weekday::Sun`]
+///
+/// The dotted and dotless `i` are matched using the Turkish case mapping, see
+/// [`tag_no_case_turkic`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::tr::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("SALI")?.1, Weekday::Tue);
+/// assert_eq!(full_named_weekday("Cumartesi")?.1, Weekday::Sat);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case_turkic("pazartesi")),
+        value(Weekday::Tue, tag_no_case_turkic("salı")),
+        value(Weekday::Wed, tag_no_case_turkic("çarşamba")),
+        value(Weekday::Thu, tag_no_case_turkic("perşembe")),
+        value(Weekday::Sat, tag_no_case_turkic("cumartesi")),
+        value(Weekday::Fri, tag_no_case_turkic("cuma")),
+        value(Weekday::Sun, tag_no_case_turkic("pazar")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Turkish`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::tr::named_weekday;
+///
+/// assert_eq!(named_weekday("cum.")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Cuma")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Turkish` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, tr::current_named_weekday_only};
+///
+/// assert_eq!(
+///     current_named_weekday_only("çarşamba")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("pzt", Ok(("", Weekday::Mon)))]
+    #[case("ÇAR", Ok(("", Weekday::Wed)))]
+    #[case("Cmt", Ok(("", Weekday::Sat)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("pzt.", Ok(("", Weekday::Mon)))]
+    #[case("PAZ.", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("pazartesi", Ok(("", Weekday::Mon)))]
+    #[case("PAZARTESİ", Ok(("", Weekday::Mon)))]
+    #[case("Salı", Ok(("", Weekday::Tue)))]
+    #[case("SALI", Ok(("", Weekday::Tue)))]
+    #[case("ÇARŞAMBA", Ok(("", Weekday::Wed)))]
+    #[case("perşembe", Ok(("", Weekday::Thu)))]
+    #[case("cuma", Ok(("", Weekday::Fri)))]
+    #[case("CUMARTESİ", Ok(("", Weekday::Sat)))]
+    #[case("Pazar", Ok(("", Weekday::Sun)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("cum", Ok(("", Weekday::Fri)))]
+    #[case("Cumartesi", Ok(("", Weekday::Sat)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("pzt", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("salı", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
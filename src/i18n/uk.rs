@@ -0,0 +1,173 @@
+mod month;
+mod relative;
+mod weekday;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use nom::branch::alt;
+
+use crate::{
+    combinators::optional_suffix,
+    context::{ParserContext, TrailingInput},
+    i18n::{dmy_named_month_with, weekday_prefixed},
+    numeric::dd_mm_y2_with,
+    time::date_time,
+    types::IResult,
+};
+
+pub use self::{month::*, relative::*, weekday::*};
+
+/// Uses the following parsers to recognize the `numeric` and
+/// `language-specific` dates in `Ukrainian`:
+/// - Numeric date parsers:
+///     - [`dd_mm_y4`](crate::numeric::dd_mm_y4)
+///     - [`dd_mm_y2`](crate::numeric::dd_mm_y2)
+///     - [`dd_mm_only`](crate::numeric::dd_mm_only)
+///     - [`dd_only`](crate::numeric::dd_only)
+/// - Language-specific
+///     - [`dd_month_y4`]
+///     - [`dd_month_only`]
+///     - [`day_before_yesterday`]
+///     - [`yesterday`]
+///     - [`today`]
+///     - [`tomorrow`]
+///     - [`day_after_tomorrow`]
+///     - [`last_named_weekday`]
+///     - [`this_named_weekday`]
+///     - [`next_named_weekday`]
+///     - [`current_named_weekday_only`]
+///
+/// The numeric and named month dates may be prefixed with the weekday (e.g.
+/// `пт 13.09`), which is consumed but not validated, see
+/// [`weekday_prefixed`], and followed by the [`year_suffix`] (e.g.
+/// `13 липня 2024 року`).
+///
+/// If the specified date doesn't exist, returns `nom::Err::Error`
+#[cfg(feature = "clock")]
+pub fn bundle(input: &str) -> IResult<&str, NaiveDate> {
+    bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle`] parser which uses the reference date of the
+/// specified [`ParserContext`].
+pub fn bundle_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(alt((
+            optional_suffix(
+                weekday_prefixed(
+                    named_weekday,
+                    alt((dd_mm_y2_with(ctx), dmy_named_month_with(ctx, named_month))),
+                ),
+                year_suffix,
+            ),
+            day_before_yesterday_with(ctx),
+            yesterday_with(ctx),
+            today_with(ctx),
+            tomorrow_with(ctx),
+            day_after_tomorrow_with(ctx),
+            last_named_weekday_with(ctx),
+            this_named_weekday_with(ctx),
+            next_named_weekday_with(ctx),
+            current_named_weekday_only_with(ctx),
+        )))(input)
+    }
+}
+
+/// Uses the [`bundle`] parser to recognize the date, optionally followed by
+/// the time (see [`trailing_time`](crate::time::trailing_time)), and returns
+/// the corresponding [`NaiveDateTime`]. If the time is absent, uses midnight.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::uk::date_time_bundle;
+///
+/// assert_eq!(
+///     date_time_bundle("13 липня 2024 14:30")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(14, 30, 0)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn date_time_bundle(input: &str) -> IResult<&str, NaiveDateTime> {
+    date_time_bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`date_time_bundle`] parser which uses the reference date and
+/// the trailing input handling of the specified [`ParserContext`].
+pub fn date_time_bundle_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDateTime> + '_ {
+    let date_ctx = ctx.clone().with_trailing_input(TrailingInput::Keep);
+
+    move |input: &str| ctx.trailing(date_time(bundle_with(&date_ctx)))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Sub};
+
+    use chrono::{Datelike, Days, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1", Ok(("", Local::now().date_naive().with_day(1).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 12, 3).unwrap())))]
+    #[case("13 липня 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13 липня", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("позавчора", Ok(("", Local::now().sub(Days::new(2)).date_naive())))]
+    #[case("Вчора", Ok(("", Local::now().sub(Days::new(1)).date_naive())))]
+    #[case("Сьогодні", Ok(("", Local::now().date_naive())))]
+    #[case("Завтра", Ok(("", Local::now().add(Days::new(1)).date_naive())))]
+    #[case("післязавтра", Ok(("", Local::now().add(Days::new(2)).date_naive())))]
+    fn test_bundle(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(bundle(input), expected)
+    }
+
+    #[rstest]
+    #[case("09", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 9).unwrap())))]
+    #[case("03/12", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 3).unwrap())))]
+    #[case("13.07.24", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.09.2024 р.", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("позавчора", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 14).unwrap())))]
+    #[case("післязавтра", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap())))]
+    #[case("П'ятниця", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("пт 13.09", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("у наступну п'ятницю", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap())))]
+    #[case("минулий вівторок", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 9).unwrap())))]
+    #[case("в цю суботу", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("13 липня 2024 року", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(
+        "Листопад 2024",
+        Err(nom::Err::Error(crate::error::Error::Nom(
+            "Листопад 2024",
+            nom::error::ErrorKind::Tag
+        )))
+    )]
+    #[case("п'ятниця, 13 вересня 2024 р.", Ok(("", NaiveDate::from_ymd_opt(2024, 9, 13).unwrap())))]
+    #[case("1 листопада 2024 р., Київ", Ok((", Київ", NaiveDate::from_ymd_opt(2024, 11, 1).unwrap())))]
+    fn test_bundle_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("13.07.2024 14:30", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 30, 0).unwrap())))]
+    #[case("післязавтра 09:00", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 18).unwrap().and_hms_opt(9, 0, 0).unwrap())))]
+    #[case("13 липня 2024 р. 14:30", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 30, 0).unwrap())))]
+    fn test_date_time_bundle_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDateTime>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(date_time_bundle_with(&ctx)(input), expected)
+    }
+}
@@ -0,0 +1,297 @@
+use chrono::{Month, NaiveDate};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space0,
+    combinator::value,
+    sequence::{preceded, terminated},
+};
+
+use crate::{
+    combinators::{optional_suffix, tag_no_case, word_boundary},
+    context::ParserContext,
+    i18n::{dd_named_month_only_with, dd_named_month_y4},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named month in `Ukrainian`.
+///
+/// The following words are accepted:
+/// - `січ` -> [`Month::January`]
+/// - `лют` -> [`Month::February`]
+/// - `бер` -> [`Month::March`]
+/// - `квіт` -> [`Month::April`]
+/// - `трав` -> [`Month::May`]
+/// - `черв` -> [`Month::June`]
+/// - `лип` -> [`Month::July`]
+/// - `серп` -> [`Month::August`]
+/// - `вер` -> [`Month::September`]
+/// - `жовт` -> [`Month::October`]
+/// - `лист` -> [`Month::November`]
+/// - `груд` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::uk::short_named_month;
+///
+/// assert_eq!(short_named_month("лип")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        value(Month::January, tag_no_case("січ")),
+        value(Month::February, tag_no_case("лют")),
+        value(Month::March, tag_no_case("бер")),
+        value(Month::April, tag_no_case("квіт")),
+        value(Month::May, tag_no_case("трав")),
+        value(Month::June, tag_no_case("черв")),
+        value(Month::July, tag_no_case("лип")),
+        value(Month::August, tag_no_case("серп")),
+        value(Month::September, tag_no_case("вер")),
+        value(Month::October, tag_no_case("жовт")),
+        value(Month::November, tag_no_case("лист")),
+        value(Month::December, tag_no_case("груд")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named month in `Ukrainian` which
+/// ends with a `dot` symbol using the [`short_named_month`] parser.
+pub fn short_named_month_dot(input: &str) -> IResult<&str, Month> {
+    terminated(short_named_month, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named month in `Ukrainian` in either
+/// the nominative or the genitive case.
+///
+/// The following words are accepted:
+/// - `січень` | `січня` -> [`Month::January`]
+/// - `лютий` | `лютого` -> [`Month::February`]
+/// - `березень` | `березня` -> [`Month::March`]
+/// - `квітень` | `квітня` -> [`Month::April`]
+/// - `травень` | `травня` -> [`Month::May`]
+/// - `червень` | `червня` -> [`Month::June`]
+/// - `липень` | `липня` -> [`Month::July`]
+/// - `серпень` | `серпня` -> [`Month::August`]
+/// - `вересень` | `вересня` -> [`Month::September`]
+/// - `жовтень` | `жовтня` -> [`Month::October`]
+/// - `листопад` | `листопада` -> [`Month::November`]
+/// - `грудень` | `грудня` -> [`Month::December`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::uk::full_named_month;
+///
+/// assert_eq!(full_named_month("Липень")?.1, Month::July);
+/// assert_eq!(full_named_month("липня")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_month(input: &str) -> IResult<&str, Month> {
+    alt((
+        alt((
+            value(Month::January, tag_no_case("січень")),
+            value(Month::January, tag_no_case("січня")),
+            value(Month::February, tag_no_case("лютий")),
+            value(Month::February, tag_no_case("лютого")),
+            value(Month::March, tag_no_case("березень")),
+            value(Month::March, tag_no_case("березня")),
+            value(Month::April, tag_no_case("квітень")),
+            value(Month::April, tag_no_case("квітня")),
+            value(Month::May, tag_no_case("травень")),
+            value(Month::May, tag_no_case("травня")),
+            value(Month::June, tag_no_case("червень")),
+            value(Month::June, tag_no_case("червня")),
+        )),
+        alt((
+            value(Month::July, tag_no_case("липень")),
+            value(Month::July, tag_no_case("липня")),
+            value(Month::August, tag_no_case("серпень")),
+            value(Month::August, tag_no_case("серпня")),
+            value(Month::September, tag_no_case("вересень")),
+            value(Month::September, tag_no_case("вересня")),
+            value(Month::October, tag_no_case("жовтень")),
+            value(Month::October, tag_no_case("жовтня")),
+            value(Month::November, tag_no_case("листопада")),
+            value(Month::November, tag_no_case("листопад")),
+            value(Month::December, tag_no_case("грудень")),
+            value(Month::December, tag_no_case("грудня")),
+        )),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named month
+/// in `Ukrainian`. Uses the following parsers:
+/// - [`full_named_month`]
+/// - [`short_named_month_dot`]
+/// - [`short_named_month`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use nom_date_parsers::i18n::uk::named_month;
+///
+/// assert_eq!(named_month("лип")?.1, Month::July);
+/// assert_eq!(named_month("липень")?.1, Month::July);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_month(input: &str) -> IResult<&str, Month> {
+    alt((full_named_month, short_named_month_dot, short_named_month))(input)
+}
+
+/// Recognizes the `case insensitive` genitive year suffix in `Ukrainian`
+/// preceded by any number of spaces: `року`, `р.` or `р` (e.g. the suffix of
+/// `13 липня 2024 року`).
+///
+/// The suffix must not be followed by a letter or a digit, so the `р` of the
+/// next word (e.g. `ранку`) isn't consumed.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::i18n::uk::year_suffix;
+///
+/// assert_eq!(year_suffix(" року")?, ("", ()));
+/// assert_eq!(year_suffix(" р., Київ")?, (", Київ", ()));
+/// assert!(year_suffix(" ранку").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn year_suffix(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        preceded(
+            space0,
+            alt((
+                terminated(tag_no_case("року"), word_boundary),
+                tag_no_case("р."),
+                terminated(tag_no_case("р"), word_boundary),
+            )),
+        ),
+    )(input)
+}
+
+/// Recognizes the `day`, `month` and `year` parts in `Ukrainian` using the
+/// [`dd_named_month_y4`] parser with the [`named_month`] parser, followed by
+/// the optional [`year_suffix`], and returns the corresponding [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::uk::dd_month_y4;
+///
+/// assert_eq!(
+///     dd_month_y4("13 липня 2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     dd_month_y4("13 липня 2024 року")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dd_month_y4(input: &str) -> IResult<&str, NaiveDate> {
+    optional_suffix(dd_named_month_y4(named_month), year_suffix)(input)
+}
+
+/// Recognizes the `day` and `month` parts in `Ukrainian` using the
+/// [`dd_named_month_only`](crate::i18n::dd_named_month_only) parser with the
+/// [`named_month`] parser and returns the corresponding [`NaiveDate`] for the
+/// current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::uk::dd_month_only;
+///
+/// assert_eq!(
+///     dd_month_only("13 липня")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_month_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_month_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_month_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn dd_month_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_named_month_only_with(ctx, named_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[rstest]
+    #[case("січ", Ok(("", Month::January)))]
+    #[case("КВІТ", Ok(("", Month::April)))]
+    #[case("Груд", Ok(("", Month::December)))]
+    fn test_short_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("лют.", Ok(("", Month::February)))]
+    #[case("Жовт.", Ok(("", Month::October)))]
+    fn test_short_named_month_dot(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(short_named_month_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("січень", Ok(("", Month::January)))]
+    #[case("Лютого", Ok(("", Month::February)))]
+    #[case("ЛИСТОПАД", Ok(("", Month::November)))]
+    #[case("листопада", Ok(("", Month::November)))]
+    #[case("вересня", Ok(("", Month::September)))]
+    fn test_full_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(full_named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("бер", Ok(("", Month::March)))]
+    #[case("березня", Ok(("", Month::March)))]
+    fn test_named_month(#[case] input: &str, #[case] expected: IResult<&str, Month>) {
+        assert_eq!(named_month(input), expected);
+    }
+
+    #[rstest]
+    #[case(" року", Ok(("", ())))]
+    #[case("р.", Ok(("", ())))]
+    #[case(" р", Ok(("", ())))]
+    #[case(
+        " ранку",
+        Err(nom::Err::Error(Error::Nom("анку", nom::error::ErrorKind::Not)))
+    )]
+    fn test_year_suffix(#[case] input: &str, #[case] expected: IResult<&str, ()>) {
+        assert_eq!(year_suffix(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 липня 2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1 січня 2025 р.", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("1-січ-2025", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())))]
+    #[case("31 лютого 2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_month_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 липня", Ok(("", NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap())))]
+    #[case("32 липня", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 липня" })))]
+    fn test_dd_month_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_month_only(input), expected);
+    }
+}
@@ -0,0 +1,435 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{one_of, space1},
+    combinator::{opt, value},
+    sequence::{pair, preceded, terminated},
+};
+
+use crate::{
+    combinators::tag_no_case,
+    context::ParserContext,
+    error::Error,
+    i18n::{naive_date_for_weekday_at, relative_weekday},
+    types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Ukrainian`.
+///
+/// The following words are accepted:
+/// - `пн` -> [`Weekday::Mon`]
+/// - `вт` -> [`Weekday::Tue`]
+/// - `ср` -> [`Weekday::Wed`]
+/// - `чт` -> [`Weekday::Thu`]
+/// - `пт` -> [`Weekday::Fri`]
+/// - `сб` -> [`Weekday::Sat`]
+/// - `нд` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uk::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("пн")?.1, Weekday::Mon);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("пн")),
+        value(Weekday::Tue, tag_no_case("вт")),
+        value(Weekday::Wed, tag_no_case("ср")),
+        value(Weekday::Thu, tag_no_case("чт")),
+        value(Weekday::Fri, tag_no_case("пт")),
+        value(Weekday::Sat, tag_no_case("сб")),
+        value(Weekday::Sun, tag_no_case("нд")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Ukrainian` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `п` letter followed by the apostrophe (`'`, `’` or `ʼ`) and
+/// the specified rest of the `п'ятниця` word form.
+fn friday<'a>(rest: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, Weekday> {
+    value(
+        Weekday::Fri,
+        pair(pair(tag_no_case("п"), one_of("'’ʼ")), tag_no_case(rest)),
+    )
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Ukrainian`.
+///
+/// The following words are accepted:
+/// - `понеділок` -> [`Weekday::Mon`]
+/// - `вівторок` -> [`Weekday::Tue`]
+/// - `середа` -> [`Weekday::Wed`]
+/// - `четвер` -> [`Weekday::Thu`]
+/// - `п'ятниця` -> [`Weekday::Fri`]
+/// - `субота` -> [`Weekday::Sat`]
+/// - `неділя` -> [`Weekday::Sun`]
+///
+/// The apostrophe of the `п'ятниця` may be either `'`, `’` or `ʼ`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uk::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("понеділок")?.1, Weekday::Mon);
+/// assert_eq!(full_named_weekday("П’ятниця")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("понеділок")),
+        value(Weekday::Tue, tag_no_case("вівторок")),
+        value(Weekday::Wed, tag_no_case("середа")),
+        value(Weekday::Thu, tag_no_case("четвер")),
+        friday("ятниця"),
+        value(Weekday::Sat, tag_no_case("субота")),
+        value(Weekday::Sun, tag_no_case("неділя")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Ukrainian` in the
+/// accusative case, which is used after the prepositions and modifiers (e.g.
+/// `у наступну п'ятницю`).
+///
+/// The following words are accepted:
+/// - `понеділок` -> [`Weekday::Mon`]
+/// - `вівторок` -> [`Weekday::Tue`]
+/// - `середу` -> [`Weekday::Wed`]
+/// - `четвер` -> [`Weekday::Thu`]
+/// - `п'ятницю` -> [`Weekday::Fri`]
+/// - `суботу` -> [`Weekday::Sat`]
+/// - `неділю` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uk::full_named_weekday_accusative;
+///
+/// assert_eq!(full_named_weekday_accusative("п'ятницю")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday_accusative(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("понеділок")),
+        value(Weekday::Tue, tag_no_case("вівторок")),
+        value(Weekday::Wed, tag_no_case("середу")),
+        value(Weekday::Thu, tag_no_case("четвер")),
+        friday("ятницю"),
+        value(Weekday::Sat, tag_no_case("суботу")),
+        value(Weekday::Sun, tag_no_case("неділю")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Ukrainian`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uk::named_weekday;
+///
+/// assert_eq!(named_weekday("пт")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("П'ятниця")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Ukrainian` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, uk::current_named_weekday_only};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Середа")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+/// Recognizes the weekday after the modifier in either the nominative or the
+/// accusative case.
+fn modified_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((full_named_weekday_accusative, named_weekday))(input)
+}
+
+/// Recognizes the weekday modifier specified by its gender forms, optionally
+/// preceded by the `в` or `у` preposition.
+fn weekday_modifier<'a>(
+    forms: [&'static str; 4],
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    preceded(
+        opt(terminated(
+            alt((tag_no_case("в"), tag_no_case("у"))),
+            space1,
+        )),
+        alt((
+            tag_no_case(forms[0]),
+            tag_no_case(forms[1]),
+            tag_no_case(forms[2]),
+            tag_no_case(forms[3]),
+        )),
+    )
+}
+
+/// Recognizes the `case insensitive` words `минулий`, `минула`, `минуле` or
+/// `минулу` (optionally preceded by the `в` or `у` preposition) followed by
+/// the weekday in `Ukrainian` (e.g. `у минулу п'ятницю`) and returns the
+/// corresponding [`NaiveDate`] for the previous week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Days, NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, uk::last_named_weekday};
+///
+/// assert_eq!(
+///     last_named_weekday("у минулу п'ятницю")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap() - Days::new(7)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn last_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    last_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`last_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn last_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(
+            ctx,
+            -1,
+            weekday_modifier(["минулий", "минула", "минуле", "минулу"]),
+            modified_named_weekday,
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` words `цей`, `ця`, `це` or `цю`
+/// (optionally preceded by the `в` or `у` preposition) followed by the weekday
+/// in `Ukrainian` (e.g. `у цю п'ятницю`) and returns the corresponding
+/// [`NaiveDate`] for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, uk::this_named_weekday};
+///
+/// assert_eq!(
+///     this_named_weekday("у цю п'ятницю")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn this_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    this_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`this_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn this_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(
+            ctx,
+            0,
+            weekday_modifier(["цей", "ця", "це", "цю"]),
+            modified_named_weekday,
+        )(input)
+    }
+}
+
+/// Recognizes the `case insensitive` words `наступний`, `наступна`,
+/// `наступне` or `наступну` (optionally preceded by the `в` or `у`
+/// preposition) followed by the weekday in `Ukrainian` (e.g.
+/// `у наступну п'ятницю`) and returns the corresponding [`NaiveDate`] for the
+/// next week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Days, NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, uk::next_named_weekday};
+///
+/// assert_eq!(
+///     next_named_weekday("у наступну п'ятницю")?.1,
+///     naive_date_for_weekday(Weekday::Fri).unwrap() + Days::new(7)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn next_named_weekday(input: &str) -> IResult<&str, NaiveDate> {
+    next_named_weekday_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`next_named_weekday`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn next_named_weekday_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        relative_weekday(
+            ctx,
+            1,
+            weekday_modifier(["наступний", "наступна", "наступне", "наступну"]),
+            modified_named_weekday,
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("пн", Ok(("", Weekday::Mon)))]
+    #[case("ЧТ", Ok(("", Weekday::Thu)))]
+    #[case("Нд", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("пн.", Ok(("", Weekday::Mon)))]
+    #[case("Сб.", Ok(("", Weekday::Sat)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("понеділок", Ok(("", Weekday::Mon)))]
+    #[case("Вівторок", Ok(("", Weekday::Tue)))]
+    #[case("СЕРЕДА", Ok(("", Weekday::Wed)))]
+    #[case("п'ятниця", Ok(("", Weekday::Fri)))]
+    #[case("п’ятниця", Ok(("", Weekday::Fri)))]
+    #[case("пʼятниця", Ok(("", Weekday::Fri)))]
+    #[case("НеДіЛя", Ok(("", Weekday::Sun)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("середу", Ok(("", Weekday::Wed)))]
+    #[case("П'ятницю", Ok(("", Weekday::Fri)))]
+    #[case("неділю", Ok(("", Weekday::Sun)))]
+    fn test_full_named_weekday_accusative(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, Weekday>,
+    ) {
+        assert_eq!(full_named_weekday_accusative(input), expected)
+    }
+
+    #[rstest]
+    #[case("пт", Ok(("", Weekday::Fri)))]
+    #[case("субота", Ok(("", Weekday::Sat)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("пн", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("Неділя", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    fn test_current_named_weekday_only_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(current_named_weekday_only_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("минула п'ятниця", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 12).unwrap())))]
+    #[case("у минулий понеділок", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 8).unwrap())))]
+    #[case("В минулу середу", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 10).unwrap())))]
+    fn test_last_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(last_named_weekday_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("ця субота", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())))]
+    #[case("у цей понеділок", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("цю неділю", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("в цю пт", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    fn test_this_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(this_named_weekday_with(&ctx)(input), expected)
+    }
+
+    #[rstest]
+    #[case("наступний вівторок", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("у наступну п’ятницю", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap())))]
+    #[case("Наступна неділя", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 28).unwrap())))]
+    fn test_next_named_weekday_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(next_named_weekday_with(&ctx)(input), expected)
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Uzbek`.
+///
+/// The following words are accepted:
+/// - `душ` -> [`Weekday::Mon`]
+/// - `сеш` -> [`Weekday::Tue`]
+/// - `чор` -> [`Weekday::Wed`]
+/// - `пай` -> [`Weekday::Thu`]
+/// - `жум` -> [`Weekday::Fri`]
+/// - `шан` -> [`Weekday::Sat`]
+/// - `якш` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uz::cyrl::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("жум")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("душ")),
+        value(Weekday::Tue, tag_no_case("сеш")),
+        value(Weekday::Wed, tag_no_case("чор")),
+        value(Weekday::Thu, tag_no_case("пай")),
+        value(Weekday::Fri, tag_no_case("жум")),
+        value(Weekday::Sat, tag_no_case("шан")),
+        value(Weekday::Sun, tag_no_case("якш")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Uzbek` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Uzbek`.
+///
+/// The following words are accepted:
+/// - `душанба` -> [`Weekday::Mon`]
+/// - `сешанба` -> [`Weekday::Tue`]
+/// - `чоршанба` -> [`Weekday::Wed`]
+/// - `пайшанба` -> [`Weekday::Thu`]
+/// - `жума` -> [`Weekday::Fri`]
+/// - `шанба` -> [`Weekday::Sat`]
+/// - `якшанба` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uz::cyrl::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("чоршанба")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("душанба")),
+        value(Weekday::Tue, tag_no_case("сешанба")),
+        value(Weekday::Wed, tag_no_case("чоршанба")),
+        value(Weekday::Thu, tag_no_case("пайшанба")),
+        value(Weekday::Fri, tag_no_case("жума")),
+        value(Weekday::Sat, tag_no_case("шанба")),
+        value(Weekday::Sun, tag_no_case("якшанба")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Uzbek`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uz::cyrl::named_weekday;
+///
+/// assert_eq!(named_weekday("жум")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Жума")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Uzbek` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, uz::cyrl::current_named_weekday_only};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Чоршанба")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("душ", Ok(("", Weekday::Mon)))]
+    #[case("СЕШ", Ok(("", Weekday::Tue)))]
+    #[case("Чор", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("душ.", Ok(("", Weekday::Mon)))]
+    #[case("СЕШ.", Ok(("", Weekday::Tue)))]
+    #[case("Чор.", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("душанба", Ok(("", Weekday::Mon)))]
+    #[case("Сешанба", Ok(("", Weekday::Tue)))]
+    #[case("ЧОРШАНБА", Ok(("", Weekday::Wed)))]
+    #[case("Шанба", Ok(("", Weekday::Sat)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("душ", Ok(("", Weekday::Mon)))]
+    #[case("сешанба", Ok(("", Weekday::Tue)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("душ", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Сешанба", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::terminated};
+
+use crate::{
+    combinators::tag_no_case, context::ParserContext, error::Error,
+    i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the `case insensitive` short-named weekday in `Uzbek`.
+///
+/// The following words are accepted:
+/// - `dush` -> [`Weekday::Mon`]
+/// - `sesh` -> [`Weekday::Tue`]
+/// - `chor` -> [`Weekday::Wed`]
+/// - `pay` -> [`Weekday::Thu`]
+/// - `jum` -> [`Weekday::Fri`]
+/// - `shan` -> [`Weekday::Sat`]
+/// - `yak` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uz::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("jum")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("dush")),
+        value(Weekday::Tue, tag_no_case("sesh")),
+        value(Weekday::Wed, tag_no_case("chor")),
+        value(Weekday::Thu, tag_no_case("pay")),
+        value(Weekday::Fri, tag_no_case("jum")),
+        value(Weekday::Sat, tag_no_case("shan")),
+        value(Weekday::Sun, tag_no_case("yak")),
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` short-named weekday in `Uzbek` which
+/// ends with a `dot` symbol using the [`short_named_weekday`] parser.
+pub fn short_named_weekday_dot(input: &str) -> IResult<&str, Weekday> {
+    terminated(short_named_weekday, tag("."))(input)
+}
+
+/// Recognizes the `case insensitive` full-named weekday in `Uzbek`.
+///
+/// The following words are accepted:
+/// - `dushanba` -> [`Weekday::Mon`]
+/// - `seshanba` -> [`Weekday::Tue`]
+/// - `chorshanba` -> [`Weekday::Wed`]
+/// - `payshanba` -> [`Weekday::Thu`]
+/// - `juma` -> [`Weekday::Fri`]
+/// - `shanba` -> [`Weekday::Sat`]
+/// - `yakshanba` -> [`Weekday::Sun`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uz::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("chorshanba")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag_no_case("dushanba")),
+        value(Weekday::Tue, tag_no_case("seshanba")),
+        value(Weekday::Wed, tag_no_case("chorshanba")),
+        value(Weekday::Thu, tag_no_case("payshanba")),
+        value(Weekday::Fri, tag_no_case("juma")),
+        value(Weekday::Sat, tag_no_case("shanba")),
+        value(Weekday::Sun, tag_no_case("yakshanba")),
+    ))(input)
+}
+
+/// Recognizes either the `case insensitive` short-named or full-named weekday
+/// in `Uzbek`. Uses the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday_dot`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::uz::named_weekday;
+///
+/// assert_eq!(named_weekday("jum")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("Juma")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        full_named_weekday,
+        short_named_weekday_dot,
+        short_named_weekday,
+    ))(input)
+}
+
+/// Recognizes the `case insensitive` weekday in `Uzbek` using the
+/// [`named_weekday`] parser and returns the corresponding [`NaiveDate`]
+/// for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, uz::current_named_weekday_only};
+///
+/// assert_eq!(
+///     current_named_weekday_only("Chorshanba")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("dush", Ok(("", Weekday::Mon)))]
+    #[case("SESH", Ok(("", Weekday::Tue)))]
+    #[case("Chor", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("dush.", Ok(("", Weekday::Mon)))]
+    #[case("SESH.", Ok(("", Weekday::Tue)))]
+    #[case("Chor.", Ok(("", Weekday::Wed)))]
+    fn test_short_named_weekday_dot(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday_dot(input), expected);
+    }
+
+    #[rstest]
+    #[case("dushanba", Ok(("", Weekday::Mon)))]
+    #[case("Seshanba", Ok(("", Weekday::Tue)))]
+    #[case("CHORSHANBA", Ok(("", Weekday::Wed)))]
+    #[case("Shanba", Ok(("", Weekday::Sat)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("dush", Ok(("", Weekday::Mon)))]
+    #[case("seshanba", Ok(("", Weekday::Tue)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("dush", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("Seshanba", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
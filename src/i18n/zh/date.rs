@@ -0,0 +1,171 @@
+use chrono::NaiveDate;
+use nom::sequence::tuple;
+
+use crate::{
+    context::ParserContext,
+    error::Error,
+    i18n::{day_suffix, unit_suffix, y_mm_dd_suffixed},
+    numeric::{dd, mm, y4, y4_with},
+    types::IResult,
+};
+
+/// The `day` suffixes: either `日` or the colloquial `号`.
+const DAY_SUFFIXES: &[&str] = &["日", "号"];
+
+/// Recognizes the date in `Chinese` with the `year`, `month` and `day` parts
+/// followed by the `年`, `月` and `日` (or `号`) suffixes and returns the
+/// corresponding [`NaiveDate`] if the date exists, otherwise returns
+/// [`Error::NonExistentDate`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::i18n::zh::y4_mm_dd;
+///
+/// assert_eq!(
+///     y4_mm_dd("2024年7月13日")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn y4_mm_dd(input: &str) -> IResult<&str, NaiveDate> {
+    y4_mm_dd_by(y4, input)
+}
+
+/// Creates the [`y4_mm_dd`] parser which accepts the years in the
+/// [`YearRange`](crate::context::YearRange) of the specified
+/// [`ParserContext`].
+pub fn y4_mm_dd_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| y4_mm_dd_by(y4_with(ctx), input)
+}
+
+/// Recognizes the [`y4_mm_dd`] date using the specified `year` parser.
+fn y4_mm_dd_by<'a>(
+    year: impl Fn(&'a str) -> IResult<&'a str, u32>,
+    input: &'a str,
+) -> IResult<&'a str, NaiveDate> {
+    let (input, (y4, mm, dd)) = y_mm_dd_suffixed(year, DAY_SUFFIXES)(input)?;
+
+    Ok((
+        input,
+        NaiveDate::from_ymd_opt(y4 as i32, mm, dd)
+            .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+    ))
+}
+
+/// Recognizes the date in `Chinese` with the `month` and `day` parts followed
+/// by the `月` and `日` (or `号`) suffixes and returns the corresponding
+/// [`NaiveDate`] for the current year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local, NaiveDate};
+/// use nom_date_parsers::i18n::zh::mm_dd_only;
+///
+/// assert_eq!(
+///     mm_dd_only("7月13号")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn mm_dd_only(input: &str) -> IResult<&str, NaiveDate> {
+    mm_dd_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`mm_dd_only`] parser which takes the year from the reference
+/// date of the specified [`ParserContext`].
+pub fn mm_dd_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (mm, (), dd, ())) =
+            tuple((mm, unit_suffix("月"), dd, day_suffix(DAY_SUFFIXES)))(input)?;
+
+        Ok((
+            input,
+            ctx.resolve(Some(mm), dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Recognizes the `day` part in `Chinese` followed by the `日` (or `号`) suffix
+/// and returns the corresponding [`NaiveDate`] for the current month and year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, Local};
+/// use nom_date_parsers::i18n::zh::dd_only;
+///
+/// assert_eq!(
+///     dd_only("1号")?.1,
+///     Local::now().date_naive().with_day(1).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dd_only(input: &str) -> IResult<&str, NaiveDate> {
+    dd_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dd_only`] parser which takes the month and year from the
+/// reference date of the specified [`ParserContext`].
+pub fn dd_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (dd, ())) = tuple((dd, day_suffix(DAY_SUFFIXES)))(input)?;
+
+        Ok((
+            input,
+            ctx.resolve(None, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Local};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("2024年7月13日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024年07月13日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024 年 7 月 13 号", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024年13月1日", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13月1日" })))]
+    #[case("2024年2月30日", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_y4_mm_dd(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(y4_mm_dd(input), expected);
+    }
+
+    #[rstest]
+    #[case("7月13日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("12月1号", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())))]
+    #[case("7月32日", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32日" })))]
+    fn test_mm_dd_only_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(mm_dd_only_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("13日", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("1号", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 1).unwrap())))]
+    fn test_dd_only_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(dd_only_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_dd_only() {
+        assert_eq!(
+            dd_only("1日"),
+            Ok(("", Local::now().date_naive().with_day(1).unwrap()))
+        );
+    }
+}
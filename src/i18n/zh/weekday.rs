@@ -0,0 +1,164 @@
+use chrono::{NaiveDate, Weekday};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, sequence::preceded};
+
+use crate::{
+    context::ParserContext, error::Error, i18n::naive_date_for_weekday_at, types::IResult,
+};
+
+/// Recognizes the number of the weekday in `Chinese` which follows the weekday
+/// prefix (e.g. `星期`).
+///
+/// The following words are accepted:
+/// - `一` -> [`Weekday::Mon`]
+/// - `二` -> [`Weekday::Tue`]
+/// - `三` -> [`Weekday::Wed`]
+/// - `四` -> [`Weekday::Thu`]
+/// - `五` -> [`Weekday::Fri`]
+/// - `六` -> [`Weekday::Sat`]
+/// - `日` | `天` -> [`Weekday::Sun`]
+fn weekday_number(input: &str) -> IResult<&str, Weekday> {
+    alt((
+        value(Weekday::Mon, tag("一")),
+        value(Weekday::Tue, tag("二")),
+        value(Weekday::Wed, tag("三")),
+        value(Weekday::Thu, tag("四")),
+        value(Weekday::Fri, tag("五")),
+        value(Weekday::Sat, tag("六")),
+        value(Weekday::Sun, tag("日")),
+        value(Weekday::Sun, tag("天")),
+    ))(input)
+}
+
+/// Recognizes the short-named weekday in `Chinese`: the `周` prefix followed
+/// by the number of the weekday (e.g. `周一` -> [`Weekday::Mon`], `周日` or
+/// `周天` -> [`Weekday::Sun`]).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::zh::short_named_weekday;
+///
+/// assert_eq!(short_named_weekday("周五")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn short_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    preceded(tag("周"), weekday_number)(input)
+}
+
+/// Recognizes the full-named weekday in `Chinese`: either the `星期` or the
+/// colloquial `礼拜` prefix followed by the number of the weekday (e.g.
+/// `星期一` -> [`Weekday::Mon`], `星期日` or `星期天` -> [`Weekday::Sun`]).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::zh::full_named_weekday;
+///
+/// assert_eq!(full_named_weekday("星期三")?.1, Weekday::Wed);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn full_named_weekday(input: &str) -> IResult<&str, Weekday> {
+    preceded(alt((tag("星期"), tag("礼拜"))), weekday_number)(input)
+}
+
+/// Recognizes either the short-named or full-named weekday in `Chinese`. Uses
+/// the following parsers:
+/// - [`full_named_weekday`]
+/// - [`short_named_weekday`]
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::i18n::zh::named_weekday;
+///
+/// assert_eq!(named_weekday("周五")?.1, Weekday::Fri);
+/// assert_eq!(named_weekday("星期五")?.1, Weekday::Fri);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn named_weekday(input: &str) -> IResult<&str, Weekday> {
+    alt((full_named_weekday, short_named_weekday))(input)
+}
+
+/// Recognizes the weekday in `Chinese` using the [`named_weekday`] parser and
+/// returns the corresponding [`NaiveDate`] for the current week.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::i18n::{naive_date_for_weekday, zh::current_named_weekday_only};
+///
+/// assert_eq!(
+///     current_named_weekday_only("星期三")?.1,
+///     naive_date_for_weekday(Weekday::Wed).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn current_named_weekday_only(input: &str) -> IResult<&str, NaiveDate> {
+    current_named_weekday_only_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`current_named_weekday_only`] parser which uses the week of the
+/// reference date of the specified [`ParserContext`].
+pub fn current_named_weekday_only_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, weekday) = named_weekday(input)?;
+
+        Ok((
+            input,
+            naive_date_for_weekday_at(ctx.today(), weekday)
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::i18n::naive_date_for_weekday;
+
+    use super::*;
+
+    #[rstest]
+    #[case("周一", Ok(("", Weekday::Mon)))]
+    #[case("周六", Ok(("", Weekday::Sat)))]
+    #[case("周日", Ok(("", Weekday::Sun)))]
+    #[case("周天", Ok(("", Weekday::Sun)))]
+    fn test_short_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(short_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("星期一", Ok(("", Weekday::Mon)))]
+    #[case("星期四", Ok(("", Weekday::Thu)))]
+    #[case("星期天", Ok(("", Weekday::Sun)))]
+    #[case("礼拜二", Ok(("", Weekday::Tue)))]
+    fn test_full_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(full_named_weekday(input), expected);
+    }
+
+    #[rstest]
+    #[case("周日", Ok(("", Weekday::Sun)))]
+    #[case("星期日", Ok(("", Weekday::Sun)))]
+    fn test_named_weekday(#[case] input: &str, #[case] expected: IResult<&str, Weekday>) {
+        assert_eq!(named_weekday(input), expected)
+    }
+
+    #[rstest]
+    #[case("周一", Ok(("", naive_date_for_weekday(Weekday::Mon).unwrap())))]
+    #[case("星期二", Ok(("", naive_date_for_weekday(Weekday::Tue).unwrap())))]
+    fn test_current_named_weekday_only(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(current_named_weekday_only(input), expected)
+    }
+}
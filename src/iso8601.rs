@@ -0,0 +1,438 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, Utc};
+use nom::{
+    branch::alt,
+    bytes::complete::{take_while1, take_while_m_n},
+    character::complete::{char, one_of},
+    combinator::{map, map_res, opt, value},
+    sequence::{pair, preceded, tuple},
+};
+
+use crate::{error::Error, types::IResult};
+
+/// The value recognized by the [`any`] parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Iso8601 {
+    /// The calendar date only, e.g. `2024-07-13`
+    Date(NaiveDate),
+    /// The date and time without the offset, e.g. `2024-07-13T14:30:00`
+    DateTime(NaiveDateTime),
+    /// The date and time with the offset, e.g. `2024-07-13T14:30:00+03:00`
+    DateTimeOffset(DateTime<FixedOffset>),
+}
+
+/// Recognizes exactly `n` ascii digits.
+fn digits(n: usize) -> impl Fn(&str) -> IResult<&str, u32> {
+    move |input: &str| {
+        map_res(
+            take_while_m_n(n, n, |c: char| c.is_ascii_digit()),
+            |s: &str| s.parse(),
+        )(input)
+    }
+}
+
+/// Recognizes the fraction of a second (the `.` or `,` followed by one or more
+/// digits) and returns the number of nanoseconds, the digits beyond the
+/// nanosecond precision are ignored.
+fn fraction(input: &str) -> IResult<&str, u32> {
+    let (input, digits) = preceded(one_of(".,"), take_while1(|c: char| c.is_ascii_digit()))(input)?;
+
+    let nanos = digits
+        .bytes()
+        .chain(core::iter::repeat(b'0'))
+        .take(9)
+        .fold(0, |nanos, digit| nanos * 10 + u32::from(digit - b'0'));
+
+    Ok((input, nanos))
+}
+
+/// Recognizes exactly `n` ascii digits and returns the number along with the
+/// input starting with the digits.
+fn positioned_digits(n: usize) -> impl Fn(&str) -> IResult<&str, (u32, &str)> {
+    move |input: &str| map(digits(n), |number| (number, input))(input)
+}
+
+/// The date parts, the `month` and `day` parts are returned along with the
+/// input starting with them.
+type DateParts<'a> = (u32, (u32, &'a str), (u32, &'a str));
+
+/// Recognizes the date parts in either the extended (`YYYY-MM-DD`) or the basic
+/// (`YYYYMMDD`) format without validating them.
+fn date_parts(input: &str) -> IResult<&str, DateParts<'_>> {
+    alt((
+        tuple((
+            digits(4),
+            preceded(char('-'), positioned_digits(2)),
+            preceded(char('-'), positioned_digits(2)),
+        )),
+        tuple((digits(4), positioned_digits(2), positioned_digits(2))),
+    ))(input)
+}
+
+/// Creates the [`NaiveDate`] from the date parts.
+fn make_date(
+    (year, (month, month_input), (day, day_input)): DateParts<'_>,
+) -> Result<NaiveDate, nom::Err<Error<&str>>> {
+    if month == 0 || month > 12 {
+        return Err(nom::Err::Error(Error::MonthOutOfRange {
+            value: month,
+            input: month_input,
+        }));
+    }
+    if day == 0 || day > 31 {
+        return Err(nom::Err::Error(Error::DayOutOfRange {
+            value: day,
+            input: day_input,
+        }));
+    }
+    NaiveDate::from_ymd_opt(year as i32, month, day).ok_or(nom::Err::Error(Error::NonExistentDate))
+}
+
+/// Recognizes the time parts in either the extended (`hh:mm[:ss[.fff]]`) or
+/// the basic (`hhmm[ss[.fff]]`) format without validating them.
+fn time_parts(input: &str) -> IResult<&str, (u32, u32, u32, u32)> {
+    let (input, (hour, minute, second)) = alt((
+        tuple((
+            digits(2),
+            preceded(char(':'), digits(2)),
+            opt(preceded(char(':'), pair(digits(2), opt(fraction)))),
+        )),
+        tuple((digits(2), digits(2), opt(pair(digits(2), opt(fraction))))),
+    ))(input)?;
+    let (second, nanos) = second.unwrap_or((0, None));
+
+    Ok((input, (hour, minute, second, nanos.unwrap_or(0))))
+}
+
+/// Creates the [`NaiveTime`] from the time parts, the leap second (`60`) is
+/// represented as the `59th` second with the additional nanoseconds, as
+/// [`NaiveTime`] does.
+fn make_time<'a>(
+    (hour, minute, second, nanos): (u32, u32, u32, u32),
+) -> Result<NaiveTime, nom::Err<Error<&'a str>>> {
+    if hour > 23 {
+        return Err(nom::Err::Error(Error::HourOutOfRange));
+    }
+    if minute > 59 {
+        return Err(nom::Err::Error(Error::MinuteOutOfRange));
+    }
+    let (second, nanos) = match second {
+        0..=59 => (second, nanos),
+        60 => (59, 1_000_000_000 + nanos),
+        _ => return Err(nom::Err::Error(Error::SecondOutOfRange)),
+    };
+
+    NaiveTime::from_hms_nano_opt(hour, minute, second, nanos)
+        .ok_or(nom::Err::Error(Error::SecondOutOfRange))
+}
+
+/// Recognizes the separator between the date and the time: `T`, `t` or a
+/// single space (which is allowed by the `RFC 3339`).
+fn date_time_separator(input: &str) -> IResult<&str, char> {
+    one_of("Tt ")(input)
+}
+
+/// Recognizes the calendar date in either the extended (`2024-07-13`) or the
+/// basic (`20240713`) format and returns the corresponding [`NaiveDate`].
+///
+/// Returns [`Error::MonthOutOfRange`], [`Error::DayOutOfRange`] or
+/// [`Error::NonExistentDate`] if the date is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::iso8601::date;
+///
+/// assert_eq!(
+///     date("2024-07-13")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     date("20240713")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, parts) = date_parts(input)?;
+
+    Ok((input, make_date(parts)?))
+}
+
+/// Recognizes the time of day in either the extended (`14:30:00`) or the basic
+/// (`143000`) format with the optional seconds and fraction of a second (e.g.
+/// `14:30:00.123456`) and returns the corresponding [`NaiveTime`].
+///
+/// The leap second (`23:59:60`) is accepted.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use nom_date_parsers::iso8601::time;
+///
+/// assert_eq!(
+///     time("14:30")?.1,
+///     NaiveTime::from_hms_opt(14, 30, 0).unwrap()
+/// );
+/// assert_eq!(
+///     time("14:30:15.250")?.1,
+///     NaiveTime::from_hms_milli_opt(14, 30, 15, 250).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn time(input: &str) -> IResult<&str, NaiveTime> {
+    let (input, parts) = time_parts(input)?;
+
+    Ok((input, make_time(parts)?))
+}
+
+/// Recognizes the UTC offset: `Z` (or `z`), `±hh:mm`, `±hhmm` or `±hh` and
+/// returns the corresponding [`FixedOffset`].
+///
+/// Accepts offsets up to `±23:59`, otherwise returns
+/// [`Error::OffsetOutOfRange`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use nom_date_parsers::iso8601::offset;
+///
+/// assert_eq!(offset("Z")?.1, FixedOffset::east_opt(0).unwrap());
+/// assert_eq!(
+///     offset("+03:00")?.1,
+///     FixedOffset::east_opt(3 * 3600).unwrap()
+/// );
+/// assert_eq!(
+///     offset("-0530")?.1,
+///     FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn offset(input: &str) -> IResult<&str, FixedOffset> {
+    let (input, sign) = alt((
+        value(0, one_of("Zz")),
+        value(1, char('+')),
+        value(-1, alt((char('-'), char('\u{2212}')))),
+    ))(input)?;
+    if sign == 0 {
+        return Ok((input, Utc.fix()));
+    }
+    let (input, (hours, minutes)) =
+        pair(digits(2), opt(preceded(opt(char(':')), digits(2))))(input)?;
+    let minutes = minutes.unwrap_or(0);
+    if hours > 23 || minutes > 59 {
+        return Err(nom::Err::Error(Error::OffsetOutOfRange));
+    }
+
+    Ok((
+        input,
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60) as i32)
+            .ok_or(nom::Err::Error(Error::OffsetOutOfRange))?,
+    ))
+}
+
+/// Recognizes the date and time separated by `T` (or a space) without the
+/// offset, e.g. `2024-07-13T14:30:00`, and returns the corresponding
+/// [`NaiveDateTime`].
+///
+/// The offset, if present, is left in the remaining input, use the
+/// [`date_time_offset`] parser to recognize it.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::iso8601::date_time;
+///
+/// assert_eq!(
+///     date_time("2024-07-13T14:30:00")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(14, 30, 0)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date_time(input: &str) -> IResult<&str, NaiveDateTime> {
+    let (input, (date, _, time)) = tuple((date_parts, date_time_separator, time_parts))(input)?;
+
+    Ok((input, make_date(date)?.and_time(make_time(time)?)))
+}
+
+/// Recognizes the date and time with the mandatory offset, e.g.
+/// `2024-07-13T14:30:00.5+03:00` or `2024-07-13T11:30:00Z`, which covers the
+/// `RFC 3339` timestamps, and returns the corresponding
+/// [`DateTime<FixedOffset>`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset, NaiveDate};
+/// use nom_date_parsers::iso8601::date_time_offset;
+///
+/// assert_eq!(
+///     date_time_offset("2024-07-13T14:30:00+03:00")?.1,
+///     DateTime::parse_from_rfc3339("2024-07-13T11:30:00Z").unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date_time_offset(input: &str) -> IResult<&str, DateTime<FixedOffset>> {
+    let (input, (date_time, offset)) = pair(date_time, offset)(input)?;
+
+    Ok((
+        input,
+        date_time
+            .and_local_timezone(offset)
+            .single()
+            .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+    ))
+}
+
+/// Recognizes the date optionally followed by the time and the offset and
+/// returns the most precise [`Iso8601`] value.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::iso8601::{any, Iso8601};
+///
+/// assert_eq!(
+///     any("2024-07-13")?.1,
+///     Iso8601::Date(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+/// );
+/// assert!(matches!(
+///     any("2024-07-13T14:30Z")?.1,
+///     Iso8601::DateTimeOffset(_)
+/// ));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn any(input: &str) -> IResult<&str, Iso8601> {
+    let (input, date) = date(input)?;
+    let (input, Some(time)) = opt(preceded(date_time_separator, time_parts))(input)? else {
+        return Ok((input, Iso8601::Date(date)));
+    };
+    let date_time = date.and_time(make_time(time)?);
+    let (input, Some(offset)) = opt(offset)(input)? else {
+        return Ok((input, Iso8601::DateTime(date_time)));
+    };
+
+    Ok((
+        input,
+        Iso8601::DateTimeOffset(
+            date_time
+                .and_local_timezone(offset)
+                .single()
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ndt(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32, nanos: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_nano_opt(h, min, s, nanos)
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case("2024-07-13", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("20240713", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024-07-13T14:30", Ok(("T14:30", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024-13-01", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13-01" })))]
+    #[case("2024-07-32", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32" })))]
+    #[case("2023-02-29", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(
+        "2024-7-13",
+        Err(nom::Err::Error(Error::Nom("-7-13", ErrorKind::TakeWhileMN)))
+    )]
+    fn test_date(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(date(input), expected);
+    }
+
+    #[rstest]
+    #[case("14:30", Ok(("", NaiveTime::from_hms_opt(14, 30, 0).unwrap())))]
+    #[case("14:30:15", Ok(("", NaiveTime::from_hms_opt(14, 30, 15).unwrap())))]
+    #[case("143015", Ok(("", NaiveTime::from_hms_opt(14, 30, 15).unwrap())))]
+    #[case("14:30:15.5", Ok(("", NaiveTime::from_hms_milli_opt(14, 30, 15, 500).unwrap())))]
+    #[case("14:30:15,123456789123", Ok(("", NaiveTime::from_hms_nano_opt(14, 30, 15, 123_456_789).unwrap())))]
+    #[case("23:59:60", Ok(("", NaiveTime::from_hms_nano_opt(23, 59, 59, 1_000_000_000).unwrap())))]
+    #[case("24:00", Err(nom::Err::Error(Error::HourOutOfRange)))]
+    #[case("14:60", Err(nom::Err::Error(Error::MinuteOutOfRange)))]
+    #[case("14:30:61", Err(nom::Err::Error(Error::SecondOutOfRange)))]
+    fn test_time(#[case] input: &str, #[case] expected: IResult<&str, NaiveTime>) {
+        assert_eq!(time(input), expected);
+    }
+
+    #[rstest]
+    #[case("Z", Ok(("", FixedOffset::east_opt(0).unwrap())))]
+    #[case("+03:00", Ok(("", FixedOffset::east_opt(3 * 3600).unwrap())))]
+    #[case("+0545", Ok(("", FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap())))]
+    #[case("-08", Ok(("", FixedOffset::west_opt(8 * 3600).unwrap())))]
+    #[case("\u{2212}02:30", Ok(("", FixedOffset::west_opt(2 * 3600 + 30 * 60).unwrap())))]
+    #[case("+24:00", Err(nom::Err::Error(Error::OffsetOutOfRange)))]
+    #[case("+03:60", Err(nom::Err::Error(Error::OffsetOutOfRange)))]
+    fn test_offset(#[case] input: &str, #[case] expected: IResult<&str, FixedOffset>) {
+        assert_eq!(offset(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-07-13T14:30:00", Ok(("", ndt(2024, 7, 13, 14, 30, 0, 0))))]
+    #[case("2024-07-13 14:30:00.25", Ok(("", ndt(2024, 7, 13, 14, 30, 0, 250_000_000))))]
+    #[case("20240713t143000", Ok(("", ndt(2024, 7, 13, 14, 30, 0, 0))))]
+    #[case("2024-07-13T14:30:00Z", Ok(("Z", ndt(2024, 7, 13, 14, 30, 0, 0))))]
+    #[case("2024-07-13T25:00", Err(nom::Err::Error(Error::HourOutOfRange)))]
+    fn test_date_time(#[case] input: &str, #[case] expected: IResult<&str, NaiveDateTime>) {
+        assert_eq!(date_time(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-07-13T14:30:00+03:00", "2024-07-13T14:30:00+03:00")]
+    #[case("2024-07-13T11:30:00.123Z", "2024-07-13T11:30:00.123+00:00")]
+    #[case("2016-12-31T23:59:60-05:00", "2016-12-31T23:59:60-05:00")]
+    fn test_date_time_offset(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(
+            date_time_offset(input),
+            Ok(("", DateTime::parse_from_rfc3339(expected).unwrap()))
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "2024-07-13T14:30:00",
+        Err(nom::Err::Error(Error::Nom("", ErrorKind::Char)))
+    )]
+    #[case(
+        "2024-07-13T14:30:00+24:00",
+        Err(nom::Err::Error(Error::OffsetOutOfRange))
+    )]
+    fn test_date_time_offset_error(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, DateTime<FixedOffset>>,
+    ) {
+        assert_eq!(date_time_offset(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-07-13", Ok(("", Iso8601::Date(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))))]
+    #[case("2024-07-13 and", Ok((" and", Iso8601::Date(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))))]
+    #[case("2024-07-13T14:30", Ok(("", Iso8601::DateTime(ndt(2024, 7, 13, 14, 30, 0, 0)))))]
+    #[case(
+        "2024-07-13T14:30Z",
+        Ok(("", Iso8601::DateTimeOffset(DateTime::parse_from_rfc3339("2024-07-13T14:30:00Z").unwrap())))
+    )]
+    #[case("2024-07-13T24:30", Err(nom::Err::Error(Error::HourOutOfRange)))]
+    fn test_any(#[case] input: &str, #[case] expected: IResult<&str, Iso8601>) {
+        assert_eq!(any(input), expected);
+    }
+}
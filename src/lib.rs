@@ -1,11 +1,44 @@
 #![cfg_attr(all(docsrs, feature = "nightly"), feature(doc_cfg, doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod calendar;
+pub mod combinators;
+pub mod context;
 pub mod error;
+pub mod explain;
+pub mod extract;
+#[cfg(feature = "numeric")]
+pub mod format;
+#[cfg(feature = "hebrew-calendar")]
+pub mod hebrew_calendar;
+#[cfg(feature = "holidays")]
+pub mod holiday;
 pub mod i18n;
+#[cfg(feature = "iso8601")]
+pub mod iso8601;
+pub mod list;
 #[cfg(feature = "numeric")]
 pub mod numeric;
+pub mod parse;
+pub mod parser;
 pub mod prelude;
 #[cfg(feature = "quick")]
 pub mod quick;
+pub mod range;
+pub mod recurrence;
+#[cfg(feature = "rfc2822")]
+pub mod rfc2822;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod streaming;
+pub mod time;
+#[cfg(feature = "timestamp")]
+pub mod timestamp;
 
 pub mod types;
+pub mod zone;
+
+pub use extract::extract_best_date;
+pub use parse::*;
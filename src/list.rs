@@ -0,0 +1,267 @@
+use alloc::{vec, vec::Vec};
+
+use chrono::{Datelike, Month, NaiveDate};
+use nom::{
+    character::complete::{char, space0},
+    combinator::{opt, value},
+    error::ErrorKind,
+    sequence::{delimited, preceded},
+    Parser,
+};
+
+use crate::{
+    context::ParserContext,
+    error::Error,
+    i18n::day_month_separator,
+    numeric::{dd, numeric_date_parts_separator, opt_year, y4_with},
+    types::IResult,
+};
+
+/// Recognizes a symbolic separator between the dates of the list surrounded by
+/// any number of spaces: `,`.
+///
+/// The language-specific words (e.g. `and`) are recognized by the
+/// language-specific list parsers.
+pub fn list_separator(input: &str) -> IResult<&str, ()> {
+    value((), delimited(space0, char(','), space0))(input)
+}
+
+/// Creates a parser which recognizes one or more dates using the specified
+/// `parser` separated by the specified `separator` (e.g. [`list_separator`])
+/// and returns them in the order of the input.
+///
+/// The list ends before the separator which isn't followed by the date, so
+/// `13/07/2024, Kyiv` is recognized as a single date with the `, Kyiv`
+/// remaining input. The date-specific errors of the following dates (e.g.
+/// [`Error::NonExistentDate`]) are returned instead of ending the list.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     list::{date_list, list_separator},
+///     numeric::dd_mm_y4,
+/// };
+///
+/// assert_eq!(
+///     date_list(list_separator, dd_mm_y4)("13/07/2024, 15/07/2024")?.1,
+///     vec![
+///         NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date_list<'a, S, P>(
+    mut separator: S,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<NaiveDate>>
+where
+    S: Parser<&'a str, (), Error<&'a str>>,
+    P: Parser<&'a str, NaiveDate, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (mut input, date) = parser.parse(input)?;
+        let mut dates = vec![date];
+
+        loop {
+            let next = match separator.parse(input) {
+                Ok((input, ())) => parser.parse(input),
+                Err(e) => Err(e),
+            };
+            match next {
+                Ok((rest, date)) => {
+                    input = rest;
+                    dates.push(date);
+                }
+                Err(nom::Err::Error(
+                    Error::Nom(..) | Error::ParseIntError(..) | Error::Context(..),
+                )) => break,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok((input, dates))
+    }
+}
+
+/// The `day` of the [`shared_month_list_with`] with the optional `month` and
+/// `year` parts.
+type ListItem = (u32, Option<(Month, Option<u32>)>);
+
+/// Creates a parser which recognizes the list of dates with the shared named
+/// `month` and `year` parts written once after the last date of the month
+/// (e.g. `13, 15 and 20 July` or `30 June, 2 and 5 July 2024`) and returns the
+/// dates in the order of the input.
+///
+/// The dates are separated by the specified `separator`, the `month` part is
+/// recognized by the specified language-specific `named_month` parser. The
+/// missing `month` and `year` parts are taken from the following date, the
+/// missing `year` part of the last date is taken from the reference date of the
+/// specified [`ParserContext`]. The list ends at the last date with the `month`
+/// part, so the days which follow it remain in the input.
+///
+/// Returns [`Error::NonExistentDate`] if any of the dates doesn't exist and
+/// [`Error::Nom`] with the [`ErrorKind::Tag`] if none of the dates has the
+/// `month` part.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate};
+/// use nom::{bytes::complete::tag_no_case, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     list::{list_separator, shared_month_list_with},
+///     types::IResult,
+/// };
+///
+/// fn july(input: &str) -> IResult<&str, Month> {
+///     value(Month::July, tag_no_case("july"))(input)
+/// }
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     shared_month_list_with(&ctx, list_separator, july)("13, 15, 20 July")?.1,
+///     vec![
+///         NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn shared_month_list_with<'a, 'c, S>(
+    ctx: &'c ParserContext,
+    mut separator: S,
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<NaiveDate>> + 'c
+where
+    S: Parser<&'a str, (), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let item = |input: &'a str| -> IResult<&'a str, ListItem> {
+            let (input, day) = dd(input)?;
+            let (input, month) = opt(preceded(day_month_separator, named_month))(input)?;
+            match month {
+                Some(month) => {
+                    let (input, year) =
+                        opt_year(preceded(numeric_date_parts_separator, y4_with(ctx)))(input)?;
+                    Ok((input, (day, Some((month, year)))))
+                }
+                None => Ok((input, (day, None))),
+            }
+        };
+
+        let (mut rest, first) = item(input)?;
+        let first_rest = rest;
+        let mut items = vec![first];
+        let mut end = first.1.map(|_| (1, rest));
+
+        loop {
+            let next = match separator.parse(rest) {
+                Ok((input, ())) => item(input),
+                Err(e) => Err(e),
+            };
+            match next {
+                Ok((input, next)) => {
+                    rest = input;
+                    items.push(next);
+                    if next.1.is_some() {
+                        end = Some((items.len(), rest));
+                    }
+                }
+                Err(nom::Err::Error(_)) => break,
+                Err(e) => return Err(e),
+            }
+        }
+
+        let Some((len, input)) = end else {
+            return Err(nom::Err::Error(Error::Nom(first_rest, ErrorKind::Tag)));
+        };
+
+        let mut dates = Vec::with_capacity(len);
+        let (mut month, mut year) = (Month::January, ctx.today().year());
+        for &(day, parts) in items[..len].iter().rev() {
+            if let Some((item_month, item_year)) = parts {
+                month = item_month;
+                year = item_year.map_or(year, |year| year as i32);
+            }
+            dates.push(
+                NaiveDate::from_ymd_opt(year, month.number_from_month(), day)
+                    .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+            );
+        }
+        dates.reverse();
+
+        Ok((input, dates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{branch::alt, bytes::complete::tag_no_case};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::numeric::dd_mm_y4;
+
+    fn month(input: &str) -> IResult<&str, Month> {
+        alt((
+            value(Month::June, tag_no_case("june")),
+            value(Month::July, tag_no_case("july")),
+        ))(input)
+    }
+
+    fn dates(dates: &[(i32, u32, u32)]) -> Vec<NaiveDate> {
+        dates
+            .iter()
+            .map(|&(year, month, day)| NaiveDate::from_ymd_opt(year, month, day).unwrap())
+            .collect()
+    }
+
+    #[rstest]
+    #[case(",", Ok(("", ())))]
+    #[case(" , 20", Ok(("20", ())))]
+    #[case(" and ", Err(nom::Err::Error(Error::Nom("and ", ErrorKind::Char))))]
+    fn test_list_separator(#[case] input: &str, #[case] expected: IResult<&str, ()>) {
+        assert_eq!(list_separator(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024", Ok(("", dates(&[(2024, 7, 13)]))))]
+    #[case("13/07/2024, 15/07/2024,20/07/2024", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15), (2024, 7, 20)]))))]
+    #[case("13/07/2024, Kyiv", Ok((", Kyiv", dates(&[(2024, 7, 13)]))))]
+    #[case("13/07/2024, 31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(
+        "13/07/2024, 13/13/2024",
+        Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13/2024" }))
+    )]
+    fn test_date_list(#[case] input: &str, #[case] expected: IResult<&str, Vec<NaiveDate>>) {
+        assert_eq!(date_list(list_separator, dd_mm_y4)(input), expected);
+    }
+
+    #[rstest]
+    #[case("13 July", Ok(("", dates(&[(2024, 7, 13)]))))]
+    #[case("13, 15, 20 July", Ok(("", dates(&[(2024, 7, 13), (2024, 7, 15), (2024, 7, 20)]))))]
+    #[case("13,15 july 2023", Ok(("", dates(&[(2023, 7, 13), (2023, 7, 15)]))))]
+    #[case("30 June, 2, 5 July 2025", Ok(("", dates(&[(2025, 6, 30), (2025, 7, 2), (2025, 7, 5)]))))]
+    #[case("30 June 2023, 5 July", Ok(("", dates(&[(2023, 6, 30), (2024, 7, 5)]))))]
+    #[case("13 July, 15", Ok((", 15", dates(&[(2024, 7, 13)]))))]
+    #[case("13, 31 June", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("13, 15", Err(nom::Err::Error(Error::Nom(", 15", ErrorKind::Tag))))]
+    fn test_shared_month_list_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, Vec<NaiveDate>>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(
+            shared_month_list_with(&ctx, list_separator, month)(input),
+            expected
+        );
+    }
+}
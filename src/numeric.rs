@@ -1,13 +1,37 @@
-use chrono::{Datelike, Local, NaiveDate};
+use alloc::{string::String, vec::Vec};
+use core::num::ParseIntError;
+
+use chrono::{Datelike, NaiveDate, Weekday};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take},
-    character::complete::space1,
-    combinator::map_res,
-    sequence::{separated_pair, tuple},
+    bytes::complete::{tag, take, take_while_m_n},
+    character::complete::{satisfy, space1},
+    combinator::{map, map_res, not, opt, peek, rest, value, verify},
+    error::{context, ErrorKind},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    Parser,
+};
+
+use crate::{
+    context::{
+        full_year, ParserContext, Separator, SeparatorPolicy, TrailingInput, YearRange,
+        DEFAULT_CENTURY_PIVOT,
+    },
+    error::Error,
+    types::IResult,
 };
 
-use crate::{error::Error, types::IResult};
+/// The order of the `day`, `month` and `year` parts of the numeric date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    /// `day-month-year`, e.g. `13/07/2024`
+    #[default]
+    Dmy,
+    /// `month-day-year`, e.g. `07/13/2024`
+    Mdy,
+    /// `year-month-day`, e.g. `2024/07/13`
+    Ymd,
+}
 
 /// Recognizes a separator of numeric date parts in the following templates
 /// (asterisk symbol denotes some separator):
@@ -17,29 +41,124 @@ use crate::{error::Error, types::IResult};
 ///
 /// Currently the following separators are recognized: `/`, `-`, `.` and any
 /// number of spaces and tabs.
+#[inline]
 pub fn numeric_date_parts_separator(input: &str) -> IResult<&str, ()> {
     let (input, _) = alt((tag("/"), tag("-"), tag("."), space1))(input)?;
 
     Ok((input, ()))
 }
 
-/// Recognizes either one or two digits of a `day` part.
+/// Checks whether the character is either an ascii digit or an Eastern Arabic
+/// digit (`٠١٢٣٤٥٦٧٨٩`).
+#[inline]
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit() || ('٠'..='٩').contains(&c)
+}
+
+/// Parses the number written with the ascii or the Eastern Arabic digits, the
+/// latter are replaced with the corresponding ascii digits before parsing, so
+/// `١٣` is `13`.
+fn parse_digits(s: &str) -> Result<u32, ParseIntError> {
+    if s.is_ascii() {
+        return s.parse();
+    }
+
+    s.chars()
+        .map(|c| match c {
+            '٠'..='٩' => char::from_digit(c as u32 - '٠' as u32, 10).unwrap_or(c),
+            c => c,
+        })
+        .collect::<String>()
+        .parse()
+}
+
+/// The parser of a single numeric date part, e.g. [`dd`] or [`y4`].
+type PartFn<'p> = &'p dyn Fn(&str) -> IResult<&str, u32>;
+
+/// Creates a parser which recognizes a separator of numeric date parts allowed
+/// by the specified [`SeparatorPolicy`] and returns the kind of the
+/// [`Separator`].
+fn separator(policy: SeparatorPolicy) -> impl Fn(&str) -> IResult<&str, Separator> {
+    move |input: &str| {
+        verify(
+            alt((
+                value(Separator::Slash, tag("/")),
+                value(Separator::Dash, tag("-")),
+                value(Separator::Dot, tag(".")),
+                value(Separator::Space, space1),
+            )),
+            |separator| policy.allows(*separator),
+        )(input)
+    }
+}
+
+/// Creates a parser which recognizes the second separator of numeric date
+/// parts, which must be the same as the `first` one if the specified
+/// [`SeparatorPolicy`] is consistent.
+fn second_separator(
+    policy: SeparatorPolicy,
+    first: Separator,
+) -> impl Fn(&str) -> IResult<&str, Separator> {
+    move |input: &str| {
+        verify(separator(policy), |separator| {
+            !policy.is_consistent() || *separator == first
+        })(input)
+    }
+}
+
+/// Creates a parser which recognizes three numeric date parts with the
+/// specified `parts` parsers separated according to the specified
+/// [`SeparatorPolicy`].
+fn date_parts(
+    policy: SeparatorPolicy,
+    [first, second, third]: [PartFn<'_>; 3],
+) -> impl Fn(&str) -> IResult<&str, (u32, u32, u32)> + '_ {
+    move |input: &str| {
+        let (input, (a, separator, b)) = tuple((first, separator(policy), second))(input)?;
+        let (input, c) = preceded(second_separator(policy, separator), third)(input)?;
+
+        Ok((input, (a, b, c)))
+    }
+}
+
+/// Creates a parser which recognizes the separator followed by the `second`
+/// numeric date part and the optional separator followed by the `third` part,
+/// the separators are checked according to the specified [`SeparatorPolicy`].
+fn tail_parts<'p>(
+    policy: SeparatorPolicy,
+    second: PartFn<'p>,
+    third: PartFn<'p>,
+) -> impl Fn(&str) -> IResult<&str, (u32, Option<u32>)> + 'p {
+    move |input: &str| {
+        let (input, (separator, b)) = pair(separator(policy), second)(input)?;
+        let (input, c) = opt_year(preceded(second_separator(policy, separator), third))(input)?;
+
+        Ok((input, (b, c)))
+    }
+}
+
+/// Recognizes either one or two digits (ascii or Eastern Arabic, e.g. `١٣`) of
+/// a `day` part.
 ///
 /// Accepts numbers in the range `01..=31`, otherwise returns
 /// [`Error::DayOutOfRange`].
 ///
 /// It can be used to recognize the `dd` part in the `dd`/mm/yyyy pattern, for
 /// instance.
+#[inline]
 pub fn dd(input: &str) -> IResult<&str, u32> {
-    let (input, dd) = alt((
-        map_res(take(2_u8), |s: &str| s.parse()),
-        map_res(take(1_u8), |s: &str| s.parse()),
-    ))(input)?;
+    let (rest, dd) = context(
+        "day",
+        alt((
+            map_res(take(2_u8), parse_digits),
+            map_res(take(1_u8), parse_digits),
+        )),
+    )(input)?;
 
     if dd == 0 || dd > 31 {
-        return Err(nom::Err::Error(Error::DayOutOfRange));
+        return Err(nom::Err::Error(Error::DayOutOfRange { value: dd, input }));
     }
-    Ok((input, dd))
+    Ok((rest, dd))
 }
 
 /// Recognizes either one or two digits of a `day` part and returns the
@@ -56,35 +175,110 @@ pub fn dd(input: &str) -> IResult<&str, u32> {
 ///     dd_only("13")?.1,
 ///     Local::now().date_naive().with_day(13).unwrap()
 /// );
-/// assert_eq!(dd_only("42"), Err(nom::Err::Error(Error::DayOutOfRange)));
+/// assert_eq!(
+///     dd_only("42"),
+///     Err(nom::Err::Error(Error::DayOutOfRange {
+///         value: 42,
+///         input: "42"
+///     }))
+/// );
 ///
 /// # Ok::<(), Box::<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn dd_only(input: &str) -> IResult<&str, NaiveDate> {
-    let (input, day) = dd(input)?;
-    let now = Local::now();
-    let (month, year) = (now.month(), now.year());
+    dd_only_with(&ParserContext::default())(input)
+}
 
-    Ok((
-        input,
-        NaiveDate::from_ymd_opt(year, month, day).ok_or(nom::Err::Error(Error::NonExistentDate))?,
-    ))
+/// Creates the [`dd_only`] parser which takes the month and year from the
+/// reference date of the specified [`ParserContext`] according to its
+/// [`ResolutionPolicy`](crate::calendar::ResolutionPolicy).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::prelude::*;
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+///
+/// assert_eq!(
+///     dd_only_with(&ctx)("29")?.1,
+///     NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+/// );
+/// assert_eq!(
+///     dd_only_with(&ctx)("30"),
+///     Err(nom::Err::Error(Error::NonExistentDate))
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn dd_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, day) = dd(input)?;
+
+        Ok((
+            input,
+            ctx.resolve(None, day)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
 }
 
-/// Recognizes either one or two digits of a `month` part.
+/// Recognizes either one or two digits (ascii or Eastern Arabic) of a `month`
+/// part.
 ///
 /// Accepts numbers in the range `01..=12`, otherwise returns.
 /// [`Error::MonthOutOfRange`]
+#[inline]
 pub fn mm(input: &str) -> IResult<&str, u32> {
-    let (input, mm) = alt((
-        map_res(take(2_u8), |s: &str| s.parse()),
-        map_res(take(1_u8), |s: &str| s.parse()),
-    ))(input)?;
+    let (rest, mm) = context(
+        "month",
+        alt((
+            map_res(take(2_u8), parse_digits),
+            map_res(take(1_u8), parse_digits),
+        )),
+    )(input)?;
     if mm == 0 || mm > 12 {
-        return Err(nom::Err::Error(Error::MonthOutOfRange));
+        return Err(nom::Err::Error(Error::MonthOutOfRange { value: mm, input }));
+    }
+
+    Ok((rest, mm))
+}
+
+/// Recognizes either one or two digits of an ISO 8601 `week` number.
+///
+/// Accepts numbers in the range `01..=53`, otherwise returns
+/// [`Error::WeekOutOfRange`]
+#[inline]
+pub fn ww(input: &str) -> IResult<&str, u32> {
+    let (input, ww) = alt((
+        map_res(take(2_u8), parse_digits),
+        map_res(take(1_u8), parse_digits),
+    ))(input)?;
+    if ww == 0 || ww > 53 {
+        return Err(nom::Err::Error(Error::WeekOutOfRange));
+    }
+
+    Ok((input, ww))
+}
+
+/// Recognizes from one to three digits of the `day of the year` part.
+///
+/// Accepts numbers in the range `1..=366`, otherwise returns
+/// [`Error::DayOfYearOutOfRange`].
+#[inline]
+pub fn ddd(input: &str) -> IResult<&str, u32> {
+    let (input, ddd) = alt((
+        map_res(take(3_u8), parse_digits),
+        map_res(take(2_u8), parse_digits),
+        map_res(take(1_u8), parse_digits),
+    ))(input)?;
+    if ddd == 0 || ddd > 366 {
+        return Err(nom::Err::Error(Error::DayOfYearOutOfRange));
     }
 
-    Ok((input, mm))
+    Ok((input, ddd))
 }
 
 /// Recognizes the `day` and `month` parts separated by the
@@ -113,14 +307,25 @@ pub fn dd_mm(input: &str) -> IResult<&str, (u32, u32)> {
 ///
 /// # Ok::<(), Box::<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn dd_mm_only(input: &str) -> IResult<&str, NaiveDate> {
-    let (input, (day, month)) = dd_mm(input)?;
-    let year = Local::now().year();
+    dd_mm_only_with(&ParserContext::default())(input)
+}
 
-    Ok((
-        input,
-        NaiveDate::from_ymd_opt(year, month, day).ok_or(nom::Err::Error(Error::NonExistentDate))?,
-    ))
+/// Creates the [`dd_mm_only`] parser which takes the year from the reference
+/// date according to the
+/// [`ResolutionPolicy`](crate::calendar::ResolutionPolicy) and the allowed
+/// separators from the [`SeparatorPolicy`] of the specified [`ParserContext`].
+pub fn dd_mm_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (day, month)) = separated_pair(dd, separator(ctx.separators()), mm)(input)?;
+
+        Ok((
+            input,
+            ctx.resolve(Some(month), day)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
 }
 
 /// Recognizes the `month` and `day` parts separated by the
@@ -147,21 +352,89 @@ pub fn mm_dd(input: &str) -> IResult<&str, (u32, u32)> {
 ///
 /// # Ok::<(), Box::<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn mm_dd_only(input: &str) -> IResult<&str, NaiveDate> {
-    let (input, (month, day)) = mm_dd(input)?;
+    mm_dd_only_with(&ParserContext::default())(input)
+}
 
-    Ok((
-        input,
-        NaiveDate::from_ymd_opt(Local::now().year(), month, day)
-            .ok_or(nom::Err::Error(Error::NonExistentDate))?,
-    ))
+/// Creates the [`mm_dd_only`] parser which takes the year from the reference
+/// date according to the
+/// [`ResolutionPolicy`](crate::calendar::ResolutionPolicy) and the allowed
+/// separators from the [`SeparatorPolicy`] of the specified [`ParserContext`].
+pub fn mm_dd_only_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (month, day)) = separated_pair(mm, separator(ctx.separators()), dd)(input)?;
+
+        Ok((
+            input,
+            ctx.resolve(Some(month), day)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
 }
 
-/// Recognizes four digits of the `year` part.
+/// Recognizes four digits (ascii or Eastern Arabic) of the `year` part.
 ///
-/// Accepts numbers in the range `0000..=9999`, technically.
+/// Accepts numbers in the default [`YearRange`] `1900..=2100`, otherwise
+/// returns [`Error::YearOutOfRange`], use the [`y4_with`] to accept another
+/// range of years.
+#[inline]
 pub fn y4(input: &str) -> IResult<&str, u32> {
-    map_res(take(4_u8), |s: &str| s.parse::<u32>())(input)
+    y4_in(YearRange::default(), input)
+}
+
+/// Creates the [`y4`] parser which accepts the years in the [`YearRange`] of
+/// the specified [`ParserContext`].
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::{context::YearRange, prelude::*};
+///
+/// let ctx = ParserContext::new().with_year_range(YearRange::any());
+///
+/// assert_eq!(y4_with(&ctx)("0042")?.1, 42);
+/// assert_eq!(
+///     y4("0042"),
+///     Err(nom::Err::Error(Error::YearOutOfRange {
+///         value: 42,
+///         input: "0042"
+///     }))
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn y4_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, u32> + '_ {
+    move |input: &str| y4_in(ctx.year_range(), input)
+}
+
+/// Creates a parser which recognizes the optional part with the [`y4`] year
+/// like the `opt` combinator, but doesn't swallow [`Error::YearOutOfRange`],
+/// so the date with the rejected year (e.g. `13/07/0042`) isn't recognized as
+/// the date without the year followed by the rest of the input.
+pub(crate) fn opt_year<'a, O, F>(
+    mut parser: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Option<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    move |input: &'a str| match parser.parse(input) {
+        Ok((input, output)) => Ok((input, Some(output))),
+        Err(nom::Err::Error(error @ Error::YearOutOfRange { .. })) => Err(nom::Err::Error(error)),
+        Err(nom::Err::Error(_)) => Ok((input, None)),
+        Err(error) => Err(error),
+    }
+}
+
+/// Recognizes four digits of the `year` part and checks that the year is in
+/// the specified [`YearRange`].
+fn y4_in(range: YearRange, input: &str) -> IResult<&str, u32> {
+    let (rest, y4) = context("year", map_res(take(4_u8), parse_digits))(input)?;
+    if !range.contains(y4) {
+        return Err(nom::Err::Error(Error::YearOutOfRange { value: y4, input }));
+    }
+
+    Ok((rest, y4))
 }
 
 /// Recognizes the `year`, `month` and `day` parts separated by the
@@ -182,8 +455,14 @@ pub fn y4(input: &str) -> IResult<&str, u32> {
 /// # Ok::<(), Box::<dyn std::error::Error>>(())
 /// ```
 pub fn y4_mm_dd(input: &str) -> IResult<&str, NaiveDate> {
+    y4_mm_dd_in(YearRange::default(), input)
+}
+
+/// Recognizes the [`y4_mm_dd`] date with the year in the specified
+/// [`YearRange`].
+fn y4_mm_dd_in(range: YearRange, input: &str) -> IResult<&str, NaiveDate> {
     let (input, (y4, (), mm, (), dd)) = tuple((
-        y4,
+        |input| y4_in(range, input),
         numeric_date_parts_separator,
         mm,
         numeric_date_parts_separator,
@@ -197,6 +476,109 @@ pub fn y4_mm_dd(input: &str) -> IResult<&str, NaiveDate> {
     ))
 }
 
+/// Creates the [`y4_mm_dd`] parser which accepts only the separators allowed
+/// by the [`SeparatorPolicy`] and the years in the [`YearRange`] of the
+/// specified [`ParserContext`].
+pub fn y4_mm_dd_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (y4, mm, dd)) = date_parts(ctx.separators(), [&y4_with(ctx), &mm, &dd])(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(y4 as i32, mm, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Recognizes the ISO 8601 week date in the extended (`2024-W29`,
+/// `2024-W29-5`) or basic (`2024W29`, `2024W295`) format and returns the
+/// specified weekday (`1` is Monday, `7` is Sunday) of the week or its Monday
+/// if the weekday is omitted.
+///
+/// Returns [`Error::WeekOutOfRange`] if the week isn't in the range `01..=53`,
+/// [`Error::DayOutOfRange`] if the weekday isn't in the range `1..=7` and
+/// [`Error::NonExistentDate`] if the year has no such week (e.g. `2024-W53`).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     iso_week("2024-W29")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 15).unwrap()
+/// );
+/// assert_eq!(
+///     iso_week("2024-W29-5")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 19).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn iso_week(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, (y4, _, ww, weekday)) = tuple((
+        y4,
+        pair(opt(tag("-")), tag("W")),
+        ww,
+        opt(preceded(opt(tag("-")), pair(peek(rest), satisfy(is_digit)))),
+    ))(input)?;
+    let weekday = match weekday {
+        Some((digit, c)) => {
+            let value = parse_digits(&digit[..c.len_utf8()]).unwrap_or_default() as u8;
+            Weekday::try_from(value.wrapping_sub(1)).map_err(|_| {
+                nom::Err::Error(Error::DayOutOfRange {
+                    value: value.into(),
+                    input: digit,
+                })
+            })?
+        }
+        None => Weekday::Mon,
+    };
+
+    Ok((
+        input,
+        NaiveDate::from_isoywd_opt(y4 as i32, ww, weekday)
+            .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+    ))
+}
+
+/// Recognizes the ISO 8601 ordinal date in the extended (`2024-195`) or basic
+/// (`2024195`) format and returns the [`NaiveDate`] with the specified day of
+/// the year. The day of the year must be exactly three digits long.
+///
+/// Returns [`Error::DayOfYearOutOfRange`] if the day isn't in the range
+/// `001..=366` and [`Error::NonExistentDate`] if the year has no such day (the
+/// `366th` day exists only in the leap years).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     y4_ddd("2024-195")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     y4_ddd("2023-366"),
+///     Err(nom::Err::Error(Error::NonExistentDate))
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn y4_ddd(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, (y4, digits)) = separated_pair(y4, opt(tag("-")), digit_run(3))(input)?;
+    let (_, ddd) = ddd(digits)?;
+
+    Ok((
+        input,
+        NaiveDate::from_yo_opt(y4 as i32, ddd).ok_or(nom::Err::Error(Error::NonExistentDate))?,
+    ))
+}
+
 /// Recognizes the `day`, `month` and `year` parts separated by the
 /// [`numeric_date_parts_separator`] and returns [`NaiveDate`] with the selected
 /// parts if the date exists, otherwise returns [`Error::NonExistentDate`].
@@ -230,6 +612,44 @@ pub fn dd_mm_y4(input: &str) -> IResult<&str, NaiveDate> {
     ))
 }
 
+/// Creates the [`dd_mm_y4`] parser which accepts only the separators allowed
+/// by the [`SeparatorPolicy`] and the years in the [`YearRange`] of the
+/// specified [`ParserContext`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::{
+///     context::{Separator, SeparatorPolicy},
+///     prelude::*,
+/// };
+///
+/// let ctx = ParserContext::new().with_separators(
+///     SeparatorPolicy::only(&[Separator::Slash, Separator::Dot]).with_consistency(true),
+/// );
+///
+/// assert_eq!(
+///     dd_mm_y4_with(&ctx)("13/07/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert!(dd_mm_y4_with(&ctx)("13/07.2024").is_err());
+/// assert!(dd_mm_y4_with(&ctx)("13-07-2024").is_err());
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn dd_mm_y4_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (dd, mm, y4)) = date_parts(ctx.separators(), [&dd, &mm, &y4_with(ctx)])(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(y4 as i32, mm, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
 /// Recognizes the `month`, `day` and `year` parts separated by the
 /// [`numeric_date_parts_separator`] and returns [`NaiveDate`] with the selected
 /// parts if the date exists, otherwise returns [`Error::NonExistentDate`].
@@ -263,104 +683,1405 @@ pub fn mm_dd_y4(input: &str) -> IResult<&str, NaiveDate> {
     ))
 }
 
-#[cfg(test)]
-mod tests {
-    use chrono::Local;
-    use nom::error::ErrorKind;
-    use pretty_assertions::assert_eq;
-    use rstest::rstest;
-
-    use super::*;
-
-    #[rstest]
-    #[case("9", Ok(("", 9)))]
-    #[case("09", Ok(("", 9)))]
-    #[case("31", Ok(("", 31)))]
-    #[case("00", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    #[case("42", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    fn test_dd(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
-        assert_eq!(dd(input), expected);
-    }
+/// Creates the [`mm_dd_y4`] parser which accepts only the separators allowed
+/// by the [`SeparatorPolicy`] and the years in the [`YearRange`] of the
+/// specified [`ParserContext`].
+pub fn mm_dd_y4_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (mm, dd, y4)) = date_parts(ctx.separators(), [&mm, &dd, &y4_with(ctx)])(input)?;
 
-    #[rstest]
-    #[case("9", Ok(("", Local::now().date_naive().with_day(9).unwrap())))]
-    #[case("09", Ok(("", Local::now().date_naive().with_day(9).unwrap())))]
-    #[case("31", Local::now().date_naive().with_day(31).ok_or(nom::Err::Error(Error::NonExistentDate)).map(|d| ("", d)))]
-    #[case("00", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    #[case("42", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    fn test_dd_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
-        assert_eq!(dd_only(input), expected)
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(y4 as i32, mm, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
     }
+}
 
-    #[rstest]
-    #[case("9", Ok(("", 9)))]
-    #[case("09", Ok(("", 9)))]
-    #[case("12", Ok(("", 12)))]
-    #[case("00", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    #[case("13", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    fn test_mm(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
-        assert_eq!(mm(input), expected);
-    }
+/// Creates a parser which recognizes the run of exactly `len` ascii digits,
+/// which is not followed by another digit.
+fn digit_run(len: usize) -> impl Fn(&str) -> IResult<&str, &str> {
+    move |input: &str| terminated(take_while_m_n(len, len, is_digit), not(satisfy(is_digit)))(input)
+}
 
-    #[rstest]
-    #[case("3/9", Ok(("", Local::now().date_naive().with_day(3).unwrap().with_month(9).unwrap())))]
-    #[case("03-09", Ok(("", Local::now().date_naive().with_day(3).unwrap().with_month(9).unwrap())))]
-    #[case("03/12", Ok(("", Local::now().date_naive().with_day(3).unwrap().with_month(12).unwrap())))]
-    #[case("00", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    #[case("42", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    #[case("13.00", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    #[case("13\t13", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    fn test_dd_mm_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
-        assert_eq!(dd_mm_only(input), expected);
-    }
+/// Creates a parser which recognizes the run of eight digits without
+/// separators, splits it into the `day`, `month` and four-digit `year` parts
+/// with the specified `parts` parsers (in the order of the parts) and returns
+/// the [`NaiveDate`] if it exists, otherwise returns
+/// [`Error::NonExistentDate`].
+fn compact_y4(
+    parts: impl Fn(&str) -> IResult<&str, (u32, u32, u32)>,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| {
+        let (input, digits) = digit_run(8)(input)?;
+        let (_, (y4, mm, dd)) = parts(digits)?;
 
-    #[rstest]
-    #[case("0042", Ok(("", 42)))]
-    #[case("2024", Ok(("", 2024)))]
-    #[case("42", Err(nom::Err::Error(Error::Nom("42", ErrorKind::Eof))))]
-    #[case("10001", Ok(("1", 1000)))]
-    fn test_y4(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
-        assert_eq!(y4(input), expected);
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(y4 as i32, mm, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
     }
+}
 
-    #[rstest]
-    #[case("2024-06-13", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("2024/06-13", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("2024.06.13", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("2024    06\t13", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("2024/00/06", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    #[case("2024/13/06", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    #[case("2024/10/00", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    #[case("2024/10/42", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    fn test_y4_mm_dd(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
-        assert_eq!(y4_mm_dd(input), expected);
-    }
+/// Recognizes the compact `year`, `month` and `day` parts without separators
+/// (e.g. `20240713`, the basic ISO 8601 format) and returns [`NaiveDate`] with
+/// the selected parts if the date exists, otherwise returns
+/// [`Error::NonExistentDate`]. The run of digits must be exactly eight digits
+/// long.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     y4mmdd("20240713")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert!(y4mmdd("202407131").is_err());
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn y4mmdd(input: &str) -> IResult<&str, NaiveDate> {
+    compact_y4(|digits| tuple((y4, mm, dd))(digits))(input)
+}
 
-    #[rstest]
-    #[case("13-06-2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("13/06-2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("13.06.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("13    06\t2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("00/10/2024", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    #[case("42/10/2024", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    #[case("06/00/2024", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    #[case("06/13/2024", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    #[case("31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
-    fn test_dd_mm_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
-        assert_eq!(dd_mm_y4(input), expected);
-    }
+/// Recognizes the compact `day`, `month` and `year` parts without separators
+/// (e.g. `13072024`) and returns [`NaiveDate`] with the selected parts if the
+/// date exists, otherwise returns [`Error::NonExistentDate`]. The run of digits
+/// must be exactly eight digits long.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     ddmmy4("13072024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn ddmmy4(input: &str) -> IResult<&str, NaiveDate> {
+    compact_y4(|digits| map(tuple((dd, mm, y4)), |(dd, mm, y4)| (y4, mm, dd))(digits))(input)
+}
 
-    #[rstest]
-    #[case("06-13-2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("06/13-2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+/// Recognizes the compact `month`, `day` and `year` parts without separators
+/// (e.g. `07132024`) and returns [`NaiveDate`] with the selected parts if the
+/// date exists, otherwise returns [`Error::NonExistentDate`]. The run of digits
+/// must be exactly eight digits long.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     mmddy4("07132024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn mmddy4(input: &str) -> IResult<&str, NaiveDate> {
+    compact_y4(|digits| map(tuple((mm, dd, y4)), |(mm, dd, y4)| (y4, mm, dd))(digits))(input)
+}
+
+/// Returns the number of days in the specified `month` (`1..=12`) of the
+/// specified `year`, taking the leap years into account, or [`None`] if the
+/// month or the year is out of range.
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+
+    Some(match month {
+        2 if first.leap_year() => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    })
+}
+
+/// Returns the [`NaiveDate`] with the specified parts if the `day` exists in
+/// the `month` of the `year` (e.g. there is no `31/04` or `29/02/2023`),
+/// otherwise returns [`Error::DayOutOfMonth`] with the parts. Returns
+/// [`Error::NonExistentDate`] if the `month` or the `year` is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{error::Error, numeric::checked_date};
+///
+/// assert_eq!(
+///     checked_date(2024, 2, 29),
+///     Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+/// );
+/// assert_eq!(
+///     checked_date(2023, 2, 29),
+///     Err(nom::Err::Error(Error::<&str>::DayOutOfMonth {
+///         year: 2023,
+///         month: 2,
+///         day: 29
+///     }))
+/// );
+/// ```
+pub fn checked_date<'a>(
+    year: i32,
+    month: u32,
+    day: u32,
+) -> Result<NaiveDate, nom::Err<Error<&'a str>>> {
+    let days = days_in_month(year, month).ok_or(nom::Err::Error(Error::NonExistentDate))?;
+    if day == 0 || day > days {
+        return Err(nom::Err::Error(Error::DayOutOfMonth { year, month, day }));
+    }
+
+    NaiveDate::from_ymd_opt(year, month, day).ok_or(nom::Err::Error(Error::NonExistentDate))
+}
+
+/// Same as [`dd_mm_y4`], but validates the `day` against the length of the
+/// month (see [`checked_date`]), so `31/02/2024` is rejected with
+/// [`Error::DayOutOfMonth`] reporting the parts which don't form the date.
+///
+/// # Examples
+///
+/// ```
+/// use nom_date_parsers::{error::Error, numeric::dd_mm_y4_strict};
+///
+/// assert_eq!(
+///     dd_mm_y4_strict("31/04/2024"),
+///     Err(nom::Err::Error(Error::DayOutOfMonth {
+///         year: 2024,
+///         month: 4,
+///         day: 31
+///     }))
+/// );
+/// ```
+pub fn dd_mm_y4_strict(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, (dd, (), mm, (), y4)) = tuple((
+        dd,
+        numeric_date_parts_separator,
+        mm,
+        numeric_date_parts_separator,
+        y4,
+    ))(input)?;
+
+    Ok((input, checked_date(y4 as i32, mm, dd)?))
+}
+
+/// Same as [`mm_dd_y4`], but validates the `day` against the length of the
+/// month, see [`dd_mm_y4_strict`].
+pub fn mm_dd_y4_strict(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, (mm, (), dd, (), y4)) = tuple((
+        mm,
+        numeric_date_parts_separator,
+        dd,
+        numeric_date_parts_separator,
+        y4,
+    ))(input)?;
+
+    Ok((input, checked_date(y4 as i32, mm, dd)?))
+}
+
+/// Same as [`y4_mm_dd`], but validates the `day` against the length of the
+/// month, see [`dd_mm_y4_strict`].
+pub fn y4_mm_dd_strict(input: &str) -> IResult<&str, NaiveDate> {
+    let (input, (y4, (), mm, (), dd)) = tuple((
+        y4,
+        numeric_date_parts_separator,
+        mm,
+        numeric_date_parts_separator,
+        dd,
+    ))(input)?;
+
+    Ok((input, checked_date(y4 as i32, mm, dd)?))
+}
+
+/// Recognizes the `day-month-year` numeric date with the optional `month` and
+/// `year` parts, i.e. does the same as the
+/// `alt((dd_mm_y4, dd_mm_only, dd_only))` combination, but parses the common
+/// `day` (and `month`) prefix only once.
+///
+/// The missing parts are taken from the current date. Unlike the `alt`
+/// combination, returns [`Error::NonExistentDate`] instead of falling back to
+/// the shorter date if the recognized parts don't form an existing date (e.g.
+/// `31/02/2024`).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     dmy("13/07/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     dmy("13.07")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn dmy(input: &str) -> IResult<&str, NaiveDate> {
+    dmy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`dmy`] parser which takes the missing parts from the reference
+/// date, the allowed separators from the [`SeparatorPolicy`] and the accepted
+/// years from the [`YearRange`] of the specified [`ParserContext`].
+pub fn dmy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, (dd, mm_y4)) = pair(
+            dd,
+            opt_year(tail_parts(ctx.separators(), &mm, &y4_with(ctx))),
+        )(input)?;
+        let date = match mm_y4 {
+            Some((mm, Some(y4))) => NaiveDate::from_ymd_opt(y4 as i32, mm, dd),
+            Some((mm, None)) => ctx.resolve(Some(mm), dd),
+            None => ctx.resolve(None, dd),
+        };
+
+        Ok((input, date.ok_or(nom::Err::Error(Error::NonExistentDate))?))
+    }
+}
+
+/// Recognizes the `month-day-year` numeric date with the optional `year` part
+/// or the single `day` part, i.e. does the same as the
+/// `alt((mm_dd_y4, mm_dd_only, dd_only))` combination, but parses the common
+/// `month` and `day` prefix only once.
+///
+/// The missing parts are taken from the current date. Unlike the `alt`
+/// combination, returns [`Error::NonExistentDate`] instead of falling back to
+/// the shorter date if the recognized parts don't form an existing date (e.g.
+/// `02/31/2024`).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     mdy("07/13/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     mdy("07/13")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn mdy(input: &str) -> IResult<&str, NaiveDate> {
+    mdy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`mdy`] parser which takes the missing parts from the reference
+/// date, the allowed separators from the [`SeparatorPolicy`] and the accepted
+/// years from the [`YearRange`] of the specified [`ParserContext`].
+pub fn mdy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        let (input, mm_dd_y4) =
+            opt_year(pair(mm, tail_parts(ctx.separators(), &dd, &y4_with(ctx))))(input)?;
+        let Some((mm, (dd, y4))) = mm_dd_y4 else {
+            return dd_only_with(ctx)(input);
+        };
+
+        let date = match y4 {
+            Some(y4) => NaiveDate::from_ymd_opt(y4 as i32, mm, dd),
+            None => ctx.resolve(Some(mm), dd),
+        };
+
+        Ok((input, date.ok_or(nom::Err::Error(Error::NonExistentDate))?))
+    }
+}
+
+/// Recognizes exactly two digits (ascii or Eastern Arabic) of the `year` part,
+/// which are not followed by another digit.
+///
+/// Accepts numbers in the range `00..=99`, use the
+/// [`ParserContext::full_year`] to get the full year.
+#[inline]
+pub fn y2(input: &str) -> IResult<&str, u32> {
+    context(
+        "year",
+        terminated(map_res(take(2_u8), parse_digits), not(satisfy(is_digit))),
+    )(input)
+}
+
+/// Recognizes the `day`, `month` and two-digit `year` parts separated by the
+/// [`numeric_date_parts_separator`] and returns [`NaiveDate`] with the selected
+/// parts if the date exists, otherwise returns [`Error::NonExistentDate`].
+///
+/// The two-digit years are mapped onto the `1970..=2069` range, use the
+/// [`dd_mm_y2_with`] to specify another century pivot.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     dd_mm_y2("13/07/24")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     dd_mm_y2("13/07/85")?.1,
+///     NaiveDate::from_ymd_opt(1985, 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn dd_mm_y2(input: &str) -> IResult<&str, NaiveDate> {
+    dd_mm_y2_at(DEFAULT_CENTURY_PIVOT, SeparatorPolicy::default())(input)
+}
+
+/// Creates the [`dd_mm_y2`] parser which uses the century pivot and the
+/// [`SeparatorPolicy`] of the specified [`ParserContext`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// let ctx = ParserContext::new().with_century_pivot(30);
+///
+/// assert_eq!(
+///     dd_mm_y2_with(&ctx)("13/07/42")?.1,
+///     NaiveDate::from_ymd_opt(1942, 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn dd_mm_y2_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    dd_mm_y2_at(ctx.century_pivot(), ctx.separators())
+}
+
+/// Creates the [`dd_mm_y2`] parser which uses the specified century pivot and
+/// [`SeparatorPolicy`].
+fn dd_mm_y2_at(
+    century_pivot: u8,
+    separators: SeparatorPolicy,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| {
+        let (input, (dd, mm, y2)) = date_parts(separators, [&dd, &mm, &y2])(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(full_year(y2, century_pivot), mm, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Recognizes the `month`, `day` and two-digit `year` parts separated by the
+/// [`numeric_date_parts_separator`] and returns [`NaiveDate`] with the selected
+/// parts if the date exists, otherwise returns [`Error::NonExistentDate`].
+///
+/// The two-digit years are mapped onto the `1970..=2069` range, use the
+/// [`mm_dd_y2_with`] to specify another century pivot.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     mm_dd_y2("07-13-24")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn mm_dd_y2(input: &str) -> IResult<&str, NaiveDate> {
+    mm_dd_y2_at(DEFAULT_CENTURY_PIVOT, SeparatorPolicy::default())(input)
+}
+
+/// Creates the [`mm_dd_y2`] parser which uses the century pivot and the
+/// [`SeparatorPolicy`] of the specified [`ParserContext`].
+pub fn mm_dd_y2_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    mm_dd_y2_at(ctx.century_pivot(), ctx.separators())
+}
+
+/// Creates the [`mm_dd_y2`] parser which uses the specified century pivot and
+/// [`SeparatorPolicy`].
+fn mm_dd_y2_at(
+    century_pivot: u8,
+    separators: SeparatorPolicy,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| {
+        let (input, (mm, dd, y2)) = date_parts(separators, [&mm, &dd, &y2])(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(full_year(y2, century_pivot), mm, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Recognizes the two-digit `year`, `month` and `day` parts separated by the
+/// [`numeric_date_parts_separator`] and returns [`NaiveDate`] with the selected
+/// parts if the date exists, otherwise returns [`Error::NonExistentDate`].
+///
+/// The two-digit years are mapped onto the `1970..=2069` range, use the
+/// [`y2_mm_dd_with`] to specify another century pivot.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     y2_mm_dd("24.07.13")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn y2_mm_dd(input: &str) -> IResult<&str, NaiveDate> {
+    y2_mm_dd_at(DEFAULT_CENTURY_PIVOT, SeparatorPolicy::default())(input)
+}
+
+/// Creates the [`y2_mm_dd`] parser which uses the century pivot and the
+/// [`SeparatorPolicy`] of the specified [`ParserContext`].
+pub fn y2_mm_dd_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    y2_mm_dd_at(ctx.century_pivot(), ctx.separators())
+}
+
+/// Creates the [`y2_mm_dd`] parser which uses the specified century pivot and
+/// [`SeparatorPolicy`].
+fn y2_mm_dd_at(
+    century_pivot: u8,
+    separators: SeparatorPolicy,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| {
+        let (input, (y2, mm, dd)) = date_parts(separators, [&y2, &mm, &dd])(input)?;
+
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(full_year(y2, century_pivot), mm, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Recognizes the compact two-digit `year`, `month` and `day` parts without
+/// separators (e.g. `240713`) and returns [`NaiveDate`] with the selected parts
+/// if the date exists, otherwise returns [`Error::NonExistentDate`]. The run of
+/// digits must be exactly six digits long.
+///
+/// The two-digit years are mapped onto the `1970..=2069` range, use the
+/// [`y2mmdd_with`] to specify another century pivot.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     y2mmdd("240713")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+pub fn y2mmdd(input: &str) -> IResult<&str, NaiveDate> {
+    y2mmdd_at(DEFAULT_CENTURY_PIVOT)(input)
+}
+
+/// Creates the [`y2mmdd`] parser which uses the century pivot of the
+/// specified [`ParserContext`].
+pub fn y2mmdd_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    y2mmdd_at(ctx.century_pivot())
+}
+
+/// Creates the [`y2mmdd`] parser which uses the specified century pivot.
+fn y2mmdd_at(century_pivot: u8) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    move |input: &str| {
+        let (input, digits) = digit_run(6)(input)?;
+        let (_, (y2, mm, dd)) = tuple((map_res(take(2_u8), parse_digits), mm, dd))(digits)?;
+
+        Ok((
+            input,
+            NaiveDate::from_ymd_opt(full_year(y2, century_pivot), mm, dd)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+        ))
+    }
+}
+
+/// Returns the number of the leading ascii or Eastern Arabic digits of the
+/// input.
+fn leading_digits(input: &str) -> usize {
+    input.chars().take_while(|&c| is_digit(c)).count()
+}
+
+/// Creates a parser which recognizes the optional `year` part preceded by the
+/// [`numeric_date_parts_separator`] and chooses between the [`y4`] and [`y2`]
+/// parsers by the number of digits. The separator isn't consumed if it isn't
+/// followed by exactly two or four digits.
+fn smart_year(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, Option<i32>> + '_ {
+    move |input: &str| {
+        let Ok((rest, ())) = numeric_date_parts_separator(input) else {
+            return Ok((input, None));
+        };
+
+        match leading_digits(rest) {
+            4 => map(y4_with(ctx), |y4| Some(y4 as i32))(rest),
+            2 => map(y2, |y2| Some(ctx.full_year(y2)))(rest),
+            _ => Ok((input, None)),
+        }
+    }
+}
+
+/// Recognizes any numeric date in one pass counting the leading digits to
+/// choose the format deterministically instead of trying the formats one by
+/// one:
+/// - four digits: `yyyy*mm*dd`, see [`y4_mm_dd`]
+/// - eight digits: `yyyymmdd`
+/// - one or two digits: `dd*mm*yyyy`, `dd*mm*yy`, `dd*mm` or `dd`, the year
+///   format is chosen by the number of its digits as well
+///
+/// Other inputs are rejected with the `nom::error::ErrorKind::Digit` error.
+///
+/// The two-digit years are mapped onto the `1970..=2069` range, the missing
+/// parts are taken from the current date. Use the [`smart_numeric_mdy`] parser
+/// for the `month-day-year` sequence.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     smart_numeric("2024-07-13")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     smart_numeric("13/07/24")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     smart_numeric("20240713")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     smart_numeric("13.07")?.1,
+///     NaiveDate::from_ymd_opt(Local::now().year(), 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn smart_numeric(input: &str) -> IResult<&str, NaiveDate> {
+    smart_numeric_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`smart_numeric`] parser which uses the reference date, the
+/// century pivot and the [`YearRange`] of the specified [`ParserContext`].
+pub fn smart_numeric_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        smart_numeric_routed(ctx, input, |input| {
+            let (input, (dd, mm_year)) = pair(
+                dd,
+                opt_year(preceded(
+                    numeric_date_parts_separator,
+                    pair(mm, smart_year(ctx)),
+                )),
+            )(input)?;
+
+            Ok((
+                input,
+                match mm_year {
+                    Some((mm, year)) => (year, Some(mm), dd),
+                    None => (None, None, dd),
+                },
+            ))
+        })
+    }
+}
+
+/// Same as the [`smart_numeric`] parser, but recognizes the dates starting
+/// with one or two digits using the `month-day-year` sequence: `mm*dd*yyyy`,
+/// `mm*dd*yy`, `mm*dd` or `dd`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     smart_numeric_mdy("07/13/24")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(
+///     smart_numeric_mdy("2024-07-13")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn smart_numeric_mdy(input: &str) -> IResult<&str, NaiveDate> {
+    smart_numeric_mdy_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`smart_numeric_mdy`] parser which uses the reference date,
+/// the century pivot and the [`YearRange`] of the specified [`ParserContext`].
+pub fn smart_numeric_mdy_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        smart_numeric_routed(ctx, input, |input| {
+            match opt_year(pair(mm_dd, smart_year(ctx)))(input)? {
+                (input, Some(((mm, dd), year))) => Ok((input, (year, Some(mm), dd))),
+                (_, None) => map(dd, |dd| (None, None, dd))(input),
+            }
+        })
+    }
+}
+
+/// Routes the input by the number of the leading digits: the year-first
+/// formats are recognized here, the dates starting with one or two digits are
+/// recognized by the specified `short` parser which returns the `year`,
+/// `month` and `day` parts.
+fn smart_numeric_routed<'a>(
+    ctx: &ParserContext,
+    input: &'a str,
+    short: impl Fn(&'a str) -> IResult<&'a str, (Option<i32>, Option<u32>, u32)>,
+) -> IResult<&'a str, NaiveDate> {
+    match leading_digits(input) {
+        4 => y4_mm_dd_in(ctx.year_range(), input),
+        8 => {
+            let (input, (y4, mm, dd)) = tuple((y4_with(ctx), mm, dd))(input)?;
+
+            Ok((
+                input,
+                NaiveDate::from_ymd_opt(y4 as i32, mm, dd)
+                    .ok_or(nom::Err::Error(Error::NonExistentDate))?,
+            ))
+        }
+        1 | 2 => {
+            let (input, (year, month, day)) = short(input)?;
+            let date = match year {
+                Some(year) => {
+                    NaiveDate::from_ymd_opt(year, month.unwrap_or(ctx.today().month()), day)
+                }
+                None => ctx.resolve(month, day),
+            };
+
+            Ok((input, date.ok_or(nom::Err::Error(Error::NonExistentDate))?))
+        }
+        _ => Err(nom::Err::Error(Error::Nom(input, ErrorKind::Digit))),
+    }
+}
+
+/// Creates a parser which recognizes the numeric date with the optional parts
+/// using the sequence of the parts specified by the [`DateOrder`]:
+/// - [`DateOrder::Dmy`]: `dd*mm*yy`, `dd*mm*yyyy`, `dd*mm` or `dd`, see
+///   [`dd_mm_y2`] and [`dmy`];
+/// - [`DateOrder::Mdy`]: `mm*dd*yy`, `mm*dd*yyyy`, `mm*dd` or `dd`, see
+///   [`mm_dd_y2`] and [`mdy`];
+/// - [`DateOrder::Ymd`]: `yyyy*mm*dd`, `yy*mm*dd`, `mm*dd` or `dd`, see
+///   [`y4_mm_dd`], [`y2_mm_dd`], [`mm_dd_only`] and [`dd_only`].
+///
+/// The missing parts are taken from the current date.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     bundle(DateOrder::Dmy)("03/04/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 4, 3).unwrap()
+/// );
+/// assert_eq!(
+///     bundle(DateOrder::Mdy)("03/04/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 3, 4).unwrap()
+/// );
+/// assert_eq!(
+///     bundle(DateOrder::Ymd)("24/03/04")?.1,
+///     NaiveDate::from_ymd_opt(2024, 3, 4).unwrap()
+/// );
+///
+/// # Ok::<(), Box::<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
+pub fn bundle(order: DateOrder) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    let ctx = ParserContext::default();
+
+    move |input: &str| bundle_with(&ctx, order)(input)
+}
+
+/// Creates the [`bundle`] parser which uses the reference date, the century
+/// pivot and the [`SeparatorPolicy`] of the specified [`ParserContext`].
+pub fn bundle_with(
+    ctx: &ParserContext,
+    order: DateOrder,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| match order {
+        DateOrder::Dmy => alt((dd_mm_y2_with(ctx), dmy_with(ctx)))(input),
+        DateOrder::Mdy => alt((mm_dd_y2_with(ctx), mdy_with(ctx)))(input),
+        DateOrder::Ymd => alt((
+            y4_mm_dd_with(ctx),
+            y2_mm_dd_with(ctx),
+            mm_dd_only_with(ctx),
+            dd_only_with(ctx),
+        ))(input),
+    }
+}
+
+/// Recognizes the whole `input` as the numeric date using the [`bundle`]
+/// parser of every [`DateOrder`] and returns all the distinct interpretations
+/// of the date, so the application can ask the user to choose one if the
+/// date is ambiguous (e.g. `03/04` is either the 3rd of April or the 4th of
+/// March).
+///
+/// The orders are tried in the `Dmy`, `Mdy`, `Ymd` sequence, the date
+/// recognized by several orders is returned once with the first of them. The
+/// result is empty if none of the orders recognizes the date.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::prelude::*;
+/// use nom_date_parsers::prelude::*;
+///
+/// assert_eq!(
+///     parse_ambiguous("03/04/2024"),
+///     vec![
+///         (DateOrder::Dmy, NaiveDate::from_ymd_opt(2024, 4, 3).unwrap()),
+///         (DateOrder::Mdy, NaiveDate::from_ymd_opt(2024, 3, 4).unwrap()),
+///     ]
+/// );
+/// assert_eq!(
+///     parse_ambiguous("13/04/2024"),
+///     vec![(
+///         DateOrder::Dmy,
+///         NaiveDate::from_ymd_opt(2024, 4, 13).unwrap()
+///     )]
+/// );
+/// assert!(parse_ambiguous("13/13/2024").is_empty());
+/// ```
+#[cfg(feature = "clock")]
+pub fn parse_ambiguous(input: &str) -> Vec<(DateOrder, NaiveDate)> {
+    parse_ambiguous_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`parse_ambiguous`] function which uses the reference date of
+/// the specified [`ParserContext`].
+///
+/// The [`TrailingInput::Keep`] mode of the context is replaced with the
+/// [`TrailingInput::RequireEnd`], other modes are kept.
+pub fn parse_ambiguous_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> Vec<(DateOrder, NaiveDate)> + '_ {
+    move |input: &str| {
+        let ctx = match ctx.trailing_input() {
+            TrailingInput::Keep => ctx.clone().with_trailing_input(TrailingInput::RequireEnd),
+            _ => ctx.clone(),
+        };
+        let mut dates: Vec<(DateOrder, NaiveDate)> = Vec::new();
+
+        for order in [DateOrder::Dmy, DateOrder::Mdy, DateOrder::Ymd] {
+            let Ok((_, date)) = ctx.trailing(bundle_with(&ctx, order))(input) else {
+                continue;
+            };
+            if dates.iter().all(|&(_, other)| other != date) {
+                dates.push((order, date));
+            }
+        }
+
+        dates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::calendar::ResolutionPolicy;
+
+    #[rstest]
+    #[case("9", Ok(("", 9)))]
+    #[case("09", Ok(("", 9)))]
+    #[case("31", Ok(("", 31)))]
+    #[case("١٣", Ok(("", 13)))]
+    #[case("٩/", Ok(("/", 9)))]
+    #[case("00", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "00" })))]
+    #[case("42", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42" })))]
+    fn test_dd(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
+        assert_eq!(dd(input), expected);
+    }
+
+    #[rstest]
+    #[case("9", Ok(("", Local::now().date_naive().with_day(9).unwrap())))]
+    #[case("09", Ok(("", Local::now().date_naive().with_day(9).unwrap())))]
+    #[case("31", Local::now().date_naive().with_day(31).ok_or(nom::Err::Error(Error::NonExistentDate)).map(|d| ("", d)))]
+    #[case("00", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "00" })))]
+    #[case("42", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42" })))]
+    fn test_dd_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_only(input), expected)
+    }
+
+    #[rstest]
+    #[case("9", Ok(("", 9)))]
+    #[case("09", Ok(("", 9)))]
+    #[case("12", Ok(("", 12)))]
+    #[case("00", Err(nom::Err::Error(Error::MonthOutOfRange { value: 0, input: "00" })))]
+    #[case("13", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13" })))]
+    fn test_mm(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
+        assert_eq!(mm(input), expected);
+    }
+
+    #[rstest]
+    #[case("1", Ok(("", 1)))]
+    #[case("09", Ok(("", 9)))]
+    #[case("53", Ok(("", 53)))]
+    #[case("29-я", Ok(("-я", 29)))]
+    #[case("٠٧", Ok(("", 7)))]
+    #[case("00", Err(nom::Err::Error(Error::WeekOutOfRange)))]
+    #[case("54", Err(nom::Err::Error(Error::WeekOutOfRange)))]
+    fn test_ww(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
+        assert_eq!(ww(input), expected)
+    }
+
+    #[rstest]
+    #[case("3/9", Ok(("", Local::now().date_naive().with_day(3).unwrap().with_month(9).unwrap())))]
+    #[case("03-09", Ok(("", Local::now().date_naive().with_day(3).unwrap().with_month(9).unwrap())))]
+    #[case("03/12", Ok(("", Local::now().date_naive().with_day(3).unwrap().with_month(12).unwrap())))]
+    #[case("00", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "00" })))]
+    #[case("42", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42" })))]
+    #[case("13.00", Err(nom::Err::Error(Error::MonthOutOfRange { value: 0, input: "00" })))]
+    #[case("13\t13", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13" })))]
+    fn test_dd_mm_only(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_mm_only(input), expected);
+    }
+
+    #[rstest]
+    #[case("29", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case("30", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_only_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 10).unwrap());
+
+        assert_eq!(dd_only_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("29/02", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case("13-07", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    fn test_dd_mm_only_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 10).unwrap());
+
+        assert_eq!(dd_mm_only_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case(2024, "02/29", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case(2023, "02/29", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_mm_dd_only_with(
+        #[case] year: i32,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(year, 2, 10).unwrap());
+
+        assert_eq!(mm_dd_only_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case(ResolutionPolicy::CurrentPeriod, "05", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 5).unwrap())))]
+    #[case(ResolutionPolicy::NearestFuture, "05", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 5).unwrap())))]
+    #[case(ResolutionPolicy::NearestFuture, "25", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 25).unwrap())))]
+    #[case(ResolutionPolicy::NearestPast, "25", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 25).unwrap())))]
+    #[case(ResolutionPolicy::NearestFuture, "05/01", Ok(("", NaiveDate::from_ymd_opt(2025, 1, 5).unwrap())))]
+    #[case(ResolutionPolicy::NearestPast, "25.12", Ok(("", NaiveDate::from_ymd_opt(2023, 12, 25).unwrap())))]
+    #[case(ResolutionPolicy::NearestFuture, "29/02", Ok(("", NaiveDate::from_ymd_opt(2028, 2, 29).unwrap())))]
+    #[case(ResolutionPolicy::NearestFuture, "13/07/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(
+        ResolutionPolicy::NearestFuture,
+        "31/02",
+        Err(nom::Err::Error(Error::NonExistentDate))
+    )]
+    fn test_dmy_with_resolution(
+        #[case] resolution: ResolutionPolicy,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())
+            .with_resolution(resolution);
+
+        assert_eq!(dmy_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024", Ok(("", 2024)))]
+    #[case("1900", Ok(("", 1900)))]
+    #[case("2100", Ok(("", 2100)))]
+    #[case("0042", Err(nom::Err::Error(Error::YearOutOfRange { value: 42, input: "0042" })))]
+    #[case("2101", Err(nom::Err::Error(Error::YearOutOfRange { value: 2101, input: "2101" })))]
+    #[case("٢٠٢٤", Ok(("", 2024)))]
+    #[case(
+        "42",
+        Err(nom::Err::Error(Error::Context("year", Box::new(Error::Nom("42", ErrorKind::Eof)))))
+    )]
+    #[case("19001", Ok(("1", 1900)))]
+    #[case(
+        "10001",
+        Err(nom::Err::Error(Error::YearOutOfRange { value: 1000, input: "10001" }))
+    )]
+    fn test_y4(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
+        assert_eq!(y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-06-13", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("2024/06-13", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("2024.06.13", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("2024    06\t13", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("2024/00/06", Err(nom::Err::Error(Error::MonthOutOfRange { value: 0, input: "00/06" })))]
+    #[case("2024/13/06", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13/06" })))]
+    #[case("2024/10/00", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "00" })))]
+    #[case("2024/10/42", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42" })))]
+    fn test_y4_mm_dd(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(y4_mm_dd(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-W29", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())))]
+    #[case("2024-W29-5", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("2024W297", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("2020-W53-1 rest", Ok((" rest", NaiveDate::from_ymd_opt(2020, 12, 28).unwrap())))]
+    #[case("2025-W01", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 30).unwrap())))]
+    #[case("٢٠٢٤-W٢٩-٥", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("2024-W29-0", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "0" })))]
+    #[case("2024-W29-8", Err(nom::Err::Error(Error::DayOutOfRange { value: 8, input: "8" })))]
+    #[case("2024-W00", Err(nom::Err::Error(Error::WeekOutOfRange)))]
+    #[case("2024-W53", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_iso_week(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(iso_week(input), expected);
+    }
+
+    #[rstest]
+    #[case("1", Ok(("", 1)))]
+    #[case("42", Ok(("", 42)))]
+    #[case("366", Ok(("", 366)))]
+    #[case("0", Err(nom::Err::Error(Error::DayOfYearOutOfRange)))]
+    #[case("367", Err(nom::Err::Error(Error::DayOfYearOutOfRange)))]
+    fn test_ddd(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
+        assert_eq!(ddd(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-195", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024195", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("2024-001 rest", Ok((" rest", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())))]
+    #[case("2024-366", Ok(("", NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())))]
+    #[case("2023-366", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("2024-000", Err(nom::Err::Error(Error::DayOfYearOutOfRange)))]
+    #[case("2024-400", Err(nom::Err::Error(Error::DayOfYearOutOfRange)))]
+    #[case(
+        "2024-07",
+        Err(nom::Err::Error(Error::Nom("07", ErrorKind::TakeWhileMN)))
+    )]
+    #[case("20240713", Err(nom::Err::Error(Error::Nom("3", ErrorKind::Not))))]
+    fn test_y4_ddd(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(y4_ddd(input), expected);
+    }
+
+    #[rstest]
+    #[case("13-06-2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("13/06-2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("13.06.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("13    06\t2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("١٣/٠٦/٢٠٢٤", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("00/10/2024", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "00/10/2024" })))]
+    #[case("42/10/2024", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42/10/2024" })))]
+    #[case("06/00/2024", Err(nom::Err::Error(Error::MonthOutOfRange { value: 0, input: "00/2024" })))]
+    #[case("06/13/2024", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13/2024" })))]
+    #[case("31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("13/07/1812", Err(nom::Err::Error(Error::YearOutOfRange { value: 1812, input: "1812" })))]
+    fn test_dd_mm_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_mm_y4(input), expected);
+    }
+
+    #[rstest]
+    #[case("06-13-2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
+    #[case("06/13-2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
     #[case("06.13.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
     #[case("06    13\t2024", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 13).unwrap())))]
-    #[case("00/06/2024", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    #[case("13/06/2024", Err(nom::Err::Error(Error::MonthOutOfRange)))]
-    #[case("10/00/2024", Err(nom::Err::Error(Error::DayOutOfRange)))]
-    #[case("10/32/2024", Err(nom::Err::Error(Error::DayOutOfRange)))]
+    #[case("00/06/2024", Err(nom::Err::Error(Error::MonthOutOfRange { value: 0, input: "00/06/2024" })))]
+    #[case("13/06/2024", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13/06/2024" })))]
+    #[case("10/00/2024", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "00/2024" })))]
+    #[case("10/32/2024", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32/2024" })))]
     #[case("02/31/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
     fn test_mm_dd_y4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
         assert_eq!(mm_dd_y4(input), expected)
     }
+
+    #[rstest]
+    #[case(2024, 2, 29, Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()))]
+    #[case(2000, 2, 29, Ok(NaiveDate::from_ymd_opt(2000, 2, 29).unwrap()))]
+    #[case(1900, 2, 29, Err(nom::Err::Error(Error::DayOutOfMonth { year: 1900, month: 2, day: 29 })))]
+    #[case(2024, 4, 31, Err(nom::Err::Error(Error::DayOutOfMonth { year: 2024, month: 4, day: 31 })))]
+    #[case(2024, 12, 31, Ok(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()))]
+    #[case(2024, 13, 1, Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_checked_date(
+        #[case] year: i32,
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] expected: Result<NaiveDate, nom::Err<Error<&str>>>,
+    ) {
+        assert_eq!(checked_date(year, month, day), expected);
+    }
+
+    #[rstest]
+    #[case("29/02/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case("29/02/2023", Err(nom::Err::Error(Error::DayOutOfMonth { year: 2023, month: 2, day: 29 })))]
+    #[case("31.06.2024", Err(nom::Err::Error(Error::DayOutOfMonth { year: 2024, month: 6, day: 31 })))]
+    #[case("32/01/2024", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32/01/2024" })))]
+    fn test_dd_mm_y4_strict(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_mm_y4_strict(input), expected);
+    }
+
+    #[rstest]
+    #[case("02/29/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case("09/31/2024", Err(nom::Err::Error(Error::DayOutOfMonth { year: 2024, month: 9, day: 31 })))]
+    fn test_mm_dd_y4_strict(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(mm_dd_y4_strict(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-02-29", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())))]
+    #[case("2025-02-29", Err(nom::Err::Error(Error::DayOutOfMonth { year: 2025, month: 2, day: 29 })))]
+    fn test_y4_mm_dd_strict(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(y4_mm_dd_strict(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("13/07/24", Ok(("/24", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13/13/2024", Ok(("/13/2024", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("13 July", Ok((" July", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("30", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("13/07/0042", Err(nom::Err::Error(Error::YearOutOfRange { value: 42, input: "0042" })))]
+    fn test_dmy_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+
+        assert_eq!(dmy_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("07/13/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("07-13", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("13/07", Ok(("/07", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("02/31/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_mdy_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+
+        assert_eq!(mdy_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07.2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(
+        "13/07.2024",
+        Err(nom::Err::Error(Error::Nom(".2024", ErrorKind::Verify)))
+    )]
+    #[case(
+        "13-07-2024",
+        Err(nom::Err::Error(Error::Nom("-07-2024", ErrorKind::Verify)))
+    )]
+    #[case(
+        "13 07 2024",
+        Err(nom::Err::Error(Error::Nom(" 07 2024", ErrorKind::Verify)))
+    )]
+    fn test_dd_mm_y4_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_separators(
+            SeparatorPolicy::only(&[Separator::Slash, Separator::Dot]).with_consistency(true),
+        );
+
+        assert_eq!(dd_mm_y4_with(&ctx)(input), expected);
+    }
+
+    #[test]
+    fn test_year_range_with() {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(1850, 2, 1).unwrap())
+            .with_year_range(YearRange::new(1800, 1899));
+        let date = NaiveDate::from_ymd_opt(1812, 7, 13).unwrap();
+
+        assert_eq!(dd_mm_y4_with(&ctx)("13/07/1812"), Ok(("", date)));
+        assert_eq!(mm_dd_y4_with(&ctx)("07/13/1812"), Ok(("", date)));
+        assert_eq!(y4_mm_dd_with(&ctx)("1812-07-13"), Ok(("", date)));
+        assert_eq!(dmy_with(&ctx)("13.07.1812"), Ok(("", date)));
+        assert_eq!(mdy_with(&ctx)("07.13.1812"), Ok(("", date)));
+        assert_eq!(smart_numeric_with(&ctx)("18120713"), Ok(("", date)));
+        assert_eq!(smart_numeric_with(&ctx)("13/07/1812"), Ok(("", date)));
+        assert_eq!(
+            dd_mm_y4_with(&ctx)("13/07/2024"),
+            Err(nom::Err::Error(Error::YearOutOfRange {
+                value: 2024,
+                input: "2024"
+            }))
+        );
+        assert_eq!(
+            smart_numeric_with(&ctx)("13/07/2024"),
+            Err(nom::Err::Error(Error::YearOutOfRange {
+                value: 2024,
+                input: "2024"
+            }))
+        );
+        assert_eq!(
+            mdy_with(&ctx)("07/13/2024"),
+            Err(nom::Err::Error(Error::YearOutOfRange {
+                value: 2024,
+                input: "2024"
+            }))
+        );
+        assert_eq!(
+            smart_numeric_with(&ctx)("2024-07-13"),
+            Err(nom::Err::Error(Error::YearOutOfRange {
+                value: 2024,
+                input: "2024-07-13"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_separator_policy() {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())
+            .with_separators(SeparatorPolicy::only(&[Separator::Dash]).with_consistency(true));
+        let date = NaiveDate::from_ymd_opt(2024, 7, 13).unwrap();
+
+        assert_eq!(y4_mm_dd_with(&ctx)("2024-07-13"), Ok(("", date)));
+        assert!(y4_mm_dd_with(&ctx)("2024/07/13").is_err());
+        assert_eq!(mm_dd_y4_with(&ctx)("07-13-2024"), Ok(("", date)));
+        assert_eq!(mm_dd_y2_with(&ctx)("07-13-24"), Ok(("", date)));
+        assert!(dd_mm_y2_with(&ctx)("13-07.24").is_err());
+        assert_eq!(dd_mm_only_with(&ctx)("13-07"), Ok(("", date)));
+        assert!(mm_dd_only_with(&ctx)("07/13").is_err());
+        assert_eq!(dmy_with(&ctx)("13-07.2024"), Ok((".2024", date)));
+        assert_eq!(mdy_with(&ctx)("07-13-2024"), Ok(("", date)));
+        assert_eq!(
+            y4_mm_dd_with(&ParserContext::new())("2024/07-13"),
+            Ok(("", date))
+        );
+    }
+
+    #[rstest]
+    #[case("24", Ok(("", 24)))]
+    #[case("07/", Ok(("/", 7)))]
+    #[case("٢٤", Ok(("", 24)))]
+    #[case(
+        "٢٠٢٤",
+        Err(nom::Err::Error(Error::Context("year", Box::new(Error::Nom("٢٤", ErrorKind::Not)))))
+    )]
+    #[case(
+        "2024",
+        Err(nom::Err::Error(Error::Context("year", Box::new(Error::Nom("24", ErrorKind::Not)))))
+    )]
+    fn test_y2(#[case] input: &str, #[case] expected: IResult<&str, u32>) {
+        assert_eq!(y2(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/24", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07.69", Ok(("", NaiveDate::from_ymd_opt(2069, 7, 13).unwrap())))]
+    #[case("13 07 70", Ok(("", NaiveDate::from_ymd_opt(1970, 7, 13).unwrap())))]
+    #[case("29-02-23", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_dd_mm_y2(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(dd_mm_y2(input), expected);
+    }
+
+    #[rstest]
+    #[case(70, "13/07/42", Ok(("", NaiveDate::from_ymd_opt(2042, 7, 13).unwrap())))]
+    #[case(30, "13/07/42", Ok(("", NaiveDate::from_ymd_opt(1942, 7, 13).unwrap())))]
+    #[case(0, "13/07/00", Ok(("", NaiveDate::from_ymd_opt(1900, 7, 13).unwrap())))]
+    fn test_dd_mm_y2_with(
+        #[case] century_pivot: u8,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_century_pivot(century_pivot);
+
+        assert_eq!(dd_mm_y2_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("07/13/24", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("12-31-99", Ok(("", NaiveDate::from_ymd_opt(1999, 12, 31).unwrap())))]
+    fn test_mm_dd_y2(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(mm_dd_y2(input), expected);
+    }
+
+    #[rstest]
+    #[case("24/07/13", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("99-12-31", Ok(("", NaiveDate::from_ymd_opt(1999, 12, 31).unwrap())))]
+    fn test_y2_mm_dd(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(y2_mm_dd(input), expected);
+    }
+
+    #[rstest]
+    #[case("20240713", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("20240713 rest", Ok((" rest", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("20241313", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "1313" })))]
+    #[case("20240732", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32" })))]
+    #[case("20230229", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(
+        "2024071",
+        Err(nom::Err::Error(Error::Nom("2024071", ErrorKind::TakeWhileMN)))
+    )]
+    #[case("202407130", Err(nom::Err::Error(Error::Nom("0", ErrorKind::Not))))]
+    fn test_y4mmdd(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(y4mmdd(input), expected);
+    }
+
+    #[rstest]
+    #[case("13072024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("١٣٠٧٢٠٢٤", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("31042024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("00072024", Err(nom::Err::Error(Error::DayOutOfRange { value: 0, input: "00072024" })))]
+    fn test_ddmmy4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(ddmmy4(input), expected);
+    }
+
+    #[rstest]
+    #[case("07132024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13072024", Err(nom::Err::Error(Error::MonthOutOfRange { value: 13, input: "13072024" })))]
+    fn test_mmddy4(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(mmddy4(input), expected);
+    }
+
+    #[rstest]
+    #[case(DEFAULT_CENTURY_PIVOT, "240713", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(DEFAULT_CENTURY_PIVOT, "991231", Ok(("", NaiveDate::from_ymd_opt(1999, 12, 31).unwrap())))]
+    #[case(30, "420713", Ok(("", NaiveDate::from_ymd_opt(1942, 7, 13).unwrap())))]
+    #[case(DEFAULT_CENTURY_PIVOT, "٢٤٠٧١٣", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(
+        DEFAULT_CENTURY_PIVOT,
+        "20240713",
+        Err(nom::Err::Error(Error::Nom("13", ErrorKind::Not)))
+    )]
+    fn test_y2mmdd_with(
+        #[case] century_pivot: u8,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_century_pivot(century_pivot);
+
+        assert_eq!(y2mmdd_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-07-13", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("20240713", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13/07/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13/07/85", Ok(("", NaiveDate::from_ymd_opt(1985, 7, 13).unwrap())))]
+    #[case("٢٠٢٤-٠٧-١٣", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("٢٠٢٤٠٧١٣", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("١٣/٠٧/٢٤", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13.07.202", Ok((".202", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("2024-02-30", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("31/02/24", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("123", Err(nom::Err::Error(Error::Nom("123", ErrorKind::Digit))))]
+    #[case("July", Err(nom::Err::Error(Error::Nom("July", ErrorKind::Digit))))]
+    fn test_smart_numeric_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+
+        assert_eq!(smart_numeric_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-07-13", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("07/13/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("07/13/24", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("07-13", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    fn test_smart_numeric_mdy_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+
+        assert_eq!(smart_numeric_mdy_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case(DateOrder::Dmy, "03/04/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 4, 3).unwrap())))]
+    #[case(DateOrder::Dmy, "03/04/24", Ok(("", NaiveDate::from_ymd_opt(2024, 4, 3).unwrap())))]
+    #[case(DateOrder::Dmy, "03/04", Ok(("", NaiveDate::from_ymd_opt(2024, 4, 3).unwrap())))]
+    #[case(DateOrder::Dmy, "03", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 3).unwrap())))]
+    #[case(DateOrder::Dmy, "31/04", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(DateOrder::Mdy, "03/04/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 4).unwrap())))]
+    #[case(DateOrder::Mdy, "03/04/24", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 4).unwrap())))]
+    #[case(DateOrder::Mdy, "03/04", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 4).unwrap())))]
+    #[case(DateOrder::Mdy, "13/04", Ok(("/04", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case(DateOrder::Ymd, "2024/03/04", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 4).unwrap())))]
+    #[case(DateOrder::Ymd, "24/03/04", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 4).unwrap())))]
+    #[case(DateOrder::Ymd, "03/04", Ok(("", NaiveDate::from_ymd_opt(2024, 3, 4).unwrap())))]
+    #[case(DateOrder::Ymd, "03", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 3).unwrap())))]
+    fn test_bundle_with(
+        #[case] order: DateOrder,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+
+        assert_eq!(bundle_with(&ctx, order)(input), expected);
+    }
+
+    #[rstest]
+    #[case("03/04/2024", vec![(DateOrder::Dmy, (2024, 4, 3)), (DateOrder::Mdy, (2024, 3, 4))])]
+    #[case("03/04", vec![(DateOrder::Dmy, (2024, 4, 3)), (DateOrder::Mdy, (2024, 3, 4))])]
+    #[case(" 05.05.24 ", vec![(DateOrder::Dmy, (2024, 5, 5)), (DateOrder::Ymd, (2005, 5, 24))])]
+    #[case("05/05/2024", vec![(DateOrder::Dmy, (2024, 5, 5))])]
+    #[case("24/03/04", vec![(DateOrder::Dmy, (2004, 3, 24)), (DateOrder::Ymd, (2024, 3, 4))])]
+    #[case("13/04/2024", vec![(DateOrder::Dmy, (2024, 4, 13))])]
+    #[case("04/13/2024", vec![(DateOrder::Mdy, (2024, 4, 13))])]
+    #[case("2024-04-13", vec![(DateOrder::Ymd, (2024, 4, 13))])]
+    #[case("13", vec![(DateOrder::Dmy, (2024, 2, 13))])]
+    #[case("13/13/2024", vec![])]
+    #[case("03/04 later", vec![])]
+    fn test_parse_ambiguous_with(
+        #[case] input: &str,
+        #[case] expected: Vec<(DateOrder, (i32, u32, u32))>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(order, (y, m, d))| (order, NaiveDate::from_ymd_opt(y, m, d).unwrap()))
+            .collect();
+
+        assert_eq!(parse_ambiguous_with(&ctx)(input), expected);
+    }
 }
@@ -0,0 +1,430 @@
+use alloc::vec::Vec;
+use core::{fmt, ops::Deref};
+
+use chrono::NaiveDate;
+use nom::error::ErrorKind;
+
+use crate::{
+    context::{ParserContext, TrailingInput},
+    error::Error,
+    parser::ContextFn,
+};
+
+/// The language-independent parsers ordered from the most to the least specific
+/// one.
+pub(crate) fn common_parsers() -> Vec<ContextFn> {
+    let mut parsers: Vec<ContextFn> = Vec::new();
+
+    #[cfg(feature = "iso8601")]
+    parsers.push(|_, input| crate::iso8601::date(input));
+
+    #[cfg(feature = "numeric")]
+    {
+        use crate::numeric::*;
+
+        parsers.extend([
+            (|ctx, input| y4_mm_dd_with(ctx)(input)) as ContextFn,
+            |_, input| iso_week(input),
+            |_, input| y4_ddd(input),
+            |ctx, input| dd_mm_y4_with(ctx)(input),
+            |ctx, input| dd_mm_y2_with(ctx)(input),
+            |ctx, input| dd_mm_only_with(ctx)(input),
+            |ctx, input| dd_only_with(ctx)(input),
+        ]);
+    }
+
+    #[cfg(feature = "quick")]
+    parsers.push(|ctx, input| crate::quick::bundle_with(ctx)(input));
+
+    parsers
+}
+
+/// Recognizes the whole `input` using the specified `parsers`, returns the date
+/// recognized by the first parser which consumes the whole input.
+///
+/// If none of the parsers recognizes the date, returns the first date-specific
+/// error (e.g. [`Error::NonExistentDate`]) or [`Error::Nom`] with the
+/// [`ErrorKind::Alt`].
+fn parse_whole<'a>(
+    ctx: &ParserContext,
+    parsers: &[ContextFn],
+    input: &'a str,
+) -> Result<NaiveDate, Error<&'a str>> {
+    let ctx = match ctx.trailing_input() {
+        TrailingInput::Keep => ctx.clone().with_trailing_input(TrailingInput::RequireEnd),
+        _ => ctx.clone(),
+    };
+    let input = input.trim_start();
+    let mut error = None;
+
+    for &parser in parsers {
+        match ctx.trailing(|input| parser(&ctx, input))(input) {
+            Ok((_, date)) => return Ok(date),
+            Err(nom::Err::Error(
+                Error::Nom(..) | Error::ParseIntError(..) | Error::Context(..),
+            )) => {}
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                error.get_or_insert(e);
+            }
+            Err(nom::Err::Incomplete(_)) => {}
+        }
+    }
+
+    Err(error.unwrap_or(Error::Nom(input, ErrorKind::Alt)))
+}
+
+/// Recognizes the date which takes the whole input (except the surrounding
+/// whitespaces) using the `iso8601`, `numeric` (in the `day-month-year` order)
+/// and `quick` parsers (those enabled by the features).
+///
+/// Unlike the parsers, which return the remaining input, the trailing input is
+/// an error, so `31-02-2024` is rejected with the [`Error::NonExistentDate`]
+/// instead of being recognized as the 31st day of the current month by the
+/// [`dd_only`](crate::numeric::dd_only) parser.
+///
+/// If none of the parsers recognizes the date, returns the first date-specific
+/// error (e.g. [`Error::NonExistentDate`]) or [`Error::Nom`] with the
+/// [`ErrorKind::Alt`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{error::Error, parse_date};
+///
+/// assert_eq!(
+///     parse_date(" 13-07-2024 "),
+///     Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+/// );
+/// assert_eq!(parse_date("31-02-2024"), Err(Error::NonExistentDate));
+/// ```
+#[cfg(feature = "clock")]
+pub fn parse_date(input: &str) -> Result<NaiveDate, Error<&str>> {
+    parse_date_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`parse_date`] function which uses the reference date of the
+/// specified [`ParserContext`].
+///
+/// The [`TrailingInput::Keep`] mode of the context is replaced with the
+/// [`TrailingInput::RequireEnd`], other modes are kept.
+pub fn parse_date_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> Result<NaiveDate, Error<&str>> + '_ {
+    move |input: &str| parse_whole(ctx, &common_parsers(), input)
+}
+
+/// Same as [`parse_date`], but also recognizes the dates in `English` using the
+/// [`dd_month_y4`](crate::i18n::en::dd_month_y4),
+/// [`month_dd_y4`](crate::i18n::en::month_dd_y4),
+/// [`dd_month_only`](crate::i18n::en::dd_month_only) and
+/// [`bundle_dmy`](crate::i18n::en::bundle_dmy) parsers.
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use chrono::{Days, Local, NaiveDate};
+/// use nom_date_parsers::{error::Error, parse_date_en};
+///
+/// assert_eq!(
+///     parse_date_en("13 July 2024"),
+///     Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+/// );
+/// assert_eq!(
+///     parse_date_en("tomorrow"),
+///     Ok(Local::now().add(Days::new(1)).date_naive())
+/// );
+/// assert_eq!(
+///     parse_date_en("31 February 2024"),
+///     Err(Error::NonExistentDate)
+/// );
+/// ```
+#[cfg(all(feature = "en", feature = "clock"))]
+pub fn parse_date_en(input: &str) -> Result<NaiveDate, Error<&str>> {
+    parse_date_en_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`parse_date_en`] function which uses the reference date of the
+/// specified [`ParserContext`], see [`parse_date_with`].
+#[cfg(feature = "en")]
+pub fn parse_date_en_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> Result<NaiveDate, Error<&str>> + '_ {
+    move |input: &str| {
+        use crate::i18n::en::*;
+
+        let mut parsers = common_parsers();
+        parsers.extend([
+            (|_, input| dd_month_y4(input)) as ContextFn,
+            |_, input| month_dd_y4(input),
+            |ctx, input| dd_month_only_with(ctx)(input),
+            |ctx, input| bundle_dmy_with(ctx)(input),
+        ]);
+
+        parse_whole(ctx, &parsers, input)
+    }
+}
+
+/// Same as [`parse_date`], but also recognizes the dates in `Russian` using the
+/// [`dd_month_y4`](crate::i18n::ru::dd_month_y4),
+/// [`dd_month_only`](crate::i18n::ru::dd_month_only) and
+/// [`bundle`](crate::i18n::ru::bundle) parsers.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{error::Error, parse_date_ru};
+///
+/// assert_eq!(
+///     parse_date_ru("13 июля 2024 года"),
+///     Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+/// );
+/// assert_eq!(
+///     parse_date_ru("31 февраля 2024"),
+///     Err(Error::NonExistentDate)
+/// );
+/// ```
+#[cfg(all(feature = "ru", feature = "clock"))]
+pub fn parse_date_ru(input: &str) -> Result<NaiveDate, Error<&str>> {
+    parse_date_ru_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`parse_date_ru`] function which uses the reference date of the
+/// specified [`ParserContext`], see [`parse_date_with`].
+#[cfg(feature = "ru")]
+pub fn parse_date_ru_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> Result<NaiveDate, Error<&str>> + '_ {
+    move |input: &str| {
+        use crate::i18n::ru::*;
+
+        let mut parsers = common_parsers();
+        parsers.extend([
+            (|_, input| dd_month_y4(input)) as ContextFn,
+            |ctx, input| dd_month_only_with(ctx)(input),
+            |ctx, input| bundle_with(ctx)(input),
+        ]);
+
+        parse_whole(ctx, &parsers, input)
+    }
+}
+
+/// The date which is parsed from the human-readable string by the
+/// [`FromStr`](core::str::FromStr) implementation, so the crate can be used
+/// without touching `nom`, e.g. as the argument type of `clap`
+/// (`#[arg(value_parser)] date: HumanDate`) or the value of the configuration
+/// crates.
+///
+/// The string is recognized by [`parse_date_en`] if the `en` feature is
+/// enabled, otherwise by [`parse_date`], so the whole string (except the
+/// surrounding whitespaces) must be the date.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::HumanDate;
+///
+/// let date: HumanDate = "13-07-2024".parse()?;
+/// assert_eq!(
+///     date,
+///     HumanDate(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+/// );
+///
+/// let date = HumanDate::try_from(" 2024/07/13 ")?;
+/// assert_eq!(
+///     NaiveDate::from(date),
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// assert_eq!(date.to_string(), "2024-07-13");
+///
+/// assert!("31-02-2024".parse::<HumanDate>().is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanDate(pub NaiveDate);
+
+impl HumanDate {
+    /// Recognizes the date like the [`FromStr`](core::str::FromStr)
+    /// implementation, but uses the reference date of the specified
+    /// [`ParserContext`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::{context::ParserContext, HumanDate};
+    ///
+    /// let ctx = ParserContext::fixed(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+    ///
+    /// assert_eq!(
+    ///     HumanDate::parse_with(&ctx, "13/07"),
+    ///     Ok(HumanDate(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))
+    /// );
+    /// ```
+    pub fn parse_with<'a>(ctx: &ParserContext, input: &'a str) -> Result<Self, Error<&'a str>> {
+        #[cfg(feature = "en")]
+        let date = parse_date_en_with(ctx)(input);
+        #[cfg(not(feature = "en"))]
+        let date = parse_date_with(ctx)(input);
+
+        date.map(HumanDate)
+    }
+}
+
+#[cfg(feature = "clock")]
+impl core::str::FromStr for HumanDate {
+    type Err = Error<String>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HumanDate::parse_with(&ParserContext::default(), s).map_err(|e| e.to_owned())
+    }
+}
+
+#[cfg(feature = "clock")]
+impl TryFrom<&str> for HumanDate {
+    type Error = Error<String>;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<NaiveDate> for HumanDate {
+    fn from(date: NaiveDate) -> Self {
+        HumanDate(date)
+    }
+}
+
+impl From<HumanDate> for NaiveDate {
+    fn from(date: HumanDate) -> Self {
+        date.0
+    }
+}
+
+impl Deref for HumanDate {
+    type Target = NaiveDate;
+
+    fn deref(&self) -> &NaiveDate {
+        &self.0
+    }
+}
+
+impl fmt::Display for HumanDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(all(test, feature = "numeric", feature = "quick"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ctx() -> ParserContext {
+        ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+    }
+
+    #[rstest]
+    #[case("13-07-2024", Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case("  2024/07/13\n", Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case("13.07.24", Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case("13/07", Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case("13", Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case("+ 3", Ok(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap()))]
+    #[case("31-02-2024", Err(Error::NonExistentDate))]
+    #[case("13/13/2024", Err(Error::MonthOutOfRange { value: 13, input: "13/2024" }))]
+    #[case("13 later", Err(Error::Nom("13 later", ErrorKind::Alt)))]
+    #[case("", Err(Error::Nom("", ErrorKind::Alt)))]
+    fn test_parse_date_with(#[case] input: &str, #[case] expected: Result<NaiveDate, Error<&str>>) {
+        assert_eq!(parse_date_with(&ctx())(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024 14:30", TrailingInput::IgnoreTime, Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case(
+        "13/07/2024 14:30",
+        TrailingInput::RequireEnd,
+        Err(Error::Nom("13/07/2024 14:30", ErrorKind::Alt))
+    )]
+    fn test_parse_date_with_trailing_input(
+        #[case] input: &str,
+        #[case] trailing_input: TrailingInput,
+        #[case] expected: Result<NaiveDate, Error<&str>>,
+    ) {
+        let ctx = ctx().with_trailing_input(trailing_input);
+
+        assert_eq!(parse_date_with(&ctx)(input), expected);
+    }
+
+    #[cfg(feature = "en")]
+    #[rstest]
+    #[case("13 July 2024", Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case("Fri 13/09", Ok(NaiveDate::from_ymd_opt(2024, 9, 13).unwrap()))]
+    #[case("in 3 days", Ok(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap()))]
+    #[case("31 feb 2024", Err(Error::NonExistentDate))]
+    #[case(
+        "tomorrow morning",
+        Err(Error::Nom("tomorrow morning", ErrorKind::Alt))
+    )]
+    fn test_parse_date_en_with(
+        #[case] input: &str,
+        #[case] expected: Result<NaiveDate, Error<&str>>,
+    ) {
+        assert_eq!(parse_date_en_with(&ctx())(input), expected);
+    }
+
+    #[cfg(feature = "ru")]
+    #[rstest]
+    #[case("13 июля 2024 года", Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case("завтра", Ok(NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()))]
+    #[case("13.07.24г", Ok(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case("31.02.2024", Err(Error::NonExistentDate))]
+    fn test_parse_date_ru_with(
+        #[case] input: &str,
+        #[case] expected: Result<NaiveDate, Error<&str>>,
+    ) {
+        assert_eq!(parse_date_ru_with(&ctx())(input), expected);
+    }
+
+    #[rstest]
+    #[case("13-07-2024", Ok(HumanDate(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(" + 3 ", Ok(HumanDate(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("31-02-2024", Err(Error::NonExistentDate))]
+    #[case("13 later", Err(Error::Nom("13 later", ErrorKind::Alt)))]
+    fn test_human_date_parse_with(
+        #[case] input: &str,
+        #[case] expected: Result<HumanDate, Error<&str>>,
+    ) {
+        assert_eq!(HumanDate::parse_with(&ctx(), input), expected);
+    }
+
+    #[cfg(feature = "en")]
+    #[rstest]
+    #[case("13 July 2024", Ok(HumanDate(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("in 3 days", Ok(HumanDate(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    fn test_human_date_parse_with_en(
+        #[case] input: &str,
+        #[case] expected: Result<HumanDate, Error<&str>>,
+    ) {
+        assert_eq!(HumanDate::parse_with(&ctx(), input), expected);
+    }
+
+    #[cfg(feature = "clock")]
+    #[rstest]
+    #[case("2024-07-13", Ok(HumanDate(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("31-02-2024", Err(Error::NonExistentDate))]
+    #[case("later", Err(Error::Nom(String::from("later"), ErrorKind::Alt)))]
+    fn test_human_date_from_str(
+        #[case] input: &str,
+        #[case] expected: Result<HumanDate, Error<String>>,
+    ) {
+        assert_eq!(input.parse::<HumanDate>(), expected);
+        assert_eq!(HumanDate::try_from(input), expected);
+    }
+}
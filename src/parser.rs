@@ -0,0 +1,735 @@
+use alloc::{string::String, sync::Arc, vec::Vec};
+use core::{fmt, ops::Range};
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
+use nom::{
+    error::{ErrorKind, ParseError},
+    sequence::terminated,
+};
+
+use crate::{
+    combinators::{tag_no_case, with_span, word_boundary},
+    context::ParserContext,
+    error::Error,
+    types::IResult,
+};
+
+/// A date parser which can be stored and reused across calls.
+///
+/// The trait is implemented for every function or closure which recognizes
+/// the date (e.g. [`dd_mm_y4`](crate::numeric::dd_mm_y4) or the bundle
+/// parsers) and for the [`ContextParser`], which owns the [`ParserContext`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{numeric::dd_mm_y4, parser::DateParser};
+///
+/// fn parse_all(parser: &impl DateParser, inputs: &[&str]) -> Vec<NaiveDate> {
+///     inputs
+///         .iter()
+///         .filter_map(|input| parser.parse(input).ok())
+///         .map(|(_, date)| date)
+///         .collect()
+/// }
+///
+/// assert_eq!(
+///     parse_all(&dd_mm_y4, &["13/07/2024", "42"]),
+///     vec![NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()]
+/// );
+/// ```
+pub trait DateParser<O = NaiveDate> {
+    /// Recognizes the date in the specified input
+    fn parse<'a>(&self, input: &'a str) -> IResult<&'a str, O>;
+
+    /// Same as [`DateParser::parse`], but also returns the byte range of the
+    /// recognized date in the input, see [`with_span`]
+    fn parse_with_span<'a>(&self, input: &'a str) -> IResult<&'a str, (Range<usize>, O)> {
+        with_span(|input| self.parse(input))(input)
+    }
+}
+
+impl<O, F> DateParser<O> for F
+where
+    F: Fn(&str) -> IResult<&str, O>,
+{
+    fn parse<'a>(&self, input: &'a str) -> IResult<&'a str, O> {
+        self(input)
+    }
+}
+
+/// The function which recognizes the date using the specified
+/// [`ParserContext`], e.g. `|ctx, input| en::bundle_dmy_with(ctx)(input)`.
+pub type ContextFn<O = NaiveDate> = for<'a> fn(&ParserContext, &'a str) -> IResult<&'a str, O>;
+
+/// The reusable parser which owns the [`ParserContext`], so it can be created
+/// once (e.g. at the start of the application) and stored in the application
+/// state instead of creating the parser bound to the context on every call.
+///
+/// The parser is usually created with the [`ParserContext::build`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, i18n::en, parser::DateParser};
+///
+/// let parser = ParserContext::new()
+///     .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+///     .build(|ctx, input| en::bundle_dmy_with(ctx)(input));
+///
+/// assert_eq!(
+///     parser.parse("tomorrow")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()
+/// );
+/// assert_eq!(
+///     parser.parse("13")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+#[derive(Clone)]
+pub struct ContextParser<O = NaiveDate> {
+    ctx: ParserContext,
+    parser: ContextFn<O>,
+}
+
+impl<O> ContextParser<O> {
+    /// Creates the parser which uses the specified [`ParserContext`]
+    pub fn new(ctx: ParserContext, parser: ContextFn<O>) -> Self {
+        Self { ctx, parser }
+    }
+
+    /// Returns the [`ParserContext`] of the parser
+    pub fn context(&self) -> &ParserContext {
+        &self.ctx
+    }
+}
+
+impl<O> DateParser<O> for ContextParser<O> {
+    fn parse<'a>(&self, input: &'a str) -> IResult<&'a str, O> {
+        (self.parser)(&self.ctx, input)
+    }
+}
+
+impl<O> fmt::Debug for ContextParser<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextParser")
+            .field("ctx", &self.ctx)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Describes when the [`FallbackChain`] stops running the parsers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FallbackStop {
+    /// Returns the date recognized by the first successful parser
+    #[default]
+    FirstMatch,
+    /// Runs the remaining parsers of the tier where the first date was
+    /// recognized and returns the date which consumed the most input
+    FirstTier,
+    /// Runs the parsers of all the tiers and returns the date which consumed
+    /// the most input, the earlier parser wins the ties
+    Exhaustive,
+}
+
+/// The parser which owns the [`ParserContext`] and runs the ordered tiers of
+/// parsers (e.g. strict formats, then lenient formats, then keywords) until
+/// the date is recognized, see [`FallbackStop`]. The chain is usually created
+/// with the [`ParserContext::build_chain`].
+///
+/// The work can be bounded by the maximum number of the parsers to run and by
+/// the time budget, both are checked before running every parser. If nothing
+/// is recognized, returns the most meaningful error of the parsers which were
+/// run (see [`Error`]) or [`Error::Nom`] with the [`ErrorKind::Alt`] if none
+/// was run.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     i18n::en,
+///     numeric::{dd_mm_y4, dd_only_with},
+///     parser::{ContextFn, DateParser, FallbackStop},
+/// };
+///
+/// let parser = ParserContext::new()
+///     .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+///     .build_chain()
+///     .tier([(|_, input| dd_mm_y4(input)) as ContextFn])
+///     .tier([
+///         (|ctx, input| dd_only_with(ctx)(input)) as ContextFn,
+///         |ctx, input| en::dd_month_only_with(ctx)(input),
+///     ])
+///     .tier([(|ctx, input| en::bundle_dmy_with(ctx)(input)) as ContextFn])
+///     .with_stop(FallbackStop::FirstTier)
+///     .with_max_attempts(4);
+///
+/// assert_eq!(
+///     parser.parse("13 August")?.1,
+///     NaiveDate::from_ymd_opt(2024, 8, 13).unwrap()
+/// );
+/// assert_eq!(
+///     parser.parse("tomorrow")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+#[derive(Clone)]
+pub struct FallbackChain<O = NaiveDate> {
+    ctx: ParserContext,
+    tiers: Vec<Vec<ContextFn<O>>>,
+    stop: FallbackStop,
+    max_attempts: Option<usize>,
+    #[cfg(feature = "std")]
+    time_budget: Option<Duration>,
+}
+
+impl<O> FallbackChain<O> {
+    /// Creates the chain without tiers which uses the specified
+    /// [`ParserContext`]
+    pub fn new(ctx: ParserContext) -> Self {
+        Self {
+            ctx,
+            tiers: Vec::new(),
+            stop: FallbackStop::default(),
+            max_attempts: None,
+            #[cfg(feature = "std")]
+            time_budget: None,
+        }
+    }
+
+    /// Appends the tier of the specified parsers, which run in the specified
+    /// order after the parsers of the previous tiers
+    pub fn tier(mut self, parsers: impl IntoIterator<Item = ContextFn<O>>) -> Self {
+        self.tiers.push(parsers.into_iter().collect());
+        self
+    }
+
+    /// Specifies when the chain stops running the parsers, by default the
+    /// first recognized date is returned
+    pub fn with_stop(mut self, stop: FallbackStop) -> Self {
+        self.stop = stop;
+        self
+    }
+
+    /// Limits the number of the parsers run for a single input, by default the
+    /// number is unlimited
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Stops running the parsers for a single input when the specified time
+    /// budget is exhausted, by default the time is unlimited
+    #[cfg(feature = "std")]
+    pub fn with_time_budget(mut self, time_budget: Duration) -> Self {
+        self.time_budget = Some(time_budget);
+        self
+    }
+
+    /// Returns the [`ParserContext`] of the chain
+    pub fn context(&self) -> &ParserContext {
+        &self.ctx
+    }
+}
+
+impl<O> DateParser<O> for FallbackChain<O> {
+    fn parse<'a>(&self, input: &'a str) -> IResult<&'a str, O> {
+        #[cfg(feature = "std")]
+        let started = Instant::now();
+        let mut attempts = 0;
+        let mut best: Option<(&'a str, O)> = None;
+        let mut error: Option<Error<&'a str>> = None;
+
+        'tiers: for tier in &self.tiers {
+            for parser in tier {
+                if self.max_attempts.is_some_and(|max| attempts >= max) {
+                    break 'tiers;
+                }
+                #[cfg(feature = "std")]
+                if self
+                    .time_budget
+                    .is_some_and(|budget| started.elapsed() >= budget)
+                {
+                    break 'tiers;
+                }
+                attempts += 1;
+
+                match parser(&self.ctx, input) {
+                    Ok(parsed) if self.stop == FallbackStop::FirstMatch => return Ok(parsed),
+                    Ok(parsed) => {
+                        if best
+                            .as_ref()
+                            .map_or(true, |(rest, _)| parsed.0.len() < rest.len())
+                        {
+                            best = Some(parsed);
+                        }
+                    }
+                    Err(nom::Err::Error(e)) => {
+                        error = Some(match error {
+                            Some(previous) => previous.or(e),
+                            None => e,
+                        });
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+
+            if best.is_some() && self.stop == FallbackStop::FirstTier {
+                break;
+            }
+        }
+
+        best.ok_or_else(|| nom::Err::Error(error.unwrap_or(Error::Nom(input, ErrorKind::Alt))))
+    }
+}
+
+impl<O> fmt::Debug for FallbackChain<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("FallbackChain");
+        debug
+            .field("ctx", &self.ctx)
+            .field(
+                "tiers",
+                &self.tiers.iter().map(Vec::len).collect::<Vec<_>>(),
+            )
+            .field("stop", &self.stop)
+            .field("max_attempts", &self.max_attempts);
+        #[cfg(feature = "std")]
+        debug.field("time_budget", &self.time_budget);
+        debug.finish()
+    }
+}
+
+/// The builder of the bundle parser which owns the [`ParserContext`] and the
+/// named component parsers (e.g. `dd_only` or `tomorrow`), so the components
+/// can be excluded or reordered without recreating the `alt` chain by hand.
+/// The builder is usually created with the [`ParserContext::bundle_builder`]
+/// or prefilled with the components of the existing bundle, e.g.
+/// [`en::bundle_dmy_builder`](crate::i18n::en::bundle_dmy_builder).
+///
+/// The components are run in the specified order and the first recognized
+/// date is returned. If nothing is recognized, returns the most meaningful
+/// error of the components (see [`Error`]) or [`Error::Nom`] with the
+/// [`ErrorKind::Alt`] if there are no components. The custom keywords
+/// registered with the [`BundleBuilder::with_keyword`] are tried before the
+/// components.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     numeric::{dd_mm_y4, dd_only_with},
+///     quick::forward_from_now_with,
+/// };
+///
+/// let parser = ParserContext::new()
+///     .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+///     .bundle_builder()
+///     .with("dd_only", |ctx, input| dd_only_with(ctx)(input))
+///     .with("dd_mm_y4", |_, input| dd_mm_y4(input))
+///     .with("forward_from_now", |ctx, input| {
+///         forward_from_now_with(ctx)(input)
+///     })
+///     .with_order(["forward_from_now", "dd_mm_y4"])
+///     .build();
+///
+/// assert_eq!(
+///     parser("+10")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 26).unwrap()
+/// );
+/// assert_eq!(
+///     parser("13/08/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 8, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+#[derive(Clone)]
+pub struct BundleBuilder<O = NaiveDate> {
+    ctx: ParserContext,
+    components: Vec<(&'static str, ContextFn<O>)>,
+    keywords: Vec<(String, KeywordFn<O>)>,
+}
+
+/// The function which resolves the custom keyword of the [`BundleBuilder`] to
+/// the date using the reference date, e.g. `|today| today.succ_opt()`.
+pub type KeywordFn<O = NaiveDate> = Arc<dyn Fn(NaiveDate) -> Option<O> + Send + Sync>;
+
+impl<O> BundleBuilder<O> {
+    /// Creates the builder without components which uses the specified
+    /// [`ParserContext`]
+    pub fn new(ctx: ParserContext) -> Self {
+        Self {
+            ctx,
+            components: Vec::new(),
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Appends the named component parser, which runs after the previous
+    /// ones. If the component with the same name already exists, its parser is
+    /// replaced in place
+    pub fn with(mut self, name: &'static str, parser: ContextFn<O>) -> Self {
+        match self.components.iter_mut().find(|(n, _)| *n == name) {
+            Some(component) => component.1 = parser,
+            None => self.components.push((name, parser)),
+        }
+        self
+    }
+
+    /// Registers the custom keyword (e.g. `payday` or `end of week`), which is
+    /// resolved to the date by the specified function of the reference date.
+    ///
+    /// The keywords are matched `case insensitively` (see [`tag_no_case`]) and
+    /// must be followed by the [`word_boundary`]. The longest matching keyword
+    /// wins and the keywords take precedence over the components, so the
+    /// keyword may extend the built-in word (e.g. `tomorrow morning`). If the
+    /// resolver of the keyword returns [`None`], returns
+    /// [`Error::DateOutOfRange`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    /// use nom_date_parsers::{context::ParserContext, i18n::en};
+    ///
+    /// let parser = ParserContext::new()
+    ///     .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+    ///     .bundle_builder()
+    ///     .with("en", |ctx, input| en::bundle_dmy_with(ctx)(input))
+    ///     .with_keyword("payday", |today| today.with_day(25))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     parser("payday")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 7, 25).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     parser("tomorrow")?.1,
+    ///     NaiveDate::from_ymd_opt(2024, 7, 17).unwrap()
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_keyword(
+        mut self,
+        keyword: impl Into<String>,
+        resolver: impl Fn(NaiveDate) -> Option<O> + Send + Sync + 'static,
+    ) -> Self {
+        self.keywords.push((keyword.into(), Arc::new(resolver)));
+        self
+    }
+
+    /// Excludes the component with the specified name, unknown names are
+    /// ignored
+    pub fn without(mut self, name: &str) -> Self {
+        self.components.retain(|(n, _)| *n != name);
+        self
+    }
+
+    /// Keeps only the components with the specified names in the specified
+    /// order, unknown names are ignored
+    pub fn only<'n>(mut self, names: impl IntoIterator<Item = &'n str>) -> Self {
+        self.components = self.take(names);
+        self
+    }
+
+    /// Moves the components with the specified names to the front in the
+    /// specified order, the rest of the components keep their order after
+    /// them. Unknown names are ignored
+    pub fn with_order<'n>(mut self, names: impl IntoIterator<Item = &'n str>) -> Self {
+        let mut components = self.take(names);
+        components.append(&mut self.components);
+        self.components = components;
+        self
+    }
+
+    /// Removes the components with the specified names and returns them in
+    /// the specified order.
+    fn take<'n>(
+        &mut self,
+        names: impl IntoIterator<Item = &'n str>,
+    ) -> Vec<(&'static str, ContextFn<O>)> {
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let i = self.components.iter().position(|(n, _)| *n == name)?;
+                Some(self.components.remove(i))
+            })
+            .collect()
+    }
+
+    /// Returns the names of the components in the order they are run
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.components.iter().map(|(name, _)| *name)
+    }
+
+    /// Returns the [`ParserContext`] of the builder
+    pub fn context(&self) -> &ParserContext {
+        &self.ctx
+    }
+
+    /// Creates the parser which runs the components in order, the trailing
+    /// input is handled by the [`ParserContext::trailing`]
+    pub fn build(self) -> impl Fn(&str) -> IResult<&str, O> {
+        let Self {
+            ctx,
+            components,
+            keywords,
+        } = self;
+
+        move |input: &str| {
+            if !keywords.is_empty() {
+                match ctx.trailing(|input| keyword(&ctx, &keywords, input))(input) {
+                    Err(nom::Err::Error(Error::Nom(..))) => {}
+                    result => return result,
+                }
+            }
+
+            ctx.trailing(|input| {
+                let mut error: Option<Error<&str>> = None;
+                for (_, parser) in &components {
+                    match parser(&ctx, input) {
+                        Err(nom::Err::Error(e)) => {
+                            error = Some(match error {
+                                Some(previous) => previous.or(e),
+                                None => e,
+                            });
+                        }
+                        result => return result,
+                    }
+                }
+
+                Err(nom::Err::Error(
+                    error.unwrap_or(Error::Nom(input, ErrorKind::Alt)),
+                ))
+            })(input)
+        }
+    }
+}
+
+impl<O> fmt::Debug for BundleBuilder<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BundleBuilder")
+            .field("ctx", &self.ctx)
+            .field("components", &self.names().collect::<Vec<_>>())
+            .field(
+                "keywords",
+                &self
+                    .keywords
+                    .iter()
+                    .map(|(keyword, _)| keyword)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Recognizes the longest of the specified `keywords` and resolves it to the
+/// date using the reference date of the specified [`ParserContext`].
+fn keyword<'a, O>(
+    ctx: &ParserContext,
+    keywords: &[(String, KeywordFn<O>)],
+    input: &'a str,
+) -> IResult<&'a str, O> {
+    let (input, resolver) = keywords
+        .iter()
+        .filter(|(keyword, _)| !keyword.is_empty())
+        .filter_map(|(keyword, resolver)| {
+            terminated(tag_no_case(keyword), word_boundary)(input)
+                .ok()
+                .map(|(rest, _)| (rest, resolver))
+        })
+        .min_by_key(|(rest, _)| rest.len())
+        .ok_or(nom::Err::Error(Error::from_error_kind(
+            input,
+            ErrorKind::Tag,
+        )))?;
+
+    Ok((
+        input,
+        resolver(ctx.today()).ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+    ))
+}
+
+#[cfg(all(test, feature = "numeric"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::numeric::{dd_mm_only_with, dd_mm_y4, dd_only_with, dmy_with, y4_mm_dd};
+
+    use super::*;
+
+    #[rstest]
+    #[case("13/07/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_fn_date_parser(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(DateParser::parse(&dd_mm_y4, input), expected);
+    }
+
+    #[rstest]
+    #[case("13", Ok(("", NaiveDate::from_ymd_opt(2024, 2, 13).unwrap())))]
+    #[case("30", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_context_parser(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let parser = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())
+            .build(|ctx, input| dd_only_with(ctx)(input));
+
+        assert_eq!(parser.parse(input), expected);
+        assert_eq!(
+            parser.context().today(),
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("13", Ok(("", (0..2, NaiveDate::from_ymd_opt(2024, 2, 13).unwrap()))))]
+    #[case(" 13 ", Ok((" ", (1..3, NaiveDate::from_ymd_opt(2024, 2, 13).unwrap()))))]
+    #[case("30", Err(nom::Err::Error(Error::NonExistentDate)))]
+    fn test_parse_with_span(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, (Range<usize>, NaiveDate)>,
+    ) {
+        let parser = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())
+            .build(|ctx, input| ctx.trailing(dd_only_with(ctx))(input));
+
+        assert_eq!(parser.parse_with_span(input), expected);
+    }
+
+    fn chain(stop: FallbackStop) -> FallbackChain {
+        FallbackChain::new(
+            ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()),
+        )
+        .tier([(|_, input| dd_mm_y4(input)) as ContextFn, |_, input| {
+            y4_mm_dd(input)
+        }])
+        .tier([
+            (|ctx, input| dd_only_with(ctx)(input)) as ContextFn,
+            |ctx, input| dd_mm_only_with(ctx)(input),
+        ])
+        .tier([(|ctx, input| dmy_with(ctx)(input)) as ContextFn])
+        .with_stop(stop)
+    }
+
+    #[rstest]
+    #[case(FallbackStop::FirstMatch, "13/07/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(FallbackStop::FirstMatch, "13/08", Ok(("/08", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(FallbackStop::FirstTier, "13/08", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case(FallbackStop::Exhaustive, "13/08", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case(FallbackStop::Exhaustive, "31/02/2024", Ok(("/02/2024", NaiveDate::from_ymd_opt(2024, 7, 31).unwrap())))]
+    #[case(
+        FallbackStop::FirstMatch,
+        "42",
+        Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42" }))
+    )]
+    fn test_fallback_chain(
+        #[case] stop: FallbackStop,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(chain(stop).parse(input), expected);
+    }
+
+    #[rstest]
+    #[case(0, None)]
+    #[case(2, None)]
+    #[case(3, NaiveDate::from_ymd_opt(2024, 7, 13))]
+    fn test_fallback_chain_max_attempts(
+        #[case] max_attempts: usize,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        let parser = chain(FallbackStop::FirstMatch).with_max_attempts(max_attempts);
+
+        assert_eq!(parser.parse("13/08").ok().map(|(_, date)| date), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_fallback_chain_time_budget() {
+        let parser = chain(FallbackStop::FirstMatch).with_time_budget(Duration::ZERO);
+
+        assert_eq!(
+            parser.parse("13/07/2024"),
+            Err(nom::Err::Error(Error::Nom("13/07/2024", ErrorKind::Alt)))
+        );
+    }
+
+    fn builder() -> BundleBuilder {
+        ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .bundle_builder()
+            .with("dd_only", |ctx, input| dd_only_with(ctx)(input))
+            .with("dd_mm_only", |ctx, input| dd_mm_only_with(ctx)(input))
+            .with("dd_mm_y4", |_, input| dd_mm_y4(input))
+    }
+
+    #[rstest]
+    #[case(builder(), &["dd_only", "dd_mm_only", "dd_mm_y4"])]
+    #[case(builder().without("dd_only"), &["dd_mm_only", "dd_mm_y4"])]
+    #[case(builder().without("unknown"), &["dd_only", "dd_mm_only", "dd_mm_y4"])]
+    #[case(builder().only(["dd_mm_y4", "unknown", "dd_only"]), &["dd_mm_y4", "dd_only"])]
+    #[case(builder().with_order(["dd_mm_y4", "dd_mm_only"]), &["dd_mm_y4", "dd_mm_only", "dd_only"])]
+    #[case(builder().with("dd_only", |_, input| y4_mm_dd(input)), &["dd_only", "dd_mm_only", "dd_mm_y4"])]
+    fn test_bundle_builder_names(#[case] builder: BundleBuilder, #[case] expected: &[&str]) {
+        assert_eq!(builder.names().collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case(builder(), "13/08/2024", Ok(("/08/2024", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case(builder().with_order(["dd_mm_y4", "dd_mm_only"]), "13/08/2024", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case(builder().with_order(["dd_mm_y4", "dd_mm_only"]), "13/08", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case(builder().without("dd_only"), "13", Err(nom::Err::Error(Error::Nom("", ErrorKind::Space))))]
+    #[case(builder(), "42", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42" })))]
+    #[case(builder().only([]), "13", Err(nom::Err::Error(Error::Nom("13", ErrorKind::Alt))))]
+    fn test_bundle_builder(
+        #[case] builder: BundleBuilder,
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        assert_eq!(builder.build()(input), expected);
+    }
+
+    #[rstest]
+    #[case("payday", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 25).unwrap())))]
+    #[case("PayDay", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 25).unwrap())))]
+    #[case("eow", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 21).unwrap())))]
+    #[case("завтра утром", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("Завтра Утром, в 9", Ok((", в 9", NaiveDate::from_ymd_opt(2024, 7, 17).unwrap())))]
+    #[case("13/08", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())))]
+    #[case("never", Err(nom::Err::Error(Error::DateOutOfRange)))]
+    fn test_bundle_builder_keywords(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        use chrono::{Datelike, Days};
+
+        let parser = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .bundle_builder()
+            .with("dmy", |ctx, input| dmy_with(ctx)(input))
+            .with_keyword("payday", |today| today.with_day(25))
+            .with_keyword("eow", |today| {
+                today.checked_add_days(Days::new(
+                    6 - u64::from(today.weekday().num_days_from_monday()),
+                ))
+            })
+            .with_keyword("завтра утром", |today| today.succ_opt())
+            .with_keyword("never", |_| None)
+            .build();
+
+        assert_eq!(parser(input), expected);
+    }
+}
@@ -1 +1 @@
-pub use crate::{error::Error, numeric::*};
+pub use crate::{context::ParserContext, error::Error, numeric::*};
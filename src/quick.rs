@@ -1,20 +1,92 @@
-use std::ops::{Add, Sub};
-
-use chrono::{Days, Local, NaiveDate};
+use chrono::NaiveDate;
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{digit1, space0},
-    combinator::map_res,
-    sequence::tuple,
+    combinator::{map, map_res, value},
+    sequence::{pair, preceded, terminated},
+};
+
+use crate::{
+    calendar::{shift, Period},
+    combinators::{tag_no_case, word_boundary},
+    context::ParserContext,
+    error::Error,
+    types::IResult,
 };
 
-use crate::types::IResult;
+/// The unit of the offset, either the calendar [`Period`] or the business day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Period(Period),
+    /// The day which is skipped if it isn't the business day of the
+    /// [`BusinessCalendar`](crate::calendar::BusinessCalendar) of the context
+    BusinessDay,
+}
+
+/// Recognizes the `case insensitive` unit of the offset: `d` (days), `bd`
+/// (business days), `w` (weeks), `m` (months) or `y` (years) followed by the
+/// word boundary.
+fn unit(input: &str) -> IResult<&str, Unit> {
+    terminated(
+        alt((
+            value(Unit::BusinessDay, tag_no_case("bd")),
+            value(Unit::Period(Period::Day), tag_no_case("d")),
+            value(Unit::Period(Period::Week), tag_no_case("w")),
+            value(Unit::Period(Period::Month), tag_no_case("m")),
+            value(Unit::Period(Period::Year), tag_no_case("y")),
+        )),
+        word_boundary,
+    )(input)
+}
+
+/// Recognizes the specified `sign` followed by any number of spaces, the
+/// number and the optional [`unit`] (days by default) preceded by any number
+/// of spaces, and returns the
+/// reference date of the specified [`ParserContext`] shifted by the offset
+/// according to the [`MonthOverflow`](crate::calendar::MonthOverflow) policy
+/// and the [`BusinessCalendar`](crate::calendar::BusinessCalendar) of the
+/// context.
+fn offset<'a>(
+    ctx: &ParserContext,
+    sign: &'static str,
+    input: &'a str,
+) -> IResult<&'a str, NaiveDate> {
+    let (input, (amount, unit)) = preceded(
+        pair(tag(sign), space0),
+        pair(
+            map_res(digit1, |s: &str| s.parse::<u64>()),
+            alt((
+                map(preceded(space0, unit), Some),
+                value(None, word_boundary),
+            )),
+        ),
+    )(input)?;
+    let amount = i64::try_from(amount).map_err(|_| nom::Err::Error(Error::DateOutOfRange))?;
+    let amount = if sign == "-" { -amount } else { amount };
+    let date = match unit.unwrap_or(Unit::Period(Period::Day)) {
+        Unit::Period(period) => shift(ctx.today(), amount, period, ctx.month_overflow()),
+        Unit::BusinessDay => ctx
+            .business_calendar()
+            .add_business_days(ctx.today(), amount),
+    };
+
+    Ok((input, date.ok_or(nom::Err::Error(Error::DateOutOfRange))?))
+}
 
 /// Recognizes the `+ <u64>` pattern, where the `<u64>` is an unsigned 64-bit
 /// integer and returns the `NaiveDate` which is obtained by adding
 /// specified number of days to today.
 ///
+/// The number may be followed by the `case insensitive` unit: `d` (days), `bd`
+/// (business days, skipping the weekends and the holidays of the
+/// [`BusinessCalendar`](crate::calendar::BusinessCalendar) of the context),
+/// `w` (weeks), `m` (months) or `y` (years), e.g. `+2w`, `+5bd` or `+ 1m`.
+/// The unit may be separated from the number by spaces (e.g. `+5 bd`). The
+/// number without the unit is the number of days and must be followed by the
+/// word boundary (e.g. `+5bdx` isn't recognized), and the zero offset (e.g.
+/// `+0` or `+0m`) returns today.
+///
 /// # Examples
 /// ```
 /// use std::ops::Add;
@@ -28,52 +100,142 @@ use crate::types::IResult;
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "clock")]
 pub fn forward_from_now(input: &str) -> IResult<&str, NaiveDate> {
-    let (input, (_, _, add_days)) = tuple((
-        tag("+"),
-        space0,
-        map_res(digit1, |s: &str| s.parse::<u64>()),
-    ))(input)?;
+    forward_from_now_with(&ParserContext::default())(input)
+}
 
-    Ok((input, Local::now().add(Days::new(add_days)).date_naive()))
+/// Creates the [`forward_from_now`] parser which adds the offset to the
+/// reference date of the specified [`ParserContext`].
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{context::ParserContext, quick::forward_from_now_with};
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     forward_from_now_with(&ctx)("+ 16")?.1,
+///     NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn forward_from_now_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| offset(ctx, "+", input)
+}
+
+/// Creates the [`forward_from_now`] parser which adds the offset to the
+/// specified `base` date instead of today (e.g. the date of the message being
+/// processed). The months and years are clamped to the last day of the month
+/// (see [`MonthOverflow::Clamp`](crate::calendar::MonthOverflow::Clamp)).
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::quick::forward_from;
+///
+/// let base = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+///
+/// assert_eq!(
+///     forward_from(base)("+1m")?.1,
+///     NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn forward_from(base: NaiveDate) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    let ctx = ParserContext::fixed(base);
+
+    move |input: &str| offset(&ctx, "+", input)
 }
 
 /// Recognizes the `- <u64>` pattern, where the `<u64>` is an unsigned 64-bit
 /// integer, and returns the `NaiveDate` which is obtained by
 /// subtraction specified number of days from today.
 ///
+/// The number may be followed by the unit, see [`forward_from_now`].
+///
 /// # Examples
 /// ```
 /// use std::ops::Sub;
 ///
-/// use chrono::{Local, Days};
+/// use chrono::{Days, Local};
 /// use nom_date_parsers::quick::backward_from_now;
 ///
-/// assert_eq!(backward_from_now("- 42")?.1, Local::now().sub(Days::new(42)).date_naive());
+/// assert_eq!(
+///     backward_from_now("- 42")?.1,
+///     Local::now().sub(Days::new(42)).date_naive()
+/// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "clock")]
 pub fn backward_from_now(input: &str) -> IResult<&str, NaiveDate> {
-    let (input, (_, _, sub_days)) = tuple((
-        tag("-"),
-        space0,
-        map_res(digit1, |s: &str| s.parse::<u64>()),
-    ))(input)?;
+    backward_from_now_with(&ParserContext::default())(input)
+}
 
-    Ok((input, Local::now().sub(Days::new(sub_days)).date_naive()))
+/// Creates the [`backward_from_now`] parser which subtracts the offset from the
+/// reference date of the specified [`ParserContext`].
+pub fn backward_from_now_with(
+    ctx: &ParserContext,
+) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| offset(ctx, "-", input)
+}
+
+/// Creates the [`backward_from_now`] parser which subtracts the offset from the
+/// specified `base` date instead of today, see [`forward_from`].
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::quick::backward_from;
+///
+/// let base = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+///
+/// assert_eq!(
+///     backward_from(base)("-1d")?.1,
+///     NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn backward_from(base: NaiveDate) -> impl Fn(&str) -> IResult<&str, NaiveDate> {
+    let ctx = ParserContext::fixed(base);
+
+    move |input: &str| offset(&ctx, "-", input)
 }
 
 /// Parser that uses the [`backward_from_now`] and [`forward_from_now`]
 /// parsers to recognize the following patterns: `- <nod>` and `+ <nod>` (`nod`
-/// - number of days)
+/// - number of days), the number may be followed by the unit (e.g. `+2w` or
+///   `+5bd`)
+#[cfg(feature = "clock")]
 pub fn bundle(input: &str) -> IResult<&str, NaiveDate> {
-    alt((forward_from_now, backward_from_now))(input)
+    bundle_with(&ParserContext::default())(input)
+}
+
+/// Creates the [`bundle`] parser which uses the reference date of the specified
+/// [`ParserContext`].
+pub fn bundle_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+    move |input: &str| {
+        ctx.trailing(alt((
+            forward_from_now_with(ctx),
+            backward_from_now_with(ctx),
+        )))(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::Local;
+    use std::ops::{Add, Sub};
+
+    use chrono::{Days, Local};
+    use nom::error::ErrorKind;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::calendar::{BusinessCalendar, MonthOverflow};
+
     use super::*;
 
     #[rstest]
@@ -103,4 +265,163 @@ mod tests {
     fn test_bundle(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
         assert_eq!(bundle(input), expected);
     }
+
+    #[rstest]
+    #[case("+ 16", Ok(("", NaiveDate::from_ymd_opt(2024, 8, 1).unwrap())))]
+    #[case("-16", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 30).unwrap())))]
+    #[case("+366", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 17).unwrap())))]
+    #[case("+3d", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("+ 2W", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap())))]
+    #[case("-1m", Ok(("", NaiveDate::from_ymd_opt(2024, 6, 16).unwrap())))]
+    #[case("+1y", Ok(("", NaiveDate::from_ymd_opt(2025, 7, 16).unwrap())))]
+    #[case("+10d", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 26).unwrap())))]
+    #[case("-3M", Ok(("", NaiveDate::from_ymd_opt(2024, 4, 16).unwrap())))]
+    #[case("-2w", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 2).unwrap())))]
+    #[case("+0", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("-0", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("-0y", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("+ 0m", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("+3 days", Ok((" days", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("+3days", Err(nom::Err::Error(Error::Nom("+3days", ErrorKind::Tag))))]
+    #[case("+5bd", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("+ 4BD", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 22).unwrap())))]
+    #[case("-2bd", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 12).unwrap())))]
+    #[case("+0bd", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())))]
+    #[case("+5 bd", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 23).unwrap())))]
+    #[case("+ 2 w", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap())))]
+    #[case("+5bdx", Err(nom::Err::Error(Error::Nom("+5bdx", ErrorKind::Tag))))]
+    fn test_bundle_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(bundle_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case((2024, 1, 31), "+1m", MonthOverflow::Clamp, (2024, 2, 29))]
+    #[case((2024, 1, 31), "+1m", MonthOverflow::Roll, (2024, 3, 2))]
+    #[case((2024, 2, 29), "-1y", MonthOverflow::Clamp, (2023, 2, 28))]
+    #[case((2024, 2, 29), "-1y", MonthOverflow::Roll, (2023, 3, 1))]
+    #[case((2024, 1, 31), "+0m", MonthOverflow::Roll, (2024, 1, 31))]
+    #[case((2024, 5, 31), "-3m", MonthOverflow::Clamp, (2024, 2, 29))]
+    fn test_bundle_with_month_overflow(
+        #[case] today: (i32, u32, u32),
+        #[case] input: &str,
+        #[case] month_overflow: MonthOverflow,
+        #[case] expected: (i32, u32, u32),
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap())
+            .with_month_overflow(month_overflow);
+
+        assert_eq!(
+            bundle_with(&ctx)(input),
+            Ok((
+                "",
+                NaiveDate::from_ymd_opt(expected.0, expected.1, expected.2).unwrap()
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case("+1bd", (2024, 7, 23))]
+    #[case("+3bd", (2024, 7, 25))]
+    #[case("-1bd", (2024, 7, 18))]
+    #[case("+1d", (2024, 7, 20))]
+    fn test_bundle_with_business_calendar(#[case] input: &str, #[case] expected: (i32, u32, u32)) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())
+            .with_business_calendar(BusinessCalendar::new().with_holidays([
+                NaiveDate::from_ymd_opt(2024, 7, 19).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 7, 22).unwrap(),
+            ]));
+
+        assert_eq!(
+            bundle_with(&ctx)(input),
+            Ok((
+                "",
+                NaiveDate::from_ymd_opt(expected.0, expected.1, expected.2).unwrap()
+            ))
+        );
+    }
+
+    #[cfg(all(feature = "en", feature = "holidays"))]
+    #[rstest]
+    #[case("+99999999bd", Err(nom::Err::Error(Error::DateOutOfRange)))]
+    #[case("-99999999bd", Err(nom::Err::Error(Error::DateOutOfRange)))]
+    #[case("+100000bd", Ok(("", NaiveDate::from_ymd_opt(2434, 3, 6).unwrap())))]
+    fn test_bundle_with_huge_business_days(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, NaiveDate>,
+    ) {
+        let ctx = ParserContext::new()
+            .with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+            .with_business_calendar(
+                BusinessCalendar::new().with_holiday_table(&crate::i18n::en::holiday::table()),
+            );
+
+        assert_eq!(bundle_with(&ctx)(input), expected);
+    }
+
+    #[rstest]
+    #[case("+ 16", Ok(("", NaiveDate::from_ymd_opt(2023, 3, 17).unwrap())))]
+    #[case("+1m", Ok(("", NaiveDate::from_ymd_opt(2023, 4, 1).unwrap())))]
+    #[case("+0", Ok(("", NaiveDate::from_ymd_opt(2023, 3, 1).unwrap())))]
+    #[case(
+        "-1",
+        Err(nom::Err::Error(Error::Nom("-1", nom::error::ErrorKind::Tag)))
+    )]
+    fn test_forward_from(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(
+            forward_from(NaiveDate::from_ymd_opt(2023, 3, 1).unwrap())(input),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("-1", Ok(("", NaiveDate::from_ymd_opt(2023, 2, 28).unwrap())))]
+    #[case("- 1y", Ok(("", NaiveDate::from_ymd_opt(2022, 3, 1).unwrap())))]
+    #[case("-2w", Ok(("", NaiveDate::from_ymd_opt(2023, 2, 15).unwrap())))]
+    fn test_backward_from(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(
+            backward_from(NaiveDate::from_ymd_opt(2023, 3, 1).unwrap())(input),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(NaiveDate::MAX, "+1")]
+    #[case(NaiveDate::MAX, "+1w")]
+    #[case(NaiveDate::MAX, "+1bd")]
+    #[case(NaiveDate::MIN, "-1bd")]
+    #[case(NaiveDate::MAX, "+1m")]
+    #[case(NaiveDate::MAX, "+1y")]
+    #[case(NaiveDate::MIN, "-1")]
+    #[case(NaiveDate::MIN, "-1m")]
+    #[case(NaiveDate::MIN, "-1y")]
+    #[case(NaiveDate::MIN, "+999999999999")]
+    #[case(NaiveDate::MAX, "-999999999999w")]
+    #[case(NaiveDate::MAX, "+9223372036854775807y")]
+    #[case(NaiveDate::MIN, "-9223372036854775808y")]
+    fn test_date_out_of_range_at_bounds(#[case] today: NaiveDate, #[case] input: &str) {
+        let ctx = ParserContext::new().with_today(today);
+
+        assert_eq!(
+            bundle_with(&ctx)(input),
+            Err(nom::Err::Error(Error::DateOutOfRange))
+        );
+    }
+
+    #[test]
+    fn test_date_out_of_range() {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(
+            forward_from_now_with(&ctx)("+99999999999"),
+            Err(nom::Err::Error(Error::DateOutOfRange))
+        );
+        assert_eq!(
+            backward_from_now_with(&ctx)("-18446744073709551615"),
+            Err(nom::Err::Error(Error::DateOutOfRange))
+        );
+    }
 }
@@ -0,0 +1,421 @@
+use chrono::{Datelike, Month, NaiveDate};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space0,
+    combinator::{opt, value},
+    sequence::{delimited, preceded},
+    Parser,
+};
+
+use crate::{
+    context::ParserContext,
+    error::Error,
+    i18n::day_month_separator,
+    numeric::{dd, numeric_date_parts_separator, opt_year, y4_with},
+    types::IResult,
+};
+
+/// The range of dates, both `start` and `end` dates are included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    /// The first date of the range
+    pub start: NaiveDate,
+    /// The last date of the range
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// Returns `true` if the specified `date` is within the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::range::DateRange;
+    ///
+    /// let range = DateRange {
+    ///     start: NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+    ///     end: NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+    /// };
+    ///
+    /// assert!(range.contains(NaiveDate::from_ymd_opt(2024, 7, 20).unwrap()));
+    /// assert!(!range.contains(NaiveDate::from_ymd_opt(2024, 7, 21).unwrap()));
+    /// ```
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+/// The bound of the [`OpenDateRange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeBound {
+    /// The range is not limited on this side
+    Unbounded,
+    /// The range is limited by the date, the date is included
+    Date(NaiveDate),
+}
+
+/// The range of dates with the possibly implicit (unbounded) `start` or `end`,
+/// e.g. `from monday` or `until the end of the month`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpenDateRange {
+    /// The first date of the range
+    pub start: RangeBound,
+    /// The last date of the range
+    pub end: RangeBound,
+}
+
+impl OpenDateRange {
+    /// Returns `true` if the specified `date` is within the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::range::{OpenDateRange, RangeBound};
+    ///
+    /// let range = OpenDateRange {
+    ///     start: RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()),
+    ///     end: RangeBound::Unbounded,
+    /// };
+    ///
+    /// assert!(range.contains(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()));
+    /// assert!(!range.contains(NaiveDate::from_ymd_opt(2024, 7, 12).unwrap()));
+    /// ```
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        let after_start = match self.start {
+            RangeBound::Unbounded => true,
+            RangeBound::Date(start) => start <= date,
+        };
+        let before_end = match self.end {
+            RangeBound::Unbounded => true,
+            RangeBound::Date(end) => date <= end,
+        };
+
+        after_start && before_end
+    }
+}
+
+impl From<DateRange> for OpenDateRange {
+    fn from(range: DateRange) -> Self {
+        Self {
+            start: RangeBound::Date(range.start),
+            end: RangeBound::Date(range.end),
+        }
+    }
+}
+
+/// Recognizes a symbolic separator between the dates of the range surrounded by
+/// any number of spaces: `-`, `–`, `—` or `..`.
+///
+/// The language-specific words (e.g. `to`) are recognized by the
+/// language-specific range parsers.
+pub fn range_separator(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        delimited(
+            space0,
+            alt((tag(".."), tag("-"), tag("–"), tag("—"))),
+            space0,
+        ),
+    )(input)
+}
+
+/// Returns the [`DateRange`] of the specified dates, or
+/// [`Error::ReversedRange`] if the `end` date precedes the `start` one.
+fn date_range_of<'a>(
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<DateRange, nom::Err<Error<&'a str>>> {
+    if end < start {
+        return Err(nom::Err::Error(Error::ReversedRange));
+    }
+    Ok(DateRange { start, end })
+}
+
+/// Creates a parser which recognizes two dates using the specified `parser`
+/// separated by the specified `separator` (e.g. [`range_separator`]) and
+/// returns the corresponding [`DateRange`].
+///
+/// Returns [`Error::ReversedRange`] if the second date precedes the first one.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{
+///     error::Error,
+///     numeric::dd_mm_y4,
+///     range::{date_range, range_separator, DateRange},
+/// };
+///
+/// assert_eq!(
+///     date_range(range_separator, dd_mm_y4)("13/07/2024 - 20/07/2024")?.1,
+///     DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+///     }
+/// );
+/// assert_eq!(
+///     date_range(range_separator, dd_mm_y4)("20/07/2024 - 13/07/2024"),
+///     Err(nom::Err::Error(Error::ReversedRange))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date_range<'a, S, P>(
+    mut separator: S,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DateRange>
+where
+    S: Parser<&'a str, (), Error<&'a str>>,
+    P: Parser<&'a str, NaiveDate, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (input, start) = parser.parse(input)?;
+        let (input, _) = separator.parse(input)?;
+        let (input, end) = parser.parse(input)?;
+
+        Ok((input, date_range_of(start, end)?))
+    }
+}
+
+/// Creates a parser which recognizes the half-open range of dates: either the
+/// specified `from` prefix followed by the date (e.g. `from monday`), which
+/// becomes the `start` of the range, or the specified `until` prefix followed
+/// by the date (e.g. `until friday`), which becomes the `end` of the range. The
+/// other bound is [`RangeBound::Unbounded`], the dates are recognized by the
+/// specified `parser`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::bytes::complete::tag;
+/// use nom_date_parsers::{
+///     numeric::dd_mm_y4,
+///     range::{open_date_range, OpenDateRange, RangeBound},
+/// };
+///
+/// let mut parser = open_date_range(tag("from "), tag("until "), dd_mm_y4);
+///
+/// assert_eq!(
+///     parser("until 20/07/2024")?.1,
+///     OpenDateRange {
+///         start: RangeBound::Unbounded,
+///         end: RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 20).unwrap()),
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn open_date_range<'a, O1, O2, F, U, P>(
+    from: F,
+    until: U,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, OpenDateRange>
+where
+    F: Parser<&'a str, O1, Error<&'a str>>,
+    U: Parser<&'a str, O2, Error<&'a str>>,
+    P: Parser<&'a str, NaiveDate, Error<&'a str>>,
+{
+    let mut prefix = alt((from.map(|_| true), until.map(|_| false)));
+
+    move |input: &'a str| {
+        let (input, is_start) = prefix(input)?;
+        let (input, date) = parser.parse(input)?;
+        let (start, end) = if is_start {
+            (RangeBound::Date(date), RangeBound::Unbounded)
+        } else {
+            (RangeBound::Unbounded, RangeBound::Date(date))
+        };
+
+        Ok((input, OpenDateRange { start, end }))
+    }
+}
+
+/// Creates a parser which recognizes the range of dates with the shared named
+/// `month` and `year` parts written once after the second date (e.g.
+/// `13-15 July` or `13 July - 20 August 2024`) and returns the corresponding
+/// [`DateRange`].
+///
+/// The days are separated by the specified `separator`, the `month` part is
+/// recognized by the specified language-specific `named_month` parser. The
+/// missing `month` part of the first date is taken from the second date, the
+/// missing `year` part is taken from the reference date of the specified
+/// [`ParserContext`].
+///
+/// Returns [`Error::NonExistentDate`] if any of the dates doesn't exist and
+/// [`Error::ReversedRange`] if the second date precedes the first one.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate};
+/// use nom::{bytes::complete::tag_no_case, combinator::value};
+/// use nom_date_parsers::{
+///     context::ParserContext,
+///     range::{range_separator, shared_month_range_with, DateRange},
+///     types::IResult,
+/// };
+///
+/// fn july(input: &str) -> IResult<&str, Month> {
+///     value(Month::July, tag_no_case("july"))(input)
+/// }
+///
+/// let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+///
+/// assert_eq!(
+///     shared_month_range_with(&ctx, range_separator, july)("13-15 July")?.1,
+///     DateRange {
+///         start: NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+///     }
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn shared_month_range_with<'a, 'c, S>(
+    ctx: &'c ParserContext,
+    mut separator: S,
+    named_month: fn(&str) -> IResult<&str, Month>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DateRange> + 'c
+where
+    S: Parser<&'a str, (), Error<&'a str>> + 'c,
+{
+    move |input: &'a str| {
+        let (input, start_day) = dd(input)?;
+        let (input, start_month) = opt(preceded(day_month_separator, named_month))(input)?;
+        let (input, _) = separator.parse(input)?;
+        let (input, end_day) = dd(input)?;
+        let (input, _) = day_month_separator(input)?;
+        let (input, end_month) = named_month(input)?;
+        let (input, year) = opt_year(preceded(numeric_date_parts_separator, y4_with(ctx)))(input)?;
+
+        let year = year.map_or(ctx.today().year(), |year| year as i32);
+        let date = |month: Month, day| {
+            NaiveDate::from_ymd_opt(year, month.number_from_month(), day)
+                .ok_or(nom::Err::Error(Error::NonExistentDate))
+        };
+
+        Ok((
+            input,
+            date_range_of(
+                date(start_month.unwrap_or(end_month), start_day)?,
+                date(end_month, end_day)?,
+            )?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{bytes::complete::tag_no_case, error::ErrorKind};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::numeric::dd_mm_y4;
+
+    fn month(input: &str) -> IResult<&str, Month> {
+        alt((
+            value(Month::July, tag_no_case("july")),
+            value(Month::August, tag_no_case("august")),
+        ))(input)
+    }
+
+    fn range(start: (i32, u32, u32), end: (i32, u32, u32)) -> DateRange {
+        DateRange {
+            start: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            end: NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap(),
+        }
+    }
+
+    #[rstest]
+    #[case(RangeBound::Unbounded, RangeBound::Unbounded, (2024, 7, 16), true)]
+    #[case(RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()), RangeBound::Unbounded, (2024, 7, 16), true)]
+    #[case(RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()), RangeBound::Unbounded, (2024, 7, 15), false)]
+    #[case(RangeBound::Unbounded, RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()), (2000, 1, 1), true)]
+    #[case(RangeBound::Unbounded, RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()), (2024, 7, 17), false)]
+    fn test_open_date_range_contains(
+        #[case] start: RangeBound,
+        #[case] end: RangeBound,
+        #[case] date: (i32, u32, u32),
+        #[case] expected: bool,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(OpenDateRange { start, end }.contains(date), expected);
+    }
+
+    #[rstest]
+    #[case("-", Ok(("", ())))]
+    #[case(" — ", Ok(("", ())))]
+    #[case("..", Ok(("", ())))]
+    #[case(" – 20", Ok(("20", ())))]
+    #[case(" to ", Err(nom::Err::Error(Error::Nom("to ", ErrorKind::Tag))))]
+    fn test_range_separator(#[case] input: &str, #[case] expected: IResult<&str, ()>) {
+        assert_eq!(range_separator(input), expected);
+    }
+
+    #[rstest]
+    #[case("13/07/2024 - 20/07/2024", Ok(("", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case("13.07.2024..13.07.2024", Ok(("", range((2024, 7, 13), (2024, 7, 13)))))]
+    #[case("13/07/2024 — 20/07/2024 later", Ok((" later", range((2024, 7, 13), (2024, 7, 20)))))]
+    #[case("20/07/2024 - 13/07/2024", Err(nom::Err::Error(Error::ReversedRange)))]
+    #[case(
+        "13/07/2024 - 31/02/2024",
+        Err(nom::Err::Error(Error::NonExistentDate))
+    )]
+    fn test_date_range(#[case] input: &str, #[case] expected: IResult<&str, DateRange>) {
+        assert_eq!(date_range(range_separator, dd_mm_y4)(input), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "from 13/07/2024",
+        Ok(("", OpenDateRange {
+            start: RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()),
+            end: RangeBound::Unbounded
+        }))
+    )]
+    #[case(
+        "until 20/07/2024 later",
+        Ok((" later", OpenDateRange {
+            start: RangeBound::Unbounded,
+            end: RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())
+        }))
+    )]
+    #[case("from 31/02/2024", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case(
+        "since 13/07/2024",
+        Err(nom::Err::Error(Error::Nom("since 13/07/2024", ErrorKind::Tag)))
+    )]
+    fn test_open_date_range(#[case] input: &str, #[case] expected: IResult<&str, OpenDateRange>) {
+        assert_eq!(
+            open_date_range(tag("from "), tag("until "), dd_mm_y4)(input),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("13-15 July", Ok(("", range((2024, 7, 13), (2024, 7, 15)))))]
+    #[case("13 - 15 july 2023", Ok(("", range((2023, 7, 13), (2023, 7, 15)))))]
+    #[case("13 July - 20 August 2025", Ok(("", range((2025, 7, 13), (2025, 8, 20)))))]
+    #[case("13 July..2 August", Ok(("", range((2024, 7, 13), (2024, 8, 2)))))]
+    #[case("15-13 July", Err(nom::Err::Error(Error::ReversedRange)))]
+    #[case("30-32 July", Err(nom::Err::Error(Error::DayOutOfRange { value: 32, input: "32 July" })))]
+    #[case(
+        "13/07/2024 - 20/07/2024",
+        Err(nom::Err::Error(Error::Nom("/07/2024 - 20/07/2024", ErrorKind::Tag)))
+    )]
+    fn test_shared_month_range_with(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, DateRange>,
+    ) {
+        let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+        assert_eq!(
+            shared_month_range_with(&ctx, range_separator, month)(input),
+            expected
+        );
+    }
+}
@@ -0,0 +1,490 @@
+use alloc::{format, string::String, vec::Vec};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use nom::{
+    branch::alt,
+    character::complete::{char, digit1},
+    combinator::{map, map_opt, map_res, opt},
+    multi::separated_list1,
+    sequence::preceded,
+};
+
+use crate::{
+    combinators::{lookup_no_case, tag_no_case},
+    error::Error,
+    types::IResult,
+};
+
+/// The maximum number of the months tried to find the month with the day of
+/// the [`Frequency::Monthly`] recurrence, enough to reach the next leap year
+/// for the `29th` of February with any interval.
+const MAX_MONTHS_TRIED: u32 = 96;
+
+/// The unit of the [`Recurrence`] with the optional anchor within the unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    /// Every day
+    Daily,
+    /// Every week on the weekday or, if it is [`None`], on the weekday of the
+    /// first occurrence
+    Weekly(Option<Weekday>),
+    /// Every month on the day or, if it is [`None`], on the day of the first
+    /// occurrence. The months without the day (e.g. the `31st` of April) are
+    /// skipped
+    Monthly(Option<u32>),
+}
+
+/// The recurring date, e.g. `every monday` or `monthly on the 15th`, which
+/// enumerates the dates of the occurrences (see [`Recurrence::occurrences`]).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nom_date_parsers::recurrence::{Frequency, Recurrence};
+///
+/// let recurrence = Recurrence::new(Frequency::Weekly(Some(Weekday::Fri))).with_interval(2);
+/// let start = NaiveDate::from_ymd_opt(2024, 7, 16).unwrap();
+///
+/// assert_eq!(
+///     recurrence.occurrences(start).take(3).collect::<Vec<_>>(),
+///     vec![
+///         NaiveDate::from_ymd_opt(2024, 7, 19).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 8, 2).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 8, 16).unwrap(),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Recurrence {
+    /// The unit of the recurrence
+    pub frequency: Frequency,
+    /// The number of the units between the occurrences, `0` is treated as `1`
+    pub interval: u32,
+}
+
+impl Recurrence {
+    /// Creates the recurrence with the specified `frequency`, which occurs
+    /// every unit.
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+        }
+    }
+
+    /// Sets the number of the units between the occurrences (e.g. `2` for
+    /// `every other monday`).
+    pub fn with_interval(mut self, interval: u32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the iterator over the dates of the occurrences starting from the
+    /// first one on or after the `start` date. The iterator ends if the next
+    /// date is out of the range of the [`NaiveDate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nom_date_parsers::recurrence::{Frequency, Recurrence};
+    ///
+    /// let recurrence = Recurrence::new(Frequency::Monthly(Some(31)));
+    /// let start = NaiveDate::from_ymd_opt(2024, 7, 16).unwrap();
+    ///
+    /// assert_eq!(
+    ///     recurrence.occurrences(start).take(3).collect::<Vec<_>>(),
+    ///     vec![
+    ///         NaiveDate::from_ymd_opt(2024, 7, 31).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2024, 8, 31).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2024, 10, 31).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn occurrences(self, start: NaiveDate) -> Occurrences {
+        let next = match self.frequency {
+            Frequency::Daily | Frequency::Weekly(None) | Frequency::Monthly(None) => Some(start),
+            Frequency::Weekly(Some(weekday)) => start.checked_add_days(Days::new(u64::from(
+                (7 + weekday.num_days_from_monday() - start.weekday().num_days_from_monday()) % 7,
+            ))),
+            Frequency::Monthly(Some(day)) => (0..MAX_MONTHS_TRIED)
+                .filter_map(|months| month_day(start, months, day))
+                .find(|&date| date >= start),
+        };
+        let day = match self.frequency {
+            Frequency::Monthly(Some(day)) => day,
+            _ => start.day(),
+        };
+
+        Occurrences {
+            recurrence: self,
+            day,
+            next,
+        }
+    }
+
+    /// Returns the `RRULE` value of the iCalendar ([RFC 5545]) recurrence rule,
+    /// e.g. `FREQ=WEEKLY;INTERVAL=2;BYDAY=FR`, without the `RRULE:` prefix.
+    /// The `INTERVAL` is omitted if it is `1`, and so are the `BYDAY` and the
+    /// `BYMONTHDAY` if the anchor is [`None`], in which case the calendar takes
+    /// it from the `DTSTART` of the event. See [`rrule`] for the reverse
+    /// conversion.
+    ///
+    /// [RFC 5545]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use nom_date_parsers::recurrence::{Frequency, Recurrence};
+    ///
+    /// assert_eq!(
+    ///     Recurrence::new(Frequency::Weekly(Some(Weekday::Fri)))
+    ///         .with_interval(2)
+    ///         .to_rrule(),
+    ///     "FREQ=WEEKLY;INTERVAL=2;BYDAY=FR"
+    /// );
+    /// assert_eq!(
+    ///     Recurrence::new(Frequency::Monthly(Some(15))).to_rrule(),
+    ///     "FREQ=MONTHLY;BYMONTHDAY=15"
+    /// );
+    /// ```
+    pub fn to_rrule(&self) -> String {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly(_) => "WEEKLY",
+            Frequency::Monthly(_) => "MONTHLY",
+        };
+        let mut rrule = format!("FREQ={frequency}");
+
+        if self.interval() > 1 {
+            rrule += &format!(";INTERVAL={}", self.interval());
+        }
+        match self.frequency {
+            Frequency::Weekly(Some(weekday)) => {
+                rrule += &format!(
+                    ";BYDAY={}",
+                    WEEKDAY_CODES[weekday.num_days_from_monday() as usize]
+                );
+            }
+            Frequency::Monthly(Some(day)) => rrule += &format!(";BYMONTHDAY={day}"),
+            _ => {}
+        }
+        rrule
+    }
+
+    /// Returns the number of the units between the occurrences, at least `1`.
+    fn interval(&self) -> u32 {
+        self.interval.max(1)
+    }
+}
+
+/// Returns the `day` of the month which is the specified number of `months`
+/// after the month of the `date` or [`None`] if the date doesn't exist.
+fn month_day(date: NaiveDate, months: u32, day: u32) -> Option<NaiveDate> {
+    let month = i64::from(date.year()) * 12 + i64::from(date.month0()) + i64::from(months);
+
+    NaiveDate::from_ymd_opt(
+        i32::try_from(month.div_euclid(12)).ok()?,
+        month.rem_euclid(12) as u32 + 1,
+        day,
+    )
+}
+
+/// The iterator over the dates of the [`Recurrence`], see
+/// [`Recurrence::occurrences`].
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    recurrence: Recurrence,
+    /// The day of the month of the [`Frequency::Monthly`] recurrence
+    day: u32,
+    next: Option<NaiveDate>,
+}
+
+impl Iterator for Occurrences {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        let date = self.next?;
+        let interval = self.recurrence.interval();
+
+        self.next = match self.recurrence.frequency {
+            Frequency::Daily => date.checked_add_days(Days::new(u64::from(interval))),
+            Frequency::Weekly(_) => date.checked_add_days(Days::new(7 * u64::from(interval))),
+            Frequency::Monthly(_) => (1..=MAX_MONTHS_TRIED)
+                .find_map(|step| month_day(date, step.checked_mul(interval)?, self.day)),
+        };
+
+        Some(date)
+    }
+}
+
+/// The two-letter codes of the weekdays used by the `BYDAY` rule part,
+/// starting from Monday.
+const WEEKDAY_CODES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// The rule part of the `RRULE` which is supported by the [`rrule`] parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RulePart {
+    Frequency(Frequency),
+    Interval(u32),
+    Weekday(Weekday),
+    MonthDay(u32),
+}
+
+/// Recognizes the `case insensitive` two-letter code of the weekday of the
+/// `BYDAY` rule part, e.g. `MO`.
+fn weekday_code(input: &str) -> IResult<&str, Weekday> {
+    lookup_no_case(|word| match word {
+        "mo" => Some(Weekday::Mon),
+        "tu" => Some(Weekday::Tue),
+        "we" => Some(Weekday::Wed),
+        "th" => Some(Weekday::Thu),
+        "fr" => Some(Weekday::Fri),
+        "sa" => Some(Weekday::Sat),
+        "su" => Some(Weekday::Sun),
+        _ => None,
+    })(input)
+}
+
+/// Recognizes the day of the month of the `BYMONTHDAY` rule part in the range
+/// `1..=31`, otherwise returns [`Error::DayOutOfRange`].
+fn month_day_number(input: &str) -> IResult<&str, u32> {
+    let (rest, day) = map_res(digit1, str::parse)(input)?;
+
+    if day == 0 || day > 31 {
+        return Err(nom::Err::Error(Error::DayOutOfRange { value: day, input }));
+    }
+    Ok((rest, day))
+}
+
+/// Recognizes the `case insensitive` rule part of the `RRULE`:
+/// - `FREQ=DAILY` | `FREQ=WEEKLY` | `FREQ=MONTHLY`
+/// - `INTERVAL=<number>` with the number greater than zero
+/// - `BYDAY=<weekday>` with the single weekday without the ordinal, e.g. `MO`
+/// - `BYMONTHDAY=<day>` with the single positive day, e.g. `15`
+fn rule_part(input: &str) -> IResult<&str, RulePart> {
+    alt((
+        preceded(
+            tag_no_case("FREQ="),
+            alt((
+                map(tag_no_case("DAILY"), |_| {
+                    RulePart::Frequency(Frequency::Daily)
+                }),
+                map(tag_no_case("WEEKLY"), |_| {
+                    RulePart::Frequency(Frequency::Weekly(None))
+                }),
+                map(tag_no_case("MONTHLY"), |_| {
+                    RulePart::Frequency(Frequency::Monthly(None))
+                }),
+            )),
+        ),
+        preceded(
+            tag_no_case("INTERVAL="),
+            map_opt(map_res(digit1, str::parse), |interval: u32| {
+                (interval > 0).then_some(RulePart::Interval(interval))
+            }),
+        ),
+        preceded(tag_no_case("BYDAY="), map(weekday_code, RulePart::Weekday)),
+        preceded(
+            tag_no_case("BYMONTHDAY="),
+            map(month_day_number, RulePart::MonthDay),
+        ),
+    ))(input)
+}
+
+/// Combines the rule parts of the `RRULE` into the [`Recurrence`], returns
+/// [`None`] if the `FREQ` is missing, any rule part is repeated or the `BYDAY`
+/// and the `BYMONTHDAY` don't match the frequency.
+fn combine_rule_parts(parts: Vec<RulePart>) -> Option<Recurrence> {
+    let mut frequency = None;
+    let mut interval = None;
+    let mut weekday = None;
+    let mut day = None;
+
+    for part in parts {
+        let repeated = match part {
+            RulePart::Frequency(value) => frequency.replace(value).is_some(),
+            RulePart::Interval(value) => interval.replace(value).is_some(),
+            RulePart::Weekday(value) => weekday.replace(value).is_some(),
+            RulePart::MonthDay(value) => day.replace(value).is_some(),
+        };
+        if repeated {
+            return None;
+        }
+    }
+
+    let frequency = match (frequency?, weekday, day) {
+        (Frequency::Daily, None, None) => Frequency::Daily,
+        (Frequency::Weekly(_), weekday, None) => Frequency::Weekly(weekday),
+        (Frequency::Monthly(_), None, day) => Frequency::Monthly(day),
+        _ => return None,
+    };
+    Some(Recurrence::new(frequency).with_interval(interval.unwrap_or(1)))
+}
+
+/// Recognizes the simple iCalendar ([RFC 5545]) recurrence rule with the
+/// optional `RRULE:` prefix and returns the corresponding [`Recurrence`]. The
+/// rule parts are separated by the `;` and may follow in any order:
+/// - `FREQ` is required and is either `DAILY`, `WEEKLY` or `MONTHLY`
+/// - `INTERVAL` is optional
+/// - `BYDAY` with the single weekday is allowed for the `WEEKLY` frequency
+/// - `BYMONTHDAY` with the single day is allowed for the `MONTHLY` frequency
+///
+/// The other rule parts (e.g. `COUNT` or `UNTIL`) aren't supported and stop
+/// the parsing: the parts before them are recognized and the unsupported part
+/// is left in the remaining input (e.g. `;COUNT=3`), so check that the rest of
+/// the input is empty to accept only the fully supported rules. See
+/// [`Recurrence::to_rrule`] for the reverse conversion.
+///
+/// [RFC 5545]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use nom_date_parsers::recurrence::{rrule, Frequency, Recurrence};
+///
+/// assert_eq!(
+///     rrule("RRULE:FREQ=WEEKLY;BYDAY=FR;INTERVAL=2")?,
+///     (
+///         "",
+///         Recurrence::new(Frequency::Weekly(Some(Weekday::Fri))).with_interval(2)
+///     )
+/// );
+/// assert_eq!(
+///     rrule("FREQ=MONTHLY;BYMONTHDAY=15;COUNT=3")?,
+///     (";COUNT=3", Recurrence::new(Frequency::Monthly(Some(15))))
+/// );
+/// assert!(rrule("FREQ=DAILY;BYDAY=MO").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn rrule(input: &str) -> IResult<&str, Recurrence> {
+    preceded(
+        opt(tag_no_case("RRULE:")),
+        map_opt(separated_list1(char(';'), rule_part), combine_rule_parts),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn dates(dates: &[(i32, u32, u32)]) -> Vec<NaiveDate> {
+        dates
+            .iter()
+            .map(|&(year, month, day)| NaiveDate::from_ymd_opt(year, month, day).unwrap())
+            .collect()
+    }
+
+    #[rstest]
+    #[case(Frequency::Daily, 1, (2024, 7, 16), dates(&[(2024, 7, 16), (2024, 7, 17), (2024, 7, 18)]))]
+    #[case(Frequency::Daily, 0, (2024, 7, 16), dates(&[(2024, 7, 16), (2024, 7, 17), (2024, 7, 18)]))]
+    #[case(Frequency::Daily, 3, (2024, 7, 30), dates(&[(2024, 7, 30), (2024, 8, 2), (2024, 8, 5)]))]
+    #[case(Frequency::Weekly(Some(Weekday::Mon)), 1, (2024, 7, 16), dates(&[(2024, 7, 22), (2024, 7, 29), (2024, 8, 5)]))]
+    #[case(Frequency::Weekly(Some(Weekday::Tue)), 1, (2024, 7, 16), dates(&[(2024, 7, 16), (2024, 7, 23), (2024, 7, 30)]))]
+    #[case(Frequency::Weekly(Some(Weekday::Fri)), 2, (2024, 7, 16), dates(&[(2024, 7, 19), (2024, 8, 2), (2024, 8, 16)]))]
+    #[case(Frequency::Weekly(None), 2, (2024, 7, 16), dates(&[(2024, 7, 16), (2024, 7, 30), (2024, 8, 13)]))]
+    #[case(Frequency::Monthly(Some(15)), 1, (2024, 7, 16), dates(&[(2024, 8, 15), (2024, 9, 15), (2024, 10, 15)]))]
+    #[case(Frequency::Monthly(Some(16)), 1, (2024, 7, 16), dates(&[(2024, 7, 16), (2024, 8, 16), (2024, 9, 16)]))]
+    #[case(Frequency::Monthly(Some(31)), 1, (2024, 1, 31), dates(&[(2024, 1, 31), (2024, 3, 31), (2024, 5, 31)]))]
+    #[case(Frequency::Monthly(Some(15)), 3, (2024, 11, 1), dates(&[(2024, 11, 15), (2025, 2, 15), (2025, 5, 15)]))]
+    #[case(Frequency::Monthly(Some(29)), 12, (2024, 2, 1), dates(&[(2024, 2, 29), (2028, 2, 29), (2032, 2, 29)]))]
+    #[case(Frequency::Monthly(None), 1, (2024, 7, 16), dates(&[(2024, 7, 16), (2024, 8, 16), (2024, 9, 16)]))]
+    fn test_occurrences(
+        #[case] frequency: Frequency,
+        #[case] interval: u32,
+        #[case] start: (i32, u32, u32),
+        #[case] expected: Vec<NaiveDate>,
+    ) {
+        let start = NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap();
+        let recurrence = Recurrence::new(frequency).with_interval(interval);
+
+        assert_eq!(
+            recurrence.occurrences(start).take(3).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Frequency::Daily, NaiveDate::MAX, vec![NaiveDate::MAX])]
+    #[case(Frequency::Weekly(None), NaiveDate::MAX, vec![NaiveDate::MAX])]
+    #[case(Frequency::Monthly(None), NaiveDate::MAX, vec![NaiveDate::MAX])]
+    #[case(Frequency::Monthly(Some(32)), NaiveDate::from_ymd_opt(2024, 7, 16).unwrap(), vec![])]
+    fn test_occurrences_bounds(
+        #[case] frequency: Frequency,
+        #[case] start: NaiveDate,
+        #[case] expected: Vec<NaiveDate>,
+    ) {
+        assert_eq!(
+            Recurrence::new(frequency)
+                .occurrences(start)
+                .take(2)
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Frequency::Daily, 1, "FREQ=DAILY")]
+    #[case(Frequency::Daily, 0, "FREQ=DAILY")]
+    #[case(Frequency::Daily, 3, "FREQ=DAILY;INTERVAL=3")]
+    #[case(Frequency::Weekly(None), 2, "FREQ=WEEKLY;INTERVAL=2")]
+    #[case(Frequency::Weekly(Some(Weekday::Mon)), 1, "FREQ=WEEKLY;BYDAY=MO")]
+    #[case(
+        Frequency::Weekly(Some(Weekday::Sun)),
+        2,
+        "FREQ=WEEKLY;INTERVAL=2;BYDAY=SU"
+    )]
+    #[case(Frequency::Monthly(None), 1, "FREQ=MONTHLY")]
+    #[case(
+        Frequency::Monthly(Some(31)),
+        3,
+        "FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=31"
+    )]
+    fn test_to_rrule(#[case] frequency: Frequency, #[case] interval: u32, #[case] expected: &str) {
+        let recurrence = Recurrence::new(frequency).with_interval(interval);
+
+        assert_eq!(recurrence.to_rrule(), expected);
+        if interval > 0 {
+            assert_eq!(rrule(expected), Ok(("", recurrence)));
+        }
+    }
+
+    #[rstest]
+    #[case("FREQ=DAILY", Ok(("", Recurrence::new(Frequency::Daily))))]
+    #[case("rrule:freq=weekly;byday=tu", Ok(("", Recurrence::new(Frequency::Weekly(Some(Weekday::Tue))))))]
+    #[case("BYMONTHDAY=1;FREQ=MONTHLY", Ok(("", Recurrence::new(Frequency::Monthly(Some(1))))))]
+    #[case("INTERVAL=2;FREQ=DAILY", Ok(("", Recurrence::new(Frequency::Daily).with_interval(2))))]
+    #[case("FREQ=WEEKLY;COUNT=5", Ok((";COUNT=5", Recurrence::new(Frequency::Weekly(None)))))]
+    #[case("FREQ=WEEKLY;BYDAY=MO,WE", Ok((",WE", Recurrence::new(Frequency::Weekly(Some(Weekday::Mon))))))]
+    #[case(
+        "FREQ=YEARLY",
+        Err(nom::Err::Error(Error::Nom("FREQ=YEARLY", ErrorKind::Tag)))
+    )]
+    #[case(
+        "INTERVAL=2",
+        Err(nom::Err::Error(Error::Nom("INTERVAL=2", ErrorKind::MapOpt)))
+    )]
+    #[case(
+        "FREQ=DAILY;FREQ=WEEKLY",
+        Err(nom::Err::Error(Error::Nom("FREQ=DAILY;FREQ=WEEKLY", ErrorKind::MapOpt)))
+    )]
+    #[case(
+        "FREQ=DAILY;BYDAY=MO",
+        Err(nom::Err::Error(Error::Nom("FREQ=DAILY;BYDAY=MO", ErrorKind::MapOpt)))
+    )]
+    #[case(
+        "FREQ=WEEKLY;BYMONTHDAY=1",
+        Err(nom::Err::Error(Error::Nom("FREQ=WEEKLY;BYMONTHDAY=1", ErrorKind::MapOpt)))
+    )]
+    #[case("FREQ=MONTHLY;BYMONTHDAY=32", Ok((";BYMONTHDAY=32", Recurrence::new(Frequency::Monthly(None)))))]
+    fn test_rrule(#[case] input: &str, #[case] expected: IResult<&str, Recurrence>) {
+        assert_eq!(rrule(input), expected);
+    }
+}
@@ -0,0 +1,265 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, one_of, space0, space1},
+    combinator::{map, map_res, opt, value},
+    sequence::{pair, preceded, terminated, tuple},
+};
+
+use crate::{
+    combinators::tag_no_case,
+    error::Error,
+    i18n::en::{short_named_month, short_named_weekday},
+    types::IResult,
+};
+
+/// Creates a parser which recognizes from `min` to `max` ascii digits as the
+/// number.
+fn digits(min: usize, max: usize) -> impl Fn(&str) -> IResult<&str, u32> {
+    move |input: &str| {
+        map_res(
+            take_while_m_n(min, max, |c: char| c.is_ascii_digit()),
+            |s: &str| s.parse(),
+        )(input)
+    }
+}
+
+/// Recognizes the four-digit year or the obsolete two-digit year, which is
+/// mapped onto `1950..=2049` as specified by the `RFC 2822`.
+fn year(input: &str) -> IResult<&str, i32> {
+    alt((
+        map(digits(4, 4), |year| year as i32),
+        map(digits(2, 2), |year| match year {
+            0..=49 => 2000 + year as i32,
+            _ => 1900 + year as i32,
+        }),
+    ))(input)
+}
+
+/// Recognizes the numeric zone `±hhmm` or one of the obsolete zone names:
+/// `UT`, `UTC`, `GMT`, `Z` and the `North American` ones (`EST`, `EDT`,
+/// `CST`, `CDT`, `MST`, `MDT`, `PST` and `PDT`).
+fn zone(input: &str) -> IResult<&str, FixedOffset> {
+    let (input, hours) = opt(alt((
+        value(0, tag_no_case("utc")),
+        value(0, tag_no_case("ut")),
+        value(0, tag_no_case("gmt")),
+        value(0, tag_no_case("z")),
+        value(-5, tag_no_case("est")),
+        value(-4, tag_no_case("edt")),
+        value(-6, tag_no_case("cst")),
+        value(-5, tag_no_case("cdt")),
+        value(-7, tag_no_case("mst")),
+        value(-6, tag_no_case("mdt")),
+        value(-8, tag_no_case("pst")),
+        value(-7, tag_no_case("pdt")),
+    )))(input)?;
+    if let Some(hours) = hours {
+        return Ok((
+            input,
+            FixedOffset::east_opt(hours * 3600).ok_or(nom::Err::Error(Error::OffsetOutOfRange))?,
+        ));
+    }
+
+    let (input, (sign, (hours, minutes))) =
+        pair(one_of("+-"), pair(digits(2, 2), digits(2, 2)))(input)?;
+    if hours > 23 || minutes > 59 {
+        return Err(nom::Err::Error(Error::OffsetOutOfRange));
+    }
+    let seconds = (hours * 3600 + minutes * 60) as i32;
+
+    Ok((
+        input,
+        FixedOffset::east_opt(if sign == '-' { -seconds } else { seconds })
+            .ok_or(nom::Err::Error(Error::OffsetOutOfRange))?,
+    ))
+}
+
+/// Recognizes the time of day `hh:mm` or `hh:mm:ss`, the leap second `60` is
+/// accepted.
+fn time_of_day(input: &str) -> IResult<&str, NaiveTime> {
+    let (input, (hour, minute, second)) = tuple((
+        digits(2, 2),
+        preceded(char(':'), digits(2, 2)),
+        opt(preceded(char(':'), digits(2, 2))),
+    ))(input)?;
+
+    if hour > 23 {
+        return Err(nom::Err::Error(Error::HourOutOfRange));
+    }
+    if minute > 59 {
+        return Err(nom::Err::Error(Error::MinuteOutOfRange));
+    }
+    let (second, nanos) = match second.unwrap_or(0) {
+        second @ 0..=59 => (second, 0),
+        60 => (59, 1_000_000_000),
+        _ => return Err(nom::Err::Error(Error::SecondOutOfRange)),
+    };
+
+    Ok((
+        input,
+        NaiveTime::from_hms_nano_opt(hour, minute, second, nanos)
+            .ok_or(nom::Err::Error(Error::SecondOutOfRange))?,
+    ))
+}
+
+/// Recognizes the `RFC 2822` date and time (used by the email headers and
+/// HTTP), e.g. `Sat, 13 Jul 2024 14:30:00 +0300`, and returns the
+/// corresponding [`DateTime<FixedOffset>`].
+///
+/// The leading weekday is optional, but if present it must match the date,
+/// otherwise [`Error::WeekdayMismatch`] is returned. The seconds may be
+/// omitted, the obsolete two-digit years and zone names (e.g. `GMT` or `EST`)
+/// are accepted as well.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::DateTime;
+/// use nom_date_parsers::{error::Error, rfc2822::date_time};
+///
+/// assert_eq!(
+///     date_time("Sat, 13 Jul 2024 14:30:00 +0300")?.1,
+///     DateTime::parse_from_rfc3339("2024-07-13T14:30:00+03:00").unwrap()
+/// );
+/// assert_eq!(
+///     date_time("13 Jul 2024 14:30 GMT")?.1,
+///     DateTime::parse_from_rfc3339("2024-07-13T14:30:00Z").unwrap()
+/// );
+/// assert_eq!(
+///     date_time("Fri, 13 Jul 2024 00:00:00 +0000"),
+///     Err(nom::Err::Error(Error::WeekdayMismatch))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date_time(input: &str) -> IResult<&str, DateTime<FixedOffset>> {
+    let (day_input, weekday) = opt(terminated(short_named_weekday, pair(tag(","), space0)))(input)?;
+    let (input, (day, month, year)) = tuple((
+        digits(1, 2),
+        preceded(space1, short_named_month),
+        preceded(space1, year),
+    ))(day_input)?;
+    let (input, (time, offset)) =
+        pair(preceded(space1, time_of_day), preceded(space1, zone))(input)?;
+
+    if day == 0 || day > 31 {
+        return Err(nom::Err::Error(Error::DayOutOfRange {
+            value: day,
+            input: day_input,
+        }));
+    }
+    let date = NaiveDate::from_ymd_opt(year, month.number_from_month(), day)
+        .ok_or(nom::Err::Error(Error::NonExistentDate))?;
+    if weekday.is_some_and(|weekday| weekday != date.weekday()) {
+        return Err(nom::Err::Error(Error::WeekdayMismatch));
+    }
+
+    Ok((
+        input,
+        date.and_time(time)
+            .and_local_timezone(offset)
+            .single()
+            .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+    ))
+}
+
+/// Recognizes the `RFC 2822` date and time using the [`date_time`] parser and
+/// returns the corresponding [`NaiveDate`] in the specified zone, the time is
+/// discarded.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::rfc2822::date;
+///
+/// assert_eq!(
+///     date("Sat, 13 Jul 2024 23:30:00 -0500")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date(input: &str) -> IResult<&str, NaiveDate> {
+    map(date_time, |date_time| date_time.date_naive())(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Sat, 13 Jul 2024 00:00:00 +0000", "2024-07-13T00:00:00+00:00")]
+    #[case("Sat,13 Jul 2024 14:30:15 +0300", "2024-07-13T14:30:15+03:00")]
+    #[case("sat, 13 jul 2024 14:30 -0530", "2024-07-13T14:30:00-05:30")]
+    #[case("13 Jul 2024 14:30:00 GMT", "2024-07-13T14:30:00+00:00")]
+    #[case("1 Jul 2024 08:00:00 EDT", "2024-07-01T08:00:00-04:00")]
+    #[case("Tue, 16 Jul 24 08:00:00 PST", "2024-07-16T08:00:00-08:00")]
+    #[case("Fri, 31 Dec 99 23:59:60 UT", "1999-12-31T23:59:60+00:00")]
+    fn test_date_time(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(
+            date_time(input),
+            Ok(("", DateTime::parse_from_rfc3339(expected).unwrap()))
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "Fri, 13 Jul 2024 00:00:00 +0000",
+        Err(nom::Err::Error(Error::WeekdayMismatch))
+    )]
+    #[case(
+        "32 Jul 2024 00:00:00 +0000",
+        Err(nom::Err::Error(Error::DayOutOfRange {
+            value: 32,
+            input: "32 Jul 2024 00:00:00 +0000"
+        }))
+    )]
+    #[case(
+        "30 Feb 2024 00:00:00 +0000",
+        Err(nom::Err::Error(Error::NonExistentDate))
+    )]
+    #[case(
+        "13 Jul 2024 24:00:00 +0000",
+        Err(nom::Err::Error(Error::HourOutOfRange))
+    )]
+    #[case(
+        "13 Jul 2024 14:60:00 +0000",
+        Err(nom::Err::Error(Error::MinuteOutOfRange))
+    )]
+    #[case(
+        "13 Jul 2024 14:30:61 +0000",
+        Err(nom::Err::Error(Error::SecondOutOfRange))
+    )]
+    #[case(
+        "13 Jul 2024 14:30:00 +2400",
+        Err(nom::Err::Error(Error::OffsetOutOfRange))
+    )]
+    #[case(
+        "13 Jul 2024 14:30:00",
+        Err(nom::Err::Error(Error::Nom("", ErrorKind::Space)))
+    )]
+    fn test_date_time_errors(
+        #[case] input: &str,
+        #[case] expected: IResult<&str, DateTime<FixedOffset>>,
+    ) {
+        assert_eq!(date_time(input), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "Sat, 13 Jul 2024 23:30:00 -0500\r\n",
+        Ok(("\r\n", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))
+    )]
+    #[case(
+        "Sun, 14 Jul 2024 01:00:00 +0300",
+        Ok(("", NaiveDate::from_ymd_opt(2024, 7, 14).unwrap()))
+    )]
+    fn test_date(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(date(input), expected);
+    }
+}
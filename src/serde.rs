@@ -0,0 +1,266 @@
+use core::fmt;
+
+use ::serde::{de, Deserializer, Serializer};
+use chrono::NaiveDate;
+
+use crate::{
+    context::{ParserContext, TrailingInput},
+    parser::ContextFn,
+};
+
+/// The format of the serialized dates, which is accepted by every
+/// deserializer of the module.
+const FORMAT: &str = "%Y-%m-%d";
+
+/// Serializes the date in the `YYYY-MM-DD` format.
+fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&date.format(FORMAT))
+}
+
+/// Deserializes the date from the string in the `YYYY-MM-DD` format or from
+/// the string, which is recognized by the specified `parser` entirely (except
+/// the surrounding whitespaces).
+fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    expecting: &'static str,
+    parser: ContextFn,
+) -> Result<NaiveDate, D::Error> {
+    deserializer.deserialize_str(DateVisitor { expecting, parser })
+}
+
+struct DateVisitor {
+    expecting: &'static str,
+    parser: ContextFn,
+}
+
+impl<'de> de::Visitor<'de> for DateVisitor {
+    type Value = NaiveDate;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<NaiveDate, E> {
+        if let Ok(date) = NaiveDate::parse_from_str(value, FORMAT) {
+            return Ok(date);
+        }
+
+        let ctx = ParserContext::default().with_trailing_input(TrailingInput::RequireEnd);
+
+        match (self.parser)(&ctx, value.trim_start()) {
+            Ok((_, date)) => Ok(date),
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                Err(E::custom(format_args!("invalid date `{value}`: {e}")))
+            }
+            Err(nom::Err::Incomplete(_)) => {
+                Err(E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+    }
+}
+
+/// Serializes [`NaiveDate`] in the `YYYY-MM-DD` format and deserializes it
+/// from any string accepted by the [`parse_date`](crate::parse_date) function.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Event {
+///     #[serde(with = "nom_date_parsers::serde::numeric")]
+///     date: NaiveDate,
+/// }
+///
+/// let event: Event = serde_json::from_str(r#"{ "date": "13.07.2024" }"#)?;
+///
+/// assert_eq!(event.date, NaiveDate::from_ymd_opt(2024, 7, 13).unwrap());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "numeric")]
+pub mod numeric {
+    use ::serde::{Deserializer, Serializer};
+    use chrono::NaiveDate;
+
+    /// Serializes the date in the `YYYY-MM-DD` format
+    pub fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize(date, serializer)
+    }
+
+    /// Deserializes the date with the [`parse_date`](crate::parse_date)
+    /// function
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        super::deserialize(deserializer, "a numeric date", |ctx, input| {
+            crate::parse_date_with(ctx)(input)
+                .map(|date| ("", date))
+                .map_err(nom::Err::Error)
+        })
+    }
+}
+
+/// Serializes [`NaiveDate`] in the `YYYY-MM-DD` format and deserializes it
+/// from any string accepted by the
+/// [`en::bundle_dmy`](crate::i18n::en::bundle_dmy) parser, e.g. `13 July 2024`,
+/// `13/07` or `tomorrow`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "nom_date_parsers::serde::en_dmy")]
+///     date: NaiveDate,
+/// }
+///
+/// let event: Event = serde_json::from_str(r#"{ "date": "13 July 2024" }"#)?;
+///
+/// assert_eq!(event.date, NaiveDate::from_ymd_opt(2024, 7, 13).unwrap());
+/// assert_eq!(serde_json::to_string(&event)?, r#"{"date":"2024-07-13"}"#);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "en")]
+pub mod en_dmy {
+    use ::serde::{Deserializer, Serializer};
+    use chrono::NaiveDate;
+
+    /// Serializes the date in the `YYYY-MM-DD` format
+    pub fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize(date, serializer)
+    }
+
+    /// Deserializes the date with the
+    /// [`en::bundle_dmy`](crate::i18n::en::bundle_dmy) parser
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        super::deserialize(deserializer, "a date in English", |ctx, input| {
+            crate::i18n::en::bundle_dmy_with(ctx)(input)
+        })
+    }
+}
+
+/// Serializes [`NaiveDate`] in the `YYYY-MM-DD` format and deserializes it
+/// from any string accepted by the
+/// [`en::bundle_mdy`](crate::i18n::en::bundle_mdy) parser, e.g. `July 13,
+/// 2024`, `07/13` or `tomorrow`.
+#[cfg(feature = "en")]
+pub mod en_mdy {
+    use ::serde::{Deserializer, Serializer};
+    use chrono::NaiveDate;
+
+    /// Serializes the date in the `YYYY-MM-DD` format
+    pub fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize(date, serializer)
+    }
+
+    /// Deserializes the date with the
+    /// [`en::bundle_mdy`](crate::i18n::en::bundle_mdy) parser
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        super::deserialize(deserializer, "a date in English", |ctx, input| {
+            crate::i18n::en::bundle_mdy_with(ctx)(input)
+        })
+    }
+}
+
+/// Serializes [`NaiveDate`] in the `YYYY-MM-DD` format and deserializes it
+/// from any string accepted by the [`ru::bundle`](crate::i18n::ru::bundle)
+/// parser, e.g. `13 июля 2024 года`, `13.07.24` or `завтра`.
+#[cfg(feature = "ru")]
+pub mod ru {
+    use ::serde::{Deserializer, Serializer};
+    use chrono::NaiveDate;
+
+    /// Serializes the date in the `YYYY-MM-DD` format
+    pub fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize(date, serializer)
+    }
+
+    /// Deserializes the date with the [`ru::bundle`](crate::i18n::ru::bundle)
+    /// parser
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        super::deserialize(deserializer, "a date in Russian", |ctx, input| {
+            crate::i18n::ru::bundle_with(ctx)(input)
+        })
+    }
+}
+
+#[cfg(all(test, feature = "en"))]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "en_dmy")]
+        dmy: NaiveDate,
+    }
+
+    #[rstest]
+    #[case(r#"{"dmy":"2024-07-13"}"#, Some(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case(r#"{"dmy":" 13 July 2024 "}"#, Some(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case(r#"{"dmy":"Sat 13/07/2024"}"#, Some(NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()))]
+    #[case(r#"{"dmy":"13 July 2024 later"}"#, None)]
+    #[case(r#"{"dmy":"31 February 2024"}"#, None)]
+    #[case(r#"{"dmy":13}"#, None)]
+    fn test_en_dmy(#[case] json: &str, #[case] expected: Option<NaiveDate>) {
+        assert_eq!(
+            serde_json::from_str::<Event>(json)
+                .ok()
+                .map(|event| event.dmy),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_en_dmy_roundtrip() {
+        let event = Event {
+            dmy: NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+
+        assert_eq!(json, r#"{"dmy":"2024-07-13"}"#);
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+    }
+
+    #[test]
+    fn test_en_dmy_error() {
+        let error = serde_json::from_str::<Event>(r#"{"dmy":"31 February 2024"}"#).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("invalid date `31 February 2024`: "));
+    }
+
+    #[test]
+    fn test_en_mdy() {
+        #[derive(Deserialize)]
+        struct Event {
+            #[serde(with = "en_mdy")]
+            date: NaiveDate,
+        }
+
+        let event: Event = serde_json::from_str(r#"{"date":"07/13/2024"}"#).unwrap();
+
+        assert_eq!(event.date, NaiveDate::from_ymd_opt(2024, 7, 13).unwrap());
+    }
+
+    #[cfg(feature = "ru")]
+    #[test]
+    fn test_ru() {
+        #[derive(Deserialize)]
+        struct Event {
+            #[serde(with = "ru")]
+            date: NaiveDate,
+        }
+
+        let event: Event = serde_json::from_str(r#"{"date":"13 июля 2024 года"}"#).unwrap();
+
+        assert_eq!(event.date, NaiveDate::from_ymd_opt(2024, 7, 13).unwrap());
+    }
+}
@@ -0,0 +1,123 @@
+/// The streaming counterparts of the [`numeric`](crate::numeric) parsers,
+/// which return `nom::Err::Incomplete` if the result may change when more
+/// input arrives, see [`streaming`](crate::combinators::streaming).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom::Needed;
+/// use nom_date_parsers::streaming::numeric::dd_mm_y4;
+///
+/// assert_eq!(
+///     dd_mm_y4("13/07/2"),
+///     Err(nom::Err::Incomplete(Needed::Unknown))
+/// );
+/// assert_eq!(
+///     dd_mm_y4("13/07/2024 14:00")?,
+///     (" 14:00", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "numeric")]
+pub mod numeric {
+    use chrono::NaiveDate;
+
+    use crate::{combinators::streaming, context::ParserContext, numeric, types::IResult};
+
+    /// The streaming counterpart of the [`numeric::y4_mm_dd`] parser
+    pub fn y4_mm_dd(input: &str) -> IResult<&str, NaiveDate> {
+        streaming(numeric::y4_mm_dd)(input)
+    }
+
+    /// The streaming counterpart of the [`numeric::dd_mm_y4`] parser
+    pub fn dd_mm_y4(input: &str) -> IResult<&str, NaiveDate> {
+        streaming(numeric::dd_mm_y4)(input)
+    }
+
+    /// The streaming counterpart of the [`numeric::mm_dd_y4`] parser
+    pub fn mm_dd_y4(input: &str) -> IResult<&str, NaiveDate> {
+        streaming(numeric::mm_dd_y4)(input)
+    }
+
+    /// The streaming counterpart of the [`numeric::dmy`] parser
+    #[cfg(feature = "clock")]
+    pub fn dmy(input: &str) -> IResult<&str, NaiveDate> {
+        dmy_with(&ParserContext::default())(input)
+    }
+
+    /// The streaming counterpart of the [`numeric::dmy_with`] parser
+    pub fn dmy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+        move |input: &str| streaming(numeric::dmy_with(ctx))(input)
+    }
+
+    /// The streaming counterpart of the [`numeric::mdy`] parser
+    #[cfg(feature = "clock")]
+    pub fn mdy(input: &str) -> IResult<&str, NaiveDate> {
+        mdy_with(&ParserContext::default())(input)
+    }
+
+    /// The streaming counterpart of the [`numeric::mdy_with`] parser
+    pub fn mdy_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+        move |input: &str| streaming(numeric::mdy_with(ctx))(input)
+    }
+}
+
+/// The streaming counterparts of the [`quick`](crate::quick) parsers, see
+/// [`streaming`](crate::combinators::streaming).
+#[cfg(feature = "quick")]
+pub mod quick {
+    use chrono::NaiveDate;
+
+    use crate::{combinators::streaming, context::ParserContext, quick, types::IResult};
+
+    /// The streaming counterpart of the [`quick::bundle`] parser
+    #[cfg(feature = "clock")]
+    pub fn bundle(input: &str) -> IResult<&str, NaiveDate> {
+        bundle_with(&ParserContext::default())(input)
+    }
+
+    /// The streaming counterpart of the [`quick::bundle_with`] parser
+    pub fn bundle_with(ctx: &ParserContext) -> impl Fn(&str) -> IResult<&str, NaiveDate> + '_ {
+        move |input: &str| streaming(quick::bundle_with(ctx))(input)
+    }
+}
+
+#[cfg(all(test, feature = "numeric", feature = "quick"))]
+mod tests {
+    use chrono::NaiveDate;
+    use nom::Needed;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{context::ParserContext, error::Error, types::IResult};
+
+    fn ctx() -> ParserContext {
+        ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap())
+    }
+
+    #[rstest]
+    #[case("", Err(nom::Err::Incomplete(Needed::Unknown)))]
+    #[case("1", Err(nom::Err::Incomplete(Needed::Unknown)))]
+    #[case("13/", Err(nom::Err::Incomplete(Needed::Unknown)))]
+    #[case("13/07", Err(nom::Err::Incomplete(Needed::Unknown)))]
+    #[case("13/07/202", Err(nom::Err::Incomplete(Needed::Unknown)))]
+    #[case("13/07/2024", Err(nom::Err::Incomplete(Needed::Unknown)))]
+    #[case("13/07/2024\n", Ok(("\n", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("13/07 ", Ok((" ", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("31/02/2024 ", Err(nom::Err::Error(Error::NonExistentDate)))]
+    #[case("42 ", Err(nom::Err::Error(Error::DayOutOfRange { value: 42, input: "42 " })))]
+    fn test_dmy_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(numeric::dmy_with(&ctx())(input), expected);
+    }
+
+    #[rstest]
+    #[case("+", Err(nom::Err::Incomplete(Needed::Unknown)))]
+    #[case("+3", Err(nom::Err::Incomplete(Needed::Unknown)))]
+    #[case("+3 ", Ok((" ", NaiveDate::from_ymd_opt(2024, 7, 19).unwrap())))]
+    #[case("+2w,", Ok((",", NaiveDate::from_ymd_opt(2024, 7, 30).unwrap())))]
+    fn test_quick_bundle_with(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(quick::bundle_with(&ctx())(input), expected);
+    }
+}
@@ -0,0 +1,363 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, tag_no_case, take, take_while_m_n},
+    character::complete::{space0, space1},
+    combinator::{map_res, opt, value},
+    sequence::{pair, preceded},
+    Parser,
+};
+
+use crate::{error::Error, types::IResult};
+
+/// Recognizes either one or two digits of an `hour` part.
+///
+/// Accepts numbers in the range `00..=23`, otherwise returns
+/// [`Error::HourOutOfRange`].
+pub fn hour(input: &str) -> IResult<&str, u32> {
+    let (input, hour) = map_res(
+        take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+        |s: &str| s.parse(),
+    )(input)?;
+
+    if hour > 23 {
+        return Err(nom::Err::Error(Error::HourOutOfRange));
+    }
+    Ok((input, hour))
+}
+
+/// Recognizes two digits of a `minute` part.
+///
+/// Accepts numbers in the range `00..=59`, otherwise returns
+/// [`Error::MinuteOutOfRange`].
+pub fn minute(input: &str) -> IResult<&str, u32> {
+    let (input, minute) = map_res(take(2_u8), |s: &str| s.parse())(input)?;
+
+    if minute > 59 {
+        return Err(nom::Err::Error(Error::MinuteOutOfRange));
+    }
+    Ok((input, minute))
+}
+
+/// Recognizes two digits of a `second` part.
+///
+/// Accepts numbers in the range `00..=59`, otherwise returns
+/// [`Error::SecondOutOfRange`].
+pub fn second(input: &str) -> IResult<&str, u32> {
+    let (input, second) = map_res(take(2_u8), |s: &str| s.parse())(input)?;
+
+    if second > 59 {
+        return Err(nom::Err::Error(Error::SecondOutOfRange));
+    }
+    Ok((input, second))
+}
+
+/// Recognizes the time in the `hh:mm` or `hh:mm:ss` formats and returns the
+/// corresponding [`NaiveTime`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use nom_date_parsers::{error::Error, time::time};
+///
+/// assert_eq!(time("14:00")?.1, NaiveTime::from_hms_opt(14, 0, 0).unwrap());
+/// assert_eq!(
+///     time("9:05:30")?.1,
+///     NaiveTime::from_hms_opt(9, 5, 30).unwrap()
+/// );
+/// assert_eq!(time("24:00"), Err(nom::Err::Error(Error::HourOutOfRange)));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn time(input: &str) -> IResult<&str, NaiveTime> {
+    let (input, hour) = hour(input)?;
+    let (input, minute) = preceded(tag(":"), minute)(input)?;
+    let (input, second) = match opt(tag(":"))(input)? {
+        (input, Some(_)) => second(input)?,
+        (input, None) => (input, 0),
+    };
+
+    Ok((
+        input,
+        NaiveTime::from_hms_opt(hour, minute, second)
+            .ok_or(nom::Err::Error(Error::HourOutOfRange))?,
+    ))
+}
+
+/// Recognizes the `12-hour` clock time in the `h:mm am` or `h:mm:ss pm`
+/// formats (the space before the `am`/`pm` suffix is optional, the
+/// `case insensitive` `a.m.`/`p.m.` suffixes are accepted as well) and returns
+/// the corresponding [`NaiveTime`].
+///
+/// Accepts hours in the range `1..=12`, otherwise returns
+/// [`Error::HourOutOfRange`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use nom_date_parsers::time::time_12h;
+///
+/// assert_eq!(
+///     time_12h("2:30 pm")?.1,
+///     NaiveTime::from_hms_opt(14, 30, 0).unwrap()
+/// );
+/// assert_eq!(
+///     time_12h("12:05AM")?.1,
+///     NaiveTime::from_hms_opt(0, 5, 0).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn time_12h(input: &str) -> IResult<&str, NaiveTime> {
+    let (input, hour) = hour(input)?;
+    let (input, minute) = preceded(tag(":"), minute)(input)?;
+    let (input, second) = match opt(tag(":"))(input)? {
+        (input, Some(_)) => second(input)?,
+        (input, None) => (input, 0),
+    };
+    let (input, pm) = preceded(
+        space0,
+        alt((
+            value(false, alt((tag_no_case("am"), tag_no_case("a.m.")))),
+            value(true, alt((tag_no_case("pm"), tag_no_case("p.m.")))),
+        )),
+    )(input)?;
+
+    if hour == 0 || hour > 12 {
+        return Err(nom::Err::Error(Error::HourOutOfRange));
+    }
+
+    Ok((
+        input,
+        NaiveTime::from_hms_opt(hour % 12 + if pm { 12 } else { 0 }, minute, second)
+            .ok_or(nom::Err::Error(Error::HourOutOfRange))?,
+    ))
+}
+
+/// Recognizes either the `12-hour` clock time using the [`time_12h`] parser or
+/// the `24-hour` clock time using the [`time`] parser.
+pub fn any_time(input: &str) -> IResult<&str, NaiveTime> {
+    alt((time_12h, time))(input)
+}
+
+/// Recognizes a separator between the date and the time: either the `T`
+/// symbol, or the `comma` symbol followed by any number of spaces, or at least
+/// one space.
+pub fn date_time_separator(input: &str) -> IResult<&str, ()> {
+    alt((
+        value((), tag_no_case("t")),
+        value((), pair(tag(","), space0)),
+        value((), space1),
+    ))(input)
+}
+
+/// Recognizes the time using the [`any_time`] parser preceded by the
+/// [`date_time_separator`], which usually follows the date, e.g. ` 14:00` in
+/// the `13/07/2024 14:00`.
+pub fn trailing_time(input: &str) -> IResult<&str, NaiveTime> {
+    preceded(date_time_separator, any_time)(input)
+}
+
+/// Creates a parser which recognizes the date using the specified `parser`
+/// followed by the optional [`trailing_time`] and returns the corresponding
+/// [`NaiveDateTime`]. If the time is absent, the midnight is used.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::{numeric::dd_mm_y4, time::date_time};
+///
+/// assert_eq!(
+///     date_time(dd_mm_y4)("13/07/2024 14:00")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(14, 0, 0)
+///         .unwrap()
+/// );
+/// assert_eq!(
+///     date_time(dd_mm_y4)("13/07/2024")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(0, 0, 0)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date_time<'a, P>(mut parser: P) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDateTime>
+where
+    P: Parser<&'a str, NaiveDate, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (input, date) = parser.parse(input)?;
+        let (input, time) = opt(trailing_time)(input)?;
+
+        Ok((input, date.and_time(time.unwrap_or(NaiveTime::MIN))))
+    }
+}
+
+/// Creates a parser which recognizes the date using the specified `parser`
+/// followed by the mandatory [`trailing_time`] and returns the corresponding
+/// [`NaiveDateTime`].
+pub fn date_and_time<'a, P>(mut parser: P) -> impl FnMut(&'a str) -> IResult<&'a str, NaiveDateTime>
+where
+    P: Parser<&'a str, NaiveDate, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (input, date) = parser.parse(input)?;
+        let (input, time) = trailing_time(input)?;
+
+        Ok((input, date.and_time(time)))
+    }
+}
+
+/// Recognizes the date using the [`dd_mm_y4`](crate::numeric::dd_mm_y4) parser
+/// followed by the [`trailing_time`] and returns the corresponding
+/// [`NaiveDateTime`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::time::dd_mm_y4_hh_mm;
+///
+/// assert_eq!(
+///     dd_mm_y4_hh_mm("13/07/2024 2:30 pm")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(14, 30, 0)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "numeric")]
+pub fn dd_mm_y4_hh_mm(input: &str) -> IResult<&str, NaiveDateTime> {
+    date_and_time(crate::numeric::dd_mm_y4)(input)
+}
+
+/// Recognizes the date using the [`mm_dd_y4`](crate::numeric::mm_dd_y4) parser
+/// followed by the [`trailing_time`] and returns the corresponding
+/// [`NaiveDateTime`].
+#[cfg(feature = "numeric")]
+pub fn mm_dd_y4_hh_mm(input: &str) -> IResult<&str, NaiveDateTime> {
+    date_and_time(crate::numeric::mm_dd_y4)(input)
+}
+
+/// Recognizes the date using the [`y4_mm_dd`](crate::numeric::y4_mm_dd) parser
+/// followed by the [`trailing_time`] and returns the corresponding
+/// [`NaiveDateTime`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::time::y4_mm_dd_hh_mm;
+///
+/// assert_eq!(
+///     y4_mm_dd_hh_mm("2024-07-13T14:30:15")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(14, 30, 15)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "numeric")]
+pub fn y4_mm_dd_hh_mm(input: &str) -> IResult<&str, NaiveDateTime> {
+    date_and_time(crate::numeric::y4_mm_dd)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::numeric::dd_mm_y4;
+
+    use super::*;
+
+    #[rstest]
+    #[case("14:00", Ok(("", NaiveTime::from_hms_opt(14, 0, 0).unwrap())))]
+    #[case("9:05", Ok(("", NaiveTime::from_hms_opt(9, 5, 0).unwrap())))]
+    #[case("23:59:59 ", Ok((" ", NaiveTime::from_hms_opt(23, 59, 59).unwrap())))]
+    #[case("24:00", Err(nom::Err::Error(Error::HourOutOfRange)))]
+    #[case("12:60", Err(nom::Err::Error(Error::MinuteOutOfRange)))]
+    #[case("12:00:60", Err(nom::Err::Error(Error::SecondOutOfRange)))]
+    #[case("12", Err(nom::Err::Error(Error::Nom("", ErrorKind::Tag))))]
+    fn test_time(#[case] input: &str, #[case] expected: IResult<&str, NaiveTime>) {
+        assert_eq!(time(input), expected);
+    }
+
+    #[rstest]
+    #[case("2:30 pm", Ok(("", NaiveTime::from_hms_opt(14, 30, 0).unwrap())))]
+    #[case("2:30PM", Ok(("", NaiveTime::from_hms_opt(14, 30, 0).unwrap())))]
+    #[case("11:59:59 p.m.", Ok(("", NaiveTime::from_hms_opt(23, 59, 59).unwrap())))]
+    #[case("12:00 pm", Ok(("", NaiveTime::from_hms_opt(12, 0, 0).unwrap())))]
+    #[case("12:00 am", Ok(("", NaiveTime::from_hms_opt(0, 0, 0).unwrap())))]
+    #[case("9:15 A.M.", Ok(("", NaiveTime::from_hms_opt(9, 15, 0).unwrap())))]
+    #[case("13:00 pm", Err(nom::Err::Error(Error::HourOutOfRange)))]
+    #[case("0:30 am", Err(nom::Err::Error(Error::HourOutOfRange)))]
+    #[case("2:30", Err(nom::Err::Error(Error::Nom("", ErrorKind::Tag))))]
+    fn test_time_12h(#[case] input: &str, #[case] expected: IResult<&str, NaiveTime>) {
+        assert_eq!(time_12h(input), expected);
+    }
+
+    #[rstest]
+    #[case(" 14:00", Ok(("", NaiveTime::from_hms_opt(14, 0, 0).unwrap())))]
+    #[case(" 2:00 pm", Ok(("", NaiveTime::from_hms_opt(14, 0, 0).unwrap())))]
+    #[case("T14:00:30", Ok(("", NaiveTime::from_hms_opt(14, 0, 30).unwrap())))]
+    #[case(", 14:00", Ok(("", NaiveTime::from_hms_opt(14, 0, 0).unwrap())))]
+    fn test_trailing_time(#[case] input: &str, #[case] expected: IResult<&str, NaiveTime>) {
+        assert_eq!(trailing_time(input), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "13/07/2024 14:00",
+        Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 0, 0).unwrap()))
+    )]
+    #[case(
+        "13/07/2024",
+        Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(0, 0, 0).unwrap()))
+    )]
+    #[case(
+        "13/07/2024 later",
+        Ok((" later", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(0, 0, 0).unwrap()))
+    )]
+    fn test_date_time(#[case] input: &str, #[case] expected: IResult<&str, NaiveDateTime>) {
+        assert_eq!(date_time(dd_mm_y4)(input), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "13/07/2024 14:00",
+        Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 0, 0).unwrap()))
+    )]
+    #[case(
+        "13.07.2024, 9:05 am",
+        Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(9, 5, 0).unwrap()))
+    )]
+    #[case("13/07/2024", Err(nom::Err::Error(Error::Nom("", ErrorKind::Space))))]
+    fn test_dd_mm_y4_hh_mm(#[case] input: &str, #[case] expected: IResult<&str, NaiveDateTime>) {
+        assert_eq!(dd_mm_y4_hh_mm(input), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "07/13/2024 14:00",
+        Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 0, 0).unwrap()))
+    )]
+    fn test_mm_dd_y4_hh_mm(#[case] input: &str, #[case] expected: IResult<&str, NaiveDateTime>) {
+        assert_eq!(mm_dd_y4_hh_mm(input), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "2024-07-13T14:00:30",
+        Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap().and_hms_opt(14, 0, 30).unwrap()))
+    )]
+    fn test_y4_mm_dd_hh_mm(#[case] input: &str, #[case] expected: IResult<&str, NaiveDateTime>) {
+        assert_eq!(y4_mm_dd_hh_mm(input), expected);
+    }
+}
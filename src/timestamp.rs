@@ -0,0 +1,156 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{digit1, satisfy},
+    combinator::{map, map_res, not, opt, recognize},
+    sequence::{pair, preceded, terminated},
+};
+
+use crate::{error::Error, types::IResult};
+
+/// The resolution of the recognized Unix timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Resolution {
+    Seconds,
+    Millis,
+}
+
+/// Creates a parser which recognizes the run of exactly `len` ascii digits,
+/// which is not followed by another digit, as the number.
+fn digit_run(len: usize) -> impl Fn(&str) -> IResult<&str, i64> {
+    move |input: &str| {
+        map_res(
+            terminated(
+                take_while_m_n(len, len, |c: char| c.is_ascii_digit()),
+                not(satisfy(|c| c.is_ascii_digit())),
+            ),
+            |s: &str| s.parse(),
+        )(input)
+    }
+}
+
+/// Recognizes the `@` symbol followed by the optionally negative number of
+/// seconds (e.g. `@1720828800`), the bare 13-digit number of milliseconds or
+/// the bare 10-digit number of seconds since the Unix epoch.
+fn epoch(input: &str) -> IResult<&str, (i64, Resolution)> {
+    alt((
+        map(
+            preceded(
+                tag("@"),
+                map_res(recognize(pair(opt(tag("-")), digit1)), |s: &str| {
+                    s.parse::<i64>()
+                }),
+            ),
+            |seconds| (seconds, Resolution::Seconds),
+        ),
+        map(digit_run(13), |millis| (millis, Resolution::Millis)),
+        map(digit_run(10), |seconds| (seconds, Resolution::Seconds)),
+    ))(input)
+}
+
+/// Recognizes the Unix timestamp and returns the corresponding
+/// [`NaiveDateTime`] in `UTC`.
+///
+/// The following forms are accepted:
+/// - `@` followed by the number of seconds, e.g. `@1720828800` or `@-86400`
+/// - exactly 10 digits of seconds, e.g. `1720828800`
+/// - exactly 13 digits of milliseconds, e.g. `1720828800123`
+///
+/// Returns [`Error::DateOutOfRange`] if the timestamp is out of the range of
+/// [`NaiveDateTime`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::timestamp::date_time;
+///
+/// assert_eq!(
+///     date_time("@1720828800")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(0, 0, 0)
+///         .unwrap()
+/// );
+/// assert_eq!(
+///     date_time("1720873800250")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13)
+///         .unwrap()
+///         .and_hms_milli_opt(12, 30, 0, 250)
+///         .unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date_time(input: &str) -> IResult<&str, NaiveDateTime> {
+    let (input, (value, resolution)) = epoch(input)?;
+    let date_time = match resolution {
+        Resolution::Seconds => DateTime::from_timestamp(value, 0),
+        Resolution::Millis => DateTime::from_timestamp_millis(value),
+    };
+
+    Ok((
+        input,
+        date_time
+            .ok_or(nom::Err::Error(Error::DateOutOfRange))?
+            .naive_utc(),
+    ))
+}
+
+/// Recognizes the Unix timestamp using the [`date_time`] parser and returns
+/// the corresponding [`NaiveDate`] in `UTC`, the time is discarded.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nom_date_parsers::timestamp::date;
+///
+/// assert_eq!(
+///     date("1720828800")?.1,
+///     NaiveDate::from_ymd_opt(2024, 7, 13).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn date(input: &str) -> IResult<&str, NaiveDate> {
+    map(date_time, |date_time| date_time.date())(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ndt(year: i32, month: u32, day: u32, hour: u32, minute: u32, milli: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_milli_opt(hour, minute, 0, milli)
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case("@1720828800", Ok(("", ndt(2024, 7, 13, 0, 0, 0))))]
+    #[case("@0", Ok(("", ndt(1970, 1, 1, 0, 0, 0))))]
+    #[case("@-86400", Ok(("", ndt(1969, 12, 31, 0, 0, 0))))]
+    #[case("1720873800", Ok(("", ndt(2024, 7, 13, 12, 30, 0))))]
+    #[case("1720873800250 ok", Ok((" ok", ndt(2024, 7, 13, 12, 30, 250))))]
+    #[case("@99999999999999999", Err(nom::Err::Error(Error::DateOutOfRange)))]
+    #[case("17208738001", Err(nom::Err::Error(Error::Nom("1", ErrorKind::Not))))]
+    #[case(
+        "172087380",
+        Err(nom::Err::Error(Error::Nom("172087380", ErrorKind::TakeWhileMN)))
+    )]
+    fn test_date_time(#[case] input: &str, #[case] expected: IResult<&str, NaiveDateTime>) {
+        assert_eq!(date_time(input), expected);
+    }
+
+    #[rstest]
+    #[case("1720828800", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    #[case("@1720915199", Ok(("", NaiveDate::from_ymd_opt(2024, 7, 13).unwrap())))]
+    fn test_date(#[case] input: &str, #[case] expected: IResult<&str, NaiveDate>) {
+        assert_eq!(date(input), expected);
+    }
+}
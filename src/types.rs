@@ -1,3 +1,3 @@
 use crate::error::Error;
 
-pub type IResult<I, O> = std::result::Result<(I, O), nom::Err<Error<I>>>;
+pub type IResult<I, O> = core::result::Result<(I, O), nom::Err<Error<I>>>;
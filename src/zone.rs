@@ -0,0 +1,290 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, satisfy, space0},
+    combinator::{map_res, not, opt, value},
+    sequence::{pair, preceded, terminated},
+    Parser,
+};
+
+use crate::{combinators::tag_no_case, error::Error, types::IResult};
+
+/// Creates a parser which recognizes from `min` to `max` ascii digits as the
+/// number.
+fn digits(min: usize, max: usize) -> impl Fn(&str) -> IResult<&str, i32> {
+    move |input: &str| {
+        map_res(
+            take_while_m_n(min, max, |c: char| c.is_ascii_digit()),
+            |s: &str| s.parse(),
+        )(input)
+    }
+}
+
+/// Creates the [`FixedOffset`] for the specified number of minutes east of
+/// `UTC`.
+fn east_minutes<'a>(minutes: i32) -> Result<FixedOffset, nom::Err<Error<&'a str>>> {
+    FixedOffset::east_opt(minutes * 60).ok_or(nom::Err::Error(Error::OffsetOutOfRange))
+}
+
+/// Recognizes the numeric offset from `UTC`: `±hh:mm`, `±hhmm`, `±hh` or `±h`
+/// (e.g. `+03:00`, `-0530` or `+3`) and returns the corresponding
+/// [`FixedOffset`].
+///
+/// Accepts offsets up to `±23:59`, otherwise returns
+/// [`Error::OffsetOutOfRange`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use nom_date_parsers::{error::Error, zone::offset};
+///
+/// assert_eq!(
+///     offset("+03:00")?.1,
+///     FixedOffset::east_opt(3 * 3600).unwrap()
+/// );
+/// assert_eq!(offset("-5")?.1, FixedOffset::west_opt(5 * 3600).unwrap());
+/// assert_eq!(
+///     offset("+24:00"),
+///     Err(nom::Err::Error(Error::OffsetOutOfRange))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn offset(input: &str) -> IResult<&str, FixedOffset> {
+    let (input, sign) = alt((
+        value(1, char('+')),
+        value(-1, alt((char('-'), char('\u{2212}')))),
+    ))(input)?;
+    let (input, (hours, minutes)) =
+        pair(digits(1, 2), opt(preceded(opt(tag(":")), digits(2, 2))))(input)?;
+    let minutes = minutes.unwrap_or(0);
+    if hours > 23 || minutes > 59 {
+        return Err(nom::Err::Error(Error::OffsetOutOfRange));
+    }
+
+    Ok((input, east_minutes(sign * (hours * 60 + minutes))?))
+}
+
+/// Recognizes the `case insensitive` abbreviation of the time zone and returns
+/// the corresponding [`FixedOffset`].
+///
+/// The following abbreviations are accepted:
+/// - `UTC` | `UT` | `GMT` | `Z` | `WET` -> `+00:00`
+/// - `BST` | `CET` | `WEST` -> `+01:00`
+/// - `CEST` | `EET` -> `+02:00`
+/// - `EEST` | `MSK` -> `+03:00`
+/// - `JST` -> `+09:00`
+/// - `EST` -> `-05:00`, `EDT` -> `-04:00`
+/// - `CST` -> `-06:00`, `CDT` -> `-05:00`
+/// - `MST` -> `-07:00`, `MDT` -> `-06:00`
+/// - `PST` -> `-08:00`, `PDT` -> `-07:00`
+/// - `AKST` -> `-09:00`, `AKDT` -> `-08:00`
+/// - `HST` -> `-10:00`
+///
+/// The abbreviation must not be followed by a letter, so `Zulu` or `ESTONIA`
+/// aren't recognized.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use nom_date_parsers::zone::abbreviation;
+///
+/// assert_eq!(
+///     abbreviation("MSK")?.1,
+///     FixedOffset::east_opt(3 * 3600).unwrap()
+/// );
+/// assert_eq!(
+///     abbreviation("est")?.1,
+///     FixedOffset::west_opt(5 * 3600).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn abbreviation(input: &str) -> IResult<&str, FixedOffset> {
+    let (input, hours) = terminated(
+        alt((
+            alt((
+                value(0, tag_no_case("utc")),
+                value(0, tag_no_case("ut")),
+                value(0, tag_no_case("gmt")),
+                value(0, tag_no_case("z")),
+                value(0, tag_no_case("wet")),
+                value(1, tag_no_case("west")),
+                value(1, tag_no_case("bst")),
+                value(1, tag_no_case("cet")),
+                value(2, tag_no_case("cest")),
+                value(2, tag_no_case("eet")),
+                value(3, tag_no_case("eest")),
+                value(3, tag_no_case("msk")),
+                value(9, tag_no_case("jst")),
+            )),
+            alt((
+                value(-5, tag_no_case("est")),
+                value(-4, tag_no_case("edt")),
+                value(-6, tag_no_case("cst")),
+                value(-5, tag_no_case("cdt")),
+                value(-7, tag_no_case("mst")),
+                value(-6, tag_no_case("mdt")),
+                value(-8, tag_no_case("pst")),
+                value(-7, tag_no_case("pdt")),
+                value(-9, tag_no_case("akst")),
+                value(-8, tag_no_case("akdt")),
+                value(-10, tag_no_case("hst")),
+            )),
+        )),
+        not(satisfy(char::is_alphabetic)),
+    )(input)?;
+
+    Ok((input, east_minutes(hours * 60)?))
+}
+
+/// Recognizes the time zone specifier: the numeric [`offset`] optionally
+/// prefixed with `UTC` or `GMT` (e.g. `UTC+3` or `GMT-05:00`), or the
+/// [`abbreviation`] of the time zone, and returns the corresponding
+/// [`FixedOffset`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use nom_date_parsers::zone::zone;
+///
+/// assert_eq!(zone("UTC+3")?.1, FixedOffset::east_opt(3 * 3600).unwrap());
+/// assert_eq!(zone("UTC")?.1, FixedOffset::east_opt(0).unwrap());
+/// assert_eq!(
+///     zone("-05:30")?.1,
+///     FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn zone(input: &str) -> IResult<&str, FixedOffset> {
+    alt((
+        preceded(alt((tag_no_case("utc"), tag_no_case("gmt"))), offset),
+        offset,
+        abbreviation,
+    ))(input)
+}
+
+/// Recognizes the [`zone`] preceded by any number of spaces, which usually
+/// follows the date or the time, e.g. ` MSK` in the `13/07/2024 14:00 MSK`.
+pub fn trailing_zone(input: &str) -> IResult<&str, FixedOffset> {
+    preceded(space0, zone)(input)
+}
+
+/// Creates a parser which recognizes the date and time using the specified
+/// `parser` followed by the mandatory [`trailing_zone`] and returns the
+/// corresponding [`DateTime<FixedOffset>`].
+///
+/// Combine it with the [`time::date_time`](crate::time::date_time) or the
+/// `date_time_bundle` parsers to recognize the dates with the time zones.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::DateTime;
+/// use nom_date_parsers::{numeric::dd_mm_y4, time::date_time, zone::with_offset};
+///
+/// assert_eq!(
+///     with_offset(date_time(dd_mm_y4))("13/07/2024 14:00 MSK")?.1,
+///     DateTime::parse_from_rfc3339("2024-07-13T14:00:00+03:00").unwrap()
+/// );
+/// assert_eq!(
+///     with_offset(date_time(dd_mm_y4))("13/07/2024 UTC")?.1,
+///     DateTime::parse_from_rfc3339("2024-07-13T00:00:00Z").unwrap()
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn with_offset<'a, P>(
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DateTime<FixedOffset>>
+where
+    P: Parser<&'a str, NaiveDateTime, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (input, date_time) = parser.parse(input)?;
+        let (input, offset) = trailing_zone(input)?;
+
+        Ok((
+            input,
+            date_time
+                .and_local_timezone(offset)
+                .single()
+                .ok_or(nom::Err::Error(Error::DateOutOfRange))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn east(hours: i32, minutes: i32) -> FixedOffset {
+        FixedOffset::east_opt(hours * 3600 + minutes * 60).unwrap()
+    }
+
+    #[rstest]
+    #[case("+03:00", Ok(("", east(3, 0))))]
+    #[case("+0545", Ok(("", east(5, 45))))]
+    #[case("-08", Ok(("", east(-8, 0))))]
+    #[case("+3", Ok(("", east(3, 0))))]
+    #[case("\u{2212}02:30", Ok(("", east(-2, -30))))]
+    #[case("+24:00", Err(nom::Err::Error(Error::OffsetOutOfRange)))]
+    #[case("+03:60", Err(nom::Err::Error(Error::OffsetOutOfRange)))]
+    #[case("03:00", Err(nom::Err::Error(Error::Nom("03:00", ErrorKind::Char))))]
+    fn test_offset(#[case] input: &str, #[case] expected: IResult<&str, FixedOffset>) {
+        assert_eq!(offset(input), expected);
+    }
+
+    #[rstest]
+    #[case("UTC", Ok(("", east(0, 0))))]
+    #[case("gmt", Ok(("", east(0, 0))))]
+    #[case("Z", Ok(("", east(0, 0))))]
+    #[case("MSK", Ok(("", east(3, 0))))]
+    #[case("CEST)", Ok((")", east(2, 0))))]
+    #[case("EEST", Ok(("", east(3, 0))))]
+    #[case("EST", Ok(("", east(-5, 0))))]
+    #[case("PDT", Ok(("", east(-7, 0))))]
+    #[case("AKST", Ok(("", east(-9, 0))))]
+    #[case("Zulu", Err(nom::Err::Error(Error::Nom("ulu", ErrorKind::Not))))]
+    fn test_abbreviation(#[case] input: &str, #[case] expected: IResult<&str, FixedOffset>) {
+        assert_eq!(abbreviation(input), expected);
+    }
+
+    #[rstest]
+    #[case("UTC+3", Ok(("", east(3, 0))))]
+    #[case("GMT-05:00", Ok(("", east(-5, 0))))]
+    #[case("UTC", Ok(("", east(0, 0))))]
+    #[case("+03:00", Ok(("", east(3, 0))))]
+    #[case("msk", Ok(("", east(3, 0))))]
+    fn test_zone(#[case] input: &str, #[case] expected: IResult<&str, FixedOffset>) {
+        assert_eq!(zone(input), expected);
+    }
+
+    #[cfg(feature = "numeric")]
+    #[rstest]
+    #[case("13/07/2024 14:00 MSK", "2024-07-13T14:00:00+03:00")]
+    #[case("13/07/2024 14:00+05:30", "2024-07-13T14:00:00+05:30")]
+    #[case("13/07/2024 2:30 pm EST", "2024-07-13T14:30:00-05:00")]
+    #[case("13/07/2024 UTC", "2024-07-13T00:00:00+00:00")]
+    fn test_with_offset(#[case] input: &str, #[case] expected: &str) {
+        use crate::{numeric::dd_mm_y4, time::date_time};
+
+        assert_eq!(
+            with_offset(date_time(dd_mm_y4))(input),
+            Ok(("", DateTime::parse_from_rfc3339(expected).unwrap()))
+        );
+    }
+
+    #[cfg(feature = "numeric")]
+    #[test]
+    fn test_with_offset_requires_zone() {
+        use crate::{numeric::dd_mm_y4, time::date_time};
+
+        assert!(with_offset(date_time(dd_mm_y4))("13/07/2024 14:00").is_err());
+    }
+}
@@ -0,0 +1,115 @@
+//! Runs the real-world inputs collected in the `tests/corpus/<locale>.tsv`
+//! files through the parsers of the corresponding locale.
+//!
+//! Every non-empty line of the file which doesn't start with `#` contains three
+//! tab-separated columns:
+//! - the input as the user wrote it (without tabs)
+//! - the reference date (`today`) in the `YYYY-MM-DD` format
+//! - the expected date in the `YYYY-MM-DD` format or `-` if the input must be
+//!   rejected
+//!
+//! To add a sample append the line to the file of the locale, the file of the
+//! new locale also has to be registered in the [`LOCALES`].
+
+use std::{fs, path::Path};
+
+use chrono::NaiveDate;
+use nom_date_parsers::context::ParserContext;
+
+/// Recognizes the whole input in the specified context.
+type ParseFn = fn(&ParserContext, &str) -> Option<NaiveDate>;
+
+/// The locales with the corpus file and the parser used to recognize it.
+const LOCALES: &[(&str, ParseFn)] = &[
+    #[cfg(all(feature = "numeric", feature = "quick"))]
+    ("numeric", |ctx, input| {
+        nom_date_parsers::parse_date_with(ctx)(input).ok()
+    }),
+    #[cfg(all(feature = "numeric", feature = "quick", feature = "en"))]
+    ("en", |ctx, input| {
+        nom_date_parsers::parse_date_en_with(ctx)(input).ok()
+    }),
+    #[cfg(all(feature = "numeric", feature = "quick", feature = "ru"))]
+    ("ru", |ctx, input| {
+        nom_date_parsers::parse_date_ru_with(ctx)(input).ok()
+    }),
+    #[cfg(feature = "de")]
+    ("de", |ctx, input| {
+        let ctx = ctx
+            .clone()
+            .with_trailing_input(nom_date_parsers::context::TrailingInput::RequireEnd);
+        let date = nom_date_parsers::i18n::de::bundle_with(&ctx)(input).ok()?.1;
+        Some(date)
+    }),
+];
+
+/// A line of the corpus file.
+struct Sample<'a> {
+    line: usize,
+    input: &'a str,
+    today: NaiveDate,
+    expected: Option<NaiveDate>,
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|e| format!("invalid date `{value}`: {e}"))
+}
+
+fn samples(content: &str) -> Result<Vec<Sample<'_>>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let line_number = index + 1;
+            let columns: Vec<&str> = line.split('\t').collect();
+            let [input, today, expected] = columns[..] else {
+                return Err(format!(
+                    "line {line_number}: expected 3 tab-separated columns, found {}",
+                    columns.len()
+                ));
+            };
+            let today = parse_date(today).map_err(|e| format!("line {line_number}: {e}"))?;
+            let expected = match expected.trim() {
+                "-" => None,
+                expected => {
+                    Some(parse_date(expected).map_err(|e| format!("line {line_number}: {e}"))?)
+                }
+            };
+
+            Ok(Sample {
+                line: line_number,
+                input,
+                today,
+                expected,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_corpus() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut failures = Vec::new();
+
+    for (locale, parse) in LOCALES {
+        let path = dir.join(format!("{locale}.tsv"));
+        let content = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("can't read {}: {e}", path.display()));
+        let samples = samples(&content).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+
+        for sample in samples {
+            let ctx = ParserContext::new().with_today(sample.today);
+            let actual = parse(&ctx, sample.input);
+
+            if actual != sample.expected {
+                failures.push(format!(
+                    "{locale}.tsv:{}: `{}` (today is {}): expected {:?}, got {actual:?}",
+                    sample.line, sample.input, sample.today, sample.expected
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
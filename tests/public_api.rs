@@ -0,0 +1,439 @@
+//! Pins the public API of the crate: the signatures of the free-function
+//! parsers, the items re-exported by the `prelude` and the variants of the
+//! [`Error`].
+//!
+//! If this file stops compiling after a change, the change breaks the existing
+//! users and must be reflected in the `Changed` section of the `CHANGELOG.md`
+//! (and in the version number). The signatures are checked by coercing the
+//! parsers to the function pointers, so adding new parsers doesn't require
+//! updating this file.
+
+use chrono::{Month, NaiveDate, Weekday};
+use nom_date_parsers::{
+    calendar::{MonthOverflow, ResolutionPolicy},
+    context::{ParserContext, Separator, SeparatorPolicy, YearRange},
+    error::Error,
+    types::IResult,
+};
+
+type DateFn = fn(&str) -> IResult<&str, NaiveDate>;
+type WeekdayFn = fn(&str) -> IResult<&str, Weekday>;
+type MonthFn = fn(&str) -> IResult<&str, Month>;
+
+/// Checks that every listed item can be coerced to the specified function
+/// pointer type.
+macro_rules! assert_signature {
+    ($ty:ty: $($item:path),+ $(,)?) => {
+        $(let _: $ty = $item;)+
+    };
+}
+
+/// Checks that the parser created by the `_with` parser generator recognizes
+/// the date.
+fn assert_date_parser(_: impl Fn(&str) -> IResult<&str, NaiveDate>) {}
+
+#[test]
+fn test_error_variants() {
+    // Adding a variant breaks the exhaustive `match` in the user code.
+    fn variant_name(error: Error<&str>) -> &'static str {
+        match error {
+            Error::DayOutOfRange { .. } => "DayOutOfRange",
+            Error::MonthOutOfRange { .. } => "MonthOutOfRange",
+            Error::YearOutOfRange { .. } => "YearOutOfRange",
+            Error::WeekOutOfRange => "WeekOutOfRange",
+            Error::DayOfYearOutOfRange => "DayOfYearOutOfRange",
+            Error::NonExistentDate => "NonExistentDate",
+            Error::DayOutOfMonth { .. } => "DayOutOfMonth",
+            Error::DateOutOfRange => "DateOutOfRange",
+            Error::WeekdayMismatch => "WeekdayMismatch",
+            Error::ReversedRange => "ReversedRange",
+            Error::HourOutOfRange => "HourOutOfRange",
+            Error::MinuteOutOfRange => "MinuteOutOfRange",
+            Error::SecondOutOfRange => "SecondOutOfRange",
+            Error::OffsetOutOfRange => "OffsetOutOfRange",
+            Error::InputTooLong => "InputTooLong",
+            Error::ParseIntError(..) => "ParseIntError",
+            Error::Context(..) => "Context",
+            Error::Nom(..) => "Nom",
+        }
+    }
+
+    assert_eq!(variant_name(Error::NonExistentDate), "NonExistentDate");
+}
+
+#[test]
+fn test_error_traits() {
+    let error: Box<dyn std::error::Error + Send + Sync> =
+        Box::new(Error::Nom("x", nom::error::ErrorKind::Tag).to_owned());
+
+    assert!(!error.to_string().is_empty());
+}
+
+#[test]
+fn test_context() {
+    let ctx = ParserContext::new().with_today(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+
+    assert_eq!(ctx.today(), NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+    assert_eq!(
+        ParserContext::default().today(),
+        chrono::Local::now().date_naive()
+    );
+    assert_eq!(
+        ctx.with_month_overflow(MonthOverflow::Roll)
+            .month_overflow(),
+        MonthOverflow::Roll
+    );
+    assert_eq!(
+        ParserContext::fixed(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()).today(),
+        NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()
+    );
+    assert_eq!(
+        ParserContext::new()
+            .with_separators(SeparatorPolicy::only(&[Separator::Dot]))
+            .separators(),
+        SeparatorPolicy::only(&[Separator::Dot])
+    );
+    assert_eq!(
+        ParserContext::new()
+            .with_resolution(ResolutionPolicy::NearestFuture)
+            .resolution(),
+        ResolutionPolicy::NearestFuture
+    );
+    assert_eq!(
+        ParserContext::new()
+            .with_year_range(YearRange::any())
+            .year_range(),
+        YearRange::any()
+    );
+    let _: Option<NaiveDate> = ParserContext::new().resolve(Some(7), 13);
+}
+
+mod calendar {
+    use nom_date_parsers::calendar::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(fn(NaiveDate, i64, MonthOverflow) -> Option<NaiveDate>: add_months);
+        assert_signature!(fn(NaiveDate, i64, Period, MonthOverflow) -> Option<NaiveDate>: shift);
+        assert_signature!(fn(NaiveDate, Period) -> Option<NaiveDate>: start_of_period, end_of_period);
+        assert_signature!(fn(NaiveDate, MonthPart) -> Option<NaiveDate>: part_of_month);
+        assert_signature!(fn(NaiveDate, Weekday, i8) -> Option<NaiveDate>: nth_weekday_of_month);
+        assert_signature!(fn(NaiveDate, Option<u32>, u32, ResolutionPolicy) -> Option<NaiveDate>: resolve);
+        assert_eq!(MonthOverflow::default(), MonthOverflow::Clamp);
+        assert_eq!(ResolutionPolicy::default(), ResolutionPolicy::CurrentPeriod);
+    }
+}
+
+mod parse {
+    use nom_date_parsers::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(fn(&str) -> Result<NaiveDate, Error<&str>>: parse_date);
+        #[cfg(feature = "en")]
+        assert_signature!(fn(&str) -> Result<NaiveDate, Error<&str>>: parse_date_en);
+        #[cfg(feature = "ru")]
+        assert_signature!(fn(&str) -> Result<NaiveDate, Error<&str>>: parse_date_ru);
+    }
+}
+
+#[cfg(feature = "numeric")]
+mod prelude {
+    use nom_date_parsers::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(DateFn: dd_only, dd_mm_only, mm_dd_only, y4_mm_dd, dd_mm_y4, mm_dd_y4);
+    }
+
+    #[test]
+    fn test_prelude() {
+        let ctx: ParserContext = ParserContext::new();
+        let error: Error<&str> = Error::NonExistentDate;
+
+        assert_date_parser(dd_only_with(&ctx));
+        assert_eq!(dd_mm_y4("31/02/2024"), Err(nom::Err::Error(error)));
+    }
+}
+
+#[cfg(feature = "numeric")]
+mod numeric {
+    use nom_date_parsers::numeric::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(fn(&str) -> IResult<&str, ()>: numeric_date_parts_separator);
+        assert_signature!(fn(&str) -> IResult<&str, u32>: dd, mm, ww, ddd, y4);
+        assert_signature!(fn(&str) -> IResult<&str, (u32, u32)>: dd_mm, mm_dd);
+        assert_signature!(DateFn: dd_only, dd_mm_only, mm_dd_only, y4_mm_dd, dd_mm_y4, mm_dd_y4, iso_week, y4_ddd);
+        assert_signature!(DateFn: dd_mm_y2, mm_dd_y2, y2_mm_dd, dmy, mdy, smart_numeric, smart_numeric_mdy);
+        assert_signature!(DateFn: y4_mm_dd_strict, dd_mm_y4_strict, mm_dd_y4_strict);
+        assert_signature!(DateFn: y4mmdd, ddmmy4, mmddy4, y2mmdd);
+        assert!(checked_date(2024, 2, 29).is_ok());
+        let _ = bundle(DateOrder::Ymd);
+        let _: fn(&str) -> Vec<(DateOrder, NaiveDate)> = parse_ambiguous;
+    }
+
+    #[test]
+    fn test_with() {
+        let ctx = ParserContext::new();
+
+        assert_date_parser(dd_only_with(&ctx));
+        assert_date_parser(dd_mm_only_with(&ctx));
+        assert_date_parser(mm_dd_only_with(&ctx));
+        assert_date_parser(y4_mm_dd_with(&ctx));
+        assert_date_parser(dd_mm_y4_with(&ctx));
+        assert_date_parser(mm_dd_y4_with(&ctx));
+        assert_date_parser(dd_mm_y2_with(&ctx));
+        assert_date_parser(mm_dd_y2_with(&ctx));
+        assert_date_parser(y2_mm_dd_with(&ctx));
+        assert_date_parser(y2mmdd_with(&ctx));
+        assert_date_parser(dmy_with(&ctx));
+        assert_date_parser(mdy_with(&ctx));
+        assert_date_parser(smart_numeric_with(&ctx));
+        assert_date_parser(smart_numeric_mdy_with(&ctx));
+        assert_date_parser(bundle_with(&ctx, DateOrder::Dmy));
+        let _: Vec<(DateOrder, NaiveDate)> = parse_ambiguous_with(&ctx)("03/04");
+    }
+}
+
+#[cfg(feature = "quick")]
+mod quick {
+    use nom_date_parsers::quick::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(DateFn: forward_from_now, backward_from_now, bundle);
+    }
+
+    #[test]
+    fn test_with() {
+        let ctx = ParserContext::new();
+
+        assert_date_parser(forward_from_now_with(&ctx));
+        assert_date_parser(backward_from_now_with(&ctx));
+        assert_date_parser(bundle_with(&ctx));
+    }
+}
+
+#[cfg(feature = "numeric")]
+mod format {
+    use nom_date_parsers::{context::Separator, format::*, i18n::Language, numeric::DateOrder};
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(fn(NaiveDate, DateOrder, Separator) -> String: numeric);
+        assert_signature!(fn(NaiveDate, Language) -> Option<String>: named_month);
+        assert_signature!(fn(NaiveDate, NaiveDate, Language) -> String: relative);
+    }
+}
+
+mod extract {
+    use nom_date_parsers::extract::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(fn(&str, &Config) -> Option<Parsed>: extract_best_date, nom_date_parsers::extract_best_date);
+
+        let config = Config::new()
+            .with_context(ParserContext::new())
+            .with_languages(Language::all())
+            .with_min_confidence(50);
+        let _: (&ParserContext, &[Language], u8) = (
+            config.context(),
+            config.languages(),
+            config.min_confidence(),
+        );
+
+        if let Some(Parsed {
+            date,
+            span,
+            language,
+            confidence,
+        }) = extract_best_date("", &config)
+        {
+            let _: (NaiveDate, std::ops::Range<usize>, Option<Language>, u8) =
+                (date, span, language, confidence);
+        }
+    }
+
+    #[test]
+    fn test_language() {
+        use nom_date_parsers::{i18n, numeric::DateOrder};
+
+        let _: fn(&str) -> Option<Language> = Language::from_code;
+        let _: fn(Language) -> &'static str = Language::code;
+        let _: Option<i18n::Language> = Language::all().first().copied();
+
+        for language in Language::all() {
+            let _ = i18n::bundle_for(language, DateOrder::Dmy)("13/07/2024");
+        }
+    }
+}
+
+mod combinators {
+    use nom_date_parsers::combinators::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(fn(&str) -> IResult<&str, ()>: word_boundary, day_month_separator, weekday_separator, range_separator);
+        assert_signature!(fn(&str) -> IResult<&str, &str>: ordinal_suffix, bidi_marks);
+        #[cfg(feature = "numeric")]
+        assert_signature!(fn(&str) -> IResult<&str, ()>: numeric_date_parts_separator);
+
+        assert_eq!(tag_no_case("kw")("KW 29"), Ok((" 29", "KW")));
+        assert_eq!(
+            optional_prefix(tag_no_case("on "), tag_no_case("13"))("13"),
+            Ok(("", "13"))
+        );
+        assert_eq!(
+            optional_suffix(tag_no_case("13"), tag_no_case("th"))("13th"),
+            Ok(("", "13"))
+        );
+    }
+}
+
+mod range {
+    use nom_date_parsers::range::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(fn(&str) -> IResult<&str, ()>: range_separator);
+
+        let range = DateRange {
+            start: NaiveDate::from_ymd_opt(2024, 7, 13).unwrap(),
+            end: NaiveDate::from_ymd_opt(2024, 7, 20).unwrap(),
+        };
+        assert!(range.contains(range.end));
+
+        let range = OpenDateRange::from(range);
+        assert_eq!(
+            range.end,
+            RangeBound::Date(NaiveDate::from_ymd_opt(2024, 7, 20).unwrap())
+        );
+        assert!(OpenDateRange {
+            start: RangeBound::Unbounded,
+            end: RangeBound::Unbounded
+        }
+        .contains(NaiveDate::MIN));
+    }
+}
+
+mod i18n {
+    use nom_date_parsers::i18n::{end_of_period, naive_date_for_weekday, Period};
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(fn(Weekday) -> Option<NaiveDate>: naive_date_for_weekday);
+        assert_signature!(fn(NaiveDate, Period) -> Option<NaiveDate>: end_of_period);
+    }
+}
+
+#[cfg(feature = "en")]
+mod en {
+    use nom_date_parsers::{
+        i18n::en::*,
+        range::{DateRange, OpenDateRange},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(WeekdayFn: short_named_weekday, short_named_weekday_dot, full_named_weekday, named_weekday);
+        assert_signature!(MonthFn: short_named_month, short_named_month_dot, full_named_month, named_month);
+        assert_signature!(DateFn: yesterday, today, tomorrow, current_named_weekday_only, ordinal_date, end_of, calendar_week, day_of_year, month_part, relative_period, nth_weekday_of_month, bundle_dmy, bundle_mdy);
+        assert_signature!(fn(&str) -> IResult<&str, u32>: ordinal_dd);
+        assert_signature!(fn(&str) -> IResult<&str, DateRange>: date_range_dmy, date_range_mdy, relative_period_range);
+        assert_signature!(fn(&str) -> IResult<&str, OpenDateRange>: open_date_range_dmy, open_date_range_mdy);
+    }
+
+    #[test]
+    fn test_with() {
+        let ctx = ParserContext::new();
+
+        assert_date_parser(yesterday_with(&ctx));
+        assert_date_parser(today_with(&ctx));
+        assert_date_parser(tomorrow_with(&ctx));
+        assert_date_parser(current_named_weekday_only_with(&ctx));
+        assert_date_parser(ordinal_date_with(&ctx));
+        assert_date_parser(end_of_with(&ctx));
+        assert_date_parser(calendar_week_with(&ctx));
+        assert_date_parser(day_of_year_with(&ctx));
+        assert_date_parser(month_part_with(&ctx));
+        assert_date_parser(relative_period_with(&ctx));
+        assert_date_parser(nth_weekday_of_month_with(&ctx));
+        assert_date_parser(bundle_dmy_with(&ctx));
+        assert_date_parser(bundle_mdy_with(&ctx));
+        let _ = date_range_dmy_with(&ctx);
+        let _ = date_range_mdy_with(&ctx);
+        let _ = open_date_range_dmy_with(&ctx);
+        let _ = open_date_range_mdy_with(&ctx);
+        let _ = relative_period_range_with(&ctx);
+    }
+}
+
+#[cfg(feature = "ru")]
+mod ru {
+    use nom_date_parsers::i18n::ru::*;
+
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_signature!(WeekdayFn: short_named_weekday, short_named_weekday_dot, full_named_weekday, named_weekday);
+        assert_signature!(MonthFn: short_named_month, short_named_month_dot, full_named_month, named_month);
+        assert_signature!(
+            DateFn: day_before_yesterday,
+            yesterday,
+            today,
+            tomorrow,
+            day_after_tomorrow,
+            calendar_week,
+            month_part,
+            relative_period,
+            nth_weekday_of_month,
+            dd_month_current_year,
+            current_named_weekday_only,
+            bundle,
+        );
+    }
+
+    #[test]
+    fn test_with() {
+        let ctx = ParserContext::new();
+
+        assert_date_parser(day_before_yesterday_with(&ctx));
+        assert_date_parser(yesterday_with(&ctx));
+        assert_date_parser(today_with(&ctx));
+        assert_date_parser(tomorrow_with(&ctx));
+        assert_date_parser(day_after_tomorrow_with(&ctx));
+        assert_date_parser(calendar_week_with(&ctx));
+        assert_date_parser(month_part_with(&ctx));
+        assert_date_parser(relative_period_with(&ctx));
+        assert_date_parser(nth_weekday_of_month_with(&ctx));
+        assert_date_parser(dd_month_current_year_with(&ctx));
+        assert_date_parser(current_named_weekday_only_with(&ctx));
+        assert_date_parser(bundle_with(&ctx));
+    }
+}